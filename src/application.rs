@@ -1,6 +1,6 @@
 mod app_data;
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::Result;
 use parking_lot::Mutex;
@@ -9,13 +9,16 @@ pub(crate) use crate::application::app_data::AppData;
 use crate::{
 	Args,
 	Exit,
-	config::{Config, ConfigLoader, DiffIgnoreWhitespaceSetting},
+	arguments::Mode,
+	commit_message::CommitMessage,
+	config::{Config, ConfigLoader, DiffIgnoreWhitespaceSetting, LineFormatField},
 	diff::{self, CommitDiffLoader, CommitDiffLoaderOptions},
 	display::Display,
 	git::open_repository_from_env,
 	help::build_help,
 	input::{Event, EventHandler, EventReaderFn, KeyBindings, StandardEvent},
 	module::{self, ExitStatus, ModuleHandler, State},
+	plugin,
 	process::{self, Process},
 	runtime::{Runtime, ThreadStatuses, Threadable},
 	search,
@@ -29,6 +32,7 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 	process: Process<ModuleProvider>,
 	threads: Option<Vec<Box<dyn Threadable>>>,
 	thread_statuses: ThreadStatuses,
+	todo_file: Arc<Mutex<TodoFile>>,
 }
 
 impl<ModuleProvider> Application<ModuleProvider>
@@ -43,19 +47,23 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 		let repository = Self::open_repository()?;
 		let config_loader = ConfigLoader::from(repository);
 		let config = Self::load_config(&config_loader)?;
-		let todo_file = Arc::new(Mutex::new(Self::load_todo_file(filepath.as_str(), &config)?));
+		// when editing a commit message, the todo file is empty, and is never written
+		let mut commit_message = None;
+		let todo_file = if args.mode() == &Mode::CommitMessage {
+			commit_message = Some(Self::load_commit_message(filepath.as_str(), &config)?);
+			TodoFile::new(filepath.as_str(), todo_file_options(&config))
+		}
+		else {
+			Self::load_todo_file(filepath.as_str(), args.import_json_path(), &config)?
+		};
+		let todo_file = Arc::new(Mutex::new(todo_file));
 
 		let display = Display::new(tui, &config.theme);
 		let initial_display_size = display.get_window_size();
 		let view = View::new(
 			display,
 			config.theme.character_vertical_spacing.as_str(),
-			config
-				.key_bindings
-				.help
-				.first()
-				.map_or_else(|| String::from("?"), String::from)
-				.as_str(),
+			config.key_bindings.help_indicator_key(),
 		);
 
 		let thread_statuses = ThreadStatuses::new();
@@ -74,24 +82,34 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 		let search_state = search_threads.state();
 		threads.push(Box::new(search_threads));
 
-		let commit_diff_loader_options = CommitDiffLoaderOptions::new()
-			.context_lines(config.git.diff_context)
-			.copies(config.git.diff_copies)
-			.ignore_whitespace(config.diff_ignore_whitespace == DiffIgnoreWhitespaceSetting::All)
-			.ignore_whitespace_change(config.diff_ignore_whitespace == DiffIgnoreWhitespaceSetting::Change)
-			.ignore_blank_lines(config.diff_ignore_blank_lines)
-			.interhunk_context(config.git.diff_interhunk_lines)
-			.renames(config.git.diff_renames, config.git.diff_rename_limit);
-		let commit_diff_loader = CommitDiffLoader::new(config_loader.eject_repository(), commit_diff_loader_options);
+		let repository = config_loader.eject_repository();
+		if let Some(message) = commit_message.as_mut() {
+			if let Ok(signature) = repository.signature() {
+				message.set_signature(signature.to_string().as_str());
+			}
+		}
+		let commit_authors = load_commit_authors_for_config(&config, &repository, &todo_file.lock());
+		let commit_diff_loader = CommitDiffLoader::new(repository, commit_diff_loader_options(&config));
 
 		let diff_update_handler = Self::create_diff_update_handler(input_state.clone());
 		let diff_thread = diff::thread::Thread::new(commit_diff_loader, diff_update_handler);
 		let diff_state = diff_thread.state();
 		threads.push(Box::new(diff_thread));
 
+		let plugin_update_handler = Self::create_plugin_update_handler(input_state.clone());
+		let plugins = if commit_message.is_some() {
+			vec![]
+		}
+		else {
+			config.plugins.clone()
+		};
+		let plugin_thread = plugin::Thread::new(plugins, plugin_update_handler);
+		let plugin_state = plugin_thread.state();
+		threads.push(Box::new(plugin_thread));
+
 		let keybindings = KeyBindings::new(&config.key_bindings);
 
-		let app_data = AppData::new(
+		let mut app_data = AppData::new(
 			config,
 			State::WindowSizeError,
 			Arc::clone(&todo_file),
@@ -99,7 +117,12 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 			view_state.clone(),
 			input_state.clone(),
 			search_state.clone(),
-		);
+		)
+		.with_commit_authors(commit_authors)
+		.with_plugin_state(plugin_state);
+		if let Some(message) = commit_message {
+			app_data = app_data.with_commit_message(message);
+		}
 
 		let module_handler = ModuleHandler::new(EventHandler::new(keybindings), ModuleProvider::new(&app_data));
 
@@ -111,6 +134,7 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 			process,
 			threads: Some(threads),
 			thread_statuses,
+			todo_file,
 		})
 	}
 
@@ -144,6 +168,10 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 		Ok(())
 	}
 
+	pub(crate) fn todo_file(&self) -> Arc<Mutex<TodoFile>> {
+		Arc::clone(&self.todo_file)
+	}
+
 	fn filepath_from_args(args: &Args) -> Result<String, Exit> {
 		args.todo_file_path().map(String::from).ok_or_else(|| {
 			Exit::new(
@@ -166,19 +194,15 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 		Config::try_from(config_loader).map_err(|err| Exit::new(ExitStatus::ConfigError, format!("{err:#}").as_str()))
 	}
 
-	fn todo_file_options(config: &Config) -> TodoFileOptions {
-		let mut todo_file_options = TodoFileOptions::new(config.undo_limit, config.git.comment_char.as_str());
-		if let Some(command) = config.post_modified_line_exec_command.as_deref() {
-			todo_file_options.line_changed_command(command);
+	fn load_todo_file(filepath: &str, import_json_path: Option<&str>, config: &Config) -> Result<TodoFile, Exit> {
+		let mut todo_file = TodoFile::new(filepath, todo_file_options(config));
+		if let Some(import_json_path) = import_json_path {
+			todo_file.load_json_file(import_json_path)
 		}
-		todo_file_options
-	}
-
-	fn load_todo_file(filepath: &str, config: &Config) -> Result<TodoFile, Exit> {
-		let mut todo_file = TodoFile::new(filepath, Self::todo_file_options(config));
-		todo_file
-			.load_file()
-			.map_err(|err| Exit::new(ExitStatus::FileReadError, err.to_string().as_str()))?;
+		else {
+			todo_file.load_file()
+		}
+		.map_err(|err| Exit::new(ExitStatus::FileReadError, err.to_string().as_str()))?;
 
 		if todo_file.is_noop() {
 			return Err(Exit::new(
@@ -197,6 +221,14 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 		Ok(todo_file)
 	}
 
+	fn load_commit_message(filepath: &str, config: &Config) -> Result<CommitMessage, Exit> {
+		let mut commit_message = CommitMessage::new(filepath, config.git.comment_char.as_str());
+		commit_message
+			.load_file()
+			.map_err(|err| Exit::new(ExitStatus::FileReadError, err.to_string().as_str()))?;
+		Ok(commit_message)
+	}
+
 	fn create_search_update_handler(input_state: crate::input::State) -> impl Fn() + Send + Sync {
 		move || input_state.push_event(Event::Standard(StandardEvent::SearchUpdate))
 	}
@@ -204,6 +236,66 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 	fn create_diff_update_handler(input_state: crate::input::State) -> impl Fn() + Send + Sync {
 		move || input_state.push_event(Event::Standard(StandardEvent::DiffUpdate))
 	}
+
+	fn create_plugin_update_handler(input_state: crate::input::State) -> impl Fn() + Send + Sync {
+		move || input_state.push_event(Event::Standard(StandardEvent::PluginUpdate))
+	}
+}
+
+/// Build the options of the todo file from the configuration.
+pub(crate) fn todo_file_options(config: &Config) -> TodoFileOptions {
+	let mut todo_file_options = TodoFileOptions::new(config.undo_limit, config.git.comment_char.as_str());
+	if let Some(command) = config.post_modified_line_exec_command.as_deref() {
+		todo_file_options.line_changed_command(command);
+	}
+	todo_file_options
+}
+
+/// Build the options used to load the diff of a commit from the configuration.
+pub(crate) fn commit_diff_loader_options(config: &Config) -> CommitDiffLoaderOptions {
+	CommitDiffLoaderOptions::new()
+		.context_lines(config.git.diff_context)
+		.copies(config.git.diff_copies)
+		.exclude_paths(config.diff_exclude_paths.clone())
+		.ignore_whitespace(config.diff_ignore_whitespace == DiffIgnoreWhitespaceSetting::All)
+		.ignore_whitespace_change(config.diff_ignore_whitespace == DiffIgnoreWhitespaceSetting::Change)
+		.ignore_blank_lines(config.diff_ignore_blank_lines)
+		.interhunk_context(config.git.diff_interhunk_lines)
+		.renames(config.git.diff_renames, config.git.diff_rename_limit)
+}
+
+/// Load the author names of the commits in the todo file, when the line format of the list shows the author.
+pub(crate) fn load_commit_authors_for_config(
+	config: &Config,
+	repository: &git2::Repository,
+	todo_file: &TodoFile,
+) -> HashMap<String, String> {
+	if config
+		.list_line_format
+		.as_ref()
+		.is_some_and(|line_format| line_format.has_field(LineFormatField::Author))
+	{
+		load_commit_authors(repository, todo_file)
+	}
+	else {
+		HashMap::new()
+	}
+}
+
+fn load_commit_authors(repository: &git2::Repository, todo_file: &TodoFile) -> HashMap<String, String> {
+	todo_file
+		.lines_iter()
+		.filter(|line| line.has_reference())
+		.filter_map(|line| {
+			let commit = repository
+				.revparse_single(line.get_hash())
+				.ok()?
+				.peel_to_commit()
+				.ok()?;
+			let author = String::from_utf8_lossy(commit.author().name_bytes()).into_owned();
+			Some((String::from(line.get_hash()), author))
+		})
+		.collect()
 }
 
 #[cfg(all(unix, test))]
@@ -219,13 +311,18 @@ mod tests {
 		module::Modules,
 		runtime::{Installer, RuntimeError},
 		test_helpers::{
+			CreateCommitOptions,
 			DefaultTestModule,
 			TestModuleProvider,
+			create_commit,
 			create_config,
 			create_event_reader,
 			mocks,
 			with_git_directory,
+			with_temp_repository,
+			with_todo_file,
 		},
+		todo_file::Line,
 	};
 
 	fn args(args: &[&str]) -> Args {
@@ -295,10 +392,7 @@ mod tests {
 		config.post_modified_line_exec_command = None;
 
 		let expected = TodoFileOptions::new(10, "#");
-		assert_eq!(
-			Application::<TestModuleProvider<DefaultTestModule>>::todo_file_options(&config),
-			expected
-		);
+		assert_eq!(todo_file_options(&config), expected);
 	}
 
 	#[test]
@@ -311,10 +405,7 @@ mod tests {
 		let mut expected = TodoFileOptions::new(10, "#");
 		expected.line_changed_command("command");
 
-		assert_eq!(
-			Application::<TestModuleProvider<DefaultTestModule>>::todo_file_options(&config),
-			expected
-		);
+		assert_eq!(todo_file_options(&config), expected);
 	}
 
 	#[test]
@@ -363,6 +454,84 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn load_todo_file_import_json() {
+		with_git_directory("fixtures/simple", |_| {
+			let directory = tempfile::tempdir().unwrap();
+			let plan = directory.path().join("plan.json");
+			std::fs::write(
+				plan.as_path(),
+				r#"{"lines": [{"action": "fixup", "hash": "aaa", "content": "c1", "option": "-C"}]}"#,
+			)
+			.unwrap();
+			let todo_path = directory.path().join("git-rebase-todo");
+			let event_provider = create_event_reader(|| Ok(None));
+			let application: Application<Modules> = Application::new(
+				&args(&["--import-json", plan.to_str().unwrap(), todo_path.to_str().unwrap()]),
+				event_provider,
+				create_mocked_crossterm(),
+			)
+			.unwrap();
+			let lines = application
+				.todo_file()
+				.lock()
+				.lines_iter()
+				.map(Line::to_text)
+				.collect::<Vec<String>>();
+			assert_eq!(lines, vec!["fixup -C aaa c1"]);
+		});
+	}
+
+	#[test]
+	fn load_commit_message() {
+		with_git_directory("fixtures/simple", |_| {
+			let directory = tempfile::tempdir().unwrap();
+			let message_path = directory.path().join("COMMIT_EDITMSG");
+			std::fs::write(message_path.as_path(), "Subject\n# comment\n").unwrap();
+			let event_provider = create_event_reader(|| Ok(None));
+			let application: Application<Modules> = Application::new(
+				&args(&[message_path.to_str().unwrap()]),
+				event_provider,
+				create_mocked_crossterm(),
+			)
+			.unwrap();
+			assert_eq!(application.process.initial_module(), State::CommitMessageEditor);
+			assert!(application.todo_file().lock().is_empty());
+		});
+	}
+
+	#[test]
+	fn load_commit_message_error() {
+		with_git_directory("fixtures/simple", |_| {
+			let event_provider = create_event_reader(|| Ok(None));
+			let application: Result<Application<TestModuleProvider<DefaultTestModule>>, Exit> = Application::new(
+				&args(&["--commit-message", "does-not-exist"]),
+				event_provider,
+				create_mocked_crossterm(),
+			);
+			let exit = application_error!(application);
+			assert_eq!(exit.get_status(), &ExitStatus::FileReadError);
+		});
+	}
+
+	#[test]
+	fn load_todo_file_import_json_error() {
+		with_git_directory("fixtures/simple", |git_dir| {
+			let directory = tempfile::tempdir().unwrap();
+			let plan = directory.path().join("plan.json");
+			std::fs::write(plan.as_path(), r#"{"lines": [{"action": "invalid"}]}"#).unwrap();
+			let rebase_todo = format!("{git_dir}/rebase-todo");
+			let event_provider = create_event_reader(|| Ok(None));
+			let application: Result<Application<TestModuleProvider<DefaultTestModule>>, Exit> = Application::new(
+				&args(&["--import-json", plan.to_str().unwrap(), rebase_todo.as_str()]),
+				event_provider,
+				create_mocked_crossterm(),
+			);
+			let exit = application_error!(application);
+			assert_eq!(exit.get_status(), &ExitStatus::FileReadError);
+		});
+	}
+
 	#[test]
 	#[serial_test::serial]
 	fn search_update_handler_handles_update() {
@@ -376,6 +545,19 @@ mod tests {
 		assert_eq!(input_state.read_event(), Event::Standard(StandardEvent::SearchUpdate));
 	}
 
+	#[test]
+	#[serial_test::serial]
+	fn plugin_update_handler_handles_update() {
+		let event_provider = create_event_reader(|| Ok(None));
+		let input_threads = crate::input::Thread::new(event_provider);
+		let input_state = input_threads.state();
+		let update_handler =
+			Application::<TestModuleProvider<DefaultTestModule>>::create_plugin_update_handler(input_state.clone());
+		update_handler();
+
+		assert_eq!(input_state.read_event(), Event::Standard(StandardEvent::PluginUpdate));
+	}
+
 	#[test]
 	fn run_until_finished_success() {
 		with_git_directory("fixtures/simple", |git_dir| {
@@ -463,4 +645,22 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn load_commit_authors() {
+		with_temp_repository(|repository| {
+			let commit = create_commit(&repository, Some(CreateCommitOptions::new().author("Tim Smith")));
+			let pick = format!("pick {} comment", commit.hash());
+			with_todo_file(
+				&[pick.as_str(), "pick aaaaaaaa missing", "break"],
+				|todo_file_context| {
+					let authors = super::load_commit_authors(&repository, todo_file_context.todo_file());
+					assert_eq!(
+						authors,
+						HashMap::from([(String::from(commit.hash()), String::from("Tim Smith"))])
+					);
+				},
+			);
+		});
+	}
 }
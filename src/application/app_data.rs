@@ -1,18 +1,31 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use parking_lot::Mutex;
 
-use crate::{config::Config, diff, input, module, search, todo_file::TodoFile, view};
+use crate::{
+	commit_message::CommitMessage,
+	config::Config,
+	diff,
+	input,
+	module,
+	plugin,
+	search,
+	todo_file::TodoFile,
+	view,
+};
 
 #[derive(Clone, Debug)]
 pub(crate) struct AppData {
-	config: Arc<Config>,
+	config: Arc<Mutex<Arc<Config>>>,
+	commit_authors: Arc<Mutex<Arc<HashMap<String, String>>>>,
 	active_module: Arc<Mutex<module::State>>,
 	todo_file: Arc<Mutex<TodoFile>>,
 	diff_state: diff::thread::State,
 	view_state: view::State,
 	input_state: input::State,
 	search_state: search::State,
+	plugin_state: plugin::State,
+	commit_message: Option<Arc<Mutex<CommitMessage>>>,
 }
 
 impl AppData {
@@ -26,18 +39,56 @@ impl AppData {
 		search_state: search::State,
 	) -> Self {
 		Self {
-			config: Arc::new(config),
+			config: Arc::new(Mutex::new(Arc::new(config))),
+			commit_authors: Arc::new(Mutex::new(Arc::new(HashMap::new()))),
 			active_module: Arc::new(Mutex::new(active_module)),
 			todo_file,
 			diff_state,
 			view_state,
 			input_state,
 			search_state,
+			plugin_state: plugin::State::new(vec![]),
+			commit_message: None,
+		}
+	}
+
+	/// Replace the configuration, such as after the configuration has been reloaded, for this instance and all of
+	/// its copies.
+	pub(crate) fn set_config(&self, config: Config) {
+		*self.config.lock() = Arc::new(config);
+	}
+
+	/// Create a copy of this instance with the author names of commits, keyed by the hash used in the todo file.
+	pub(crate) fn with_commit_authors(self, commit_authors: HashMap<String, String>) -> Self {
+		self.set_commit_authors(commit_authors);
+		self
+	}
+
+	/// Replace the author names of commits, such as after the configuration has been reloaded, for this instance and
+	/// all of its copies.
+	pub(crate) fn set_commit_authors(&self, commit_authors: HashMap<String, String>) {
+		*self.commit_authors.lock() = Arc::new(commit_authors);
+	}
+
+	/// Create a copy of this instance with the state of the plugins.
+	pub(crate) fn with_plugin_state(self, plugin_state: plugin::State) -> Self {
+		Self { plugin_state, ..self }
+	}
+
+	/// Create a copy of this instance that edits a commit message, in place of the todo file.
+	pub(crate) fn with_commit_message(self, commit_message: CommitMessage) -> Self {
+		Self {
+			commit_message: Some(Arc::new(Mutex::new(commit_message))),
+			..self
 		}
 	}
 
 	pub(crate) fn config(&self) -> Arc<Config> {
-		Arc::clone(&self.config)
+		Arc::clone(&self.config.lock())
+	}
+
+	pub(crate) fn commit_authors(&self) -> Arc<HashMap<String, String>> {
+		Arc::clone(&self.commit_authors.lock())
 	}
 
 	pub(crate) fn active_module(&self) -> Arc<Mutex<module::State>> {
@@ -63,4 +114,22 @@ impl AppData {
 	pub(crate) fn search_state(&self) -> search::State {
 		self.search_state.clone()
 	}
+
+	pub(crate) fn plugin_state(&self) -> plugin::State {
+		self.plugin_state.clone()
+	}
+
+	pub(crate) fn commit_message(&self) -> Option<Arc<Mutex<CommitMessage>>> {
+		self.commit_message.as_ref().map(Arc::clone)
+	}
+
+	/// The module that is first shown, the commit message editor when editing a commit message.
+	pub(crate) const fn initial_module(&self) -> module::State {
+		if self.commit_message.is_some() {
+			module::State::CommitMessageEditor
+		}
+		else {
+			module::State::List
+		}
+	}
 }
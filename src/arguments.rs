@@ -2,7 +2,7 @@ use std::ffi::OsString;
 
 use pico_args::Arguments;
 
-use crate::{exit::Exit, module::ExitStatus};
+use crate::{commit_message::is_commit_message_path, exit::Exit, module::ExitStatus};
 
 #[derive(Debug, Eq, PartialEq)]
 pub(crate) enum Mode {
@@ -10,12 +10,24 @@ pub(crate) enum Mode {
 	Help,
 	Version,
 	License,
+	PrintKeyBindings,
+	Script,
+	Check,
+	Rebase,
+	CommitMessage,
 }
 
 #[derive(Debug)]
 pub(crate) struct Args {
 	mode: Mode,
+	script_path: Option<String>,
+	import_json_path: Option<String>,
+	export_json_path: Option<String>,
+	summary_json_path: Option<String>,
 	todo_file_path: Option<String>,
+	upstream: Option<String>,
+	rebase_merges: bool,
+	autosquash: bool,
 }
 
 impl Args {
@@ -23,18 +35,64 @@ impl Args {
 		&self.mode
 	}
 
+	pub(crate) fn script_path(&self) -> Option<&str> {
+		self.script_path.as_deref()
+	}
+
+	pub(crate) fn import_json_path(&self) -> Option<&str> {
+		self.import_json_path.as_deref()
+	}
+
+	pub(crate) fn export_json_path(&self) -> Option<&str> {
+		self.export_json_path.as_deref()
+	}
+
+	pub(crate) fn summary_json_path(&self) -> Option<&str> {
+		self.summary_json_path.as_deref()
+	}
+
 	pub(crate) fn todo_file_path(&self) -> Option<&str> {
 		self.todo_file_path.as_deref()
 	}
+
+	pub(crate) fn upstream(&self) -> Option<&str> {
+		self.upstream.as_deref()
+	}
+
+	pub(crate) const fn rebase_merges(&self) -> bool {
+		self.rebase_merges
+	}
+
+	pub(crate) const fn autosquash(&self) -> bool {
+		self.autosquash
+	}
 }
 
 impl TryFrom<Vec<OsString>> for Args {
 	type Error = Exit;
 
 	fn try_from(args: Vec<OsString>) -> Result<Self, Self::Error> {
+		// the rebase subcommand is only recognized as the first argument, so a todo file named `rebase` still works
+		let rebase_command = args.first().is_some_and(|arg| arg == "rebase");
 		let mut pargs = Arguments::from_vec(args);
 
-		let mode = if pargs.contains(["-h", "--help"]) {
+		let script_path = pargs
+			.opt_value_from_str("--script")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		let import_json_path = pargs
+			.opt_value_from_str("--import-json")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		let export_json_path = pargs
+			.opt_value_from_str("--export-json")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		let summary_json_path = pargs
+			.opt_value_from_str("--summary-json")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		let rebase_merges = pargs.contains("--rebase-merges");
+		let autosquash = pargs.contains("--autosquash");
+		let commit_message = pargs.contains("--commit-message");
+
+		let mut mode = if pargs.contains(["-h", "--help"]) {
 			Mode::Help
 		}
 		else if pargs.contains(["-v", "--version"]) {
@@ -43,15 +101,56 @@ impl TryFrom<Vec<OsString>> for Args {
 		else if pargs.contains("--license") {
 			Mode::License
 		}
+		else if pargs.contains("--print-keybindings") {
+			Mode::PrintKeyBindings
+		}
+		else if pargs.contains("--check") {
+			Mode::Check
+		}
+		else if script_path.is_some() {
+			Mode::Script
+		}
 		else {
 			Mode::Editor
 		};
 
-		let todo_file_path = pargs
+		let mut todo_file_path: Option<String> = pargs
 			.opt_free_from_str()
 			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
 
-		Ok(Self { mode, todo_file_path })
+		// the rebase subcommand takes the place of the todo file path
+		let mut upstream = None;
+		if mode == Mode::Editor && rebase_command && todo_file_path.as_deref() == Some("rebase") {
+			mode = Mode::Rebase;
+			todo_file_path = None;
+			upstream = pargs
+				.opt_free_from_str()
+				.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		}
+		else if mode == Mode::Editor
+			&& (commit_message || todo_file_path.as_deref().is_some_and(is_commit_message_path))
+		{
+			mode = Mode::CommitMessage;
+		}
+
+		if mode != Mode::Rebase && (rebase_merges || autosquash) {
+			return Err(Exit::new(
+				ExitStatus::StateError,
+				"The --rebase-merges and --autosquash options are only valid with the rebase command",
+			));
+		}
+
+		Ok(Self {
+			mode,
+			script_path,
+			import_json_path,
+			export_json_path,
+			summary_json_path,
+			todo_file_path,
+			upstream,
+			rebase_merges,
+			autosquash,
+		})
 	}
 }
 
@@ -86,6 +185,122 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn mode_print_key_bindings() {
+		assert_eq!(
+			Args::try_from(create_args(&["--print-keybindings"])).unwrap().mode(),
+			&Mode::PrintKeyBindings
+		);
+	}
+
+	#[test]
+	fn mode_script() {
+		let args = Args::try_from(create_args(&["--script", "script.txt", "todofile"])).unwrap();
+		assert_eq!(args.mode(), &Mode::Script);
+		assert_eq!(args.script_path(), Some("script.txt"));
+		assert_eq!(args.todo_file_path(), Some("todofile"));
+	}
+
+	#[test]
+	fn mode_script_missing_value() {
+		_ = Args::try_from(create_args(&["--script"])).unwrap_err();
+	}
+
+	#[test]
+	fn summary_json() {
+		let args = Args::try_from(create_args(&["--summary-json", "summary.json", "todofile"])).unwrap();
+		assert_eq!(args.mode(), &Mode::Editor);
+		assert_eq!(args.summary_json_path(), Some("summary.json"));
+		assert_eq!(args.todo_file_path(), Some("todofile"));
+	}
+
+	#[test]
+	fn summary_json_missing_value() {
+		_ = Args::try_from(create_args(&["--summary-json"])).unwrap_err();
+	}
+
+	#[test]
+	fn import_and_export_json() {
+		let args = Args::try_from(create_args(&[
+			"--import-json",
+			"plan.json",
+			"--export-json",
+			"result.json",
+			"todofile",
+		]))
+		.unwrap();
+		assert_eq!(args.mode(), &Mode::Editor);
+		assert_eq!(args.import_json_path(), Some("plan.json"));
+		assert_eq!(args.export_json_path(), Some("result.json"));
+		assert_eq!(args.todo_file_path(), Some("todofile"));
+	}
+
+	#[test]
+	fn mode_check() {
+		let args = Args::try_from(create_args(&["--check", "todofile"])).unwrap();
+		assert_eq!(args.mode(), &Mode::Check);
+		assert_eq!(args.todo_file_path(), Some("todofile"));
+	}
+
+	#[test]
+	fn mode_rebase() {
+		let args = Args::try_from(create_args(&["rebase", "main"])).unwrap();
+		assert_eq!(args.mode(), &Mode::Rebase);
+		assert_eq!(args.upstream(), Some("main"));
+		assert!(args.todo_file_path().is_none());
+		assert!(!args.rebase_merges());
+		assert!(!args.autosquash());
+	}
+
+	#[test]
+	fn mode_rebase_with_options() {
+		let args = Args::try_from(create_args(&["rebase", "--rebase-merges", "main", "--autosquash"])).unwrap();
+		assert_eq!(args.mode(), &Mode::Rebase);
+		assert_eq!(args.upstream(), Some("main"));
+		assert!(args.rebase_merges());
+		assert!(args.autosquash());
+	}
+
+	#[test]
+	fn mode_rebase_missing_upstream() {
+		let args = Args::try_from(create_args(&["rebase"])).unwrap();
+		assert_eq!(args.mode(), &Mode::Rebase);
+		assert!(args.upstream().is_none());
+	}
+
+	#[test]
+	fn mode_rebase_only_first_argument() {
+		let args = Args::try_from(create_args(&["--summary-json", "summary.json", "rebase"])).unwrap();
+		assert_eq!(args.mode(), &Mode::Editor);
+		assert_eq!(args.todo_file_path(), Some("rebase"));
+		assert!(args.upstream().is_none());
+	}
+
+	#[test]
+	fn rebase_options_without_rebase_command() {
+		let exit = Args::try_from(create_args(&["--autosquash", "todofile"])).unwrap_err();
+		assert_eq!(exit.get_status(), &ExitStatus::StateError);
+		assert_eq!(
+			exit.get_message(),
+			Some("The --rebase-merges and --autosquash options are only valid with the rebase command")
+		);
+		_ = Args::try_from(create_args(&["--rebase-merges", "todofile"])).unwrap_err();
+	}
+
+	#[test]
+	fn mode_commit_message() {
+		let args = Args::try_from(create_args(&["--commit-message", "message.txt"])).unwrap();
+		assert_eq!(args.mode(), &Mode::CommitMessage);
+		assert_eq!(args.todo_file_path(), Some("message.txt"));
+	}
+
+	#[test]
+	fn mode_commit_message_from_path() {
+		let args = Args::try_from(create_args(&[".git/COMMIT_EDITMSG"])).unwrap();
+		assert_eq!(args.mode(), &Mode::CommitMessage);
+		assert_eq!(args.todo_file_path(), Some(".git/COMMIT_EDITMSG"));
+	}
+
 	#[test]
 	fn todo_file_ok() {
 		let args = Args::try_from(create_args(&["todofile"])).unwrap();
@@ -0,0 +1,441 @@
+use std::{collections::HashMap, fs};
+
+use git2::Repository;
+
+use crate::{
+	arguments::Args,
+	config::{Config, ConfigLoader},
+	exit::Exit,
+	git::open_repository_from_env,
+	help::build_help,
+	module::ExitStatus,
+	todo_file::{Action, Line},
+};
+
+/// A problem found in a todo file.
+#[derive(Debug, PartialEq, Eq)]
+struct Diagnostic {
+	line_number: usize,
+	message: String,
+}
+
+impl Diagnostic {
+	fn new(line_number: usize, message: String) -> Self {
+		Self { line_number, message }
+	}
+}
+
+/// Tracks the state of the todo file while checking the lines in order.
+struct Checker<'repository> {
+	repository: &'repository Repository,
+	labels: Vec<String>,
+	update_refs: HashMap<String, usize>,
+	has_commit: bool,
+	diagnostics: Vec<Diagnostic>,
+}
+
+impl<'repository> Checker<'repository> {
+	fn new(repository: &'repository Repository) -> Self {
+		Self {
+			repository,
+			labels: vec![],
+			update_refs: HashMap::new(),
+			has_commit: false,
+			diagnostics: vec![],
+		}
+	}
+
+	fn is_commit(&self, revision: &str) -> bool {
+		self.repository
+			.revparse_single(revision)
+			.and_then(|object| object.peel_to_commit())
+			.is_ok()
+	}
+
+	fn check_commit(&mut self, line_number: usize, hash: &str) {
+		if !self.is_commit(hash) {
+			self.diagnostics
+				.push(Diagnostic::new(line_number, format!("Unknown commit '{hash}'")));
+		}
+	}
+
+	/// A label reference is valid if the label is defined on an earlier line, or if it is a commit.
+	fn check_label(&mut self, line_number: usize, label: &str) {
+		if !self.labels.iter().any(|defined| defined == label) && !self.is_commit(label) {
+			self.diagnostics
+				.push(Diagnostic::new(line_number, format!("Undefined label '{label}'")));
+		}
+	}
+
+	fn check_merge(&mut self, line_number: usize, content: &str) {
+		let mut arguments = content
+			.split_whitespace()
+			.take_while(|argument| !argument.starts_with('#'));
+		let mut labels = vec![];
+		while let Some(argument) = arguments.next() {
+			if argument == "-C" || argument == "-c" {
+				if let Some(hash) = arguments.next() {
+					self.check_commit(line_number, hash);
+				}
+			}
+			else {
+				labels.push(argument);
+			}
+		}
+		if labels.is_empty() {
+			self.diagnostics.push(Diagnostic::new(
+				line_number,
+				String::from("The merge is missing a label"),
+			));
+		}
+		for label in labels {
+			self.check_label(line_number, label);
+		}
+	}
+
+	fn check_line(&mut self, line_number: usize, line: &Line) {
+		let content = line.get_content().trim();
+		match *line.get_action() {
+			Action::Squash | Action::Fixup => {
+				if !self.has_commit {
+					self.diagnostics.push(Diagnostic::new(
+						line_number,
+						format!("Cannot {} without a previous commit", line.get_action()),
+					));
+				}
+				self.check_commit(line_number, line.get_hash());
+			},
+			Action::Revert => {
+				self.diagnostics.push(Diagnostic::new(
+					line_number,
+					String::from("The revert command can not be used in a rebase"),
+				));
+				self.check_commit(line_number, line.get_hash());
+			},
+			Action::Pick | Action::Reword | Action::Edit | Action::Drop => {
+				self.check_commit(line_number, line.get_hash());
+			},
+			Action::Merge => self.check_merge(line_number, content),
+			Action::Label => {
+				if let Some(label) = content.split_whitespace().next() {
+					self.labels.push(String::from(label));
+				}
+			},
+			Action::Reset => {
+				if !content.starts_with("[new root]") {
+					if let Some(label) = content.split_whitespace().next() {
+						self.check_label(line_number, label);
+					}
+				}
+			},
+			Action::UpdateRef => {
+				if let Some(&first_line_number) = self.update_refs.get(content) {
+					self.diagnostics.push(Diagnostic::new(
+						line_number,
+						format!("The reference '{content}' is already updated on line {first_line_number}"),
+					));
+				}
+				else {
+					_ = self.update_refs.insert(String::from(content), line_number);
+				}
+			},
+			Action::Break | Action::Exec | Action::Noop => {},
+		}
+		// like git, any line that is not dropped gives a following squash or fixup a commit to change
+		if !matches!(
+			*line.get_action(),
+			Action::Drop | Action::Noop | Action::Squash | Action::Fixup
+		) {
+			self.has_commit = true;
+		}
+	}
+}
+
+/// Check the content of a todo file, returning the problems found, in line order.
+fn check_todo_file(content: &str, comment_prefix: &str, repository: &Repository) -> Vec<Diagnostic> {
+	let mut checker = Checker::new(repository);
+	for (index, input_line) in content.lines().enumerate() {
+		if input_line.is_empty() || input_line.starts_with(comment_prefix) {
+			continue;
+		}
+		match Line::parse(input_line) {
+			Ok(line) => checker.check_line(index + 1, &line),
+			Err(err) => checker.diagnostics.push(Diagnostic::new(index + 1, err.to_string())),
+		}
+	}
+	checker.diagnostics
+}
+
+fn run_check(todo_file_path: &str) -> Result<Vec<Diagnostic>, Exit> {
+	let repository = open_repository_from_env().map_err(|err| {
+		Exit::new(
+			ExitStatus::StateError,
+			format!("Unable to load Git repository: {err}").as_str(),
+		)
+	})?;
+	let config_loader = ConfigLoader::from(repository);
+	let config = Config::try_from(&config_loader)
+		.map_err(|err| Exit::new(ExitStatus::ConfigError, format!("{err:#}").as_str()))?;
+	let repository = config_loader.eject_repository();
+
+	let content = fs::read_to_string(todo_file_path).map_err(|err| {
+		Exit::new(
+			ExitStatus::FileReadError,
+			format!("Unable to read todo file '{todo_file_path}': {err}").as_str(),
+		)
+	})?;
+
+	Ok(check_todo_file(
+		content.as_str(),
+		config.git.comment_char.as_str(),
+		&repository,
+	))
+}
+
+pub(crate) fn run(args: &Args) -> Exit {
+	let Some(todo_file_path) = args.todo_file_path()
+	else {
+		return Exit::new(
+			ExitStatus::StateError,
+			build_help(Some(String::from("A todo file path must be provided."))).as_str(),
+		);
+	};
+	match run_check(todo_file_path) {
+		Ok(diagnostics) if diagnostics.is_empty() => Exit::from(ExitStatus::Good),
+		Ok(diagnostics) => {
+			Exit::new(
+				ExitStatus::CheckFailed,
+				diagnostics
+					.iter()
+					.map(|diagnostic| format!("{todo_file_path}:{}: {}", diagnostic.line_number, diagnostic.message))
+					.collect::<Vec<String>>()
+					.join("\n")
+					.as_str(),
+			)
+		},
+		Err(exit) => exit,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::ffi::OsString;
+
+	use rstest::rstest;
+	use tempfile::tempdir;
+
+	use super::*;
+	use crate::test_helpers::{create_commit, with_git_directory, with_temp_repository};
+
+	fn check(lines: &[&str], repository: &Repository) -> Vec<(usize, String)> {
+		check_todo_file(lines.join("\n").as_str(), "#", repository)
+			.into_iter()
+			.map(|diagnostic| (diagnostic.line_number, diagnostic.message))
+			.collect()
+	}
+
+	fn args(args: &[&str]) -> Args {
+		Args::try_from(args.iter().map(OsString::from).collect::<Vec<OsString>>()).unwrap()
+	}
+
+	#[test]
+	fn valid() {
+		with_temp_repository(|repository| {
+			let commit = create_commit(&repository, None);
+			let pick = format!("pick {} comment", commit.hash());
+			let fixup = format!("fixup -C {} comment", commit.hash());
+			let merge = format!("merge -C {} onto # Merge", commit.hash());
+			assert_eq!(
+				check(
+					&[
+						"# comment",
+						"",
+						"label onto",
+						pick.as_str(),
+						fixup.as_str(),
+						"update-ref refs/heads/feature",
+						"reset onto",
+						merge.as_str(),
+						"reset [new root]",
+						"exec make",
+						"break",
+					],
+					&repository
+				),
+				vec![]
+			);
+		});
+	}
+
+	#[test]
+	fn invalid_line() {
+		with_temp_repository(|repository| {
+			assert_eq!(check(&["# comment", "invalid aaaa"], &repository), vec![(
+				2,
+				String::from("The action `invalid` is not valid")
+			)]);
+		});
+	}
+
+	#[test]
+	fn unknown_commit() {
+		with_temp_repository(|repository| {
+			assert_eq!(check(&["pick aaaaaaaa c1", "drop bbbbbbbb c2"], &repository), vec![
+				(1, String::from("Unknown commit 'aaaaaaaa'")),
+				(2, String::from("Unknown commit 'bbbbbbbb'")),
+			]);
+		});
+	}
+
+	#[test]
+	fn undefined_label() {
+		with_temp_repository(|repository| {
+			assert_eq!(
+				check(&["reset onto", "label onto", "merge onto branch # Merge"], &repository),
+				vec![
+					(1, String::from("Undefined label 'onto'")),
+					(3, String::from("Undefined label 'branch'")),
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn merge_missing_label() {
+		with_temp_repository(|repository| {
+			assert_eq!(check(&["merge # Merge"], &repository), vec![(
+				1,
+				String::from("The merge is missing a label")
+			)]);
+		});
+	}
+
+	#[test]
+	fn leading_squash() {
+		with_temp_repository(|repository| {
+			let commit = create_commit(&repository, None);
+			let squash = format!("squash {} comment", commit.hash());
+			let fixup = format!("fixup {} comment", commit.hash());
+			let drop = format!("drop {} comment", commit.hash());
+			assert_eq!(
+				check(&[drop.as_str(), squash.as_str(), fixup.as_str()], &repository),
+				vec![
+					(2, String::from("Cannot squash without a previous commit")),
+					(3, String::from("Cannot fixup without a previous commit")),
+				]
+			);
+		});
+	}
+
+	#[rstest]
+	#[case::exec("exec make")]
+	#[case::label("label onto")]
+	#[case::reset("reset [new root]")]
+	#[case::break_action("break")]
+	fn squash_after_non_commit_line(#[case] previous: &str) {
+		with_temp_repository(|repository| {
+			let commit = create_commit(&repository, None);
+			let squash = format!("squash {} comment", commit.hash());
+			let fixup = format!("fixup {} comment", commit.hash());
+			assert_eq!(check(&[previous, squash.as_str(), fixup.as_str()], &repository), vec![]);
+		});
+	}
+
+	#[test]
+	fn revert_in_rebase() {
+		with_temp_repository(|repository| {
+			let commit = create_commit(&repository, None);
+			let revert = format!("revert {} comment", commit.hash());
+			assert_eq!(check(&[revert.as_str()], &repository), vec![(
+				1,
+				String::from("The revert command can not be used in a rebase")
+			)]);
+		});
+	}
+
+	#[test]
+	fn duplicate_update_ref() {
+		with_temp_repository(|repository| {
+			assert_eq!(
+				check(
+					&[
+						"update-ref refs/heads/a",
+						"update-ref refs/heads/b",
+						"update-ref refs/heads/a"
+					],
+					&repository
+				),
+				vec![(
+					3,
+					String::from("The reference 'refs/heads/a' is already updated on line 1")
+				)]
+			);
+		});
+	}
+
+	#[test]
+	fn run_valid() {
+		with_git_directory("fixtures/simple", |_| {
+			let directory = tempdir().unwrap();
+			let todo_path = directory.path().join("git-rebase-todo");
+			fs::write(todo_path.as_path(), "pick aed0fd1 c1\nfixup c028f42 c2\n").unwrap();
+			let exit = run(&args(&["--check", todo_path.to_str().unwrap()]));
+			assert_eq!(exit.get_status(), &ExitStatus::Good);
+		});
+	}
+
+	#[test]
+	fn run_invalid() {
+		with_git_directory("fixtures/simple", |_| {
+			let directory = tempdir().unwrap();
+			let todo_path = directory.path().join("git-rebase-todo");
+			fs::write(todo_path.as_path(), "fixup aed0fd1 c1\npick aaaaaaaa c2\n").unwrap();
+			let path = todo_path.to_str().unwrap();
+			let exit = run(&args(&["--check", path]));
+			assert_eq!(exit.get_status(), &ExitStatus::CheckFailed);
+			assert_eq!(
+				exit.get_message().unwrap(),
+				format!("{path}:1: Cannot fixup without a previous commit\n{path}:2: Unknown commit 'aaaaaaaa'")
+			);
+		});
+	}
+
+	#[test]
+	fn run_todo_file_read_error() {
+		with_git_directory("fixtures/simple", |_| {
+			let exit = run(&args(&["--check", "does-not-exist"]));
+			assert_eq!(exit.get_status(), &ExitStatus::FileReadError);
+		});
+	}
+
+	#[test]
+	fn run_repository_error() {
+		with_git_directory("fixtures/not-a-repository", |_| {
+			assert_eq!(
+				run(&args(&["--check", "todofile"])).get_status(),
+				&ExitStatus::StateError
+			);
+		});
+	}
+
+	#[test]
+	fn run_config_error() {
+		with_git_directory("fixtures/invalid-config", |_| {
+			assert_eq!(
+				run(&args(&["--check", "todofile"])).get_status(),
+				&ExitStatus::ConfigError
+			);
+		});
+	}
+
+	#[test]
+	fn run_missing_todo_file() {
+		let exit = run(&args(&["--check"]));
+		assert_eq!(exit.get_status(), &ExitStatus::StateError);
+		assert!(
+			exit.get_message()
+				.unwrap()
+				.contains("A todo file path must be provided.")
+		);
+	}
+}
@@ -0,0 +1,292 @@
+use std::{
+	fs,
+	io,
+	path::{Path, PathBuf},
+};
+
+#[cfg(not(test))]
+use crate::display::CrossTerm;
+#[cfg(test)]
+use crate::test_helpers::mocks::CrossTerm;
+use crate::{
+	application::Application,
+	arguments::Args,
+	exit::Exit,
+	input::read_event,
+	module::{ExitStatus, Modules},
+};
+
+/// The line that git uses to mark the start of content, such as the diff of a verbose commit, that is removed
+/// from the message.
+const SCISSORS: &str = "------------------------ >8 ------------------------";
+
+/// Get if the line is a trailer, such as `Signed-off-by: Tim Oram <dev@mitmaro.ca>`.
+fn is_trailer(line: &str) -> bool {
+	line.split_once(": ")
+		.is_some_and(|(token, _)| !token.is_empty() && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+}
+
+/// A commit message being edited, such as the `COMMIT_EDITMSG` file opened by git for a `reword` or `squash`.
+#[derive(Debug)]
+pub(crate) struct CommitMessage {
+	path: PathBuf,
+	lines: Vec<String>,
+	comment_char: String,
+	signature: Option<String>,
+}
+
+impl CommitMessage {
+	pub(crate) fn new<FilePath: AsRef<Path>>(path: FilePath, comment_char: &str) -> Self {
+		Self {
+			path: PathBuf::from(path.as_ref()),
+			lines: vec![],
+			comment_char: String::from(comment_char),
+			signature: None,
+		}
+	}
+
+	/// Set the name and email, in the format `Name <email>`, used for the `Signed-off-by` trailer.
+	pub(crate) fn set_signature(&mut self, signature: &str) {
+		self.signature = Some(String::from(signature));
+	}
+
+	pub(crate) fn signature(&self) -> Option<&str> {
+		self.signature.as_deref()
+	}
+
+	pub(crate) fn load_file(&mut self) -> io::Result<()> {
+		let content = fs::read_to_string(self.path.as_path())?;
+		self.lines = content.lines().map(String::from).collect();
+		Ok(())
+	}
+
+	pub(crate) fn write_file(&self) -> io::Result<()> {
+		let mut content = self.lines.join("\n");
+		if !content.is_empty() {
+			content.push('\n');
+		}
+		fs::write(self.path.as_path(), content)
+	}
+
+	pub(crate) fn lines(&self) -> &[String] {
+		&self.lines
+	}
+
+	pub(crate) fn set_lines(&mut self, lines: Vec<String>) {
+		self.lines = lines;
+	}
+
+	pub(crate) fn is_comment(&self, line: &str) -> bool {
+		line.starts_with(self.comment_char.as_str())
+	}
+
+	/// The index of the scissors line, after which all lines are removed from the message.
+	pub(crate) fn scissors_index(&self) -> Option<usize> {
+		self.lines.iter().position(|line| {
+			line.strip_prefix(self.comment_char.as_str())
+				.is_some_and(|rest| rest.trim() == SCISSORS)
+		})
+	}
+
+	/// The index of the subject, the first line that is not blank or a comment.
+	pub(crate) fn subject_index(&self) -> Option<usize> {
+		let end = self.scissors_index().unwrap_or(self.lines.len());
+		self.lines[..end]
+			.iter()
+			.position(|line| !line.trim().is_empty() && !self.is_comment(line))
+	}
+
+	/// The message as it is committed, with the comments, the content after the scissors line, trailing
+	/// whitespace, and repeated blank lines removed, the same as the default `strip` cleanup of git.
+	pub(crate) fn cleaned_lines(&self) -> Vec<String> {
+		let end = self.scissors_index().unwrap_or(self.lines.len());
+		let mut cleaned: Vec<String> = vec![];
+		for line in self.lines[..end].iter().filter(|line| !self.is_comment(line)) {
+			let line = line.trim_end();
+			if line.is_empty() && cleaned.last().is_none_or(String::is_empty) {
+				continue;
+			}
+			cleaned.push(String::from(line));
+		}
+		while cleaned.last().is_some_and(String::is_empty) {
+			drop(cleaned.pop());
+		}
+		cleaned
+	}
+
+	/// Add a trailer to the end of the message, before any comments, returning the index of the trailer line. A
+	/// blank line is added before the trailer when the message does not already end with trailers. An existing
+	/// trailer with the same value is not repeated.
+	pub(crate) fn add_trailer(&mut self, trailer: &str) -> usize {
+		let end = self.scissors_index().unwrap_or(self.lines.len());
+		let content_end = self.lines[..end]
+			.iter()
+			.rposition(|line| !line.trim().is_empty() && !self.is_comment(line));
+
+		let Some(last_index) = content_end
+		else {
+			// an empty message gets a blank subject, so the trailer is not taken as the subject
+			self.lines.insert(0, String::from(trailer));
+			self.lines.insert(0, String::new());
+			self.lines.insert(0, String::new());
+			return 2;
+		};
+
+		let paragraph_start = self.lines[..=last_index]
+			.iter()
+			.rposition(|line| line.trim().is_empty())
+			.map_or(0, |index| index + 1);
+		let paragraph = &self.lines[paragraph_start..=last_index];
+		let has_trailers = Some(paragraph_start) != self.subject_index()
+			&& paragraph.iter().all(|line| self.is_comment(line) || is_trailer(line));
+
+		if has_trailers {
+			if let Some(offset) = paragraph.iter().position(|line| line == trailer) {
+				return paragraph_start + offset;
+			}
+			self.lines.insert(last_index + 1, String::from(trailer));
+			last_index + 1
+		}
+		else {
+			self.lines.insert(last_index + 1, String::from(trailer));
+			self.lines.insert(last_index + 1, String::new());
+			last_index + 2
+		}
+	}
+}
+
+/// Get if the path is a file that git opens in the editor for a commit message.
+pub(crate) fn is_commit_message_path(path: &str) -> bool {
+	Path::new(path)
+		.file_name()
+		.is_some_and(|name| name == "COMMIT_EDITMSG" || name == "MERGE_MSG")
+}
+
+#[cfg(not(tarpaulin_include))]
+pub(crate) fn run(args: &Args) -> Exit {
+	let mut application: Application<Modules> = match Application::new(args, read_event, CrossTerm::new()) {
+		Ok(app) => app,
+		Err(exit) => return exit,
+	};
+
+	match application.run_until_finished() {
+		Ok(..) => Exit::from(ExitStatus::Good),
+		Err(exit) => exit,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	fn create_commit_message(lines: &[&str]) -> CommitMessage {
+		let mut commit_message = CommitMessage::new("COMMIT_EDITMSG", "#");
+		commit_message.set_lines(lines.iter().map(|line| String::from(*line)).collect());
+		commit_message
+	}
+
+	#[test]
+	fn load_and_write_file() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("COMMIT_EDITMSG");
+		fs::write(path.as_path(), "Subject\n\nBody\n# comment\n").unwrap();
+		let mut commit_message = CommitMessage::new(path.as_path(), "#");
+		commit_message.load_file().unwrap();
+		assert_eq!(commit_message.lines(), ["Subject", "", "Body", "# comment"]);
+		commit_message.set_lines(vec![String::from("New subject")]);
+		commit_message.write_file().unwrap();
+		assert_eq!(fs::read_to_string(path).unwrap(), "New subject\n");
+	}
+
+	#[test]
+	fn write_file_empty() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("COMMIT_EDITMSG");
+		let commit_message = CommitMessage::new(path.as_path(), "#");
+		commit_message.write_file().unwrap();
+		assert_eq!(fs::read_to_string(path).unwrap(), "");
+	}
+
+	#[test]
+	fn load_file_missing() {
+		let directory = tempfile::tempdir().unwrap();
+		let mut commit_message = CommitMessage::new(directory.path().join("COMMIT_EDITMSG"), "#");
+		_ = commit_message.load_file().unwrap_err();
+	}
+
+	#[test]
+	fn signature() {
+		let mut commit_message = create_commit_message(&[]);
+		assert_eq!(commit_message.signature(), None);
+		commit_message.set_signature("Tim Oram <dev@mitmaro.ca>");
+		assert_eq!(commit_message.signature(), Some("Tim Oram <dev@mitmaro.ca>"));
+	}
+
+	#[rstest]
+	#[case::subject(&["Subject", "", "Body"], Some(0))]
+	#[case::leading_blank_and_comment(&["", "# comment", "Subject"], Some(2))]
+	#[case::only_comments(&["# comment"], None)]
+	#[case::after_scissors(&["# ------------------------ >8 ------------------------", "diff"], None)]
+	fn subject_index(#[case] lines: &[&str], #[case] expected: Option<usize>) {
+		assert_eq!(create_commit_message(lines).subject_index(), expected);
+	}
+
+	#[test]
+	fn cleaned_lines() {
+		let commit_message = create_commit_message(&[
+			"",
+			"Subject  ",
+			"# comment",
+			"",
+			"",
+			"Body",
+			"",
+			"# ------------------------ >8 ------------------------",
+			"diff --git a/file b/file",
+		]);
+		assert_eq!(commit_message.cleaned_lines(), vec!["Subject", "", "Body"]);
+	}
+
+	#[test]
+	fn cleaned_lines_custom_comment_char() {
+		let mut commit_message = CommitMessage::new("COMMIT_EDITMSG", ";");
+		commit_message.set_lines(vec![
+			String::from("Subject"),
+			String::from("; comment"),
+			String::from("# not a comment"),
+		]);
+		assert_eq!(commit_message.cleaned_lines(), vec!["Subject", "# not a comment"]);
+	}
+
+	#[rstest]
+	#[case::subject_only(&["Subject", "# comment"], &["Subject", "", "Trailer: value", "# comment"], 2)]
+	#[case::with_body(&["Subject", "", "Body", ""], &["Subject", "", "Body", "", "Trailer: value", ""], 4)]
+	#[case::existing_trailers(
+		&["Subject", "", "Fixes: 123"],
+		&["Subject", "", "Fixes: 123", "Trailer: value"],
+		3
+	)]
+	#[case::repeated_trailer(&["Subject", "", "Trailer: value"], &["Subject", "", "Trailer: value"], 2)]
+	#[case::subject_like_trailer(&["Fix: crash"], &["Fix: crash", "", "Trailer: value"], 2)]
+	#[case::empty(&["# comment"], &["", "", "Trailer: value", "# comment"], 2)]
+	#[case::before_scissors(
+		&["Subject", "# ------------------------ >8 ------------------------", "Body"],
+		&["Subject", "", "Trailer: value", "# ------------------------ >8 ------------------------", "Body"],
+		2
+	)]
+	fn add_trailer(#[case] lines: &[&str], #[case] expected: &[&str], #[case] expected_index: usize) {
+		let mut commit_message = create_commit_message(lines);
+		assert_eq!(commit_message.add_trailer("Trailer: value"), expected_index);
+		assert_eq!(commit_message.lines(), expected);
+	}
+
+	#[rstest]
+	#[case::commit_editmsg(".git/COMMIT_EDITMSG", true)]
+	#[case::merge_msg("/repo/.git/MERGE_MSG", true)]
+	#[case::todo_file(".git/rebase-merge/git-rebase-todo", false)]
+	fn commit_message_path(#[case] path: &str, #[case] expected: bool) {
+		assert_eq!(is_commit_message_path(path), expected);
+	}
+}
@@ -3,6 +3,7 @@ mod tests;
 
 use std::sync::LazyLock;
 
+pub(crate) use crate::components::shared::{EditAction, EditableText};
 use crate::{
 	components::shared::EditableLine,
 	display::DisplayColor,
@@ -1,3 +1,7 @@
 mod editable_line;
+mod editable_text;
 
-pub(crate) use self::editable_line::{EditAction, EditableLine};
+pub(crate) use self::{
+	editable_line::{EditAction, EditableLine},
+	editable_text::EditableText,
+};
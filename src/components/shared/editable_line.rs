@@ -56,6 +56,24 @@ impl EditableLine {
 		self.cursor_position
 	}
 
+	/// The length of the content, in graphemes.
+	pub(crate) fn content_length(&self) -> usize {
+		UnicodeSegmentation::graphemes(self.content.as_str(), true).count()
+	}
+
+	/// Move the cursor to the position, limited to the end of the content.
+	pub(crate) fn set_cursor_position(&mut self, position: usize) {
+		self.cursor_position = position.min(self.content_length());
+	}
+
+	/// Remove the content after the cursor, returning the removed content.
+	pub(crate) fn split_off_at_cursor(&mut self) -> String {
+		let start = UnicodeSegmentation::graphemes(self.content.as_str(), true)
+			.take(self.cursor_position)
+			.collect::<String>();
+		self.content.split_off(start.len())
+	}
+
 	pub(crate) fn line_segments(&self) -> Vec<LineSegment> {
 		if self.read_only {
 			return vec![LineSegment::new(self.get_content())];
@@ -644,6 +662,32 @@ mod tests {
 		assert_eq!(editable_line.line_segments().len(), 1);
 	}
 
+	#[test]
+	fn set_cursor_position() {
+		let mut editable_line = EditableLine::new();
+		editable_line.set_content("abcd");
+		editable_line.set_cursor_position(2);
+		assert_eq!(editable_line.cursor_position(), 2);
+	}
+
+	#[test]
+	fn set_cursor_position_past_end() {
+		let mut editable_line = EditableLine::new();
+		editable_line.set_content("abcd");
+		editable_line.set_cursor_position(10);
+		assert_eq!(editable_line.cursor_position(), 4);
+	}
+
+	#[test]
+	fn split_off_at_cursor() {
+		let mut editable_line = EditableLine::new();
+		editable_line.set_content("a😀bcd");
+		editable_line.set_cursor_position(2);
+		assert_eq!(editable_line.split_off_at_cursor(), "bcd");
+		assert_eq!(editable_line.get_content(), "a😀");
+		assert_eq!(editable_line.cursor_position(), 2);
+	}
+
 	#[test]
 	fn clear_content() {
 		let mut editable_line = EditableLine::new();
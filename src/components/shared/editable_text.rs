@@ -0,0 +1,307 @@
+use crate::{
+	components::shared::{EditAction, EditableLine},
+	input::{Event, KeyCode, KeyEvent, KeyModifiers},
+	view::LineSegment,
+};
+
+/// Multiple lines of editable text, with a cursor on one of the lines.
+pub(crate) struct EditableText {
+	lines: Vec<EditableLine>,
+	line_index: usize,
+}
+
+impl EditableText {
+	pub(crate) fn new() -> Self {
+		Self {
+			lines: vec![EditableLine::new()],
+			line_index: 0,
+		}
+	}
+
+	/// Replace the content, moving the cursor to the start of the first line.
+	pub(crate) fn set_content(&mut self, lines: &[String]) {
+		self.lines = lines
+			.iter()
+			.map(|content| {
+				let mut line = EditableLine::new();
+				line.set_content(content);
+				line.set_cursor_position(0);
+				line
+			})
+			.collect();
+		if self.lines.is_empty() {
+			self.lines.push(EditableLine::new());
+		}
+		self.line_index = 0;
+	}
+
+	pub(crate) fn get_content(&self) -> Vec<String> {
+		self.lines.iter().map(|line| String::from(line.get_content())).collect()
+	}
+
+	pub(crate) fn line_count(&self) -> usize {
+		self.lines.len()
+	}
+
+	pub(crate) fn get_line(&self, index: usize) -> Option<&str> {
+		self.lines.get(index).map(EditableLine::get_content)
+	}
+
+	pub(crate) const fn line_index(&self) -> usize {
+		self.line_index
+	}
+
+	pub(crate) fn cursor_position(&self) -> usize {
+		self.lines[self.line_index].cursor_position()
+	}
+
+	/// Move the cursor to the position on the line, limited to the end of the text.
+	pub(crate) fn set_cursor(&mut self, line_index: usize, position: usize) {
+		self.line_index = line_index.min(self.lines.len() - 1);
+		self.lines[self.line_index].set_cursor_position(position);
+	}
+
+	/// The segments of the line, with the cursor shown when the line has the cursor.
+	pub(crate) fn line_segments(&self, index: usize) -> Vec<LineSegment> {
+		match self.lines.get(index) {
+			Some(line) if index == self.line_index => line.line_segments(),
+			Some(line) => vec![LineSegment::new(line.get_content())],
+			None => vec![],
+		}
+	}
+
+	pub(crate) fn handle_event(&mut self, event: Event) -> EditAction {
+		let Event::Key(KeyEvent {
+			code,
+			modifiers: KeyModifiers::NONE,
+		}) = event
+		else {
+			return EditAction::None;
+		};
+
+		match code {
+			KeyCode::Enter => {
+				let content = self.lines[self.line_index].split_off_at_cursor();
+				let mut line = EditableLine::new();
+				line.set_content(content.as_str());
+				line.set_cursor_position(0);
+				self.line_index += 1;
+				self.lines.insert(self.line_index, line);
+				EditAction::ContentUpdate
+			},
+			KeyCode::Backspace if self.cursor_position() == 0 => {
+				if self.line_index == 0 {
+					return EditAction::None;
+				}
+				let line = self.lines.remove(self.line_index);
+				self.line_index -= 1;
+				self.join_line(line.get_content());
+				EditAction::ContentUpdate
+			},
+			KeyCode::Delete if self.is_cursor_at_line_end() => {
+				if self.line_index + 1 == self.lines.len() {
+					return EditAction::None;
+				}
+				let line = self.lines.remove(self.line_index + 1);
+				self.join_line(line.get_content());
+				EditAction::ContentUpdate
+			},
+			KeyCode::Up => {
+				if self.line_index == 0 {
+					return EditAction::None;
+				}
+				self.set_cursor(self.line_index - 1, self.cursor_position());
+				EditAction::CursorMove
+			},
+			KeyCode::Down => {
+				if self.line_index + 1 == self.lines.len() {
+					return EditAction::None;
+				}
+				self.set_cursor(self.line_index + 1, self.cursor_position());
+				EditAction::CursorMove
+			},
+			_ => self.lines[self.line_index].handle_event(event),
+		}
+	}
+
+	fn is_cursor_at_line_end(&self) -> bool {
+		self.lines[self.line_index].content_length() == self.cursor_position()
+	}
+
+	/// Append content to the line with the cursor, moving the cursor to the start of the appended content.
+	fn join_line(&mut self, content: &str) {
+		let line = &mut self.lines[self.line_index];
+		let position = line.content_length();
+		line.set_content(format!("{}{content}", line.get_content()).as_str());
+		line.set_cursor_position(position);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn create_editable_text(lines: &[&str]) -> EditableText {
+		let mut editable_text = EditableText::new();
+		editable_text.set_content(&lines.iter().map(|line| String::from(*line)).collect::<Vec<String>>());
+		editable_text
+	}
+
+	fn handle_events(editable_text: &mut EditableText, events: &[Event]) {
+		for event in events {
+			_ = editable_text.handle_event(*event);
+		}
+	}
+
+	#[test]
+	fn set_content_empty() {
+		let mut editable_text = EditableText::new();
+		editable_text.set_content(&[]);
+		assert_eq!(editable_text.get_content(), vec![String::new()]);
+		assert_eq!(editable_text.line_count(), 1);
+	}
+
+	#[test]
+	fn set_content_cursor_at_start() {
+		let editable_text = create_editable_text(&["abc", "def"]);
+		assert_eq!(editable_text.line_index(), 0);
+		assert_eq!(editable_text.cursor_position(), 0);
+	}
+
+	#[test]
+	fn set_cursor_limited() {
+		let mut editable_text = create_editable_text(&["abc", "def"]);
+		editable_text.set_cursor(5, 10);
+		assert_eq!(editable_text.line_index(), 1);
+		assert_eq!(editable_text.cursor_position(), 3);
+	}
+
+	#[test]
+	fn get_line() {
+		let editable_text = create_editable_text(&["abc", "def"]);
+		assert_eq!(editable_text.get_line(1), Some("def"));
+		assert_eq!(editable_text.get_line(2), None);
+	}
+
+	#[test]
+	fn line_segments() {
+		let editable_text = create_editable_text(&["abc", "def"]);
+		assert_eq!(editable_text.line_segments(0).len(), 2);
+		assert_eq!(editable_text.line_segments(1).len(), 1);
+		assert!(editable_text.line_segments(2).is_empty());
+	}
+
+	#[test]
+	fn enter_splits_line() {
+		let mut editable_text = create_editable_text(&["abcdef"]);
+		editable_text.set_cursor(0, 3);
+		assert_eq!(
+			editable_text.handle_event(Event::from(KeyCode::Enter)),
+			EditAction::ContentUpdate
+		);
+		assert_eq!(editable_text.get_content(), vec!["abc", "def"]);
+		assert_eq!(editable_text.line_index(), 1);
+		assert_eq!(editable_text.cursor_position(), 0);
+	}
+
+	#[test]
+	fn enter_at_end_adds_line() {
+		let mut editable_text = create_editable_text(&["abc"]);
+		handle_events(&mut editable_text, &[
+			Event::from(KeyCode::End),
+			Event::from(KeyCode::Enter),
+			Event::from('x'),
+		]);
+		assert_eq!(editable_text.get_content(), vec!["abc", "x"]);
+	}
+
+	#[test]
+	fn backspace_at_line_start_joins_lines() {
+		let mut editable_text = create_editable_text(&["abc", "def"]);
+		editable_text.set_cursor(1, 0);
+		assert_eq!(
+			editable_text.handle_event(Event::from(KeyCode::Backspace)),
+			EditAction::ContentUpdate
+		);
+		assert_eq!(editable_text.get_content(), vec!["abcdef"]);
+		assert_eq!(editable_text.line_index(), 0);
+		assert_eq!(editable_text.cursor_position(), 3);
+	}
+
+	#[test]
+	fn backspace_at_text_start() {
+		let mut editable_text = create_editable_text(&["abc"]);
+		assert_eq!(
+			editable_text.handle_event(Event::from(KeyCode::Backspace)),
+			EditAction::None
+		);
+		assert_eq!(editable_text.get_content(), vec!["abc"]);
+	}
+
+	#[test]
+	fn delete_at_line_end_joins_lines() {
+		let mut editable_text = create_editable_text(&["abc", "def"]);
+		editable_text.set_cursor(0, 3);
+		assert_eq!(
+			editable_text.handle_event(Event::from(KeyCode::Delete)),
+			EditAction::ContentUpdate
+		);
+		assert_eq!(editable_text.get_content(), vec!["abcdef"]);
+		assert_eq!(editable_text.cursor_position(), 3);
+	}
+
+	#[test]
+	fn delete_at_text_end() {
+		let mut editable_text = create_editable_text(&["abc"]);
+		editable_text.set_cursor(0, 3);
+		assert_eq!(
+			editable_text.handle_event(Event::from(KeyCode::Delete)),
+			EditAction::None
+		);
+	}
+
+	#[test]
+	fn move_up_and_down() {
+		let mut editable_text = create_editable_text(&["abcdef", "ab", "abcd"]);
+		editable_text.set_cursor(0, 5);
+		assert_eq!(
+			editable_text.handle_event(Event::from(KeyCode::Down)),
+			EditAction::CursorMove
+		);
+		assert_eq!((editable_text.line_index(), editable_text.cursor_position()), (1, 2));
+		assert_eq!(
+			editable_text.handle_event(Event::from(KeyCode::Down)),
+			EditAction::CursorMove
+		);
+		assert_eq!((editable_text.line_index(), editable_text.cursor_position()), (2, 2));
+		assert_eq!(editable_text.handle_event(Event::from(KeyCode::Down)), EditAction::None);
+		assert_eq!(
+			editable_text.handle_event(Event::from(KeyCode::Up)),
+			EditAction::CursorMove
+		);
+		assert_eq!(
+			editable_text.handle_event(Event::from(KeyCode::Up)),
+			EditAction::CursorMove
+		);
+		assert_eq!(editable_text.handle_event(Event::from(KeyCode::Up)), EditAction::None);
+		assert_eq!(editable_text.line_index(), 0);
+	}
+
+	#[test]
+	fn edit_line_with_cursor() {
+		let mut editable_text = create_editable_text(&["abc", "def"]);
+		editable_text.set_cursor(1, 3);
+		handle_events(&mut editable_text, &[Event::from('x'), Event::from(KeyCode::Backspace)]);
+		_ = editable_text.handle_event(Event::from('g'));
+		assert_eq!(editable_text.get_content(), vec!["abc", "defg"]);
+	}
+
+	#[test]
+	fn ignore_modified_keys() {
+		let mut editable_text = create_editable_text(&["abc"]);
+		assert_eq!(
+			editable_text.handle_event(Event::from(KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL))),
+			EditAction::None
+		);
+	}
+}
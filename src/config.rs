@@ -1,34 +1,57 @@
 //! Git Interactive Rebase Tool - Configuration Module
 //!
 //! # Description
-//! This module is used to handle the loading of configuration from the Git config system.
+//! This module is used to handle the loading of configuration from the Git config system, and from the TOML
+//! configuration files.
 //!
 //! ## Test Utilities
 //! To facilitate testing the usages of this crate, a set of testing utilities are provided. Since
 //! these utilities are not tested, and often are optimized for developer experience than
 //! performance should only be used in test code.
 mod color;
+mod config_file;
 mod config_loader;
+mod config_override;
+mod custom_command;
 mod diff_ignore_whitespace_setting;
 mod diff_show_whitespace_setting;
 mod errors;
 mod git_config;
 mod key_bindings;
+mod key_preset;
+mod line_format;
+mod plugin_config;
 mod theme;
+mod theme_preset;
 mod utils;
 
-use self::utils::{get_bool, get_diff_ignore_whitespace, get_diff_show_whitespace, get_string, get_unsigned_integer};
+use self::utils::{
+	get_bool,
+	get_diff_ignore_whitespace,
+	get_diff_show_whitespace,
+	get_line_format,
+	get_string,
+	get_unsigned_integer,
+};
 pub(crate) use self::{
 	color::Color,
+	config_file::ConfigFile,
 	config_loader::ConfigLoader,
+	custom_command::CustomCommand,
 	diff_ignore_whitespace_setting::DiffIgnoreWhitespaceSetting,
 	diff_show_whitespace_setting::DiffShowWhitespaceSetting,
 	git_config::GitConfig,
-	key_bindings::KeyBindings,
+	key_bindings::{KeyBindings, format_key_binding},
+	key_preset::KeyPreset,
+	line_format::{LineFormat, LineFormatField, LineFormatPart, align_value},
+	plugin_config::PluginConfig,
 	theme::Theme,
+	theme_preset::ThemePreset,
+	utils::parse_input,
 };
 use crate::config::{
 	errors::{ConfigError, ConfigErrorCause, InvalidColorError},
+	plugin_config::get_plugin_configs,
 	utils::get_optional_string,
 };
 
@@ -45,6 +68,8 @@ pub(crate) struct Config {
 	pub(crate) diff_ignore_whitespace: DiffIgnoreWhitespaceSetting,
 	/// If to ignore blank lines when calculating diffs.
 	pub(crate) diff_ignore_blank_lines: bool,
+	/// Pathspecs of files that are excluded from diffs.
+	pub(crate) diff_exclude_paths: Vec<String>,
 	/// How to show whitespace in diffs.
 	pub(crate) diff_show_whitespace: DiffShowWhitespaceSetting,
 	/// The symbol used to replace space characters.
@@ -53,6 +78,10 @@ pub(crate) struct Config {
 	pub(crate) diff_tab_symbol: String,
 	/// The display width of the tab character.
 	pub(crate) diff_tab_width: u32,
+	/// The layout of the lines in the list, if set.
+	pub(crate) list_line_format: Option<LineFormat>,
+	/// External programs that extend the tool, started when the tool starts.
+	pub(crate) plugins: Vec<PluginConfig>,
 	/// If set, automatically add an exec line with the command after every modified line
 	pub(crate) post_modified_line_exec_command: Option<String>,
 	/// The maximum number of undo steps.
@@ -74,6 +103,10 @@ impl Config {
 				"interactive-rebase-tool.diffIgnoreWhitespace",
 			)?,
 			diff_ignore_blank_lines: get_bool(git_config, "interactive-rebase-tool.diffIgnoreBlankLines", false)?,
+			diff_exclude_paths: get_string(git_config, "interactive-rebase-tool.diffExcludePaths", "")?
+				.split_whitespace()
+				.map(String::from)
+				.collect(),
 			diff_show_whitespace: get_diff_show_whitespace(git_config, "interactive-rebase-tool.diffShowWhitespace")?,
 			diff_space_symbol: get_string(
 				git_config,
@@ -82,6 +115,8 @@ impl Config {
 			)?,
 			diff_tab_symbol: get_string(git_config, "interactive-rebase-tool.diffTabSymbol", DEFAULT_TAB_SYMBOL)?,
 			diff_tab_width: get_unsigned_integer(git_config, "interactive-rebase-tool.diffTabWidth", 4)?,
+			list_line_format: get_line_format(git_config, "interactive-rebase-tool.listLineFormat")?,
+			plugins: get_plugin_configs(git_config)?,
 			undo_limit: get_unsigned_integer(git_config, "interactive-rebase-tool.undoLimit", 5000)?,
 			post_modified_line_exec_command: get_optional_string(
 				git_config,
@@ -103,10 +138,16 @@ impl TryFrom<&ConfigLoader> for Config {
 	///
 	/// Will return an `Err` if there is a problem loading the configuration.
 	fn try_from(config_loader: &ConfigLoader) -> Result<Self, Self::Error> {
+		let config_files = config_loader.load_config_files()?;
 		let config = config_loader
-			.load_config()
+			.load_config(&config_files)
 			.map_err(|e| ConfigError::new_read_error("", ConfigErrorCause::GitError(e)))?;
-		Self::new_with_config(Some(&config))
+		// the last file that defines a setting has the highest precedence
+		Self::new_with_config(Some(&config)).map_err(|err| {
+			config_files
+				.iter()
+				.fold(err, |err, config_file| config_file.locate_error(err))
+		})
 	}
 }
 
@@ -126,7 +167,14 @@ mod tests {
 	use rstest::rstest;
 
 	use super::*;
-	use crate::test_helpers::{invalid_utf, with_git_config, with_temp_bare_repository};
+	use crate::test_helpers::{
+		EnvVarAction,
+		invalid_utf,
+		with_env_var,
+		with_git_config,
+		with_temp_bare_repository,
+		with_temp_repository,
+	};
 
 	#[test]
 	fn try_from_config_loader() {
@@ -136,6 +184,38 @@ mod tests {
 		});
 	}
 
+	#[cfg(unix)]
+	#[test]
+	fn try_from_config_loader_config_file_error() {
+		let directory = tempfile::tempdir().unwrap();
+		with_env_var(
+			&[EnvVarAction::Set(
+				"XDG_CONFIG_HOME",
+				String::from(directory.path().to_str().unwrap()),
+			)],
+			|| {
+				with_temp_repository(|repository| {
+					let path = repository.commondir().join("interactive-rebase-tool.toml");
+					std::fs::write(
+						path.as_path(),
+						"autoSelectNext = true
+undoLimit = \"many\"\n",
+					)
+					.unwrap();
+					let loader = ConfigLoader::from(repository);
+					assert_eq!(
+						Config::try_from(&loader).unwrap_err().to_string(),
+						format!(
+							"Provided value 'many' is invalid for 'interactive-rebase-tool.undoLimit' at {}:2: The \
+							 input provided is outside of valid range for an unsigned 32-bit integer.",
+							path.display()
+						)
+					);
+				});
+			},
+		);
+	}
+
 	#[test]
 	fn try_from_git_config() {
 		with_git_config(&[], |git_config| {
@@ -289,6 +369,13 @@ mod tests {
 		DiffShowWhitespaceSetting::Trailing,
 		|config: Config| config.diff_show_whitespace)
 	]
+	#[case::diff_exclude_paths_default("diffExcludePaths", "", vec![], |config: Config| config.diff_exclude_paths)]
+	#[case::diff_exclude_paths(
+		"diffExcludePaths",
+		"Cargo.lock *.snap",
+		vec![String::from("Cargo.lock"), String::from("*.snap")],
+		|config: Config| config.diff_exclude_paths
+	)]
 	#[case::diff_tab_width_default("diffTabWidth", "", 4, |config: Config| config.diff_tab_width)]
 	#[case::diff_tab_width("diffTabWidth", "42", 42, |config: Config| config.diff_tab_width)]
 	#[case::diff_tab_symbol_default("diffTabSymbol", "", String::from("→"), |config: Config| config.diff_tab_symbol)]
@@ -300,6 +387,13 @@ mod tests {
 		|config: Config| config.diff_space_symbol)
 	]
 	#[case::diff_space_symbol("diffSpaceSymbol", "-", String::from("-"), |config: Config| config.diff_space_symbol)]
+	#[case::list_line_format(
+		"listLineFormat",
+		"{action} {subject}",
+		Some(LineFormat::parse("{action} {subject}").unwrap()),
+		|config: Config| config.list_line_format
+	)]
+	#[case::list_line_format_default("listLineFormat", "", None, |config: Config| config.list_line_format)]
 	#[case::undo_limit_default("undoLimit", "", 5000, |config: Config| config.undo_limit)]
 	#[case::undo_limit("undoLimit", "42", 42, |config: Config| config.undo_limit)]
 	#[case::post_modified_line_exec_command(
@@ -367,8 +461,10 @@ mod tests {
 	#[rstest]
 	#[case::diff_tab_symbol("diffIgnoreWhitespace")]
 	#[case::diff_show_whitespace("diffShowWhitespace")]
+	#[case::diff_exclude_paths("diffExcludePaths")]
 	#[case::diff_tab_symbol("diffTabSymbol")]
 	#[case::diff_space_symbol("diffSpaceSymbol")]
+	#[case::list_line_format("listLineFormat")]
 	#[case::post_modified_line_exec_command("postModifiedLineExecCommand")]
 	fn value_parsing_invalid_utf(#[case] config_name: &str) {
 		with_git_config(
@@ -79,6 +79,23 @@ impl TryFrom<&str> for Color {
 			"dark yellow" => Ok(Self::DarkYellow),
 			"dark grey" => Ok(Self::DarkGrey),
 			"transparent" | "-1" => Ok(Self::Default),
+			hex if hex.starts_with('#') => {
+				let value = hex
+					.strip_prefix('#')
+					.filter(|value| value.len() == 6 && value.chars().all(|c| c.is_ascii_hexdigit()))
+					.ok_or(InvalidColorError::Hex {})?;
+				let component = |start: usize| {
+					value
+						.get(start..start + 2)
+						.and_then(|component| u8::from_str_radix(component, 16).ok())
+						.ok_or(InvalidColorError::Hex {})
+				};
+				Ok(Self::Rgb {
+					red: component(0)?,
+					green: component(2)?,
+					blue: component(4)?,
+				})
+			},
 			_ => {
 				let matches: Vec<&str> = s.split(',').collect();
 
@@ -157,6 +174,11 @@ mod tests {
 		green: 101,
 		blue: 102
 	})]
+	#[case::hex("#2aa198", Color::Rgb {
+		red: 42,
+		green: 161,
+		blue: 152
+	})]
 	fn try_from(#[case] color_string: &str, #[case] expected: Color) {
 		assert_ok_eq!(Color::try_from(color_string), expected);
 	}
@@ -175,6 +197,9 @@ mod tests {
 	#[case::index_lower_limit("-2", InvalidColorError::Indexed {})]
 	#[case::str_single_value("invalid", InvalidColorError::Indexed {})]
 	#[case::str_multiple_value("invalid,invalid", InvalidColorError::Invalid {})]
+	#[case::hex_short("#fff", InvalidColorError::Hex {})]
+	#[case::hex_invalid_digit("#00000g", InvalidColorError::Hex {})]
+	#[case::hex_sign("#+00000", InvalidColorError::Hex {})]
 	fn color_try_from_fail(#[case] color_string: &str, #[case] expected: InvalidColorError) {
 		assert_err_eq!(Color::try_from(color_string), expected);
 	}
@@ -0,0 +1,511 @@
+use std::{
+	env,
+	fmt::Write as _,
+	fs,
+	io::ErrorKind,
+	path::{Path, PathBuf},
+};
+
+use toml_edit::{ImDocument, Item, Table, Value};
+
+use crate::config::{ConfigError, ConfigErrorCause, utils::escape_value};
+
+const SETTING_PREFIX: &str = "interactive-rebase-tool";
+const USER_CONFIG_FILE: &str = "git-interactive-rebase-tool/config.toml";
+const REPOSITORY_CONFIG_FILE: &str = "interactive-rebase-tool.toml";
+
+/// A setting read from a configuration file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Entry {
+	/// The subsection of the setting, for settings defined in a nested table.
+	subsection: Option<String>,
+	/// The variable name of the setting.
+	variable: String,
+	/// The value of the setting, in the form that Git config would provide it.
+	value: String,
+	/// The line in the file where the value is defined.
+	line: usize,
+}
+
+impl Entry {
+	fn name(&self) -> String {
+		match self.subsection.as_deref() {
+			Some(subsection) => format!("{SETTING_PREFIX}.{subsection}.{}", self.variable),
+			None => format!("{SETTING_PREFIX}.{}", self.variable),
+		}
+	}
+}
+
+/// A TOML configuration file, containing the same settings as the `interactive-rebase-tool` Git config section.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ConfigFile {
+	path: PathBuf,
+	entries: Vec<Entry>,
+}
+
+impl ConfigFile {
+	/// The path to the user configuration file, in the XDG configuration directory.
+	#[must_use]
+	pub(crate) fn user_path() -> Option<PathBuf> {
+		env::var_os("XDG_CONFIG_HOME")
+			.filter(|path| !path.is_empty())
+			.map(PathBuf::from)
+			.or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+			.map(|path| path.join(USER_CONFIG_FILE))
+	}
+
+	/// The path to the repository configuration file, in the Git directory.
+	#[must_use]
+	pub(crate) fn repository_path(git_directory: &Path) -> PathBuf {
+		git_directory.join(REPOSITORY_CONFIG_FILE)
+	}
+
+	/// Load a configuration file, returning `None` if the file does not exist.
+	///
+	/// # Errors
+	/// Will result in an error if the file cannot be read or is not valid.
+	pub(crate) fn load(path: &Path) -> Result<Option<Self>, ConfigError> {
+		match fs::read_to_string(path) {
+			Ok(content) => Self::parse(path, content.as_str()).map(Some),
+			Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+			Err(err) => {
+				Err(ConfigError::new_read_error(
+					SETTING_PREFIX,
+					ConfigErrorCause::InvalidConfigFile(format!("Unable to read '{}': {err}", path.display())),
+				))
+			},
+		}
+	}
+
+	/// Parse the content of a configuration file.
+	///
+	/// # Errors
+	/// Will result in an error if the content is not valid TOML, or contains a setting that cannot be converted.
+	pub(crate) fn parse(path: &Path, content: &str) -> Result<Self, ConfigError> {
+		let line_of = |offset: usize| content.get(..offset).unwrap_or(content).matches('\n').count() + 1;
+		let document = ImDocument::parse(content).map_err(|err| {
+			ConfigError::new_read_error(
+				SETTING_PREFIX,
+				ConfigErrorCause::InvalidConfigFile(String::from(err.message().trim_end())),
+			)
+			.with_location(path, err.span().map_or(1, |span| line_of(span.start)))
+		})?;
+
+		let mut config_file = Self {
+			path: PathBuf::from(path),
+			entries: vec![],
+		};
+		config_file.add_table(document.as_table(), &[], &line_of)?;
+		Ok(config_file)
+	}
+
+	fn add_table(
+		&mut self,
+		table: &Table,
+		parents: &[&str],
+		line_of: &impl Fn(usize) -> usize,
+	) -> Result<(), ConfigError> {
+		for (key, item) in table {
+			let line = item.span().map_or(1, |span| line_of(span.start));
+			let mut keys = parents.to_vec();
+			keys.push(key);
+			match *item {
+				Item::Table(ref table) => self.add_table(table, keys.as_slice(), line_of)?,
+				Item::Value(Value::InlineTable(ref table)) => {
+					self.add_table(&table.clone().into_table(), keys.as_slice(), line_of)?;
+				},
+				Item::Value(ref value) => self.add_value(keys.as_slice(), value, line)?,
+				Item::None | Item::ArrayOfTables(_) => {
+					return Err(self.error(keys.as_slice(), line, ConfigErrorCause::UnsupportedConfigFileValue));
+				},
+			}
+		}
+		Ok(())
+	}
+
+	fn add_value(&mut self, keys: &[&str], value: &Value, line: usize) -> Result<(), ConfigError> {
+		let value = match *value {
+			Value::String(ref value) => value.value().clone(),
+			Value::Boolean(ref value) => value.value().to_string(),
+			Value::Integer(ref value) => value.value().to_string(),
+			Value::Array(ref values) => {
+				values
+					.iter()
+					.map(|value| value.as_str().map(String::from))
+					.collect::<Option<Vec<String>>>()
+					.ok_or_else(|| self.error(keys, line, ConfigErrorCause::UnsupportedConfigFileValue))?
+					.join(" ")
+			},
+			_ => return Err(self.error(keys, line, ConfigErrorCause::UnsupportedConfigFileValue)),
+		};
+
+		let (variable, subsection) = keys.split_last().expect("a value always has a key");
+		// Git restricts variable names to alphanumeric characters and dashes, starting with a letter
+		let valid_variable = variable.starts_with(|c: char| c.is_ascii_alphabetic())
+			&& variable.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+		if !valid_variable || subsection.iter().any(|key| key.contains(['\n', '\0'])) {
+			return Err(self.error(keys, line, ConfigErrorCause::InvalidConfigFileSetting));
+		}
+
+		self.entries.push(Entry {
+			subsection: (!subsection.is_empty()).then(|| subsection.join(".")),
+			variable: String::from(*variable),
+			value,
+			line,
+		});
+		Ok(())
+	}
+
+	fn error(&self, keys: &[&str], line: usize, cause: ConfigErrorCause) -> ConfigError {
+		ConfigError::new_read_error(format!("{SETTING_PREFIX}.{}", keys.join(".")).as_str(), cause)
+			.with_location(self.path.as_path(), line)
+	}
+
+	/// The path of the file.
+	pub(crate) fn path(&self) -> &Path {
+		self.path.as_path()
+	}
+
+	/// Get the value and line of a setting that is not in a table, where the last definition takes precedence.
+	pub(crate) fn get(&self, variable: &str) -> Option<(&str, usize)> {
+		self.entries
+			.iter()
+			.rev()
+			.find(|entry| entry.subsection.is_none() && entry.variable.eq_ignore_ascii_case(variable))
+			.map(|entry| (entry.value.as_str(), entry.line))
+	}
+
+	/// Ensure that the file only contains settings, that are not in a table, from a list of accepted settings.
+	///
+	/// # Errors
+	/// Will result in an error, with the provided cause, for the first setting that is not accepted.
+	pub(crate) fn ensure_only_settings(&self, variables: &[&str], cause: ConfigErrorCause) -> Result<(), ConfigError> {
+		let invalid = self.entries.iter().find(|entry| {
+			entry.subsection.is_some()
+				|| !variables
+					.iter()
+					.any(|variable| variable.eq_ignore_ascii_case(entry.variable.as_str()))
+		});
+		match invalid {
+			Some(entry) => {
+				Err(ConfigError::new(entry.name().as_str(), entry.value.as_str(), cause)
+					.with_location(self.path.as_path(), entry.line))
+			},
+			None => Ok(()),
+		}
+	}
+
+	/// Add the location of the setting to an error, if the setting is defined in this file.
+	#[must_use]
+	pub(crate) fn locate_error(&self, error: ConfigError) -> ConfigError {
+		let line = self
+			.entries
+			.iter()
+			.rev()
+			.find(|entry| entry.name().eq_ignore_ascii_case(error.name()))
+			.map(|entry| entry.line);
+		match line {
+			Some(line) => error.with_location(self.path.as_path(), line),
+			None => error,
+		}
+	}
+
+	/// Convert the settings to the Git config file format.
+	#[must_use]
+	pub(crate) fn to_git_config(&self) -> String {
+		let mut output = String::new();
+		let mut section = None;
+		for entry in &self.entries {
+			if section != Some(&entry.subsection) {
+				match entry.subsection.as_deref() {
+					Some(subsection) => _ = writeln!(output, "[{SETTING_PREFIX} \"{}\"]", escape_value(subsection)),
+					None => _ = writeln!(output, "[{SETTING_PREFIX}]"),
+				}
+				section = Some(&entry.subsection);
+			}
+			_ = writeln!(
+				output,
+				"\t{} = \"{}\"",
+				entry.variable,
+				escape_value(entry.value.as_str())
+			);
+		}
+		output
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_err_eq, assert_none, assert_ok, assert_some_eq};
+	use tempfile::tempdir;
+
+	use super::*;
+	use crate::test_helpers::{EnvVarAction, with_env_var, with_git_config};
+
+	fn parse(lines: &[&str]) -> Result<ConfigFile, ConfigError> {
+		ConfigFile::parse(Path::new("config.toml"), lines.join("\n").as_str())
+	}
+
+	#[test]
+	fn user_path_xdg_config_home() {
+		with_env_var(&[EnvVarAction::Set("XDG_CONFIG_HOME", String::from("/config"))], || {
+			assert_some_eq!(
+				ConfigFile::user_path(),
+				PathBuf::from("/config/git-interactive-rebase-tool/config.toml")
+			);
+		});
+	}
+
+	#[test]
+	fn user_path_home() {
+		with_env_var(
+			&[
+				EnvVarAction::Set("XDG_CONFIG_HOME", String::new()),
+				EnvVarAction::Set("HOME", String::from("/home/user")),
+			],
+			|| {
+				assert_some_eq!(
+					ConfigFile::user_path(),
+					PathBuf::from("/home/user/.config/git-interactive-rebase-tool/config.toml")
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn user_path_without_home() {
+		with_env_var(
+			&[EnvVarAction::Remove("XDG_CONFIG_HOME"), EnvVarAction::Remove("HOME")],
+			|| {
+				assert_none!(ConfigFile::user_path());
+			},
+		);
+	}
+
+	#[test]
+	fn repository_path() {
+		assert_eq!(
+			ConfigFile::repository_path(Path::new("/repo/.git")),
+			PathBuf::from("/repo/.git/interactive-rebase-tool.toml")
+		);
+	}
+
+	#[test]
+	fn load_missing_file() {
+		let directory = tempdir().unwrap();
+		assert_none!(ConfigFile::load(directory.path().join("config.toml").as_path()).unwrap());
+	}
+
+	#[test]
+	fn load_file() {
+		let directory = tempdir().unwrap();
+		let path = directory.path().join("config.toml");
+		fs::write(path.as_path(), "autoSelectNext = true\n").unwrap();
+		let config_file = ConfigFile::load(path.as_path()).unwrap().unwrap();
+		assert_eq!(
+			config_file.to_git_config(),
+			"[interactive-rebase-tool]\n\tautoSelectNext = \"true\"\n"
+		);
+	}
+
+	#[test]
+	fn parse_values() {
+		let config_file = parse(&[
+			"autoSelectNext = true",
+			"undoLimit = 10",
+			"diffTabSymbol = \"\\t\\\"\\\\\"",
+			"inputMoveUp = [\"Up\", \"k\"]",
+		])
+		.unwrap();
+		assert_eq!(
+			config_file.to_git_config(),
+			[
+				"[interactive-rebase-tool]",
+				"\tautoSelectNext = \"true\"",
+				"\tundoLimit = \"10\"",
+				"\tdiffTabSymbol = \"\\t\\\"\\\\\"",
+				"\tinputMoveUp = \"Up k\"",
+				""
+			]
+			.join("\n")
+		);
+	}
+
+	#[test]
+	fn parse_tables() {
+		let config_file = parse(&[
+			"[command.review]",
+			"run = \"open-review\"",
+			"[command]",
+			"\"Test.All\" = { run = \"make test\" }",
+		])
+		.unwrap();
+		assert_eq!(
+			config_file.to_git_config(),
+			[
+				"[interactive-rebase-tool \"command.review\"]",
+				"\trun = \"open-review\"",
+				"[interactive-rebase-tool \"command.Test.All\"]",
+				"\trun = \"make test\"",
+				""
+			]
+			.join("\n")
+		);
+	}
+
+	#[test]
+	fn parse_git_config_round_trip() {
+		let config_file = parse(&[
+			"diffTabSymbol = \"a\\tb\"",
+			"[command.review]",
+			"run = \"echo \\\"$INTERACTIVE_REBASE_TOOL_HASH\\\"\"",
+		])
+		.unwrap();
+		with_git_config(&[config_file.to_git_config().as_str()], |git_config| {
+			assert_eq!(
+				git_config.get_string("interactive-rebase-tool.diffTabSymbol").unwrap(),
+				"a\tb"
+			);
+			assert_eq!(
+				git_config
+					.get_string("interactive-rebase-tool.command.review.run")
+					.unwrap(),
+				"echo \"$INTERACTIVE_REBASE_TOOL_HASH\""
+			);
+		});
+	}
+
+	#[test]
+	fn parse_invalid_toml() {
+		let error = parse(&["autoSelectNext = true", "undoLimit = "]).unwrap_err();
+		assert!(
+			format!("{error}")
+				.starts_with("Provided value is invalid for 'interactive-rebase-tool' at config.toml:2: ")
+		);
+	}
+
+	#[test]
+	fn parse_unsupported_value() {
+		assert_err_eq!(
+			parse(&["", "diffTabWidth = 1.5"]),
+			ConfigError::new_read_error(
+				"interactive-rebase-tool.diffTabWidth",
+				ConfigErrorCause::UnsupportedConfigFileValue
+			)
+			.with_location(Path::new("config.toml"), 2)
+		);
+	}
+
+	#[test]
+	fn parse_unsupported_array_value() {
+		assert_err_eq!(
+			parse(&["inputMoveUp = [\"Up\", 1]"]),
+			ConfigError::new_read_error(
+				"interactive-rebase-tool.inputMoveUp",
+				ConfigErrorCause::UnsupportedConfigFileValue
+			)
+			.with_location(Path::new("config.toml"), 1)
+		);
+	}
+
+	#[test]
+	fn parse_array_of_tables() {
+		assert_err_eq!(
+			parse(&["[[command]]", "run = \"true\""]),
+			ConfigError::new_read_error(
+				"interactive-rebase-tool.command",
+				ConfigErrorCause::UnsupportedConfigFileValue
+			)
+			.with_location(Path::new("config.toml"), 1)
+		);
+	}
+
+	#[test]
+	fn parse_invalid_setting_name() {
+		assert_err_eq!(
+			parse(&["\"input move\" = \"Up\""]),
+			ConfigError::new_read_error(
+				"interactive-rebase-tool.input move",
+				ConfigErrorCause::InvalidConfigFileSetting
+			)
+			.with_location(Path::new("config.toml"), 1)
+		);
+	}
+
+	#[test]
+	fn get() {
+		let config_file = parse(&[
+			"pickColor = \"red\"",
+			"pickcolor = \"blue\"",
+			"[table]",
+			"dropColor = \"red\"",
+		])
+		.unwrap();
+		assert_some_eq!(config_file.get("pickColor"), ("blue", 2));
+		assert_none!(config_file.get("dropColor"));
+	}
+
+	#[test]
+	fn ensure_only_settings() {
+		let config_file = parse(&["pickColor = \"red\""]).unwrap();
+		assert_ok!(config_file.ensure_only_settings(&["pickColor"], ConfigErrorCause::InvalidThemeFileSetting));
+	}
+
+	#[test]
+	fn ensure_only_settings_invalid() {
+		let config_file = parse(&["pickColor = \"red\"", "undoLimit = 10"]).unwrap();
+		assert_err_eq!(
+			config_file.ensure_only_settings(&["pickColor"], ConfigErrorCause::InvalidThemeFileSetting),
+			ConfigError::new(
+				"interactive-rebase-tool.undoLimit",
+				"10",
+				ConfigErrorCause::InvalidThemeFileSetting
+			)
+			.with_location(Path::new("config.toml"), 2)
+		);
+	}
+
+	#[test]
+	fn ensure_only_settings_in_table() {
+		let config_file = parse(&["[table]", "pickColor = \"red\""]).unwrap();
+		_ = config_file
+			.ensure_only_settings(&["pickColor"], ConfigErrorCause::InvalidThemeFileSetting)
+			.unwrap_err();
+	}
+
+	#[test]
+	fn locate_error_in_file() {
+		let config_file = parse(&["", "autoSelectNext = \"invalid\""]).unwrap();
+		assert_eq!(
+			config_file.locate_error(ConfigError::new(
+				"interactive-rebase-tool.autoSelectNext",
+				"invalid",
+				ConfigErrorCause::InvalidBoolean
+			)),
+			ConfigError::new(
+				"interactive-rebase-tool.autoSelectNext",
+				"invalid",
+				ConfigErrorCause::InvalidBoolean
+			)
+			.with_location(Path::new("config.toml"), 2)
+		);
+	}
+
+	#[test]
+	fn locate_error_not_in_file() {
+		let config_file = parse(&["autoSelectNext = true"]).unwrap();
+		let error = ConfigError::new(
+			"interactive-rebase-tool.undoLimit",
+			"x",
+			ConfigErrorCause::InvalidUnsignedInteger,
+		);
+		assert_eq!(
+			config_file.locate_error(ConfigError::new(
+				"interactive-rebase-tool.undoLimit",
+				"x",
+				ConfigErrorCause::InvalidUnsignedInteger
+			)),
+			error
+		);
+	}
+}
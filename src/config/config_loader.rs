@@ -1,20 +1,98 @@
-use std::fmt::{Debug, Formatter};
+use std::{
+	fmt::{Debug, Formatter},
+	fs,
+	iter,
+};
 
-use git2::Repository;
+use git2::{ConfigLevel, Reference, Repository};
+use uuid::Uuid;
 
-use crate::git::{Config, GitError};
+use crate::{
+	config::{
+		ConfigError,
+		ConfigFile,
+		config_override::{OverrideContext, get_override_config},
+	},
+	git::{Config, GitError},
+};
 
 pub(crate) struct ConfigLoader {
 	repository: Repository,
 }
 
 impl ConfigLoader {
-	/// Load the git configuration for the repository.
+	/// Load the TOML configuration files, from the user configuration directory and the Git directory of the
+	/// repository, in order of increasing precedence.
+	///
+	/// # Errors
+	/// Will result in an error if a configuration file cannot be read or is invalid.
+	pub(crate) fn load_config_files(&self) -> Result<Vec<ConfigFile>, ConfigError> {
+		ConfigFile::user_path()
+			.into_iter()
+			.chain(iter::once(ConfigFile::repository_path(self.repository.commondir())))
+			.filter_map(|path| ConfigFile::load(path.as_path()).transpose())
+			.collect()
+	}
+
+	/// Load the git configuration for the repository, with the settings from the configuration files taking
+	/// precedence over the Git configuration, and the settings from matching override sections taking precedence
+	/// over both.
 	///
 	/// # Errors
 	/// Will result in an error if the configuration is invalid.
-	pub(crate) fn load_config(&self) -> Result<Config, GitError> {
-		self.repository.config().map_err(|e| GitError::ConfigLoad { cause: e })
+	pub(crate) fn load_config(&self, config_files: &[ConfigFile]) -> Result<Config, GitError> {
+		let mut content = config_files.iter().map(ConfigFile::to_git_config).collect::<String>();
+		let config = self.load_config_with_content(content.as_str())?;
+
+		let override_content = get_override_config(&config, &self.override_context())?;
+		if override_content.is_empty() {
+			return Ok(config);
+		}
+		// a snapshot cannot be extended, so the configuration is loaded again with the overrides appended
+		content.push_str(override_content.as_str());
+		self.load_config_with_content(content.as_str())
+	}
+
+	fn load_config_with_content(&self, content: &str) -> Result<Config, GitError> {
+		let mut config = self
+			.repository
+			.config()
+			.map_err(|e| GitError::ConfigLoad { cause: e })?;
+		if content.is_empty() {
+			return Ok(config);
+		}
+
+		// Git config can only be loaded from a file, so the additional configuration is written to a temporary
+		// file, and a snapshot of the configuration is used, so that the file can be removed.
+		let path = std::env::temp_dir().join(format!("interactive-rebase-tool-config-{}", Uuid::new_v4()));
+		fs::write(path.as_path(), content).map_err(|e| {
+			GitError::ConfigLoad {
+				cause: git2::Error::from_str(e.to_string().as_str()),
+			}
+		})?;
+		let result = config
+			.add_file(path.as_path(), ConfigLevel::App, true)
+			.and_then(|()| config.snapshot());
+		// failing to remove the temporary file is not fatal
+		drop(fs::remove_file(path.as_path()));
+		result.map_err(|e| GitError::ConfigLoad { cause: e })
+	}
+
+	fn override_context(&self) -> OverrideContext {
+		// during a rebase the head is detached, so the branch being rebased is read from the rebase state
+		let head_name = fs::read_to_string(self.repository.path().join("rebase-merge").join("head-name"))
+			.ok()
+			.or_else(|| {
+				let head = self.repository.head().ok().filter(Reference::is_branch)?;
+				head.name().map(String::from)
+			});
+		let branch = head_name
+			.as_deref()
+			.and_then(|name| name.trim_end().strip_prefix("refs/heads/"));
+		OverrideContext::new(
+			branch,
+			self.repository.workdir().unwrap_or_else(|| self.repository.path()),
+		)
 	}
 
 	pub(crate) fn eject_repository(self) -> Repository {
@@ -40,17 +118,153 @@ impl Debug for ConfigLoader {
 #[cfg(all(unix, test))]
 mod unix_tests {
 	use claims::assert_ok;
+	use tempfile::tempdir;
 
-	use crate::{
-		config::ConfigLoader,
-		test_helpers::{with_temp_bare_repository, with_temp_repository},
-	};
+	use super::*;
+	use crate::test_helpers::{EnvVarAction, with_env_var, with_temp_bare_repository, with_temp_repository};
+
+	fn without_user_config_file<C>(callback: C)
+	where C: FnOnce() {
+		let directory = tempdir().unwrap();
+		with_env_var(
+			&[EnvVarAction::Set(
+				"XDG_CONFIG_HOME",
+				String::from(directory.path().to_str().unwrap()),
+			)],
+			callback,
+		);
+	}
 
 	#[test]
 	fn load_config() {
 		with_temp_bare_repository(|repository| {
 			let config = ConfigLoader::from(repository);
-			assert_ok!(config.load_config());
+			assert_ok!(config.load_config(&[]));
+		});
+	}
+
+	#[test]
+	fn load_config_files_none() {
+		without_user_config_file(|| {
+			with_temp_repository(|repository| {
+				let loader = ConfigLoader::from(repository);
+				assert_eq!(loader.load_config_files().unwrap(), vec![]);
+			});
+		});
+	}
+
+	#[test]
+	fn load_config_with_config_files() {
+		let directory = tempdir().unwrap();
+		let user_path = directory.path().join("git-interactive-rebase-tool/config.toml");
+		fs::create_dir_all(user_path.parent().unwrap()).unwrap();
+		fs::write(user_path.as_path(), "undoLimit = 10\ndiffTabWidth = 2\n").unwrap();
+		with_env_var(
+			&[EnvVarAction::Set(
+				"XDG_CONFIG_HOME",
+				String::from(directory.path().to_str().unwrap()),
+			)],
+			|| {
+				with_temp_repository(|repository| {
+					let mut git_config = repository.config().unwrap();
+					git_config.set_i32("interactive-rebase-tool.undoLimit", 20).unwrap();
+					git_config.set_i32("interactive-rebase-tool.diffTabWidth", 20).unwrap();
+					git_config
+						.set_bool("interactive-rebase-tool.autoSelectNext", true)
+						.unwrap();
+					fs::write(
+						repository.commondir().join("interactive-rebase-tool.toml"),
+						"diffTabWidth = 3\n",
+					)
+					.unwrap();
+
+					let loader = ConfigLoader::from(repository);
+					let config_files = loader.load_config_files().unwrap();
+					assert_eq!(config_files.len(), 2);
+					let config = loader.load_config(&config_files).unwrap();
+					assert_eq!(config.get_i32("interactive-rebase-tool.undoLimit").unwrap(), 10);
+					assert_eq!(config.get_i32("interactive-rebase-tool.diffTabWidth").unwrap(), 3);
+					assert!(config.get_bool("interactive-rebase-tool.autoSelectNext").unwrap());
+				});
+			},
+		);
+	}
+
+	#[test]
+	fn load_config_with_branch_override() {
+		without_user_config_file(|| {
+			with_temp_repository(|repository| {
+				let mut git_config = repository.config().unwrap();
+				git_config.set_i32("interactive-rebase-tool.undoLimit", 10).unwrap();
+				git_config
+					.set_i32("interactive-rebase-tool.branch:main.undoLimit", 20)
+					.unwrap();
+				git_config
+					.set_i32("interactive-rebase-tool.branch:release/*.undoLimit", 30)
+					.unwrap();
+
+				let loader = ConfigLoader::from(repository);
+				let config = loader.load_config(&[]).unwrap();
+				assert_eq!(config.get_i32("interactive-rebase-tool.undoLimit").unwrap(), 20);
+			});
+		});
+	}
+
+	#[test]
+	fn load_config_with_branch_override_during_rebase() {
+		without_user_config_file(|| {
+			with_temp_repository(|repository| {
+				let mut git_config = repository.config().unwrap();
+				git_config
+					.set_i32("interactive-rebase-tool.branch:release/*.undoLimit", 30)
+					.unwrap();
+				let rebase_directory = repository.path().join("rebase-merge");
+				fs::create_dir_all(rebase_directory.as_path()).unwrap();
+				fs::write(rebase_directory.join("head-name"), "refs/heads/release/1.0\n").unwrap();
+
+				let loader = ConfigLoader::from(repository);
+				let config = loader.load_config(&[]).unwrap();
+				assert_eq!(config.get_i32("interactive-rebase-tool.undoLimit").unwrap(), 30);
+			});
+		});
+	}
+
+	#[test]
+	fn load_config_with_override_and_config_files() {
+		without_user_config_file(|| {
+			with_temp_repository(|repository| {
+				fs::write(
+					repository.commondir().join("interactive-rebase-tool.toml"),
+					"undoLimit = 10\n[\"branch:main\"]\npostModifiedLineExecCommand = \"make test\"\n",
+				)
+				.unwrap();
+
+				let loader = ConfigLoader::from(repository);
+				let config_files = loader.load_config_files().unwrap();
+				let config = loader.load_config(&config_files).unwrap();
+				assert_eq!(config.get_i32("interactive-rebase-tool.undoLimit").unwrap(), 10);
+				assert_eq!(
+					config
+						.get_string("interactive-rebase-tool.postModifiedLineExecCommand")
+						.unwrap(),
+					"make test"
+				);
+			});
+		});
+	}
+
+	#[test]
+	fn load_config_files_invalid() {
+		without_user_config_file(|| {
+			with_temp_repository(|repository| {
+				fs::write(
+					repository.commondir().join("interactive-rebase-tool.toml"),
+					"diffTabWidth = \n",
+				)
+				.unwrap();
+				let loader = ConfigLoader::from(repository);
+				_ = loader.load_config_files().unwrap_err();
+			});
 		});
 	}
 
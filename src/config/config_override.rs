@@ -0,0 +1,204 @@
+use std::{
+	env,
+	fmt::Write as _,
+	iter,
+	path::{Component, Path, PathBuf},
+};
+
+use git2::{Pathspec, PathspecFlags};
+
+use crate::{
+	config::utils::{escape_value, glob_matches},
+	git::{Config, GitError},
+};
+
+const SETTING_PREFIX: &str = "interactive-rebase-tool";
+const BRANCH_CONDITION_PREFIX: &str = "branch:";
+const PATH_CONDITION_PREFIX: &str = "path:";
+
+fn pattern_matches(pattern: &str, value: &Path) -> bool {
+	Pathspec::new(iter::once(pattern)).is_ok_and(|pathspec| pathspec.matches_path(value, PathspecFlags::DEFAULT))
+}
+
+/// The state of the repository that the conditions of override sections are matched against.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct OverrideContext {
+	branch: Option<String>,
+	path: PathBuf,
+}
+
+impl OverrideContext {
+	/// Create a new instance, from the short name of the branch and the path of the repository.
+	pub(crate) fn new(branch: Option<&str>, path: &Path) -> Self {
+		Self {
+			branch: branch.map(String::from),
+			// pathspecs only match relative paths, so the path is matched relative to the root
+			path: path
+				.components()
+				.filter(|component| matches!(component, Component::Normal(_)))
+				.collect(),
+		}
+	}
+
+	/// Check if the condition of an override section, such as `branch:release/*`, matches.
+	fn matches(&self, condition: &str) -> bool {
+		if let Some(pattern) = condition.strip_prefix(BRANCH_CONDITION_PREFIX) {
+			// like the `onbranch` condition of Git, a pattern ending in a slash matches all branches under it
+			let pattern = if pattern.ends_with('/') {
+				format!("{pattern}**")
+			}
+			else {
+				String::from(pattern)
+			};
+			self.branch
+				.as_deref()
+				.is_some_and(|branch| glob_matches(pattern.as_str(), branch))
+		}
+		else if let Some(pattern) = condition.strip_prefix(PATH_CONDITION_PREFIX) {
+			let pattern = match (pattern.strip_prefix("~/"), env::var("HOME")) {
+				(Some(relative), Ok(home)) => format!("{home}/{relative}"),
+				_ => String::from(pattern),
+			};
+			pattern_matches(pattern.trim_matches('/'), self.path.as_path())
+		}
+		else {
+			false
+		}
+	}
+}
+
+/// Build the settings of the override sections with a matching condition, as Git config content. The settings
+/// are in the order that they are defined, so later sections take precedence over earlier sections.
+///
+/// # Errors
+/// Will result in an error if the configuration cannot be read.
+pub(crate) fn get_override_config(config: &Config, context: &OverrideContext) -> Result<String, GitError> {
+	let mut output = String::new();
+	let mut entries = config
+		.entries(Some(r"^interactive-rebase-tool\.(branch|path):.*\.[^.]+$"))
+		.map_err(|cause| GitError::ConfigLoad { cause })?;
+	while let Some(entry) = entries.next() {
+		let entry = entry.map_err(|cause| GitError::ConfigLoad { cause })?;
+		let Some((condition, variable)) = entry
+			.name()
+			.and_then(|name| name.strip_prefix(SETTING_PREFIX))
+			.and_then(|name| name.strip_prefix('.'))
+			.and_then(|name| name.rsplit_once('.'))
+		else {
+			continue;
+		};
+		if !context.matches(condition) {
+			continue;
+		}
+		if entry.has_value() {
+			_ = writeln!(
+				output,
+				"\t{variable} = \"{}\"",
+				escape_value(String::from_utf8_lossy(entry.value_bytes()).as_ref())
+			);
+		}
+		else {
+			// a variable without a value is a true boolean
+			_ = writeln!(output, "\t{variable}");
+		}
+	}
+	if !output.is_empty() {
+		output.insert_str(0, format!("[{SETTING_PREFIX}]\n").as_str());
+	}
+	Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+	use crate::test_helpers::{EnvVarAction, with_env_var, with_git_config};
+
+	#[rstest]
+	#[case::branch_exact("branch:main", true)]
+	#[case::branch_glob("branch:release/*", false)]
+	#[case::branch_other("branch:develop", false)]
+	#[case::path_exact("path:/work/project", true)]
+	#[case::path_glob("path:/work/*", true)]
+	#[case::path_directory("path:/work/", true)]
+	#[case::path_other("path:/other/*", false)]
+	#[case::unknown("other:main", false)]
+	fn matches(#[case] condition: &str, #[case] expected: bool) {
+		let context = OverrideContext::new(Some("main"), Path::new("/work/project/"));
+		assert_eq!(context.matches(condition), expected);
+	}
+
+	#[rstest]
+	#[case::nested_glob("branch:release/*/hotfix", true)]
+	#[case::prefix("branch:release", false)]
+	#[case::prefix_directory("branch:release/", true)]
+	#[case::single_level_glob("branch:release/*", false)]
+	#[case::multi_level_glob("branch:release/**", true)]
+	#[case::other("branch:feature/*", false)]
+	fn matches_branch_pattern(#[case] condition: &str, #[case] expected: bool) {
+		let context = OverrideContext::new(Some("release/1.0/hotfix"), Path::new("/work/project"));
+		assert_eq!(context.matches(condition), expected);
+	}
+
+	#[test]
+	fn matches_without_branch() {
+		let context = OverrideContext::new(None, Path::new("/work/project"));
+		assert!(!context.matches("branch:*"));
+	}
+
+	#[test]
+	fn matches_path_in_home() {
+		with_env_var(&[EnvVarAction::Set("HOME", String::from("/home/user"))], || {
+			let context = OverrideContext::new(None, Path::new("/home/user/work/project"));
+			assert!(context.matches("path:~/work/*"));
+		});
+	}
+
+	#[test]
+	fn get_override_config_matching() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool]",
+				"undoLimit = 10",
+				"[interactive-rebase-tool \"branch:release/*\"]",
+				"postModifiedLineExecCommand = \"make \\\"test\\\"\"",
+				"autoSelectNext",
+				"[interactive-rebase-tool \"branch:main\"]",
+				"undoLimit = 20",
+				"[interactive-rebase-tool \"path:/work/*\"]",
+				"undoLimit = 30",
+			],
+			|git_config| {
+				let context = OverrideContext::new(Some("release/1.0"), Path::new("/work/project"));
+				assert_eq!(
+					get_override_config(&git_config, &context).unwrap(),
+					"[interactive-rebase-tool]\n\tpostmodifiedlineexeccommand = \"make \
+					 \\\"test\\\"\"\n\tautoselectnext\n\tundolimit = \"30\"\n"
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn get_override_config_none_matching() {
+		with_git_config(
+			&["[interactive-rebase-tool \"branch:main\"]", "undoLimit = 20"],
+			|git_config| {
+				let context = OverrideContext::new(Some("develop"), Path::new("/work/project"));
+				assert_eq!(get_override_config(&git_config, &context).unwrap(), "");
+			},
+		);
+	}
+
+	#[test]
+	fn get_override_config_ignores_custom_commands() {
+		with_git_config(
+			&["[interactive-rebase-tool \"command.review\"]", "run = review"],
+			|git_config| {
+				let context = OverrideContext::new(Some("main"), Path::new("/work/project"));
+				assert_eq!(get_override_config(&git_config, &context).unwrap(), "");
+			},
+		);
+	}
+}
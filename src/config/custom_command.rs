@@ -0,0 +1,156 @@
+use crate::{
+	config::{
+		ConfigError,
+		ConfigErrorCause,
+		utils::{get_bool, get_input, get_string},
+	},
+	git::{Config, GitError},
+};
+
+const CUSTOM_COMMAND_PREFIX: &str = "interactive-rebase-tool.command.";
+const CUSTOM_COMMAND_RUN_SUFFIX: &str = ".run";
+
+/// Represents a user defined command, run on the selected line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub(crate) struct CustomCommand {
+	/// The name of the command, from the subsection of the configuration.
+	pub(crate) name: String,
+	/// Key bindings for running the command.
+	pub(crate) key: Vec<String>,
+	/// The shell command to run.
+	pub(crate) run: String,
+	/// If to reload the todo file after the command has run.
+	pub(crate) reload: bool,
+}
+
+impl CustomCommand {
+	fn new_with_config(git_config: Option<&Config>, name: &str) -> Result<Self, ConfigError> {
+		let config_name = format!("{CUSTOM_COMMAND_PREFIX}{name}");
+		Ok(Self {
+			name: String::from(name),
+			key: get_input(git_config, format!("{config_name}.key").as_str(), "")?,
+			run: get_string(git_config, format!("{config_name}.run").as_str(), "")?,
+			reload: get_bool(git_config, format!("{config_name}.reload").as_str(), false)?,
+		})
+	}
+}
+
+/// Load all custom commands, in the order that they are first defined.
+pub(super) fn get_custom_commands(git_config: Option<&Config>) -> Result<Vec<CustomCommand>, ConfigError> {
+	let Some(config) = git_config
+	else {
+		return Ok(vec![]);
+	};
+
+	let map_error = |cause| {
+		ConfigError::new_read_error(
+			"interactive-rebase-tool.command",
+			ConfigErrorCause::GitError(GitError::ConfigLoad { cause }),
+		)
+	};
+
+	let mut names: Vec<String> = vec![];
+	let mut entries = config
+		.entries(Some(r"^interactive-rebase-tool\.command\..+\.run$"))
+		.map_err(map_error)?;
+	while let Some(entry) = entries.next() {
+		let entry = entry.map_err(map_error)?;
+		let name = entry
+			.name()
+			.and_then(|n| n.strip_prefix(CUSTOM_COMMAND_PREFIX))
+			.and_then(|n| n.strip_suffix(CUSTOM_COMMAND_RUN_SUFFIX));
+		if let Some(name) = name {
+			if !names.iter().any(|n| n == name) {
+				names.push(String::from(name));
+			}
+		}
+	}
+
+	names
+		.iter()
+		.map(|name| CustomCommand::new_with_config(git_config, name))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_err_eq, assert_ok_eq};
+
+	use super::*;
+	use crate::test_helpers::with_git_config;
+
+	#[test]
+	fn get_custom_commands_without_config() {
+		assert_ok_eq!(get_custom_commands(None), vec![]);
+	}
+
+	#[test]
+	fn get_custom_commands_empty() {
+		with_git_config(&[], |git_config| {
+			assert_ok_eq!(get_custom_commands(Some(&git_config)), vec![]);
+		});
+	}
+
+	#[test]
+	fn get_custom_commands_with_commands() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool \"command.review\"]",
+				"key = R",
+				"run = open-review $INTERACTIVE_REBASE_TOOL_HASH",
+				"[interactive-rebase-tool \"command.Test.All\"]",
+				"key = Control+t t,t",
+				"run = make test",
+				"reload = true",
+			],
+			|git_config| {
+				assert_ok_eq!(get_custom_commands(Some(&git_config)), vec![
+					CustomCommand {
+						name: String::from("review"),
+						key: vec![String::from("R")],
+						run: String::from("open-review $INTERACTIVE_REBASE_TOOL_HASH"),
+						reload: false,
+					},
+					CustomCommand {
+						name: String::from("Test.All"),
+						key: vec![String::from("Controlt"), String::from("t t")],
+						run: String::from("make test"),
+						reload: true,
+					},
+				]);
+			},
+		);
+	}
+
+	#[test]
+	fn get_custom_commands_without_run() {
+		with_git_config(
+			&["[interactive-rebase-tool \"command.review\"]", "key = R"],
+			|git_config| {
+				assert_ok_eq!(get_custom_commands(Some(&git_config)), vec![]);
+			},
+		);
+	}
+
+	#[test]
+	fn get_custom_commands_invalid_key() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool \"command.review\"]",
+				"key = Control+invalid",
+				"run = true",
+			],
+			|git_config| {
+				assert_err_eq!(
+					get_custom_commands(Some(&git_config)),
+					ConfigError::new(
+						"interactive-rebase-tool.command.review.key",
+						"Control+invalid",
+						ConfigErrorCause::InvalidKeyBinding
+					)
+				);
+			},
+		);
+	}
+}
@@ -6,7 +6,10 @@
 mod config_error_cause;
 mod invalid_color;
 
-use std::fmt::{Display, Formatter};
+use std::{
+	fmt::{Display, Formatter},
+	path::{Path, PathBuf},
+};
 
 use thiserror::Error;
 
@@ -18,6 +21,7 @@ pub(crate) use self::{config_error_cause::ConfigErrorCause, invalid_color::Inval
 pub(crate) struct ConfigError {
 	name: String,
 	input: Option<String>,
+	location: Option<(PathBuf, usize)>,
 	#[source]
 	cause: ConfigErrorCause,
 }
@@ -27,6 +31,7 @@ impl ConfigError {
 		Self {
 			name: String::from(name),
 			input: Some(String::from(input)),
+			location: None,
 			cause,
 		}
 	}
@@ -35,6 +40,7 @@ impl ConfigError {
 		Self {
 			name: String::from(name),
 			input,
+			location: None,
 			cause,
 		}
 	}
@@ -43,22 +49,43 @@ impl ConfigError {
 		Self {
 			name: String::from(name),
 			input: None,
+			location: None,
 			cause,
 		}
 	}
+
+	/// Set the file and line where the invalid value is defined.
+	#[must_use]
+	pub(crate) fn with_location(mut self, path: &Path, line: usize) -> Self {
+		self.location = Some((PathBuf::from(path), line));
+		self
+	}
+
+	pub(crate) fn name(&self) -> &str {
+		self.name.as_str()
+	}
 }
 
 impl Display for ConfigError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let location = self
+			.location
+			.as_ref()
+			.map(|&(ref path, line)| format!(" at {}:{line}", path.display()))
+			.unwrap_or_default();
 		if let Some(input) = self.input.as_deref() {
 			write!(
 				f,
-				"Provided value '{input}' is invalid for '{}': {}.",
+				"Provided value '{input}' is invalid for '{}'{location}: {}.",
 				self.name, self.cause
 			)
 		}
 		else {
-			write!(f, "Provided value is invalid for '{}': {}.", self.name, self.cause)
+			write!(
+				f,
+				"Provided value is invalid for '{}'{location}: {}.",
+				self.name, self.cause
+			)
 		}
 	}
 }
@@ -111,6 +138,18 @@ mod tests {
 			"Provided value 'input' is invalid for 'name': The input provided is not a valid boolean value."
 		);
 	}
+	#[test]
+	fn display_valid_input_with_location() {
+		let err = ConfigError::new("name", "input", ConfigErrorCause::InvalidBoolean)
+			.with_location(Path::new("config.toml"), 3);
+
+		assert_eq!(
+			format!("{err}"),
+			"Provided value 'input' is invalid for 'name' at config.toml:3: The input provided is not a valid boolean \
+			 value."
+		);
+	}
+
 	#[test]
 	fn display_valid_input_without_input() {
 		let err = ConfigError::new_read_error("name", ConfigErrorCause::InvalidBoolean);
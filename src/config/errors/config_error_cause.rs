@@ -30,6 +30,33 @@ pub(crate) enum ConfigErrorCause {
 	/// The input provided is not a valid input keybinding.
 	#[error("The input provided is not a valid input keybinding.")]
 	InvalidKeyBinding,
+	/// The input provided is not a valid key preset.
+	#[error("Must match one of 'default', 'vim' or 'emacs'")]
+	InvalidKeyPreset,
+	/// The input provided is not a valid theme preset.
+	#[error("Must match one of 'default', 'light', 'dark', 'solarized', 'high-contrast' or 'colorblind-safe'")]
+	InvalidThemePreset,
+	/// The theme file could not be found.
+	#[error("The theme file does not exist")]
+	ThemeFileNotFound,
+	/// The setting in the theme file is not a theme setting.
+	#[error("Only colors and the vertical spacing character can be set in a theme file")]
+	InvalidThemeFileSetting,
+	/// The input provided is not a valid line format.
+	#[error("{0}")]
+	InvalidLineFormat(String),
+	/// The input provided is a key binding that is already used by another action in the same view.
+	#[error("The key binding is already used by '{0}'")]
+	KeyBindingConflict(String),
+	/// The configuration file could not be read or parsed.
+	#[error("{0}")]
+	InvalidConfigFile(String),
+	/// The value in the configuration file is not a type that can be used for a setting.
+	#[error("Must be a string, boolean, integer or an array of strings")]
+	UnsupportedConfigFileValue,
+	/// The setting name in the configuration file is not a valid setting name.
+	#[error("Setting names must start with a letter and only contain letters, numbers and dashes")]
+	InvalidConfigFileSetting,
 	/// The input provided is not valid UTF.
 	#[error("The input provided is not valid UTF")]
 	InvalidUtf,
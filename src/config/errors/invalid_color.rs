@@ -16,6 +16,9 @@ pub(crate) enum InvalidColorError {
 	/// The blue color is invalid.
 	#[error("Blue color value must be between 0-255")]
 	Blue,
+	/// The hex color is invalid.
+	#[error("Hex color value must be in the form #rrggbb")]
+	Hex,
 	/// An unknown color was used.
 	#[error("Unknown color value")]
 	Invalid,
@@ -1,8 +1,16 @@
 use crate::{
-	config::{ConfigError, utils::get_input},
+	config::{
+		ConfigError,
+		ConfigErrorCause,
+		CustomCommand,
+		custom_command::get_custom_commands,
+		utils::{get_input, get_key_preset},
+	},
 	git::Config,
 };
 
+const SETTING_PREFIX: &str = "interactive-rebase-tool.";
+
 fn map_single_ascii_to_lower(s: &str) -> String {
 	if s.is_ascii() && s.len() == 1 {
 		s.to_lowercase()
@@ -12,6 +20,36 @@ fn map_single_ascii_to_lower(s: &str) -> String {
 	}
 }
 
+/// Format a key binding, as stored in the configuration, in the format used to configure it.
+pub(crate) fn format_key_binding(binding: &str) -> String {
+	if binding.contains(' ') {
+		return binding.replace(' ', ",");
+	}
+
+	let mut parts = vec![];
+	let mut key = binding;
+	for modifier in ["Control", "Alt", "Shift"] {
+		if let Some(rest) = key.strip_prefix(modifier) {
+			if !rest.is_empty() {
+				parts.push(modifier);
+				key = rest;
+			}
+		}
+	}
+	parts.push(key);
+	parts.join("+")
+}
+
+/// The key bindings used by a single view, where each key may only be bound to a single action.
+#[derive(Debug)]
+#[non_exhaustive]
+pub(crate) struct ViewKeyBindings<'key_bindings> {
+	/// The name of the view.
+	pub(crate) name: &'static str,
+	/// The setting name, without the `interactive-rebase-tool.` prefix, and key bindings of each action.
+	pub(crate) bindings: Vec<(String, &'key_bindings [String])>,
+}
+
 /// Represents the key binding configuration options.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
@@ -28,6 +66,8 @@ pub(crate) struct KeyBindings {
 	pub(crate) action_fixup: Vec<String>,
 	/// Key bindings for the pick action.
 	pub(crate) action_pick: Vec<String>,
+	/// Key bindings for the revert action.
+	pub(crate) action_revert: Vec<String>,
 	/// Key bindings for the reword action.
 	pub(crate) action_reword: Vec<String>,
 	/// Key bindings for the squash action.
@@ -36,6 +76,16 @@ pub(crate) struct KeyBindings {
 	pub(crate) confirm_no: Vec<String>,
 	/// Key bindings for positive confirmation.
 	pub(crate) confirm_yes: Vec<String>,
+	/// Key bindings for aborting the commit in the commit message editor.
+	pub(crate) commit_message_abort: Vec<String>,
+	/// Key bindings for showing the help in the commit message editor.
+	pub(crate) commit_message_help: Vec<String>,
+	/// Key bindings for previewing the message in the commit message editor.
+	pub(crate) commit_message_preview: Vec<String>,
+	/// Key bindings for saving the message in the commit message editor.
+	pub(crate) commit_message_save: Vec<String>,
+	/// Key bindings for adding a trailer in the commit message editor.
+	pub(crate) commit_message_trailer: Vec<String>,
 	/// Key bindings for editing.
 	pub(crate) edit: Vec<String>,
 	/// Key bindings for forcing a abort.
@@ -93,6 +143,8 @@ pub(crate) struct KeyBindings {
 	pub(crate) rebase: Vec<String>,
 	/// Key bindings for redoing a change.
 	pub(crate) redo: Vec<String>,
+	/// Key bindings for reloading the configuration.
+	pub(crate) reload_config: Vec<String>,
 	/// Key bindings for removing a line.
 	pub(crate) remove_line: Vec<String>,
 	/// Key bindings for starting search.
@@ -105,6 +157,10 @@ pub(crate) struct KeyBindings {
 	pub(crate) show_commit: Vec<String>,
 	/// Key bindings for showing a diff.
 	pub(crate) show_diff: Vec<String>,
+	/// Key bindings for showing the range diff.
+	pub(crate) show_range_diff: Vec<String>,
+	/// Key bindings for toggling the excluded files in a diff.
+	pub(crate) toggle_excluded_files: Vec<String>,
 	/// Key bindings for toggling visual mode.
 	pub(crate) toggle_visual_mode: Vec<String>,
 	/// Key bindings for undoing a change.
@@ -113,71 +169,240 @@ pub(crate) struct KeyBindings {
 	pub(crate) fixup_keep_message_with_editor: Vec<String>,
 	/// Key bindings for the fixup specific action to toggle the c option.
 	pub(crate) fixup_keep_message: Vec<String>,
+	/// User defined commands, with their key bindings.
+	pub(crate) custom_commands: Vec<CustomCommand>,
 }
 
 impl KeyBindings {
 	pub(super) fn new_with_config(git_config: Option<&Config>) -> Result<Self, ConfigError> {
-		let confirm_no = get_input(git_config, "interactive-rebase-tool.inputConfirmNo", "n")?
+		let preset = get_key_preset(git_config, "interactive-rebase-tool.keyPreset")?;
+		// a preset only changes the default, so individual settings still take precedence
+		let input = |name: &str, default: &str| {
+			let default = name
+				.strip_prefix(SETTING_PREFIX)
+				.and_then(|setting| preset.default_key_binding(setting))
+				.unwrap_or(default);
+			get_input(git_config, name, default)
+		};
+		let confirm_no = input("interactive-rebase-tool.inputConfirmNo", "n")?
 			.iter()
 			.map(|s| map_single_ascii_to_lower(s))
 			.collect();
-		let confirm_yes = get_input(git_config, "interactive-rebase-tool.inputConfirmYes", "y")?
+		let confirm_yes = input("interactive-rebase-tool.inputConfirmYes", "y")?
 			.iter()
 			.map(|s| map_single_ascii_to_lower(s))
 			.collect();
-		Ok(Self {
-			abort: get_input(git_config, "interactive-rebase-tool.inputAbort", "q")?,
-			action_break: get_input(git_config, "interactive-rebase-tool.inputActionBreak", "b")?,
-			action_drop: get_input(git_config, "interactive-rebase-tool.inputActionDrop", "d")?,
-			action_edit: get_input(git_config, "interactive-rebase-tool.inputActionEdit", "e")?,
-			action_fixup: get_input(git_config, "interactive-rebase-tool.inputActionFixup", "f")?,
-			action_pick: get_input(git_config, "interactive-rebase-tool.inputActionPick", "p")?,
-			action_reword: get_input(git_config, "interactive-rebase-tool.inputActionReword", "r")?,
-			action_squash: get_input(git_config, "interactive-rebase-tool.inputActionSquash", "s")?,
+		let key_bindings = Self {
+			abort: input("interactive-rebase-tool.inputAbort", "q")?,
+			action_break: input("interactive-rebase-tool.inputActionBreak", "b")?,
+			action_drop: input("interactive-rebase-tool.inputActionDrop", "d")?,
+			action_edit: input("interactive-rebase-tool.inputActionEdit", "e")?,
+			action_fixup: input("interactive-rebase-tool.inputActionFixup", "f")?,
+			action_pick: input("interactive-rebase-tool.inputActionPick", "p")?,
+			action_revert: input("interactive-rebase-tool.inputActionRevert", "V")?,
+			action_reword: input("interactive-rebase-tool.inputActionReword", "r")?,
+			action_squash: input("interactive-rebase-tool.inputActionSquash", "s")?,
 			confirm_no,
 			confirm_yes,
-			edit: get_input(git_config, "interactive-rebase-tool.inputEdit", "E")?,
-			force_abort: get_input(git_config, "interactive-rebase-tool.inputForceAbort", "Q")?,
-			force_rebase: get_input(git_config, "interactive-rebase-tool.inputForceRebase", "W")?,
-			help: get_input(git_config, "interactive-rebase-tool.inputHelp", "?")?,
-			insert_line: get_input(git_config, "interactive-rebase-tool.insertLine", "I")?,
-			duplicate_line: get_input(git_config, "interactive-rebase-tool.inputDuplicateLine", "control+d")?,
-			move_down: get_input(git_config, "interactive-rebase-tool.inputMoveDown", "Down")?,
-			move_end: get_input(git_config, "interactive-rebase-tool.inputMoveEnd", "End")?,
-			move_home: get_input(git_config, "interactive-rebase-tool.inputMoveHome", "Home")?,
-			move_left: get_input(git_config, "interactive-rebase-tool.inputMoveLeft", "Left")?,
-			move_right: get_input(git_config, "interactive-rebase-tool.inputMoveRight", "Right")?,
-			move_down_step: get_input(git_config, "interactive-rebase-tool.inputMoveStepDown", "PageDown")?,
-			move_up_step: get_input(git_config, "interactive-rebase-tool.inputMoveStepUp", "PageUp")?,
-			move_up: get_input(git_config, "interactive-rebase-tool.inputMoveUp", "Up")?,
-			move_selection_down: get_input(git_config, "interactive-rebase-tool.inputMoveSelectionDown", "j")?,
-			move_selection_up: get_input(git_config, "interactive-rebase-tool.inputMoveSelectionUp", "k")?,
-			scroll_down: get_input(git_config, "interactive-rebase-tool.inputScrollDown", "Down")?,
-			scroll_end: get_input(git_config, "interactive-rebase-tool.inputScrollEnd", "End")?,
-			scroll_home: get_input(git_config, "interactive-rebase-tool.inputScrollHome", "Home")?,
-			scroll_left: get_input(git_config, "interactive-rebase-tool.inputScrollLeft", "Left")?,
-			scroll_right: get_input(git_config, "interactive-rebase-tool.inputScrollRight", "Right")?,
-			scroll_up: get_input(git_config, "interactive-rebase-tool.inputScrollUp", "Up")?,
-			scroll_step_down: get_input(git_config, "interactive-rebase-tool.inputScrollStepDown", "PageDown")?,
-			scroll_step_up: get_input(git_config, "interactive-rebase-tool.inputScrollStepUp", "PageUp")?,
-			open_in_external_editor: get_input(git_config, "interactive-rebase-tool.inputOpenInExternalEditor", "!")?,
-			rebase: get_input(git_config, "interactive-rebase-tool.inputRebase", "w")?,
-			redo: get_input(git_config, "interactive-rebase-tool.inputRedo", "control+y")?,
-			remove_line: get_input(git_config, "interactive-rebase-tool.removeLine", "delete")?,
-			search_start: get_input(git_config, "interactive-rebase-tool.searchStart", "/")?,
-			search_next: get_input(git_config, "interactive-rebase-tool.searchNext", "n")?,
-			search_previous: get_input(git_config, "interactive-rebase-tool.searchPrevious", "N")?,
-			show_commit: get_input(git_config, "interactive-rebase-tool.inputShowCommit", "c")?,
-			show_diff: get_input(git_config, "interactive-rebase-tool.inputShowDiff", "d")?,
-			toggle_visual_mode: get_input(git_config, "interactive-rebase-tool.inputToggleVisualMode", "v")?,
-			undo: get_input(git_config, "interactive-rebase-tool.inputUndo", "control+z")?,
-			fixup_keep_message_with_editor: get_input(
-				git_config,
-				"interactive-rebase-tool.fixupKeepMessageWithEditor",
-				"U",
-			)?,
-			fixup_keep_message: get_input(git_config, "interactive-rebase-tool.fixupKeepMessage", "u")?,
-		})
+			commit_message_abort: input("interactive-rebase-tool.inputCommitMessageAbort", "Control+q")?,
+			commit_message_help: input("interactive-rebase-tool.inputCommitMessageHelp", "F1")?,
+			commit_message_preview: input("interactive-rebase-tool.inputCommitMessagePreview", "Control+p")?,
+			commit_message_save: input("interactive-rebase-tool.inputCommitMessageSave", "Control+s")?,
+			commit_message_trailer: input("interactive-rebase-tool.inputCommitMessageTrailer", "Control+t")?,
+			edit: input("interactive-rebase-tool.inputEdit", "E")?,
+			force_abort: input("interactive-rebase-tool.inputForceAbort", "Q")?,
+			force_rebase: input("interactive-rebase-tool.inputForceRebase", "W")?,
+			help: input("interactive-rebase-tool.inputHelp", "?")?,
+			insert_line: input("interactive-rebase-tool.insertLine", "I")?,
+			duplicate_line: input("interactive-rebase-tool.inputDuplicateLine", "control+d")?,
+			move_down: input("interactive-rebase-tool.inputMoveDown", "Down")?,
+			move_end: input("interactive-rebase-tool.inputMoveEnd", "End")?,
+			move_home: input("interactive-rebase-tool.inputMoveHome", "Home")?,
+			move_left: input("interactive-rebase-tool.inputMoveLeft", "Left")?,
+			move_right: input("interactive-rebase-tool.inputMoveRight", "Right")?,
+			move_down_step: input("interactive-rebase-tool.inputMoveStepDown", "PageDown")?,
+			move_up_step: input("interactive-rebase-tool.inputMoveStepUp", "PageUp")?,
+			move_up: input("interactive-rebase-tool.inputMoveUp", "Up")?,
+			move_selection_down: input("interactive-rebase-tool.inputMoveSelectionDown", "j")?,
+			move_selection_up: input("interactive-rebase-tool.inputMoveSelectionUp", "k")?,
+			scroll_down: input("interactive-rebase-tool.inputScrollDown", "Down")?,
+			scroll_end: input("interactive-rebase-tool.inputScrollEnd", "End")?,
+			scroll_home: input("interactive-rebase-tool.inputScrollHome", "Home")?,
+			scroll_left: input("interactive-rebase-tool.inputScrollLeft", "Left")?,
+			scroll_right: input("interactive-rebase-tool.inputScrollRight", "Right")?,
+			scroll_up: input("interactive-rebase-tool.inputScrollUp", "Up")?,
+			scroll_step_down: input("interactive-rebase-tool.inputScrollStepDown", "PageDown")?,
+			scroll_step_up: input("interactive-rebase-tool.inputScrollStepUp", "PageUp")?,
+			open_in_external_editor: input("interactive-rebase-tool.inputOpenInExternalEditor", "!")?,
+			rebase: input("interactive-rebase-tool.inputRebase", "w")?,
+			redo: input("interactive-rebase-tool.inputRedo", "control+y")?,
+			reload_config: input("interactive-rebase-tool.inputReloadConfig", "F5")?,
+			remove_line: input("interactive-rebase-tool.removeLine", "delete")?,
+			search_start: input("interactive-rebase-tool.searchStart", "/")?,
+			search_next: input("interactive-rebase-tool.searchNext", "n")?,
+			search_previous: input("interactive-rebase-tool.searchPrevious", "N")?,
+			show_commit: input("interactive-rebase-tool.inputShowCommit", "c")?,
+			show_diff: input("interactive-rebase-tool.inputShowDiff", "d")?,
+			show_range_diff: input("interactive-rebase-tool.inputShowRangeDiff", "R")?,
+			toggle_excluded_files: input("interactive-rebase-tool.inputToggleExcludedFiles", "x")?,
+			toggle_visual_mode: input("interactive-rebase-tool.inputToggleVisualMode", "v")?,
+			undo: input("interactive-rebase-tool.inputUndo", "control+z")?,
+			fixup_keep_message_with_editor: input("interactive-rebase-tool.fixupKeepMessageWithEditor", "U")?,
+			fixup_keep_message: input("interactive-rebase-tool.fixupKeepMessage", "u")?,
+			custom_commands: get_custom_commands(git_config)?,
+		};
+		key_bindings.check_conflicts()?;
+		Ok(key_bindings)
+	}
+
+	/// Get the key shown in the title as the indicator for help, which is the first help key binding.
+	pub(crate) fn help_indicator_key(&self) -> &str {
+		self.help.first().map_or("?", String::as_str)
+	}
+
+	/// Get the key bindings of each view, along with the name of the setting of each binding.
+	pub(crate) fn views(&self) -> Vec<ViewKeyBindings<'_>> {
+		let scroll = [
+			("inputScrollDown", &self.scroll_down),
+			("inputScrollEnd", &self.scroll_end),
+			("inputScrollHome", &self.scroll_home),
+			("inputScrollLeft", &self.scroll_left),
+			("inputScrollRight", &self.scroll_right),
+			("inputScrollUp", &self.scroll_up),
+			("inputScrollStepDown", &self.scroll_step_down),
+			("inputScrollStepUp", &self.scroll_step_up),
+		];
+
+		let list = [
+			("inputAbort", &self.abort),
+			("inputActionBreak", &self.action_break),
+			("inputActionDrop", &self.action_drop),
+			("inputActionEdit", &self.action_edit),
+			("inputActionFixup", &self.action_fixup),
+			("inputActionPick", &self.action_pick),
+			("inputActionRevert", &self.action_revert),
+			("inputActionReword", &self.action_reword),
+			("inputActionSquash", &self.action_squash),
+			("inputEdit", &self.edit),
+			("inputForceAbort", &self.force_abort),
+			("inputForceRebase", &self.force_rebase),
+			("inputHelp", &self.help),
+			("insertLine", &self.insert_line),
+			("inputDuplicateLine", &self.duplicate_line),
+			("inputMoveDown", &self.move_down),
+			("inputMoveEnd", &self.move_end),
+			("inputMoveHome", &self.move_home),
+			("inputMoveLeft", &self.move_left),
+			("inputMoveRight", &self.move_right),
+			("inputMoveUp", &self.move_up),
+			("inputMoveStepDown", &self.move_down_step),
+			("inputMoveStepUp", &self.move_up_step),
+			("inputMoveSelectionDown", &self.move_selection_down),
+			("inputMoveSelectionUp", &self.move_selection_up),
+			("inputOpenInExternalEditor", &self.open_in_external_editor),
+			("inputRebase", &self.rebase),
+			("inputRedo", &self.redo),
+			("inputReloadConfig", &self.reload_config),
+			("removeLine", &self.remove_line),
+			("searchStart", &self.search_start),
+			("searchNext", &self.search_next),
+			("searchPrevious", &self.search_previous),
+			("inputShowCommit", &self.show_commit),
+			("inputShowRangeDiff", &self.show_range_diff),
+			("inputToggleVisualMode", &self.toggle_visual_mode),
+			("inputUndo", &self.undo),
+			("fixupKeepMessageWithEditor", &self.fixup_keep_message_with_editor),
+			("fixupKeepMessage", &self.fixup_keep_message),
+		]
+		.into_iter()
+		.map(|(name, keys)| (String::from(name), keys.as_slice()))
+		.chain(
+			self.custom_commands
+				.iter()
+				.map(|command| (format!("command.{}.key", command.name), command.key.as_slice())),
+		)
+		.collect();
+
+		let show_commit = scroll
+			.into_iter()
+			.chain([
+				("inputShowDiff", &self.show_diff),
+				("inputToggleExcludedFiles", &self.toggle_excluded_files),
+				("inputHelp", &self.help),
+				("inputUndo", &self.undo),
+				("inputRedo", &self.redo),
+			])
+			.map(|(name, keys)| (String::from(name), keys.as_slice()))
+			.collect();
+
+		let range_diff = scroll
+			.into_iter()
+			.chain([("inputHelp", &self.help)])
+			.map(|(name, keys)| (String::from(name), keys.as_slice()))
+			.collect();
+
+		let commit_message = [
+			("inputCommitMessageAbort", &self.commit_message_abort),
+			("inputCommitMessageHelp", &self.commit_message_help),
+			("inputCommitMessagePreview", &self.commit_message_preview),
+			("inputCommitMessageSave", &self.commit_message_save),
+			("inputCommitMessageTrailer", &self.commit_message_trailer),
+		]
+		.into_iter()
+		.map(|(name, keys)| (String::from(name), keys.as_slice()))
+		.collect();
+
+		let confirm = [
+			("inputConfirmYes", &self.confirm_yes),
+			("inputConfirmNo", &self.confirm_no),
+		]
+		.into_iter()
+		.map(|(name, keys)| (String::from(name), keys.as_slice()))
+		.collect();
+
+		vec![
+			ViewKeyBindings {
+				name: "List",
+				bindings: list,
+			},
+			ViewKeyBindings {
+				name: "Show Commit",
+				bindings: show_commit,
+			},
+			ViewKeyBindings {
+				name: "Range Diff",
+				bindings: range_diff,
+			},
+			ViewKeyBindings {
+				name: "Commit Message",
+				bindings: commit_message,
+			},
+			ViewKeyBindings {
+				name: "Confirm",
+				bindings: confirm,
+			},
+		]
+	}
+
+	// a key bound to multiple actions in the same view would only ever trigger one of the actions
+	fn check_conflicts(&self) -> Result<(), ConfigError> {
+		for view in self.views() {
+			for (index, &(ref name, keys)) in view.bindings.iter().enumerate() {
+				for key in keys {
+					let conflict = view.bindings[..index]
+						.iter()
+						.find(|&&(_, other_keys)| other_keys.contains(key));
+					if let Some((other_name, _)) = conflict {
+						return Err(ConfigError::new(
+							format!("{SETTING_PREFIX}{name}").as_str(),
+							format_key_binding(key).as_str(),
+							ConfigErrorCause::KeyBindingConflict(format!("{SETTING_PREFIX}{other_name}")),
+						));
+					}
+				}
+			}
+		}
+		Ok(())
 	}
 }
 
@@ -191,7 +416,8 @@ impl TryFrom<&Config> for KeyBindings {
 
 #[cfg(test)]
 mod tests {
-	use claims::assert_ok;
+	use claims::{assert_err_eq, assert_ok};
+	use rstest::rstest;
 
 	use super::*;
 	use crate::test_helpers::with_git_config;
@@ -247,8 +473,14 @@ mod tests {
 		config_test!(action_edit, "inputActionEdit", "e");
 		config_test!(action_fixup, "inputActionFixup", "f");
 		config_test!(action_pick, "inputActionPick", "p");
+		config_test!(action_revert, "inputActionRevert", "V");
 		config_test!(action_reword, "inputActionReword", "r");
 		config_test!(action_squash, "inputActionSquash", "s");
+		config_test!(commit_message_abort, "inputCommitMessageAbort", "Controlq");
+		config_test!(commit_message_help, "inputCommitMessageHelp", "F1");
+		config_test!(commit_message_preview, "inputCommitMessagePreview", "Controlp");
+		config_test!(commit_message_save, "inputCommitMessageSave", "Controls");
+		config_test!(commit_message_trailer, "inputCommitMessageTrailer", "Controlt");
 		config_test!(confirm_no, "inputConfirmNo", "n");
 		config_test!(confirm_yes, "inputConfirmYes", "y");
 		config_test!(edit, "inputEdit", "E");
@@ -284,9 +516,207 @@ mod tests {
 		config_test!(search_previous, "searchPrevious", "N");
 		config_test!(show_commit, "inputShowCommit", "c");
 		config_test!(show_diff, "inputShowDiff", "d");
+		config_test!(reload_config, "inputReloadConfig", "F5");
+		config_test!(show_range_diff, "inputShowRangeDiff", "R");
+		config_test!(toggle_excluded_files, "inputToggleExcludedFiles", "x");
 		config_test!(toggle_visual_mode, "inputToggleVisualMode", "v");
 		config_test!(undo, "inputUndo", "Controlz");
 		config_test!(fixup_keep_message_with_editor, "fixupKeepMessageWithEditor", "U");
 		config_test!(fixup_keep_message, "fixupKeepMessage", "u");
 	}
+
+	#[rstest]
+	#[case::single("a", "a")]
+	#[case::named("Delete", "Delete")]
+	#[case::function("F12", "F12")]
+	#[case::modifier("Controlz", "Control+z")]
+	#[case::modifiers("ControlAltShiftDelete", "Control+Alt+Shift+Delete")]
+	#[case::sequence("g g", "g,g")]
+	fn format_key_binding_value(#[case] binding: &str, #[case] expected: &str) {
+		assert_eq!(format_key_binding(binding), expected);
+	}
+
+	#[test]
+	fn help_indicator_key() {
+		with_git_config(&["[interactive-rebase-tool]", "inputHelp = F1 H"], |git_config| {
+			assert_eq!(
+				KeyBindings::new_with_config(Some(&git_config))
+					.unwrap()
+					.help_indicator_key(),
+				"F1"
+			);
+		});
+	}
+
+	#[test]
+	fn help_indicator_key_default() {
+		let mut key_bindings = KeyBindings::new_with_config(None).unwrap();
+		key_bindings.help.clear();
+		assert_eq!(key_bindings.help_indicator_key(), "?");
+	}
+
+	#[test]
+	fn default_key_bindings_have_no_conflicts() {
+		assert_ok!(KeyBindings::new_with_config(None));
+	}
+
+	#[test]
+	fn conflict_in_list() {
+		with_git_config(&["[interactive-rebase-tool]", "searchNext = d"], |git_config| {
+			assert_err_eq!(
+				KeyBindings::new_with_config(Some(&git_config)),
+				ConfigError::new(
+					"interactive-rebase-tool.searchNext",
+					"d",
+					ConfigErrorCause::KeyBindingConflict(String::from("interactive-rebase-tool.inputActionDrop"))
+				)
+			);
+		});
+	}
+
+	#[test]
+	fn conflict_in_show_commit() {
+		with_git_config(
+			&["[interactive-rebase-tool]", "inputToggleExcludedFiles = Control+z"],
+			|git_config| {
+				assert_err_eq!(
+					KeyBindings::new_with_config(Some(&git_config)),
+					ConfigError::new(
+						"interactive-rebase-tool.inputUndo",
+						"Control+z",
+						ConfigErrorCause::KeyBindingConflict(String::from(
+							"interactive-rebase-tool.inputToggleExcludedFiles"
+						))
+					)
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn conflict_in_commit_message() {
+		with_git_config(
+			&["[interactive-rebase-tool]", "inputCommitMessageSave = Control+q"],
+			|git_config| {
+				assert_err_eq!(
+					KeyBindings::new_with_config(Some(&git_config)),
+					ConfigError::new(
+						"interactive-rebase-tool.inputCommitMessageSave",
+						"Control+q",
+						ConfigErrorCause::KeyBindingConflict(String::from(
+							"interactive-rebase-tool.inputCommitMessageAbort"
+						))
+					)
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn conflict_in_confirm() {
+		with_git_config(&["[interactive-rebase-tool]", "inputConfirmNo = y"], |git_config| {
+			assert_err_eq!(
+				KeyBindings::new_with_config(Some(&git_config)),
+				ConfigError::new(
+					"interactive-rebase-tool.inputConfirmNo",
+					"y",
+					ConfigErrorCause::KeyBindingConflict(String::from("interactive-rebase-tool.inputConfirmYes"))
+				)
+			);
+		});
+	}
+
+	#[test]
+	fn conflict_with_custom_command() {
+		with_git_config(
+			&["[interactive-rebase-tool \"command.review\"]", "key = c", "run = true"],
+			|git_config| {
+				assert_err_eq!(
+					KeyBindings::new_with_config(Some(&git_config)),
+					ConfigError::new(
+						"interactive-rebase-tool.command.review.key",
+						"c",
+						ConfigErrorCause::KeyBindingConflict(String::from("interactive-rebase-tool.inputShowCommit"))
+					)
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn same_key_in_different_views() {
+		with_git_config(&["[interactive-rebase-tool]", "inputShowDiff = q"], |git_config| {
+			assert_ok!(KeyBindings::new_with_config(Some(&git_config)));
+		});
+	}
+
+	#[test]
+	fn key_sequence_starting_with_bound_key() {
+		with_git_config(&["[interactive-rebase-tool]", "searchNext = d,d"], |git_config| {
+			assert_ok!(KeyBindings::new_with_config(Some(&git_config)));
+		});
+	}
+
+	#[test]
+	fn key_preset_vim() {
+		with_git_config(&["[interactive-rebase-tool]", "keyPreset = vim"], |git_config| {
+			let key_bindings = KeyBindings::new_with_config(Some(&git_config)).unwrap();
+			assert_eq!(key_bindings.move_up, vec!["Up", "k"]);
+			assert_eq!(key_bindings.scroll_down, vec!["Down", "j"]);
+			assert_eq!(key_bindings.move_home, vec!["Home", "g g"]);
+			assert_eq!(key_bindings.remove_line, vec!["d d", "Delete"]);
+			assert_eq!(key_bindings.undo, vec!["u", "Controlz"]);
+			assert_eq!(key_bindings.redo, vec!["Controlr", "Controly"]);
+			assert_eq!(key_bindings.rebase, vec!["w"]);
+		});
+	}
+
+	#[test]
+	fn key_preset_emacs() {
+		with_git_config(&["[interactive-rebase-tool]", "keyPreset = emacs"], |git_config| {
+			let key_bindings = KeyBindings::new_with_config(Some(&git_config)).unwrap();
+			assert_eq!(key_bindings.move_up, vec!["Up", "Controlp"]);
+			assert_eq!(key_bindings.scroll_down, vec!["Down", "Controln"]);
+			assert_eq!(key_bindings.move_end, vec!["End", "Alt>"]);
+			assert_eq!(key_bindings.undo, vec!["Control/", "Controlz"]);
+			assert_eq!(key_bindings.rebase, vec!["w"]);
+		});
+	}
+
+	#[test]
+	fn key_preset_with_override() {
+		with_git_config(
+			&["[interactive-rebase-tool]", "keyPreset = vim", "inputMoveUp = Up"],
+			|git_config| {
+				let key_bindings = KeyBindings::new_with_config(Some(&git_config)).unwrap();
+				assert_eq!(key_bindings.move_up, vec!["Up"]);
+				assert_eq!(key_bindings.move_down, vec!["Down", "j"]);
+			},
+		);
+	}
+
+	#[rstest]
+	#[case::default("default")]
+	#[case::vim("vim")]
+	#[case::emacs("emacs")]
+	fn key_preset_has_no_conflicts(#[case] preset: &str) {
+		let preset_config = format!("keyPreset = {preset}");
+		with_git_config(&["[interactive-rebase-tool]", preset_config.as_str()], |git_config| {
+			assert_ok!(KeyBindings::new_with_config(Some(&git_config)));
+		});
+	}
+
+	#[test]
+	fn key_preset_invalid() {
+		with_git_config(&["[interactive-rebase-tool]", "keyPreset = nano"], |git_config| {
+			assert_err_eq!(
+				KeyBindings::new_with_config(Some(&git_config)),
+				ConfigError::new(
+					"interactive-rebase-tool.keyPreset",
+					"nano",
+					ConfigErrorCause::InvalidKeyPreset
+				)
+			);
+		});
+	}
 }
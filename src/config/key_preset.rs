@@ -0,0 +1,87 @@
+/// Configuration option for the layout of the default key bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub(crate) enum KeyPreset {
+	/// The standard key bindings.
+	Default,
+	/// Key bindings modelled after Vim, with `hjkl` movement, `d,d` to remove a line and `u` to undo.
+	Vim,
+	/// Key bindings modelled after Emacs, with `Control+n`, `Control+p` movement and `Control+/` to undo.
+	Emacs,
+}
+
+impl KeyPreset {
+	/// Get the default key binding of a setting, without the `interactive-rebase-tool.` prefix, for this preset.
+	/// Returns `None` if the preset does not change the standard default of the setting.
+	pub(crate) fn default_key_binding(self, name: &str) -> Option<&'static str> {
+		match self {
+			Self::Default => None,
+			Self::Vim => {
+				match name {
+					"inputActionDrop" => Some("x"),
+					"inputDuplicateLine" => Some("y,y"),
+					"inputMoveDown" | "inputScrollDown" => Some("Down j"),
+					"inputMoveEnd" | "inputScrollEnd" => Some("End G"),
+					"inputMoveHome" | "inputScrollHome" => Some("Home g,g"),
+					"inputMoveLeft" | "inputScrollLeft" => Some("Left h"),
+					"inputMoveRight" | "inputScrollRight" => Some("Right l"),
+					"inputMoveUp" | "inputScrollUp" => Some("Up k"),
+					"inputMoveStepDown" | "inputScrollStepDown" => Some("PageDown Control+f"),
+					"inputMoveStepUp" | "inputScrollStepUp" => Some("PageUp Control+b"),
+					"inputMoveSelectionDown" => Some("J"),
+					"inputMoveSelectionUp" => Some("K"),
+					"inputRedo" => Some("Control+r Control+y"),
+					"inputUndo" => Some("u Control+z"),
+					"removeLine" => Some("d,d Delete"),
+					"fixupKeepMessage" => Some("m"),
+					"fixupKeepMessageWithEditor" => Some("M"),
+					_ => None,
+				}
+			},
+			Self::Emacs => {
+				match name {
+					"inputAbort" => Some("q Control+g"),
+					"inputMoveDown" | "inputScrollDown" => Some("Down Control+n"),
+					"inputMoveEnd" | "inputScrollEnd" => Some("End Alt+>"),
+					"inputMoveHome" | "inputScrollHome" => Some("Home Alt+<"),
+					"inputMoveLeft" | "inputScrollLeft" => Some("Left Control+b"),
+					"inputMoveRight" | "inputScrollRight" => Some("Right Control+f"),
+					"inputMoveUp" | "inputScrollUp" => Some("Up Control+p"),
+					"inputMoveStepDown" | "inputScrollStepDown" => Some("PageDown Control+v"),
+					"inputMoveStepUp" | "inputScrollStepUp" => Some("PageUp Alt+v"),
+					"inputUndo" => Some("Control+/ Control+z"),
+					"searchStart" => Some("/ Control+s"),
+					_ => None,
+				}
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_none, assert_some_eq};
+
+	use super::*;
+
+	#[test]
+	fn default_key_binding_default() {
+		assert_none!(KeyPreset::Default.default_key_binding("inputMoveUp"));
+	}
+
+	#[test]
+	fn default_key_binding_vim() {
+		assert_some_eq!(KeyPreset::Vim.default_key_binding("inputMoveUp"), "Up k");
+	}
+
+	#[test]
+	fn default_key_binding_emacs() {
+		assert_some_eq!(KeyPreset::Emacs.default_key_binding("inputMoveUp"), "Up Control+p");
+	}
+
+	#[test]
+	fn default_key_binding_unchanged() {
+		assert_none!(KeyPreset::Vim.default_key_binding("inputRebase"));
+		assert_none!(KeyPreset::Emacs.default_key_binding("inputRebase"));
+	}
+}
@@ -0,0 +1,223 @@
+/// A field of a todo line that can be used in a line format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LineFormatField {
+	/// The action of the line, including any option, such as `fixup -c`.
+	Action,
+	/// The abbreviated hash of the commit.
+	Hash,
+	/// The name of the author of the commit.
+	Author,
+	/// The subject of the commit, or the content of lines without a commit.
+	Subject,
+}
+
+impl LineFormatField {
+	fn parse(name: &str) -> Option<Self> {
+		match name {
+			"action" => Some(Self::Action),
+			"hash" => Some(Self::Hash),
+			"author" => Some(Self::Author),
+			"subject" => Some(Self::Subject),
+			_ => None,
+		}
+	}
+}
+
+/// The alignment of a field value within the width of the field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LineFormatAlignment {
+	/// Align the value to the start of the field.
+	Left,
+	/// Align the value to the end of the field.
+	Right,
+	/// Center the value in the field.
+	Center,
+}
+
+/// A part of a line format, either literal text or a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LineFormatPart {
+	/// Text that is rendered as is.
+	Text(String),
+	/// A field that is replaced with a value from the todo line.
+	Field {
+		/// The field.
+		field: LineFormatField,
+		/// The alignment of the value.
+		alignment: LineFormatAlignment,
+		/// The width of the field, values are padded or truncated to this width.
+		width: Option<usize>,
+	},
+}
+
+/// The layout of a line in the list, parsed from a format such as `{action:>6} {hash:8} {subject}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LineFormat {
+	parts: Vec<LineFormatPart>,
+}
+
+impl LineFormat {
+	/// Parse a line format.
+	///
+	/// # Errors
+	/// Will result in an error describing the problem, if the format is not valid.
+	pub(crate) fn parse(format: &str) -> Result<Self, String> {
+		let mut parts = vec![];
+		let mut text = String::new();
+		let mut chars = format.chars();
+		while let Some(c) = chars.next() {
+			match c {
+				'{' if chars.as_str().starts_with('{') => {
+					text.push('{');
+					_ = chars.next();
+				},
+				'}' if chars.as_str().starts_with('}') => {
+					text.push('}');
+					_ = chars.next();
+				},
+				'{' => {
+					let Some((field, rest)) = chars.as_str().split_once('}')
+					else {
+						return Err(String::from("Unclosed '{' in line format"));
+					};
+					let field = Self::parse_field(field)?;
+					chars = rest.chars();
+					if !text.is_empty() {
+						parts.push(LineFormatPart::Text(text.clone()));
+						text.clear();
+					}
+					parts.push(field);
+				},
+				'}' => return Err(String::from("Unmatched '}' in line format, use '}}' for a literal '}'")),
+				_ => text.push(c),
+			}
+		}
+		if !text.is_empty() {
+			parts.push(LineFormatPart::Text(text));
+		}
+		Ok(Self { parts })
+	}
+
+	fn parse_field(field: &str) -> Result<LineFormatPart, String> {
+		let (name, spec) = field.split_once(':').unwrap_or((field, ""));
+		let field = LineFormatField::parse(name.trim())
+			.ok_or_else(|| format!("Unknown field '{name}', must be one of 'action', 'hash', 'author' or 'subject'"))?;
+		let (alignment, width) = match spec.chars().next() {
+			Some('<') => (LineFormatAlignment::Left, spec.get(1..).unwrap_or_default()),
+			Some('>') => (LineFormatAlignment::Right, spec.get(1..).unwrap_or_default()),
+			Some('^') => (LineFormatAlignment::Center, spec.get(1..).unwrap_or_default()),
+			_ => (LineFormatAlignment::Left, spec),
+		};
+		let width = if width.is_empty() {
+			None
+		}
+		else {
+			Some(
+				width
+					.parse::<usize>()
+					.ok()
+					.filter(|width| *width > 0)
+					.ok_or_else(|| format!("Invalid width '{width}' for field '{name}'"))?,
+			)
+		};
+		Ok(LineFormatPart::Field {
+			field,
+			alignment,
+			width,
+		})
+	}
+
+	/// The parts of the format, in order.
+	pub(crate) fn parts(&self) -> &[LineFormatPart] {
+		self.parts.as_slice()
+	}
+
+	/// If the format contains a field.
+	pub(crate) fn has_field(&self, field: LineFormatField) -> bool {
+		self.parts
+			.iter()
+			.any(|part| matches!(*part, LineFormatPart::Field { field: f, .. } if f == field))
+	}
+}
+
+/// Pad, or truncate, a value to a width with an alignment.
+pub(crate) fn align_value(value: &str, alignment: LineFormatAlignment, width: usize) -> String {
+	match alignment {
+		LineFormatAlignment::Left => format!("{value:<width$.width$}"),
+		LineFormatAlignment::Right => format!("{value:>width$.width$}"),
+		LineFormatAlignment::Center => format!("{value:^width$.width$}"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::assert_err_eq;
+	use rstest::rstest;
+
+	use super::*;
+
+	fn field(field: LineFormatField, alignment: LineFormatAlignment, width: Option<usize>) -> LineFormatPart {
+		LineFormatPart::Field {
+			field,
+			alignment,
+			width,
+		}
+	}
+
+	#[test]
+	fn parse() {
+		let format = LineFormat::parse("{action:>6} {hash:8} {author:^12} | {subject}").unwrap();
+		assert_eq!(format.parts(), &[
+			field(LineFormatField::Action, LineFormatAlignment::Right, Some(6)),
+			LineFormatPart::Text(String::from(" ")),
+			field(LineFormatField::Hash, LineFormatAlignment::Left, Some(8)),
+			LineFormatPart::Text(String::from(" ")),
+			field(LineFormatField::Author, LineFormatAlignment::Center, Some(12)),
+			LineFormatPart::Text(String::from(" | ")),
+			field(LineFormatField::Subject, LineFormatAlignment::Left, None),
+		]);
+	}
+
+	#[test]
+	fn parse_escaped_braces() {
+		let format = LineFormat::parse("{{{subject}}}").unwrap();
+		assert_eq!(format.parts(), &[
+			LineFormatPart::Text(String::from("{")),
+			field(LineFormatField::Subject, LineFormatAlignment::Left, None),
+			LineFormatPart::Text(String::from("}")),
+		]);
+	}
+
+	#[rstest]
+	#[case::unclosed("{action", "Unclosed '{' in line format")]
+	#[case::unmatched("action}", "Unmatched '}' in line format, use '}}' for a literal '}'")]
+	#[case::unknown_field(
+		"{message}",
+		"Unknown field 'message', must be one of 'action', 'hash', 'author' or 'subject'"
+	)]
+	#[case::invalid_width("{hash:x}", "Invalid width 'x' for field 'hash'")]
+	#[case::zero_width("{hash:>0}", "Invalid width '0' for field 'hash'")]
+	fn parse_invalid(#[case] format: &str, #[case] expected: &str) {
+		assert_err_eq!(LineFormat::parse(format), String::from(expected));
+	}
+
+	#[test]
+	fn has_field() {
+		let format = LineFormat::parse("{action} {subject}").unwrap();
+		assert!(format.has_field(LineFormatField::Subject));
+		assert!(!format.has_field(LineFormatField::Author));
+	}
+
+	#[rstest]
+	#[case::left(LineFormatAlignment::Left, "ab   ")]
+	#[case::right(LineFormatAlignment::Right, "   ab")]
+	#[case::center(LineFormatAlignment::Center, " ab  ")]
+	fn align_value_padded(#[case] alignment: LineFormatAlignment, #[case] expected: &str) {
+		assert_eq!(align_value("ab", alignment, 5), expected);
+	}
+
+	#[test]
+	fn align_value_truncated() {
+		assert_eq!(align_value("abcdef", LineFormatAlignment::Right, 3), "abc");
+	}
+}
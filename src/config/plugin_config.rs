@@ -0,0 +1,153 @@
+use crate::{
+	config::{
+		ConfigError,
+		ConfigErrorCause,
+		utils::{get_string, get_unsigned_integer},
+	},
+	git::{Config, GitError},
+};
+
+const PLUGIN_PREFIX: &str = "interactive-rebase-tool.plugin.";
+const PLUGIN_RUN_SUFFIX: &str = ".run";
+
+/// Represents an external program that extends the tool, communicating with JSON-RPC over stdin and stdout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub(crate) struct PluginConfig {
+	/// The name of the plugin, from the subsection of the configuration.
+	pub(crate) name: String,
+	/// The shell command that starts the plugin.
+	pub(crate) run: String,
+	/// The time, in milliseconds, to wait for the plugin to exit after it is asked to shutdown.
+	pub(crate) shutdown_timeout: u32,
+}
+
+impl PluginConfig {
+	fn new_with_config(git_config: Option<&Config>, name: &str) -> Result<Self, ConfigError> {
+		let config_name = format!("{PLUGIN_PREFIX}{name}");
+		Ok(Self {
+			name: String::from(name),
+			run: get_string(git_config, format!("{config_name}.run").as_str(), "")?,
+			shutdown_timeout: get_unsigned_integer(
+				git_config,
+				format!("{config_name}.shutdownTimeout").as_str(),
+				1000,
+			)?,
+		})
+	}
+}
+
+/// Load all plugins, in the order that they are first defined.
+pub(super) fn get_plugin_configs(git_config: Option<&Config>) -> Result<Vec<PluginConfig>, ConfigError> {
+	let Some(config) = git_config
+	else {
+		return Ok(vec![]);
+	};
+
+	let map_error = |cause| {
+		ConfigError::new_read_error(
+			"interactive-rebase-tool.plugin",
+			ConfigErrorCause::GitError(GitError::ConfigLoad { cause }),
+		)
+	};
+
+	let mut names: Vec<String> = vec![];
+	let mut entries = config
+		.entries(Some(r"^interactive-rebase-tool\.plugin\..+\.run$"))
+		.map_err(map_error)?;
+	while let Some(entry) = entries.next() {
+		let entry = entry.map_err(map_error)?;
+		let name = entry
+			.name()
+			.and_then(|n| n.strip_prefix(PLUGIN_PREFIX))
+			.and_then(|n| n.strip_suffix(PLUGIN_RUN_SUFFIX));
+		if let Some(name) = name {
+			if !names.iter().any(|n| n == name) {
+				names.push(String::from(name));
+			}
+		}
+	}
+
+	names
+		.iter()
+		.map(|name| PluginConfig::new_with_config(git_config, name))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_err_eq, assert_ok_eq};
+
+	use super::*;
+	use crate::test_helpers::with_git_config;
+
+	#[test]
+	fn get_plugin_configs_without_config() {
+		assert_ok_eq!(get_plugin_configs(None), vec![]);
+	}
+
+	#[test]
+	fn get_plugin_configs_empty() {
+		with_git_config(&[], |git_config| {
+			assert_ok_eq!(get_plugin_configs(Some(&git_config)), vec![]);
+		});
+	}
+
+	#[test]
+	fn get_plugin_configs_with_plugins() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool \"plugin.review\"]",
+				"run = review-plugin --stdio",
+				"[interactive-rebase-tool \"plugin.ci.status\"]",
+				"run = ci-status",
+				"shutdownTimeout = 200",
+			],
+			|git_config| {
+				assert_ok_eq!(get_plugin_configs(Some(&git_config)), vec![
+					PluginConfig {
+						name: String::from("review"),
+						run: String::from("review-plugin --stdio"),
+						shutdown_timeout: 1000,
+					},
+					PluginConfig {
+						name: String::from("ci.status"),
+						run: String::from("ci-status"),
+						shutdown_timeout: 200,
+					},
+				]);
+			},
+		);
+	}
+
+	#[test]
+	fn get_plugin_configs_without_run() {
+		with_git_config(
+			&["[interactive-rebase-tool \"plugin.review\"]", "shutdownTimeout = 200"],
+			|git_config| {
+				assert_ok_eq!(get_plugin_configs(Some(&git_config)), vec![]);
+			},
+		);
+	}
+
+	#[test]
+	fn get_plugin_configs_invalid_shutdown_timeout() {
+		with_git_config(
+			&[
+				"[interactive-rebase-tool \"plugin.review\"]",
+				"run = review",
+				"shutdownTimeout = -1",
+			],
+			|git_config| {
+				assert_err_eq!(
+					get_plugin_configs(Some(&git_config)),
+					ConfigError::new(
+						"interactive-rebase-tool.plugin.review.shutdownTimeout",
+						"-1",
+						ConfigErrorCause::InvalidUnsignedInteger
+					)
+				);
+			},
+		);
+	}
+}
@@ -1,25 +1,119 @@
+use std::{env, path::PathBuf};
+
 use crate::{
 	config::{
 		Color,
 		ConfigError,
 		ConfigErrorCause,
-		utils::{get_optional_string, get_string},
+		ConfigFile,
+		ThemePreset,
+		utils::{get_optional_string, get_theme_preset},
 	},
 	git::Config,
 };
 
-fn get_color(config: Option<&Config>, name: &str, default: Color) -> Result<Color, ConfigError> {
-	if let Some(value) = get_optional_string(config, name)? {
-		Color::try_from(value.to_lowercase().as_str()).map_err(|invalid_color_error| {
-			ConfigError::new(
-				name,
-				value.as_str(),
-				ConfigErrorCause::InvalidColor(invalid_color_error),
-			)
+const SETTING_PREFIX: &str = "interactive-rebase-tool";
+
+/// The settings that can be provided by a theme file.
+const THEME_SETTINGS: [&str; 25] = [
+	"verticalSpacingCharacter",
+	"breakColor",
+	"dropColor",
+	"editColor",
+	"execColor",
+	"fixupColor",
+	"pickColor",
+	"revertColor",
+	"rewordColor",
+	"squashColor",
+	"labelColor",
+	"resetColor",
+	"mergeColor",
+	"updateRefColor",
+	"backgroundColor",
+	"diffAddColor",
+	"diffChangeColor",
+	"diffContextColor",
+	"diffHunkColor",
+	"diffRemoveColor",
+	"diffWhitespace",
+	"foregroundColor",
+	"indicatorColor",
+	"searchMatchColor",
+	"selectedBackgroundColor",
+];
+
+fn parse_color(name: &str, value: &str) -> Result<Color, ConfigError> {
+	Color::try_from(value.to_lowercase().as_str()).map_err(|invalid_color_error| {
+		ConfigError::new(name, value, ConfigErrorCause::InvalidColor(invalid_color_error))
+	})
+}
+
+fn load_theme_file(git_config: Option<&Config>) -> Result<Option<ConfigFile>, ConfigError> {
+	let name = format!("{SETTING_PREFIX}.themeFile");
+	let Some(value) = get_optional_string(git_config, name.as_str())?
+	else {
+		return Ok(None);
+	};
+
+	let path = match (value.strip_prefix("~/"), env::var_os("HOME")) {
+		(Some(relative), Some(home)) => PathBuf::from(home).join(relative),
+		_ => PathBuf::from(value.as_str()),
+	};
+	// relative paths are relative to the directory of the user configuration file
+	let path = match ConfigFile::user_path()
+		.as_deref()
+		.and_then(|user_path| user_path.parent())
+	{
+		Some(directory) if path.is_relative() => directory.join(path),
+		_ => path,
+	};
+	let theme_file = ConfigFile::load(path.as_path())?
+		.ok_or_else(|| ConfigError::new(name.as_str(), value.as_str(), ConfigErrorCause::ThemeFileNotFound))?;
+	theme_file.ensure_only_settings(&THEME_SETTINGS, ConfigErrorCause::InvalidThemeFileSetting)?;
+	Ok(Some(theme_file))
+}
+
+/// The source of the defaults of the theme settings, which are used when a setting is not set in the Git config.
+struct ThemeDefaults<'config> {
+	git_config: Option<&'config Config>,
+	preset: ThemePreset,
+	file: Option<ConfigFile>,
+}
+
+impl<'config> ThemeDefaults<'config> {
+	fn new(git_config: Option<&'config Config>) -> Result<Self, ConfigError> {
+		Ok(Self {
+			git_config,
+			preset: get_theme_preset(git_config, "interactive-rebase-tool.themePreset")?,
+			file: load_theme_file(git_config)?,
 		})
 	}
-	else {
-		Ok(default)
+
+	fn get_string(&self, variable: &str, default: &str) -> Result<String, ConfigError> {
+		let name = format!("{SETTING_PREFIX}.{variable}");
+		if let Some(value) = get_optional_string(self.git_config, name.as_str())? {
+			return Ok(value);
+		}
+		Ok(String::from(
+			self.file
+				.as_ref()
+				.and_then(|file| file.get(variable))
+				.map_or(default, |(value, _)| value),
+		))
+	}
+
+	fn get_color(&self, variable: &str, default: Color) -> Result<Color, ConfigError> {
+		let name = format!("{SETTING_PREFIX}.{variable}");
+		if let Some(value) = get_optional_string(self.git_config, name.as_str())? {
+			return parse_color(name.as_str(), value.as_str());
+		}
+		if let Some(file) = self.file.as_ref() {
+			if let Some((value, line)) = file.get(variable) {
+				return parse_color(name.as_str(), value).map_err(|err| err.with_location(file.path(), line));
+			}
+		}
+		Ok(self.preset.default_color(variable).unwrap_or(default))
 	}
 }
 
@@ -41,6 +135,8 @@ pub(crate) struct Theme {
 	pub(crate) color_action_fixup: Color,
 	/// The color for the pick action.
 	pub(crate) color_action_pick: Color,
+	/// The color for the revert action.
+	pub(crate) color_action_revert: Color,
 	/// The color for the reword action.
 	pub(crate) color_action_reword: Color,
 	/// The color for the squash action.
@@ -61,6 +157,8 @@ pub(crate) struct Theme {
 	pub(crate) color_diff_change: Color,
 	/// The color for context lines in a diff.
 	pub(crate) color_diff_context: Color,
+	/// The color for the markers of hunk headers in a diff.
+	pub(crate) color_diff_hunk: Color,
 	/// The color for removed lines in a diff.
 	pub(crate) color_diff_remove: Color,
 	/// The color for whitespace characters in a diff.
@@ -69,6 +167,8 @@ pub(crate) struct Theme {
 	pub(crate) color_foreground: Color,
 	/// The color for indicator text.
 	pub(crate) color_indicator: Color,
+	/// The color for text matching the search term.
+	pub(crate) color_search_match: Color,
 	/// The background color for selected lines.
 	pub(crate) color_selected_background: Color,
 }
@@ -76,49 +176,33 @@ pub(crate) struct Theme {
 impl Theme {
 	/// Create a new theme from a Git Config reference.
 	pub(crate) fn new_with_config(git_config: Option<&Config>) -> Result<Self, ConfigError> {
+		let defaults = ThemeDefaults::new(git_config)?;
 		Ok(Self {
-			character_vertical_spacing: get_string(
-				git_config,
-				"interactive-rebase-tool.verticalSpacingCharacter",
-				"~",
-			)?,
-			color_action_break: get_color(git_config, "interactive-rebase-tool.breakColor", Color::LightWhite)?,
-			color_action_drop: get_color(git_config, "interactive-rebase-tool.dropColor", Color::LightRed)?,
-			color_action_edit: get_color(git_config, "interactive-rebase-tool.editColor", Color::LightBlue)?,
-			color_action_exec: get_color(git_config, "interactive-rebase-tool.execColor", Color::LightWhite)?,
-			color_action_fixup: get_color(git_config, "interactive-rebase-tool.fixupColor", Color::LightMagenta)?,
-			color_action_pick: get_color(git_config, "interactive-rebase-tool.pickColor", Color::LightGreen)?,
-			color_action_reword: get_color(git_config, "interactive-rebase-tool.rewordColor", Color::LightYellow)?,
-			color_action_squash: get_color(git_config, "interactive-rebase-tool.squashColor", Color::LightCyan)?,
-			color_action_label: get_color(git_config, "interactive-rebase-tool.labelColor", Color::DarkYellow)?,
-			color_action_reset: get_color(git_config, "interactive-rebase-tool.resetColor", Color::DarkYellow)?,
-			color_action_merge: get_color(git_config, "interactive-rebase-tool.mergeColor", Color::DarkYellow)?,
-			color_action_update_ref: get_color(
-				git_config,
-				"interactive-rebase-tool.updateRefColor",
-				Color::DarkMagenta,
-			)?,
-			color_background: get_color(git_config, "interactive-rebase-tool.backgroundColor", Color::Default)?,
-			color_diff_add: get_color(git_config, "interactive-rebase-tool.diffAddColor", Color::LightGreen)?,
-			color_diff_change: get_color(
-				git_config,
-				"interactive-rebase-tool.diffChangeColor",
-				Color::LightYellow,
-			)?,
-			color_diff_context: get_color(
-				git_config,
-				"interactive-rebase-tool.diffContextColor",
-				Color::LightWhite,
-			)?,
-			color_diff_remove: get_color(git_config, "interactive-rebase-tool.diffRemoveColor", Color::LightRed)?,
-			color_diff_whitespace: get_color(git_config, "interactive-rebase-tool.diffWhitespace", Color::LightBlack)?,
-			color_foreground: get_color(git_config, "interactive-rebase-tool.foregroundColor", Color::Default)?,
-			color_indicator: get_color(git_config, "interactive-rebase-tool.indicatorColor", Color::LightCyan)?,
-			color_selected_background: get_color(
-				git_config,
-				"interactive-rebase-tool.selectedBackgroundColor",
-				Color::Index(237),
-			)?,
+			character_vertical_spacing: defaults.get_string("verticalSpacingCharacter", "~")?,
+			color_action_break: defaults.get_color("breakColor", Color::LightWhite)?,
+			color_action_drop: defaults.get_color("dropColor", Color::LightRed)?,
+			color_action_edit: defaults.get_color("editColor", Color::LightBlue)?,
+			color_action_exec: defaults.get_color("execColor", Color::LightWhite)?,
+			color_action_fixup: defaults.get_color("fixupColor", Color::LightMagenta)?,
+			color_action_pick: defaults.get_color("pickColor", Color::LightGreen)?,
+			color_action_revert: defaults.get_color("revertColor", Color::DarkCyan)?,
+			color_action_reword: defaults.get_color("rewordColor", Color::LightYellow)?,
+			color_action_squash: defaults.get_color("squashColor", Color::LightCyan)?,
+			color_action_label: defaults.get_color("labelColor", Color::DarkYellow)?,
+			color_action_reset: defaults.get_color("resetColor", Color::DarkYellow)?,
+			color_action_merge: defaults.get_color("mergeColor", Color::DarkYellow)?,
+			color_action_update_ref: defaults.get_color("updateRefColor", Color::DarkMagenta)?,
+			color_background: defaults.get_color("backgroundColor", Color::Default)?,
+			color_diff_add: defaults.get_color("diffAddColor", Color::LightGreen)?,
+			color_diff_change: defaults.get_color("diffChangeColor", Color::LightYellow)?,
+			color_diff_context: defaults.get_color("diffContextColor", Color::LightWhite)?,
+			color_diff_hunk: defaults.get_color("diffHunkColor", Color::Default)?,
+			color_diff_remove: defaults.get_color("diffRemoveColor", Color::LightRed)?,
+			color_diff_whitespace: defaults.get_color("diffWhitespace", Color::LightBlack)?,
+			color_foreground: defaults.get_color("foregroundColor", Color::Default)?,
+			color_indicator: defaults.get_color("indicatorColor", Color::LightCyan)?,
+			color_search_match: defaults.get_color("searchMatchColor", Color::LightCyan)?,
+			color_selected_background: defaults.get_color("selectedBackgroundColor", Color::Index(237))?,
 		})
 	}
 }
@@ -133,15 +217,31 @@ impl TryFrom<&Config> for Theme {
 
 #[cfg(test)]
 mod tests {
+	use std::{fs, path::Path};
+
 	use claims::{assert_err, assert_err_eq, assert_ok};
 	use rstest::rstest;
+	use tempfile::tempdir;
 
 	use super::*;
 	use crate::{
 		config::InvalidColorError,
-		test_helpers::{invalid_utf, with_git_config},
+		test_helpers::{EnvVarAction, invalid_utf, with_env_var, with_git_config},
 	};
 
+	fn with_theme_file<F>(content: &[&str], config: &[&str], callback: F)
+	where F: FnOnce(Result<Theme, ConfigError>, &Path) {
+		let directory = tempdir().unwrap();
+		let path = directory.path().join("theme.toml");
+		fs::write(path.as_path(), content.join("\n")).unwrap();
+		let theme_file = format!("themeFile = \"{}\"", path.display());
+		let mut lines = vec!["[interactive-rebase-tool]", theme_file.as_str()];
+		lines.extend_from_slice(config);
+		with_git_config(lines.as_slice(), |git_config| {
+			callback(Theme::new_with_config(Some(&git_config)), path.as_path());
+		});
+	}
+
 	macro_rules! config_test {
 		($key:ident, $config_name:literal, $default:expr) => {
 			let config = Theme::new_with_config(None).unwrap();
@@ -205,6 +305,7 @@ mod tests {
 		config_test!(color_action_exec, "execColor", Color::LightWhite);
 		config_test!(color_action_fixup, "fixupColor", Color::LightMagenta);
 		config_test!(color_action_pick, "pickColor", Color::LightGreen);
+		config_test!(color_action_revert, "revertColor", Color::DarkCyan);
 		config_test!(color_action_reword, "rewordColor", Color::LightYellow);
 		config_test!(color_action_squash, "squashColor", Color::LightCyan);
 		config_test!(color_action_label, "labelColor", Color::DarkYellow);
@@ -215,10 +316,12 @@ mod tests {
 		config_test!(color_diff_add, "diffAddColor", Color::LightGreen);
 		config_test!(color_diff_change, "diffChangeColor", Color::LightYellow);
 		config_test!(color_diff_context, "diffContextColor", Color::LightWhite);
+		config_test!(color_diff_hunk, "diffHunkColor", Color::Default);
 		config_test!(color_diff_remove, "diffRemoveColor", Color::LightRed);
 		config_test!(color_diff_whitespace, "diffWhitespace", Color::LightBlack);
 		config_test!(color_foreground, "foregroundColor", Color::Default);
 		config_test!(color_indicator, "indicatorColor", Color::LightCyan);
+		config_test!(color_search_match, "searchMatchColor", Color::LightCyan);
 		config_test!(color_selected_background, "selectedBackgroundColor", Color::Index(237));
 	}
 
@@ -256,4 +359,128 @@ mod tests {
 			},
 		);
 	}
+
+	#[test]
+	fn theme_preset() {
+		with_git_config(&["[interactive-rebase-tool]", "themePreset = light"], |git_config| {
+			let theme = Theme::new_with_config(Some(&git_config)).unwrap();
+			assert_eq!(theme.color_action_pick, Color::DarkGreen);
+			assert_eq!(theme.color_background, Color::Default);
+		});
+	}
+
+	#[test]
+	fn theme_preset_setting_precedence() {
+		with_git_config(
+			&["[interactive-rebase-tool]", "themePreset = light", "pickColor = red"],
+			|git_config| {
+				let theme = Theme::new_with_config(Some(&git_config)).unwrap();
+				assert_eq!(theme.color_action_pick, Color::LightRed);
+			},
+		);
+	}
+
+	#[test]
+	fn theme_preset_invalid() {
+		with_git_config(&["[interactive-rebase-tool]", "themePreset = invalid"], |git_config| {
+			assert_err_eq!(
+				Theme::new_with_config(Some(&git_config)),
+				ConfigError::new(
+					"interactive-rebase-tool.themePreset",
+					"invalid",
+					ConfigErrorCause::InvalidThemePreset
+				)
+			);
+		});
+	}
+
+	#[test]
+	fn theme_file() {
+		with_theme_file(
+			&[
+				"pickColor = \"#ff8000\"",
+				"dropColor = \"#102030\"",
+				"verticalSpacingCharacter = \"-\"",
+			],
+			&["themePreset = light", "dropColor = blue"],
+			|theme, _| {
+				let theme = theme.unwrap();
+				assert_eq!(theme.color_action_pick, Color::Rgb {
+					red: 255,
+					green: 128,
+					blue: 0
+				});
+				assert_eq!(theme.color_action_drop, Color::LightBlue);
+				assert_eq!(theme.color_action_edit, Color::DarkBlue);
+				assert_eq!(theme.character_vertical_spacing, "-");
+			},
+		);
+	}
+
+	#[test]
+	fn theme_file_invalid_color() {
+		with_theme_file(&["", "pickColor = \"#ff80\""], &[], |theme, path| {
+			assert_err_eq!(
+				theme,
+				ConfigError::new(
+					"interactive-rebase-tool.pickColor",
+					"#ff80",
+					ConfigErrorCause::InvalidColor(InvalidColorError::Hex)
+				)
+				.with_location(path, 2)
+			);
+		});
+	}
+
+	#[test]
+	fn theme_file_invalid_setting() {
+		with_theme_file(&["undoLimit = 10"], &[], |theme, path| {
+			assert_err_eq!(
+				theme,
+				ConfigError::new(
+					"interactive-rebase-tool.undoLimit",
+					"10",
+					ConfigErrorCause::InvalidThemeFileSetting
+				)
+				.with_location(path, 1)
+			);
+		});
+	}
+
+	#[test]
+	fn theme_file_relative_path() {
+		let directory = tempdir().unwrap();
+		let config_directory = directory.path().join("git-interactive-rebase-tool");
+		fs::create_dir_all(config_directory.as_path()).unwrap();
+		fs::write(config_directory.join("theme.toml"), "pickColor = \"red\"\n").unwrap();
+		with_env_var(
+			&[EnvVarAction::Set(
+				"XDG_CONFIG_HOME",
+				directory.path().to_string_lossy().into_owned(),
+			)],
+			|| {
+				with_git_config(&["[interactive-rebase-tool]", "themeFile = theme.toml"], |git_config| {
+					let theme = Theme::new_with_config(Some(&git_config)).unwrap();
+					assert_eq!(theme.color_action_pick, Color::LightRed);
+				});
+			},
+		);
+	}
+
+	#[test]
+	fn theme_file_not_found() {
+		with_git_config(
+			&["[interactive-rebase-tool]", "themeFile = /does/not/exist.toml"],
+			|git_config| {
+				assert_err_eq!(
+					Theme::new_with_config(Some(&git_config)),
+					ConfigError::new(
+						"interactive-rebase-tool.themeFile",
+						"/does/not/exist.toml",
+						ConfigErrorCause::ThemeFileNotFound
+					)
+				);
+			},
+		);
+	}
 }
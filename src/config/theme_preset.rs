@@ -0,0 +1,138 @@
+use crate::config::Color;
+
+const fn rgb(red: u8, green: u8, blue: u8) -> Color {
+	Color::Rgb { red, green, blue }
+}
+
+/// Configuration option for the set of default colors of the theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub(crate) enum ThemePreset {
+	/// The standard colors, using the basic terminal colors.
+	Default,
+	/// Colors for terminals with a light background.
+	Light,
+	/// True colors for terminals with a dark background.
+	Dark,
+	/// The Solarized palette.
+	Solarized,
+	/// Bright colors on a black background, with a blue selection.
+	HighContrast,
+	/// Colors from the Okabe-Ito palette, which avoid relying on telling red and green apart.
+	ColorblindSafe,
+}
+
+impl ThemePreset {
+	/// Get the default color of a setting, without the `interactive-rebase-tool.` prefix, for this preset. Returns
+	/// `None` if the preset does not change the standard default of the setting.
+	pub(crate) fn default_color(self, name: &str) -> Option<Color> {
+		match self {
+			Self::Default => None,
+			Self::Light => {
+				match name {
+					"breakColor" | "execColor" | "diffContextColor" => Some(Color::DarkBlack),
+					"dropColor" | "diffRemoveColor" => Some(Color::DarkRed),
+					"editColor" | "indicatorColor" | "searchMatchColor" => Some(Color::DarkBlue),
+					"fixupColor" | "updateRefColor" => Some(Color::DarkMagenta),
+					"pickColor" | "diffAddColor" => Some(Color::DarkGreen),
+					"rewordColor" | "diffChangeColor" => Some(Color::DarkYellow),
+					"squashColor" | "diffHunkColor" => Some(Color::DarkCyan),
+					"diffWhitespace" => Some(Color::Index(250)),
+					"selectedBackgroundColor" => Some(Color::Index(254)),
+					_ => None,
+				}
+			},
+			Self::Dark => {
+				match name {
+					"breakColor" | "execColor" | "diffContextColor" | "foregroundColor" => Some(rgb(171, 178, 191)),
+					"dropColor" | "diffRemoveColor" => Some(rgb(224, 108, 117)),
+					"editColor" | "diffHunkColor" => Some(rgb(97, 175, 239)),
+					"fixupColor" | "updateRefColor" => Some(rgb(198, 120, 221)),
+					"pickColor" | "diffAddColor" => Some(rgb(152, 195, 121)),
+					"rewordColor" | "diffChangeColor" | "searchMatchColor" => Some(rgb(229, 192, 123)),
+					"squashColor" | "indicatorColor" => Some(rgb(86, 182, 194)),
+					"labelColor" | "resetColor" | "mergeColor" => Some(rgb(209, 154, 102)),
+					"revertColor" => Some(rgb(190, 80, 70)),
+					"diffWhitespace" => Some(rgb(92, 99, 112)),
+					"selectedBackgroundColor" => Some(rgb(44, 49, 60)),
+					_ => None,
+				}
+			},
+			Self::Solarized => {
+				match name {
+					"breakColor" | "execColor" => Some(rgb(147, 161, 161)),
+					"diffContextColor" | "foregroundColor" => Some(rgb(131, 148, 150)),
+					"dropColor" | "diffRemoveColor" => Some(rgb(220, 50, 47)),
+					"editColor" => Some(rgb(38, 139, 210)),
+					"fixupColor" => Some(rgb(211, 54, 130)),
+					"pickColor" | "diffAddColor" => Some(rgb(133, 153, 0)),
+					"rewordColor" | "diffChangeColor" => Some(rgb(181, 137, 0)),
+					"squashColor" | "indicatorColor" => Some(rgb(42, 161, 152)),
+					"labelColor" | "resetColor" | "mergeColor" | "searchMatchColor" => Some(rgb(203, 75, 22)),
+					"updateRefColor" | "diffHunkColor" | "revertColor" => Some(rgb(108, 113, 196)),
+					"diffWhitespace" => Some(rgb(88, 110, 117)),
+					"selectedBackgroundColor" => Some(rgb(7, 54, 66)),
+					_ => None,
+				}
+			},
+			Self::HighContrast => {
+				match name {
+					"breakColor" | "execColor" | "diffContextColor" | "foregroundColor" => Some(Color::LightWhite),
+					"dropColor" | "diffRemoveColor" => Some(Color::LightRed),
+					"editColor" | "squashColor" | "diffHunkColor" => Some(Color::LightCyan),
+					"fixupColor" | "updateRefColor" => Some(Color::LightMagenta),
+					"pickColor" | "diffAddColor" => Some(Color::LightGreen),
+					"rewordColor" | "diffChangeColor" | "indicatorColor" | "searchMatchColor" | "labelColor"
+					| "resetColor" | "mergeColor" => Some(Color::LightYellow),
+					"diffWhitespace" => Some(Color::LightGrey),
+					"revertColor" => Some(Color::LightBlue),
+					"backgroundColor" => Some(Color::DarkBlack),
+					"selectedBackgroundColor" => Some(Color::DarkBlue),
+					_ => None,
+				}
+			},
+			Self::ColorblindSafe => {
+				match name {
+					"dropColor" => Some(rgb(213, 94, 0)),
+					"diffRemoveColor" | "fixupColor" | "labelColor" | "resetColor" | "mergeColor" => {
+						Some(rgb(230, 159, 0))
+					},
+					"editColor" | "diffAddColor" => Some(rgb(0, 114, 178)),
+					"pickColor" | "indicatorColor" => Some(rgb(86, 180, 233)),
+					"rewordColor" | "diffChangeColor" | "searchMatchColor" => Some(rgb(240, 228, 66)),
+					"squashColor" | "updateRefColor" | "diffHunkColor" => Some(rgb(204, 121, 167)),
+					"revertColor" => Some(rgb(0, 158, 115)),
+					_ => None,
+				}
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_none, assert_some_eq};
+	use rstest::rstest;
+
+	use super::*;
+
+	#[test]
+	fn default_color_default() {
+		assert_none!(ThemePreset::Default.default_color("pickColor"));
+	}
+
+	#[rstest]
+	#[case::light(ThemePreset::Light, Color::DarkGreen)]
+	#[case::dark(ThemePreset::Dark, rgb(152, 195, 121))]
+	#[case::solarized(ThemePreset::Solarized, rgb(133, 153, 0))]
+	#[case::high_contrast(ThemePreset::HighContrast, Color::LightGreen)]
+	#[case::colorblind_safe(ThemePreset::ColorblindSafe, rgb(86, 180, 233))]
+	fn default_color(#[case] preset: ThemePreset, #[case] expected: Color) {
+		assert_some_eq!(preset.default_color("pickColor"), expected);
+	}
+
+	#[test]
+	fn default_color_unchanged_setting() {
+		assert_none!(ThemePreset::Dark.default_color("backgroundColor"));
+	}
+}
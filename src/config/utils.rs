@@ -1,17 +1,27 @@
+mod escape_value;
 mod get_bool;
 mod get_diff_ignore_whitespace;
 mod get_diff_rename;
 mod get_diff_show_whitespace;
 mod get_input;
+mod get_key_preset;
+mod get_line_format;
 mod get_string;
+mod get_theme_preset;
 mod get_unsigned_integer;
+mod glob_matches;
 
 pub(crate) use self::{
+	escape_value::escape_value,
 	get_bool::get_bool,
 	get_diff_ignore_whitespace::get_diff_ignore_whitespace,
 	get_diff_rename::git_diff_renames,
 	get_diff_show_whitespace::get_diff_show_whitespace,
-	get_input::get_input,
+	get_input::{get_input, parse_input},
+	get_key_preset::get_key_preset,
+	get_line_format::get_line_format,
 	get_string::{get_optional_string, get_string},
+	get_theme_preset::get_theme_preset,
 	get_unsigned_integer::get_unsigned_integer,
+	glob_matches::glob_matches,
 };
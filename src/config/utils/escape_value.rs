@@ -0,0 +1,18 @@
+/// Escape a value so that it can be written as a quoted value in a Git config file.
+pub(crate) fn escape_value(value: &str) -> String {
+	value
+		.replace('\\', "\\\\")
+		.replace('"', "\\\"")
+		.replace('\n', "\\n")
+		.replace('\t', "\\t")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn escape_value_special_characters() {
+		assert_eq!(escape_value("a\\b\"c\nd\te"), "a\\\\b\\\"c\\nd\\te");
+	}
+}
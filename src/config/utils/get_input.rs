@@ -3,10 +3,23 @@ use crate::{
 	git::Config,
 };
 
-#[expect(clippy::string_slice, reason = "Slice usage is guarded.")]
+// the maximum number of keys in a key sequence, such as `g,g`
+const MAXIMUM_KEY_SEQUENCE_LENGTH: usize = 4;
+
 pub(crate) fn get_input(config: Option<&Config>, name: &str, default: &str) -> Result<Vec<String>, ConfigError> {
+	parse_input(name, get_string(config, name, default)?.as_str())
+}
+
+/// A key sequence is two or more single characters, separated by a comma, such as `g,g`.
+fn is_key_sequence(value: &str) -> bool {
+	let keys = value.split(',').collect::<Vec<_>>();
+	(2..=MAXIMUM_KEY_SEQUENCE_LENGTH).contains(&keys.len()) && keys.iter().all(|key| key.chars().count() == 1)
+}
+
+/// Parse whitespace separated key bindings, such as `Control+r`, for the setting with the name.
+#[expect(clippy::string_slice, reason = "Slice usage is guarded.")]
+pub(crate) fn parse_input(name: &str, input: &str) -> Result<Vec<String>, ConfigError> {
 	let mut values = vec![];
-	let input = get_string(config, name, default)?;
 	for mut value in input.split_whitespace().map(String::from) {
 		let mut modifiers = vec![];
 
@@ -41,19 +54,20 @@ pub(crate) fn get_input(config: Option<&Config>, name: &str, default: &str) -> R
 			"up" => String::from("Up"),
 			v => {
 				let v_len = v.chars().count();
+				let is_function_key = v_len > 1 && v.starts_with('f') && v[1..].chars().all(|c| c.is_ascii_digit());
 				// allow F{number} values
-				if v_len > 1 && v.starts_with('f') && v[1..].parse::<u8>().is_ok() {
+				if is_function_key && v[1..].parse::<u8>().is_ok() {
 					v.to_uppercase()
 				}
 				else if v_len == 1 {
 					value
 				}
+				// single characters separated by a comma are a sequence of keys, stored separated by a space
+				else if modifiers.is_empty() && shift_index.is_none() && is_key_sequence(value.as_str()) {
+					value.split(',').collect::<Vec<_>>().join(" ")
+				}
 				else {
-					return Err(ConfigError::new(
-						name,
-						input.as_str(),
-						ConfigErrorCause::InvalidKeyBinding,
-					));
+					return Err(ConfigError::new(name, input, ConfigErrorCause::InvalidKeyBinding));
 				}
 			},
 		};
@@ -121,6 +135,12 @@ mod tests {
 	#[case::shift_with_printable_upper("Shift+A", "A")]
 	#[case::multiple("a b ẞ c d", "a,b,ẞ,c,d")]
 	#[case::multiple_with_modifiers("Control+End Control+A", "ControlEnd,ControlA")]
+	#[case::sequence("g,g", "g g")]
+	#[case::sequence_mixed_case("g,G", "g G")]
+	#[case::sequence_maximum_length("a,b,c,d", "a b c d")]
+	#[case::sequence_function_like("f,x", "f x")]
+	#[case::sequence_non_ascii("ẞ,ẞ", "ẞ ẞ")]
+	#[case::multiple_with_sequence("a d,d", "a,d d")]
 	fn read_value(#[case] binding: &str, #[case] expected: &str) {
 		with_git_config(&["[test]", format!("value = {binding}").as_str()], |git_config| {
 			assert_ok_eq!(
@@ -139,6 +159,12 @@ mod tests {
 
 	#[rstest]
 	#[case::multiple_characters("abcd")]
+	#[case::sequence_too_long("a,b,c,d,e")]
+	#[case::sequence_with_modifier("Control+g,g")]
+	#[case::sequence_with_shift("Shift+g,g")]
+	#[case::sequence_multiple_characters("g,gg")]
+	#[case::sequence_empty_key("g,,g")]
+	#[case::sequence_trailing_separator("g,")]
 	#[case::function_key_index("F256")]
 	#[case::multiple_bindings_one_invalid("f foo")]
 	fn read_value_invalid(#[case] binding: &str) {
@@ -0,0 +1,63 @@
+use crate::{
+	config::{ConfigError, ConfigErrorCause, KeyPreset, utils::get_string},
+	git::Config,
+};
+
+pub(crate) fn get_key_preset(git_config: Option<&Config>, name: &str) -> Result<KeyPreset, ConfigError> {
+	match get_string(git_config, name, "default")?.to_lowercase().as_str() {
+		"default" => Ok(KeyPreset::Default),
+		"vim" => Ok(KeyPreset::Vim),
+		"emacs" => Ok(KeyPreset::Emacs),
+		input => Err(ConfigError::new(name, input, ConfigErrorCause::InvalidKeyPreset)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_err_eq, assert_ok_eq};
+	use rstest::rstest;
+
+	use super::*;
+	use crate::test_helpers::{invalid_utf, with_git_config};
+
+	#[rstest]
+	#[case::default("default", KeyPreset::Default)]
+	#[case::vim("vim", KeyPreset::Vim)]
+	#[case::emacs("emacs", KeyPreset::Emacs)]
+	#[case::mixed_case("ViM", KeyPreset::Vim)]
+	fn read_ok(#[case] value: &str, #[case] expected: KeyPreset) {
+		with_git_config(&["[test]", format!("value = \"{value}\"").as_str()], |git_config| {
+			assert_ok_eq!(get_key_preset(Some(&git_config), "test.value"), expected);
+		});
+	}
+
+	#[test]
+	fn read_default() {
+		with_git_config(&[], |git_config| {
+			assert_ok_eq!(get_key_preset(Some(&git_config), "test.value"), KeyPreset::Default);
+		});
+	}
+
+	#[test]
+	fn read_invalid_value() {
+		with_git_config(&["[test]", "value = invalid"], |git_config| {
+			assert_err_eq!(
+				get_key_preset(Some(&git_config), "test.value"),
+				ConfigError::new("test.value", "invalid", ConfigErrorCause::InvalidKeyPreset)
+			);
+		});
+	}
+
+	#[test]
+	fn read_invalid_non_utf() {
+		with_git_config(
+			&["[test]", format!("value = {}", invalid_utf()).as_str()],
+			|git_config| {
+				assert_err_eq!(
+					get_key_preset(Some(&git_config), "test.value"),
+					ConfigError::new_read_error("test.value", ConfigErrorCause::InvalidUtf)
+				);
+			},
+		);
+	}
+}
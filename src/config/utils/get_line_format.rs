@@ -0,0 +1,63 @@
+use crate::{
+	config::{ConfigError, ConfigErrorCause, LineFormat, utils::get_optional_string},
+	git::Config,
+};
+
+pub(crate) fn get_line_format(git_config: Option<&Config>, name: &str) -> Result<Option<LineFormat>, ConfigError> {
+	let Some(value) = get_optional_string(git_config, name)?
+	else {
+		return Ok(None);
+	};
+	LineFormat::parse(value.as_str())
+		.map(Some)
+		.map_err(|message| ConfigError::new(name, value.as_str(), ConfigErrorCause::InvalidLineFormat(message)))
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_err_eq, assert_none, assert_ok, assert_some};
+
+	use super::*;
+	use crate::test_helpers::{invalid_utf, with_git_config};
+
+	#[test]
+	fn read_ok() {
+		with_git_config(&["[test]", "value = \"{action} {subject}\""], |git_config| {
+			assert_some!(assert_ok!(get_line_format(Some(&git_config), "test.value")));
+		});
+	}
+
+	#[test]
+	fn read_default() {
+		with_git_config(&[], |git_config| {
+			assert_none!(assert_ok!(get_line_format(Some(&git_config), "test.value")));
+		});
+	}
+
+	#[test]
+	fn read_invalid_value() {
+		with_git_config(&["[test]", "value = \"{action\""], |git_config| {
+			assert_err_eq!(
+				get_line_format(Some(&git_config), "test.value"),
+				ConfigError::new(
+					"test.value",
+					"{action",
+					ConfigErrorCause::InvalidLineFormat(String::from("Unclosed '{' in line format"))
+				)
+			);
+		});
+	}
+
+	#[test]
+	fn read_invalid_non_utf() {
+		with_git_config(
+			&["[test]", format!("value = {}", invalid_utf()).as_str()],
+			|git_config| {
+				assert_err_eq!(
+					get_line_format(Some(&git_config), "test.value"),
+					ConfigError::new_read_error("test.value", ConfigErrorCause::InvalidUtf)
+				);
+			},
+		);
+	}
+}
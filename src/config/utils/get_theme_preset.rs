@@ -0,0 +1,69 @@
+use crate::{
+	config::{ConfigError, ConfigErrorCause, ThemePreset, utils::get_string},
+	git::Config,
+};
+
+pub(crate) fn get_theme_preset(git_config: Option<&Config>, name: &str) -> Result<ThemePreset, ConfigError> {
+	match get_string(git_config, name, "default")?.to_lowercase().as_str() {
+		"default" => Ok(ThemePreset::Default),
+		"light" => Ok(ThemePreset::Light),
+		"dark" => Ok(ThemePreset::Dark),
+		"solarized" => Ok(ThemePreset::Solarized),
+		"high-contrast" => Ok(ThemePreset::HighContrast),
+		"colorblind-safe" => Ok(ThemePreset::ColorblindSafe),
+		input => Err(ConfigError::new(name, input, ConfigErrorCause::InvalidThemePreset)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_err_eq, assert_ok_eq};
+	use rstest::rstest;
+
+	use super::*;
+	use crate::test_helpers::{invalid_utf, with_git_config};
+
+	#[rstest]
+	#[case::default("default", ThemePreset::Default)]
+	#[case::light("light", ThemePreset::Light)]
+	#[case::dark("dark", ThemePreset::Dark)]
+	#[case::solarized("solarized", ThemePreset::Solarized)]
+	#[case::high_contrast("high-contrast", ThemePreset::HighContrast)]
+	#[case::colorblind_safe("colorblind-safe", ThemePreset::ColorblindSafe)]
+	#[case::mixed_case("Solarized", ThemePreset::Solarized)]
+	fn read_ok(#[case] value: &str, #[case] expected: ThemePreset) {
+		with_git_config(&["[test]", format!("value = \"{value}\"").as_str()], |git_config| {
+			assert_ok_eq!(get_theme_preset(Some(&git_config), "test.value"), expected);
+		});
+	}
+
+	#[test]
+	fn read_default() {
+		with_git_config(&[], |git_config| {
+			assert_ok_eq!(get_theme_preset(Some(&git_config), "test.value"), ThemePreset::Default);
+		});
+	}
+
+	#[test]
+	fn read_invalid_value() {
+		with_git_config(&["[test]", "value = invalid"], |git_config| {
+			assert_err_eq!(
+				get_theme_preset(Some(&git_config), "test.value"),
+				ConfigError::new("test.value", "invalid", ConfigErrorCause::InvalidThemePreset)
+			);
+		});
+	}
+
+	#[test]
+	fn read_invalid_non_utf() {
+		with_git_config(
+			&["[test]", format!("value = {}", invalid_utf()).as_str()],
+			|git_config| {
+				assert_err_eq!(
+					get_theme_preset(Some(&git_config), "test.value"),
+					ConfigError::new_read_error("test.value", ConfigErrorCause::InvalidUtf)
+				);
+			},
+		);
+	}
+}
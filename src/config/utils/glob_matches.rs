@@ -0,0 +1,101 @@
+/// Match the characters of a bracket expression, such as `[a-z]`, against a character, returning the length of the
+/// expression when it is complete.
+fn match_class(pattern: &[char], value: char) -> Option<(usize, bool)> {
+	let mut index = 1;
+	let negated = matches!(pattern.get(index), Some(&('!' | '^')));
+	if negated {
+		index += 1;
+	}
+	let mut matched = false;
+	let mut first = true;
+	loop {
+		let start = *pattern.get(index)?;
+		if start == ']' && !first {
+			return Some((index + 1, matched != negated && value != '/'));
+		}
+		first = false;
+		if pattern.get(index + 1) == Some(&'-') && pattern.get(index + 2).is_some_and(|end| *end != ']') {
+			let end = pattern[index + 2];
+			matched |= (start..=end).contains(&value);
+			index += 3;
+		}
+		else {
+			matched |= start == value;
+			index += 1;
+		}
+	}
+}
+
+fn match_from(pattern: &[char], value: &[char]) -> bool {
+	match pattern.first() {
+		None => value.is_empty(),
+		Some(&'*') if pattern.get(1) == Some(&'*') => {
+			// `**/` also matches no directories, so `a/**/b` matches `a/b`
+			(pattern.get(2) == Some(&'/') && match_from(&pattern[3..], value))
+				|| (0..=value.len()).any(|index| match_from(&pattern[2..], &value[index..]))
+		},
+		Some(&'*') => {
+			(0..=value.len())
+				.take_while(|index| *index == 0 || value[index - 1] != '/')
+				.any(|index| match_from(&pattern[1..], &value[index..]))
+		},
+		Some(&'?') => value.first().is_some_and(|c| *c != '/') && match_from(&pattern[1..], &value[1..]),
+		Some(&'[') => {
+			match (value.first(), value.first().and_then(|c| match_class(pattern, *c))) {
+				(Some(_), Some((length, matched))) => matched && match_from(&pattern[length..], &value[1..]),
+				// an incomplete bracket expression is matched as a literal `[`
+				(Some(&'['), None) => match_from(&pattern[1..], &value[1..]),
+				_ => false,
+			}
+		},
+		Some(&'\\') if pattern.len() > 1 => {
+			value.first() == Some(&pattern[1]) && match_from(&pattern[2..], &value[1..])
+		},
+		Some(c) => value.first() == Some(c) && match_from(&pattern[1..], &value[1..]),
+	}
+}
+
+/// Check if the whole of a value, such as a branch name, matches a glob pattern. Like the `fnmatch` pathname
+/// matching used by Git, `*`, `?` and bracket expressions do not match a `/`, while `**` matches across
+/// directories.
+pub(crate) fn glob_matches(pattern: &str, value: &str) -> bool {
+	match_from(
+		pattern.chars().collect::<Vec<char>>().as_slice(),
+		value.chars().collect::<Vec<char>>().as_slice(),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::exact("main", "main", true)]
+	#[case::exact_other("main", "develop", false)]
+	#[case::prefix("release", "release/1.0", false)]
+	#[case::star("release/*", "release/1.0", true)]
+	#[case::star_empty("release/*", "release/", true)]
+	#[case::star_no_separator("release/*", "release/1.0/hotfix", false)]
+	#[case::star_middle("release/*/hotfix", "release/1.0/hotfix", true)]
+	#[case::star_suffix("*-fix", "bug-fix", true)]
+	#[case::double_star("release/**", "release/1.0/hotfix", true)]
+	#[case::double_star_middle("release/**/hotfix", "release/1.0/2/hotfix", true)]
+	#[case::double_star_no_directory("release/**/hotfix", "release/hotfix", true)]
+	#[case::question("v?", "v1", true)]
+	#[case::question_separator("a?b", "a/b", false)]
+	#[case::question_empty("v?", "v", false)]
+	#[case::class("v[0-9]", "v5", true)]
+	#[case::class_other("v[0-9]", "va", false)]
+	#[case::class_list("[abc]", "b", true)]
+	#[case::class_negated("v[!0-9]", "va", true)]
+	#[case::class_negated_match("v[^0-9]", "v5", false)]
+	#[case::class_bracket("[]a]", "]", true)]
+	#[case::class_incomplete("[a", "[a", true)]
+	#[case::escape("a\\*", "a*", true)]
+	#[case::escape_other("a\\*", "ab", false)]
+	fn glob_matches_pattern(#[case] pattern: &str, #[case] value: &str, #[case] expected: bool) {
+		assert_eq!(glob_matches(pattern, value), expected);
+	}
+}
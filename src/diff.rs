@@ -1,3 +1,4 @@
+mod binary_diff;
 mod commit;
 mod commit_diff;
 mod commit_diff_loader;
@@ -7,15 +8,23 @@ mod diff_line;
 mod file_mode;
 mod file_status;
 mod file_status_builder;
+mod file_type;
+mod image_preview;
 mod origin;
+mod range_diff;
+mod range_diff_commit;
+mod range_diff_entry;
+mod range_diff_status;
 mod reference;
 mod reference_kind;
 mod status;
+mod textconv;
 mod user;
 
 pub(crate) mod thread;
 
 pub(crate) use self::{
+	binary_diff::{BinaryDiff, BinaryFile},
 	commit::Commit,
 	commit_diff::CommitDiff,
 	commit_diff_loader::CommitDiffLoader,
@@ -25,9 +34,16 @@ pub(crate) use self::{
 	file_mode::FileMode,
 	file_status::FileStatus,
 	file_status_builder::FileStatusBuilder,
+	file_type::FileType,
+	image_preview::ImagePreview,
 	origin::Origin,
+	range_diff::RangeDiff,
+	range_diff_commit::RangeDiffCommit,
+	range_diff_entry::RangeDiffEntry,
+	range_diff_status::RangeDiffStatus,
 	reference::Reference,
 	reference_kind::ReferenceKind,
 	status::Status,
+	textconv::{TextConv, TextConvDrivers},
 	user::User,
 };
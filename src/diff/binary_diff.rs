@@ -0,0 +1,194 @@
+use crate::diff::{FileType, ImagePreview};
+
+// the number of bytes shown in the dump of the first difference
+const DUMP_LENGTH: usize = 16;
+
+// the dump is aligned to the width of a dump line
+const fn get_dump_offset(first_difference: Option<usize>) -> usize {
+	match first_difference {
+		Some(d) => d - d % DUMP_LENGTH,
+		None => 0,
+	}
+}
+
+/// Details on one side of a change to a binary file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BinaryFile {
+	size: usize,
+	file_type: FileType,
+	dump: Vec<u8>,
+	preview: Option<ImagePreview>,
+}
+
+impl BinaryFile {
+	fn new(data: &[u8], dump_offset: usize) -> Self {
+		let file_type = FileType::detect(data);
+		let dump_end = data.len().min(dump_offset + DUMP_LENGTH);
+		Self {
+			size: data.len(),
+			file_type,
+			dump: data.get(dump_offset..dump_end).unwrap_or_default().to_vec(),
+			preview: if file_type.is_image() {
+				ImagePreview::new(data)
+			}
+			else {
+				None
+			},
+		}
+	}
+
+	/// Get the size of the file in bytes.
+	#[must_use]
+	pub(crate) const fn size(&self) -> usize {
+		self.size
+	}
+
+	/// Get the detected type of the file.
+	#[must_use]
+	pub(crate) const fn file_type(&self) -> FileType {
+		self.file_type
+	}
+
+	/// Get the bytes of the file, starting at the dump offset of the diff.
+	#[must_use]
+	pub(crate) fn dump(&self) -> &[u8] {
+		&self.dump
+	}
+
+	/// Get the image preview of the file, if the file is a supported image.
+	#[must_use]
+	pub(crate) const fn preview(&self) -> Option<&ImagePreview> {
+		self.preview.as_ref()
+	}
+}
+
+/// A summary of the change to a binary file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BinaryDiff {
+	old: Option<BinaryFile>,
+	new: Option<BinaryFile>,
+	first_difference: Option<usize>,
+}
+
+impl BinaryDiff {
+	/// Create a new instance from the old and new contents of a file, where a missing side is an added
+	/// or deleted file.
+	#[must_use]
+	pub(crate) fn new(old: Option<&[u8]>, new: Option<&[u8]>) -> Self {
+		let first_difference = match (old, new) {
+			(Some(o), Some(n)) => {
+				o.iter()
+					.zip(n.iter())
+					.position(|(a, b)| a != b)
+					.or_else(|| (o.len() != n.len()).then(|| o.len().min(n.len())))
+			},
+			(None, None) => None,
+			_ => Some(0),
+		};
+		let dump_offset = get_dump_offset(first_difference);
+
+		Self {
+			old: old.map(|data| BinaryFile::new(data, dump_offset)),
+			new: new.map(|data| BinaryFile::new(data, dump_offset)),
+			first_difference,
+		}
+	}
+
+	/// Get the old version of the file, if the file was not added.
+	#[must_use]
+	pub(crate) const fn old(&self) -> Option<&BinaryFile> {
+		self.old.as_ref()
+	}
+
+	/// Get the new version of the file, if the file was not deleted.
+	#[must_use]
+	pub(crate) const fn new_file(&self) -> Option<&BinaryFile> {
+		self.new.as_ref()
+	}
+
+	/// Get the offset of the first byte that differs between the old and new file.
+	#[must_use]
+	pub(crate) const fn first_difference(&self) -> Option<usize> {
+		self.first_difference
+	}
+
+	/// Get the offset of the first byte in the dumps.
+	#[must_use]
+	pub(crate) const fn dump_offset(&self) -> usize {
+		get_dump_offset(self.first_difference)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_none, assert_some, assert_some_eq};
+
+	use super::*;
+
+	#[test]
+	fn modified() {
+		let old = (0..40).collect::<Vec<u8>>();
+		let mut new = old.clone();
+		new[20] = 0xFF;
+		new.push(0);
+		let diff = BinaryDiff::new(Some(&old), Some(&new));
+		assert_some_eq!(diff.first_difference(), 20);
+		assert_eq!(diff.dump_offset(), 16);
+		assert_eq!(diff.old().unwrap().size(), 40);
+		assert_eq!(diff.new_file().unwrap().size(), 41);
+		assert_eq!(diff.old().unwrap().dump(), &old[16..32]);
+		assert_eq!(diff.new_file().unwrap().dump(), &new[16..32]);
+		assert_eq!(diff.old().unwrap().file_type(), FileType::Unknown);
+	}
+
+	#[test]
+	fn truncated() {
+		let old = [1, 2, 3, 4];
+		let diff = BinaryDiff::new(Some(&old), Some(&old[..2]));
+		assert_some_eq!(diff.first_difference(), 2);
+		assert_eq!(diff.dump_offset(), 0);
+		assert_eq!(diff.new_file().unwrap().dump(), &[1, 2]);
+	}
+
+	#[test]
+	fn unchanged() {
+		let data = [1, 2, 3];
+		let diff = BinaryDiff::new(Some(&data), Some(&data));
+		assert_none!(diff.first_difference());
+		assert_eq!(diff.dump_offset(), 0);
+	}
+
+	#[test]
+	fn added() {
+		let diff = BinaryDiff::new(None, Some(b"%PDF-1.7"));
+		assert_none!(diff.old());
+		assert_some_eq!(diff.first_difference(), 0);
+		assert_eq!(diff.new_file().unwrap().file_type(), FileType::Pdf);
+		assert_eq!(diff.new_file().unwrap().dump(), b"%PDF-1.7");
+	}
+
+	#[test]
+	fn deleted() {
+		let diff = BinaryDiff::new(Some(b"PK\x03\x04"), None);
+		assert_none!(diff.new_file());
+		assert_eq!(diff.old().unwrap().file_type(), FileType::Zip);
+	}
+
+	#[test]
+	fn neither_side() {
+		let diff = BinaryDiff::new(None, None);
+		assert_none!(diff.first_difference());
+	}
+
+	#[test]
+	fn image_preview() {
+		let diff = BinaryDiff::new(None, Some(b"P5 1 1 255 \xFF"));
+		assert_some!(diff.new_file().unwrap().preview());
+	}
+
+	#[test]
+	fn no_image_preview_for_non_image() {
+		let diff = BinaryDiff::new(None, Some(b"PK\x03\x04"));
+		assert_none!(diff.new_file().unwrap().preview());
+	}
+}
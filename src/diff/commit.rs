@@ -43,7 +43,6 @@ impl Commit {
 
 	/// Get the reference to the commit
 	#[must_use]
-	#[expect(dead_code, reason = "Available for future use.")]
 	pub(crate) const fn reference(&self) -> Option<&Reference> {
 		self.reference.as_ref()
 	}
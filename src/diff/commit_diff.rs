@@ -9,6 +9,9 @@ pub(crate) struct CommitDiff {
 	number_files_changed: usize,
 	number_insertions: usize,
 	number_deletions: usize,
+	number_files_excluded: usize,
+	number_insertions_excluded: usize,
+	number_deletions_excluded: usize,
 }
 
 impl CommitDiff {
@@ -20,6 +23,9 @@ impl CommitDiff {
 			number_files_changed: 0,
 			number_insertions: 0,
 			number_deletions: 0,
+			number_files_excluded: 0,
+			number_insertions_excluded: 0,
+			number_deletions_excluded: 0,
 		}
 	}
 
@@ -60,6 +66,24 @@ impl CommitDiff {
 		self.number_deletions
 	}
 
+	/// The number of files in the diff that are excluded.
+	#[must_use]
+	pub(crate) const fn number_files_excluded(&self) -> usize {
+		self.number_files_excluded
+	}
+
+	/// The number of insertions in the excluded files of the diff.
+	#[must_use]
+	pub(crate) const fn number_insertions_excluded(&self) -> usize {
+		self.number_insertions_excluded
+	}
+
+	/// The number of deletions in the excluded files of the diff.
+	#[must_use]
+	pub(crate) const fn number_deletions_excluded(&self) -> usize {
+		self.number_deletions_excluded
+	}
+
 	/// Update the details of the diff
 	pub(crate) fn update(
 		&mut self,
@@ -74,6 +98,18 @@ impl CommitDiff {
 		self.number_deletions = number_deletions;
 	}
 
+	/// Update the details of the excluded files of the diff.
+	pub(crate) const fn update_excluded(
+		&mut self,
+		number_files_excluded: usize,
+		number_insertions_excluded: usize,
+		number_deletions_excluded: usize,
+	) {
+		self.number_files_excluded = number_files_excluded;
+		self.number_insertions_excluded = number_insertions_excluded;
+		self.number_deletions_excluded = number_deletions_excluded;
+	}
+
 	/// Reset the diff back to an empty state
 	pub(crate) fn reset(&mut self, commit: Commit, parent: Option<Commit>) {
 		self.commit = commit;
@@ -82,6 +118,9 @@ impl CommitDiff {
 		self.number_files_changed = 0;
 		self.number_insertions = 0;
 		self.number_deletions = 0;
+		self.number_files_excluded = 0;
+		self.number_insertions_excluded = 0;
+		self.number_deletions_excluded = 0;
 	}
 
 	pub(crate) fn clear(&mut self) {
@@ -91,6 +130,9 @@ impl CommitDiff {
 		self.number_files_changed = 0;
 		self.number_insertions = 0;
 		self.number_deletions = 0;
+		self.number_files_excluded = 0;
+		self.number_insertions_excluded = 0;
+		self.number_deletions_excluded = 0;
 	}
 }
 
@@ -113,6 +155,22 @@ mod tests {
 		assert_eq!(diff.number_files_changed(), 0);
 		assert_eq!(diff.number_insertions(), 0);
 		assert_eq!(diff.number_deletions(), 0);
+		assert_eq!(diff.number_files_excluded(), 0);
+		assert_eq!(diff.number_insertions_excluded(), 0);
+		assert_eq!(diff.number_deletions_excluded(), 0);
+	}
+
+	#[test]
+	fn update_excluded() {
+		let mut diff = CommitDiff::new();
+		diff.update_excluded(1, 2, 3);
+		assert_eq!(diff.number_files_excluded(), 1);
+		assert_eq!(diff.number_insertions_excluded(), 2);
+		assert_eq!(diff.number_deletions_excluded(), 3);
+		diff.clear();
+		assert_eq!(diff.number_files_excluded(), 0);
+		assert_eq!(diff.number_insertions_excluded(), 0);
+		assert_eq!(diff.number_deletions_excluded(), 0);
 	}
 
 	#[test]
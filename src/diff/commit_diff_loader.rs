@@ -1,15 +1,17 @@
 use std::{
+	cell::Cell,
 	fmt::{Debug, Formatter},
 	path::PathBuf,
 	sync::{Arc, LazyLock},
 	time::{Duration, Instant},
 };
 
-use git2::{Diff, DiffFindOptions, DiffOptions, Repository};
+use git2::{Diff, DiffFindOptions, DiffLineType, DiffOptions, Patch, Pathspec, PathspecFlags, Repository};
 use parking_lot::{Mutex, RwLock};
 
 use crate::{
 	diff::{
+		BinaryDiff,
 		Commit,
 		CommitDiff,
 		CommitDiffLoaderOptions,
@@ -18,7 +20,11 @@ use crate::{
 		FileMode,
 		FileStatus,
 		FileStatusBuilder,
+		RangeDiff,
+		RangeDiffCommit,
 		Status,
+		TextConv,
+		TextConvDrivers,
 		thread::LoadStatus,
 	},
 	git::GitError,
@@ -26,6 +32,17 @@ use crate::{
 
 static UNKNOWN_PATH: LazyLock<PathBuf> = LazyLock::new(|| PathBuf::from("unknown"));
 
+// binary files larger than this are not loaded to build a binary diff summary
+const MAX_BINARY_DIFF_SIZE: u64 = 16 * 1024 * 1024;
+
+fn count_line(origin: DiffLineType, insertions: &Cell<usize>, deletions: &Cell<usize>) {
+	match origin {
+		DiffLineType::Addition => insertions.set(insertions.get() + 1),
+		DiffLineType::Deletion => deletions.set(deletions.get() + 1),
+		_ => {},
+	}
+}
+
 pub(crate) trait DiffUpdateHandlerFn: Fn(LoadStatus) -> bool + Sync + Send {}
 
 impl<FN: Fn(LoadStatus) -> bool + Sync + Send> DiffUpdateHandlerFn for FN {}
@@ -58,16 +75,15 @@ impl CommitDiffLoader {
 		self.commit_diff.write().clear();
 	}
 
+	pub(crate) fn set_options(&mut self, config: CommitDiffLoaderOptions) {
+		self.config = config;
+	}
+
 	pub(crate) fn commit_diff(&self) -> Arc<RwLock<CommitDiff>> {
 		Arc::clone(&self.commit_diff)
 	}
 
-	fn diff<'repo>(
-		repository: &'repo Repository,
-		config: &CommitDiffLoaderOptions,
-		commit: &git2::Commit<'_>,
-		diff_options: &mut DiffOptions,
-	) -> Result<Diff<'repo>, GitError> {
+	fn configure_diff_options(config: &CommitDiffLoaderOptions, diff_options: &mut DiffOptions) {
 		_ = diff_options
 			.context_lines(config.context_lines)
 			.ignore_filemode(false)
@@ -79,9 +95,40 @@ impl CommitDiffLoader {
 			.indent_heuristic(true)
 			.interhunk_lines(config.interhunk_context)
 			.minimal(true);
+	}
+
+	fn diff<'repo>(
+		repository: &'repo Repository,
+		config: &CommitDiffLoaderOptions,
+		commit: &git2::Commit<'_>,
+		diff_options: &mut DiffOptions,
+	) -> Result<Diff<'repo>, GitError> {
+		Self::configure_diff_options(config, diff_options);
 
 		let commit_tree = commit.tree().map_err(|e| GitError::DiffLoad { cause: e })?;
 
+		// a merge commit is shown as a combined diff, limited to the files that differ from every
+		// parent, as the files that match one of the parents were taken from that parent unchanged
+		if commit.parent_count() > 1 {
+			let paths = Self::merge_changed_paths(repository, commit, &commit_tree)?;
+			let first_parent_tree = commit
+				.parent(0)
+				.and_then(|p| p.tree())
+				.map_err(|e| GitError::DiffLoad { cause: e })?;
+			if paths.is_empty() {
+				return repository
+					.diff_tree_to_tree(Some(&first_parent_tree), Some(&first_parent_tree), Some(diff_options))
+					.map_err(|e| GitError::DiffLoad { cause: e });
+			}
+			_ = diff_options.disable_pathspec_match(true);
+			for path in paths {
+				_ = diff_options.pathspec(path);
+			}
+			return repository
+				.diff_tree_to_tree(Some(&first_parent_tree), Some(&commit_tree), Some(diff_options))
+				.map_err(|e| GitError::DiffLoad { cause: e });
+		}
+
 		if let Some(p) = commit.parents().next() {
 			let parent_tree = p.tree().map_err(|e| GitError::DiffLoad { cause: e })?;
 			repository.diff_tree_to_tree(Some(&parent_tree), Some(&commit_tree), Some(diff_options))
@@ -92,11 +139,39 @@ impl CommitDiffLoader {
 		.map_err(|e| GitError::DiffLoad { cause: e })
 	}
 
+	fn merge_changed_paths(
+		repository: &Repository,
+		commit: &git2::Commit<'_>,
+		commit_tree: &git2::Tree<'_>,
+	) -> Result<Vec<PathBuf>, GitError> {
+		let mut changed_paths: Option<Vec<PathBuf>> = None;
+		for parent in commit.parents() {
+			let parent_tree = parent.tree().map_err(|e| GitError::DiffLoad { cause: e })?;
+			let parent_paths = repository
+				.diff_tree_to_tree(Some(&parent_tree), Some(commit_tree), None)
+				.map_err(|e| GitError::DiffLoad { cause: e })?
+				.deltas()
+				.filter_map(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+				.map(PathBuf::from)
+				.collect::<Vec<PathBuf>>();
+			changed_paths = Some(match changed_paths {
+				None => parent_paths,
+				Some(paths) => paths.into_iter().filter(|p| parent_paths.contains(p)).collect(),
+			});
+		}
+		Ok(changed_paths.unwrap_or_default())
+	}
+
 	pub(crate) fn load_diff(&mut self, hash: &str, update_notifier: impl DiffUpdateHandlerFn) -> Result<(), GitError> {
+		// a reference, such as the target of an update-ref, is loaded through the reference, so that
+		// the reference information is available on the loaded commit
+		let reference = self.repository.find_reference(hash).ok();
 		let oid = self
 			.repository
 			.revparse_single(hash)
 			.map_err(|e| GitError::DiffLoad { cause: e })?
+			.peel_to_commit()
+			.map_err(|e| GitError::DiffLoad { cause: e })?
 			.id();
 		let commit = self
 			.repository
@@ -107,19 +182,26 @@ impl CommitDiffLoader {
 			// only the first parent matter for things like diffs, the second parent, if it exists,
 			// is only used for conflict resolution, and has no use
 			let parent = commit.parents().next().map(|c| Commit::from(&c));
+			let loaded_commit = match reference {
+				Some(ref r) => Commit::try_from(r)?,
+				None => Commit::from(&commit),
+			};
 			let mut commit_diff = self.commit_diff.write();
-			commit_diff.reset(Commit::from(&commit), parent);
+			commit_diff.reset(loaded_commit, parent);
 			if update_notifier(LoadStatus::New) {
 				return Ok(());
 			}
 		}
 
+		let textconv_drivers = TextConvDrivers::load(&self.repository);
+
 		// when a diff contains a lot of untracked files, collecting the diff information can take
 		// upwards of a minute. This performs a quicker diff, that does not detect copies and
 		// renames against unmodified files.
 		if self.config.copies {
 			let should_continue = self.collect(
 				&Self::diff(&self.repository, &self.config, &commit, &mut DiffOptions::new())?,
+				&textconv_drivers,
 				&update_notifier,
 				true,
 			)?;
@@ -145,7 +227,7 @@ impl CommitDiffLoader {
 
 		diff.find_similar(Some(&mut diff_find_options))
 			.map_err(|e| GitError::DiffLoad { cause: e })?;
-		let should_continue = self.collect(&diff, &update_notifier, false)?;
+		let should_continue = self.collect(&diff, &textconv_drivers, &update_notifier, false)?;
 
 		if should_continue {
 			_ = update_notifier(LoadStatus::DiffComplete);
@@ -154,15 +236,83 @@ impl CommitDiffLoader {
 		Ok(())
 	}
 
+	pub(crate) fn load_range_diff(
+		&self,
+		original: &[String],
+		edited: &[RangeDiffCommit],
+	) -> Result<RangeDiff, GitError> {
+		RangeDiff::load(&self.repository, original, edited)
+	}
+
+	fn load_binary_blob(&self, file: &git2::DiffFile<'_>) -> Option<git2::Blob<'_>> {
+		if !file.exists() || file.size() > MAX_BINARY_DIFF_SIZE {
+			return None;
+		}
+		self.repository.find_blob(file.id()).ok()
+	}
+
+	fn textconv_blob(
+		&self,
+		textconv: &TextConv,
+		file: &git2::DiffFile<'_>,
+		is_cancelled: &impl Fn() -> bool,
+	) -> Option<Vec<u8>> {
+		if !file.exists() {
+			return Some(vec![]);
+		}
+		textconv.convert(self.repository.find_blob(file.id()).ok()?.content(), is_cancelled)
+	}
+
+	// the textconv output of the old and new file, when the `diff` attribute of the
+	// file selects a diff driver with a textconv program, and the program converts both files
+	fn textconv_contents(
+		&self,
+		textconv_drivers: &TextConvDrivers,
+		diff_delta: &git2::DiffDelta<'_>,
+		is_cancelled: &impl Fn() -> bool,
+	) -> Option<(Vec<u8>, Vec<u8>)> {
+		let path = diff_delta.new_file().path().or_else(|| diff_delta.old_file().path())?;
+		let textconv = textconv_drivers.for_path(&self.repository, path)?;
+		Some((
+			self.textconv_blob(textconv, &diff_delta.old_file(), is_cancelled)?,
+			self.textconv_blob(textconv, &diff_delta.new_file(), is_cancelled)?,
+		))
+	}
+
 	pub(crate) fn collect(
 		&self,
 		diff: &Diff<'_>,
+		textconv_drivers: &TextConvDrivers,
 		update_handler: &impl DiffUpdateHandlerFn,
 		quick: bool,
 	) -> Result<bool, GitError> {
 		let file_stats_builder = Mutex::new(FileStatusBuilder::new());
 		let mut unmodified_file_count: usize = 0;
 		let mut change_count: usize = 0;
+		let mut excluded_file_count: usize = 0;
+		let excluded_insertions = Cell::new(0);
+		let excluded_deletions = Cell::new(0);
+		// lines of files diffed using textconv replace the lines of the original diff of the files
+		let is_textconv_file = Cell::new(false);
+		let textconv_insertions = Cell::new(0);
+		let textconv_deletions = Cell::new(0);
+		let replaced_insertions = Cell::new(0);
+		let replaced_deletions = Cell::new(0);
+
+		let exclude_pathspec = if self.config.exclude_paths.is_empty() {
+			None
+		}
+		else {
+			Some(Pathspec::new(self.config.exclude_paths.iter()).map_err(|e| GitError::DiffLoad { cause: e })?)
+		};
+		let is_excluded = |diff_delta: &git2::DiffDelta<'_>| {
+			exclude_pathspec.as_ref().is_some_and(|pathspec| {
+				[diff_delta.old_file().path(), diff_delta.new_file().path()]
+					.into_iter()
+					.flatten()
+					.any(|path| pathspec.matches_path(path, PathspecFlags::DEFAULT))
+			})
+		};
 
 		let stats = diff.stats().map_err(|e| GitError::DiffLoad { cause: e })?;
 		let total_files_changed = stats.files_changed();
@@ -210,26 +360,105 @@ impl CommitDiffLoader {
 				let destination_file_mode = FileMode::from(destination_file.mode());
 				let destination_file_path = destination_file.path().unwrap_or(UNKNOWN_PATH.as_path());
 
-				let mut fsb = file_stats_builder.lock();
-				fsb.add_file_stat(FileStatus::new(
+				// the load is cancelled through the update handler, which is checked while a textconv program runs
+				let cancelled = Cell::new(false);
+				let is_cancelled = || {
+					cancelled.set(update_handler(create_status_update(
+						quick,
+						change_count,
+						total_files_changed,
+					)));
+					cancelled.get()
+				};
+				let textconv_contents = self.textconv_contents(textconv_drivers, &diff_delta, &is_cancelled);
+				if cancelled.get() {
+					return false;
+				}
+				is_textconv_file.set(textconv_contents.is_some());
+
+				let mut file_status = FileStatus::new(
 					source_file_path,
 					source_file_mode,
-					source_file.is_binary(),
+					source_file.is_binary() && textconv_contents.is_none(),
 					destination_file_path,
 					destination_file_mode,
-					destination_file.is_binary(),
+					destination_file.is_binary() && textconv_contents.is_none(),
 					Status::from(diff_delta.status()),
-				));
+				);
+				let excluded = is_excluded(&diff_delta);
+				if excluded {
+					excluded_file_count += 1;
+					file_status.set_excluded(true);
+				}
+				// the blobs are only read for the summary of a binary file that is shown, and the quick diff
+				// is replaced by the full diff
+				if !excluded
+					&& !quick && textconv_contents.is_none()
+					&& (source_file.is_binary() || destination_file.is_binary())
+				{
+					let source_blob = self.load_binary_blob(&source_file);
+					let destination_blob = self.load_binary_blob(&destination_file);
+					file_status.set_binary_diff(BinaryDiff::new(
+						source_blob.as_ref().map(git2::Blob::content),
+						destination_blob.as_ref().map(git2::Blob::content),
+					));
+				}
+
+				let mut fsb = file_stats_builder.lock();
+				fsb.add_file_stat(file_status);
+
+				if let Some((old_content, new_content)) = textconv_contents {
+					let mut diff_options = DiffOptions::new();
+					Self::configure_diff_options(&self.config, &mut diff_options);
+					let Ok(patch) = Patch::from_buffers(
+						old_content.as_slice(),
+						Some(source_file_path),
+						new_content.as_slice(),
+						Some(destination_file_path),
+						Some(&mut diff_options),
+					)
+					else {
+						return true;
+					};
+					for hunk_index in 0..patch.num_hunks() {
+						let Ok((diff_hunk, line_count)) = patch.hunk(hunk_index)
+						else {
+							continue;
+						};
+						fsb.add_delta(Delta::from(&diff_hunk));
+						for line_index in 0..line_count {
+							let Ok(diff_line) = patch.line_in_hunk(hunk_index, line_index)
+							else {
+								continue;
+							};
+							count_line(diff_line.origin_value(), &textconv_insertions, &textconv_deletions);
+							if excluded {
+								count_line(diff_line.origin_value(), &excluded_insertions, &excluded_deletions);
+							}
+							fsb.add_diff_line(DiffLine::from(&diff_line));
+						}
+					}
+				}
 
 				true
 			},
 			None,
 			Some(&mut |_, diff_hunk| {
+				if is_textconv_file.get() {
+					return true;
+				}
 				let mut fsb = file_stats_builder.lock();
 				fsb.add_delta(Delta::from(&diff_hunk));
 				true
 			}),
-			Some(&mut |_, _, diff_line| {
+			Some(&mut |diff_delta, _, diff_line| {
+				if is_textconv_file.get() {
+					count_line(diff_line.origin_value(), &replaced_insertions, &replaced_deletions);
+					return true;
+				}
+				if is_excluded(&diff_delta) {
+					count_line(diff_line.origin_value(), &excluded_insertions, &excluded_deletions);
+				}
 				let mut fsb = file_stats_builder.lock();
 				fsb.add_diff_line(DiffLine::from(&diff_line));
 				true
@@ -244,11 +473,13 @@ impl CommitDiffLoader {
 		let mut commit_diff = self.commit_diff.write();
 
 		let number_files_changed = total_files_changed - unmodified_file_count;
-		let number_insertions = stats.insertions();
-		let number_deletions = stats.deletions();
+		let number_insertions =
+			stats.insertions().saturating_sub(replaced_insertions.get()) + textconv_insertions.get();
+		let number_deletions = stats.deletions().saturating_sub(replaced_deletions.get()) + textconv_deletions.get();
 
 		let fsb = file_stats_builder.into_inner();
 		commit_diff.update(fsb.build(), number_files_changed, number_insertions, number_deletions);
+		commit_diff.update_excluded(excluded_file_count, excluded_insertions.get(), excluded_deletions.get());
 		Ok(true)
 	}
 }
@@ -272,6 +503,7 @@ mod tests {
 		os::unix::fs::symlink,
 	};
 
+	use claims::{assert_none, assert_some_eq};
 	use git2::Index;
 
 	use super::*;
@@ -545,6 +777,73 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn load_from_hash_exclude_paths() {
+		with_temp_repository(|repository| {
+			write_normal_file(&repository, "a", &["line1"]);
+			write_normal_file(&repository, "c.snap", &["old"]);
+			create_commit(&repository);
+			write_normal_file(&repository, "a", &["line2"]);
+			write_normal_file(&repository, "b.lock", &["x", "y"]);
+			write_normal_file(&repository, "c.snap", &["new"]);
+			create_commit(&repository);
+
+			let loader = diff_from_head(
+				repository,
+				CommitDiffLoaderOptions::new().exclude_paths(vec![String::from("*.lock"), String::from("*.snap")]),
+			)
+			.unwrap();
+			let diff = loader.take_diff();
+
+			assert_eq!(diff.number_files_changed(), 3);
+			assert_eq!(diff.number_insertions(), 4);
+			assert_eq!(diff.number_deletions(), 2);
+			assert_eq!(diff.number_files_excluded(), 2);
+			assert_eq!(diff.number_insertions_excluded(), 3);
+			assert_eq!(diff.number_deletions_excluded(), 1);
+			let excluded = diff
+				.file_statuses()
+				.iter()
+				.map(|s| (s.destination_path().to_string_lossy().to_string(), s.is_excluded()))
+				.collect::<Vec<_>>();
+			assert_eq!(excluded, vec![
+				(String::from("a"), false),
+				(String::from("b.lock"), true),
+				(String::from("c.snap"), true),
+			]);
+		});
+	}
+
+	#[test]
+	fn load_from_hash_after_set_options() {
+		with_temp_repository(|repository| {
+			write_normal_file(&repository, "a.lock", &["line1"]);
+			create_commit(&repository);
+
+			let mut loader = diff_from_head(repository, CommitDiffLoaderOptions::new()).unwrap();
+			loader.set_options(CommitDiffLoaderOptions::new().exclude_paths(vec![String::from("*.lock")]));
+			let hash = loader.commit_diff().read().commit().hash().to_owned();
+			loader.load_diff(hash.as_str(), |_| false).unwrap();
+			let diff = loader.take_diff();
+
+			assert_eq!(diff.number_files_excluded(), 1);
+		});
+	}
+
+	#[test]
+	fn load_from_hash_without_exclude_paths() {
+		with_temp_repository(|repository| {
+			write_normal_file(&repository, "a.lock", &["line1"]);
+			create_commit(&repository);
+
+			let loader = diff_from_head(repository, CommitDiffLoaderOptions::new()).unwrap();
+			let diff = loader.take_diff();
+
+			assert_eq!(diff.number_files_excluded(), 0);
+			assert!(!diff.file_statuses()[0].is_excluded());
+		});
+	}
+
 	#[test]
 	fn load_from_hash_with_context() {
 		with_temp_repository(|repository| {
@@ -845,6 +1144,231 @@ mod tests {
 			assert_eq!(diff.number_insertions(), 0);
 			assert_eq!(diff.number_deletions(), 0);
 			assert_commit_diff!(&diff, "a (n,b)", "Status Modified");
+			let binary_diff = diff.file_statuses()[0].binary_diff().unwrap();
+			assert_eq!(binary_diff.old().unwrap().size(), 6);
+			assert_eq!(binary_diff.new_file().unwrap().size(), 6);
+			assert_some_eq!(binary_diff.first_difference(), 4);
+		});
+	}
+
+	#[test]
+	fn load_from_hash_binary_excluded_file() {
+		with_temp_repository(|repository| {
+			write_normal_file(&repository, ".gitattributes", &["a binary"]);
+			write_normal_file(&repository, "a", &["line1"]);
+			create_commit(&repository);
+			write_normal_file(&repository, "a", &["line2"]);
+			create_commit(&repository);
+
+			let loader = diff_from_head(
+				repository,
+				CommitDiffLoaderOptions::new().exclude_paths(vec![String::from("a")]),
+			)
+			.unwrap();
+			let diff = loader.take_diff();
+
+			assert!(diff.file_statuses()[0].is_excluded());
+			assert_none!(diff.file_statuses()[0].binary_diff());
+		});
+	}
+
+	#[test]
+	fn load_from_hash_no_diff_attribute() {
+		with_temp_repository(|repository| {
+			write_normal_file(&repository, ".gitattributes", &["a -diff"]);
+			write_normal_file(&repository, "a", &["line1"]);
+			create_commit(&repository);
+			write_normal_file(&repository, "a", &["line2"]);
+			create_commit(&repository);
+
+			let loader = diff_from_head(repository, CommitDiffLoaderOptions::new()).unwrap();
+			let diff = loader.take_diff();
+
+			assert_eq!(diff.number_insertions(), 0);
+			assert_eq!(diff.number_deletions(), 0);
+			assert_commit_diff!(&diff, "a (n,b)", "Status Modified");
+		});
+	}
+
+	#[test]
+	fn load_from_hash_textconv() {
+		with_temp_repository(|repository| {
+			repository
+				.config()
+				.unwrap()
+				.set_str("diff.upper.textconv", "tr a-z A-Z <")
+				.unwrap();
+			write_normal_file(&repository, ".gitattributes", &["a diff=upper"]);
+			write_normal_file(&repository, "a", &["line1", "line2"]);
+			create_commit(&repository);
+			write_normal_file(&repository, "a", &["line1", "line3"]);
+			create_commit(&repository);
+
+			let loader = diff_from_head(repository, CommitDiffLoaderOptions::new()).unwrap();
+			let diff = loader.take_diff();
+
+			assert_eq!(diff.number_files_changed(), 1);
+			assert_eq!(diff.number_insertions(), 1);
+			assert_eq!(diff.number_deletions(), 1);
+			assert_commit_diff!(
+				&diff,
+				"a (n)",
+				"Status Modified",
+				"@@ -2,1 +2,1 @@ LINE1",
+				"-2  | LINE2",
+				"+  2| LINE3"
+			);
+		});
+	}
+
+	#[test]
+	fn load_from_hash_textconv_binary() {
+		with_temp_repository(|repository| {
+			repository
+				.config()
+				.unwrap()
+				.set_str("diff.upper.textconv", "tr a-z A-Z <")
+				.unwrap();
+			write_normal_file(&repository, ".gitattributes", &["a binary diff=upper"]);
+			write_normal_file(&repository, "a", &["line1"]);
+			create_commit(&repository);
+			write_normal_file(&repository, "a", &["line2"]);
+			create_commit(&repository);
+
+			let loader = diff_from_head(repository, CommitDiffLoaderOptions::new()).unwrap();
+			let diff = loader.take_diff();
+
+			assert_eq!(diff.number_insertions(), 1);
+			assert_eq!(diff.number_deletions(), 1);
+			assert_none!(diff.file_statuses()[0].binary_diff());
+			assert_commit_diff!(
+				&diff,
+				"a (n)",
+				"Status Modified",
+				"@@ -1,1 +1,1 @@",
+				"-1  | LINE1",
+				"+  1| LINE2"
+			);
+		});
+	}
+
+	#[test]
+	fn load_from_hash_textconv_failure() {
+		with_temp_repository(|repository| {
+			repository
+				.config()
+				.unwrap()
+				.set_str("diff.fail.textconv", "false")
+				.unwrap();
+			write_normal_file(&repository, ".gitattributes", &["a diff=fail"]);
+			write_normal_file(&repository, "a", &["line1"]);
+			create_commit(&repository);
+			write_normal_file(&repository, "a", &["line2"]);
+			create_commit(&repository);
+
+			let loader = diff_from_head(repository, CommitDiffLoaderOptions::new()).unwrap();
+			let diff = loader.take_diff();
+
+			assert_commit_diff!(
+				&diff,
+				"a (n)",
+				"Status Modified",
+				"@@ -1,1 +1,1 @@",
+				"-1  | line1",
+				"+  1| line2"
+			);
+		});
+	}
+
+	#[cfg(not(tarpaulin_include))]
+	fn create_commit_with_parents<'repo>(
+		repository: &'repo Repository,
+		update_ref: Option<&str>,
+		parents: &[&git2::Commit<'_>],
+	) -> git2::Commit<'repo> {
+		let sig = git2::Signature::new("name", "name@example.com", &git2::Time::new(1_609_459_200, 0)).unwrap();
+		let tree = repository.find_tree(index(repository).write_tree().unwrap()).unwrap();
+		let oid = repository
+			.commit(update_ref, &sig, &sig, "title", &tree, parents)
+			.unwrap();
+		repository.find_commit(oid).unwrap()
+	}
+
+	#[cfg(not(tarpaulin_include))]
+	fn create_merge(repository: &Repository, merged_contents: &[&str]) {
+		write_normal_file(repository, "a", &["base"]);
+		write_normal_file(repository, "b", &["base"]);
+		write_normal_file(repository, "c", &["base"]);
+		create_commit(repository);
+		let base = commit_from_ref(repository, "refs/heads/main");
+
+		write_normal_file(repository, "a", &["main"]);
+		create_commit(repository);
+		let main = commit_from_ref(repository, "refs/heads/main");
+
+		write_normal_file(repository, "a", &["base"]);
+		write_normal_file(repository, "b", &["side"]);
+		let side = create_commit_with_parents(repository, None, &[&base]);
+
+		write_normal_file(repository, "a", &["main"]);
+		write_normal_file(repository, "b", &["side"]);
+		write_normal_file(repository, "c", merged_contents);
+		_ = create_commit_with_parents(repository, Some("HEAD"), &[&main, &side]);
+	}
+
+	#[test]
+	fn load_from_hash_merge_combined_diff() {
+		with_temp_repository(|repository| {
+			create_merge(&repository, &["merged"]);
+			let loader = diff_from_head(repository, CommitDiffLoaderOptions::new()).unwrap();
+			let diff = loader.take_diff();
+
+			assert_eq!(diff.number_files_changed(), 1);
+			assert_commit_diff!(
+				&diff,
+				"c (n)",
+				"Status Modified",
+				"@@ -1,1 +1,1 @@",
+				"-1  | base",
+				"+  1| merged"
+			);
+		});
+	}
+
+	#[test]
+	fn load_from_hash_merge_without_changes() {
+		with_temp_repository(|repository| {
+			create_merge(&repository, &["base"]);
+			let loader = diff_from_head(repository, CommitDiffLoaderOptions::new()).unwrap();
+			let diff = loader.take_diff();
+
+			assert_eq!(diff.number_files_changed(), 0);
+			assert_eq!(diff.number_insertions(), 0);
+			assert_eq!(diff.number_deletions(), 0);
+		});
+	}
+
+	#[test]
+	fn load_from_reference() {
+		with_temp_repository(|repository| {
+			write_normal_file(&repository, "a", &["line1"]);
+			create_commit(&repository);
+			let hash = commit_from_ref(&repository, "refs/heads/main").id().to_string();
+			let mut loader = CommitDiffLoader::new(repository, CommitDiffLoaderOptions::new());
+			loader.load_diff("refs/heads/main", |_| false).unwrap();
+			let diff = loader.take_diff();
+
+			assert_eq!(diff.commit().hash(), hash);
+			assert_eq!(diff.commit().reference().unwrap().name(), "refs/heads/main");
+			assert_eq!(diff.number_files_changed(), 1);
+		});
+	}
+
+	#[test]
+	fn load_from_hash_has_no_reference() {
+		with_temp_repository(|repository| {
+			let loader = diff_from_head(repository, CommitDiffLoaderOptions::new()).unwrap();
+			assert!(loader.take_diff().commit().reference().is_none());
 		});
 	}
 
@@ -1004,6 +1528,35 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn cancel_diff_during_textconv() {
+		with_temp_repository(|repository| {
+			repository
+				.config()
+				.unwrap()
+				.set_str("diff.slow.textconv", "sleep 5; cat")
+				.unwrap();
+			write_normal_file(&repository, ".gitattributes", &["a diff=slow"]);
+			write_normal_file(&repository, "a", &["line1"]);
+			create_commit(&repository);
+
+			let calls = Arc::new(Mutex::new(Vec::new()));
+			let notifier_calls = Arc::clone(&calls);
+			let notifier = move |status| {
+				let mut c = notifier_calls.lock();
+				c.push(status);
+				c.len() == 3
+			};
+
+			let start = Instant::now();
+			_ = diff_with_notifier(repository, CommitDiffLoaderOptions::new(), notifier).unwrap();
+			assert!(start.elapsed() < Duration::from_secs(5));
+			let c = calls.lock();
+			assert_eq!(c.len(), 3);
+			assert!(matches!(c.last().unwrap(), &LoadStatus::Diff(_, 2)));
+		});
+	}
+
 	#[test]
 	fn cancel_diff_during_quick_diff_collect() {
 		with_temp_repository(|repository| {
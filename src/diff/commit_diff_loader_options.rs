@@ -1,9 +1,10 @@
 /// Options for loading a commit with diff
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[expect(clippy::struct_excessive_bools, reason = "False positive")]
 pub(crate) struct CommitDiffLoaderOptions {
 	pub(crate) context_lines: u32,
 	pub(crate) copies: bool,
+	pub(crate) exclude_paths: Vec<String>,
 	pub(crate) ignore_whitespace: bool,
 	pub(crate) ignore_whitespace_change: bool,
 	pub(crate) ignore_blank_lines: bool,
@@ -19,6 +20,7 @@ impl CommitDiffLoaderOptions {
 		Self {
 			context_lines: 0,
 			copies: false,
+			exclude_paths: vec![],
 			ignore_whitespace: false,
 			ignore_whitespace_change: false,
 			ignore_blank_lines: false,
@@ -49,6 +51,13 @@ impl CommitDiffLoaderOptions {
 		self
 	}
 
+	/// Set the pathspecs of the files that are excluded from the diff.
+	#[must_use]
+	pub(crate) fn exclude_paths(mut self, exclude_paths: Vec<String>) -> Self {
+		self.exclude_paths = exclude_paths;
+		self
+	}
+
 	/// Set if to ignore whitespace.
 	#[must_use]
 	pub(crate) const fn ignore_whitespace(mut self, ignore_whitespace: bool) -> Self {
@@ -101,6 +110,16 @@ mod tests {
 		assert!(CommitDiffLoaderOptions::new().copies(true).copies);
 	}
 
+	#[test]
+	fn exclude_paths() {
+		assert_eq!(
+			CommitDiffLoaderOptions::new()
+				.exclude_paths(vec![String::from("*.lock")])
+				.exclude_paths,
+			vec![String::from("*.lock")]
+		);
+	}
+
 	#[test]
 	fn ignore_whitespace() {
 		assert!(CommitDiffLoaderOptions::new().ignore_whitespace(true).ignore_whitespace);
@@ -1,14 +1,16 @@
 use std::path::{Path, PathBuf};
 
-use crate::diff::{Delta, FileMode, Status};
+use crate::diff::{BinaryDiff, Delta, FileMode, Status};
 
 /// Represents a file change within a Git repository
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct FileStatus {
+	binary_diff: Option<BinaryDiff>,
 	deltas: Vec<Delta>,
 	destination_is_binary: bool,
 	destination_mode: FileMode,
 	destination_path: PathBuf,
+	excluded: bool,
 	largest_new_line_number: u32,
 	largest_old_line_number: u32,
 	source_is_binary: bool,
@@ -30,10 +32,12 @@ impl FileStatus {
 		status: Status,
 	) -> Self {
 		Self {
+			binary_diff: None,
 			deltas: vec![],
 			destination_is_binary,
 			destination_mode,
 			destination_path: PathBuf::from(destination_path.as_ref()),
+			excluded: false,
 			largest_new_line_number: 0,
 			largest_old_line_number: 0,
 			source_is_binary,
@@ -56,6 +60,28 @@ impl FileStatus {
 		self.deltas.push(delta);
 	}
 
+	/// Set the summary of the change for a binary file.
+	pub(crate) fn set_binary_diff(&mut self, binary_diff: BinaryDiff) {
+		self.binary_diff = Some(binary_diff);
+	}
+
+	/// Get the summary of the change, if this is a change to a binary file.
+	#[must_use]
+	pub(crate) const fn binary_diff(&self) -> Option<&BinaryDiff> {
+		self.binary_diff.as_ref()
+	}
+
+	/// Set if the file is excluded from the diff.
+	pub(crate) const fn set_excluded(&mut self, excluded: bool) {
+		self.excluded = excluded;
+	}
+
+	/// Is the file excluded from the diff.
+	#[must_use]
+	pub(crate) const fn is_excluded(&self) -> bool {
+		self.excluded
+	}
+
 	/// Get the status of this file change.
 	#[must_use]
 	pub(crate) const fn status(&self) -> Status {
@@ -234,4 +260,28 @@ mod tests {
 		file_stat.add_delta(Delta::new("@ path/to/file.rs:56 @ impl Delta {", 10, 10, 5, 5));
 		assert_eq!(file_stat.last_new_line_number(), 25);
 	}
+
+	#[test]
+	fn binary_diff_default() {
+		assert!(create_file_stat().binary_diff().is_none());
+	}
+
+	#[test]
+	fn binary_diff() {
+		let mut file_stat = create_file_stat();
+		file_stat.set_binary_diff(BinaryDiff::new(Some(b"a"), Some(b"b")));
+		assert_eq!(file_stat.binary_diff(), Some(&BinaryDiff::new(Some(b"a"), Some(b"b"))));
+	}
+
+	#[test]
+	fn excluded_default() {
+		assert!(!create_file_stat().is_excluded());
+	}
+
+	#[test]
+	fn excluded() {
+		let mut file_stat = create_file_stat();
+		file_stat.set_excluded(true);
+		assert!(file_stat.is_excluded());
+	}
 }
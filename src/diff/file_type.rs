@@ -0,0 +1,197 @@
+use std::fmt::{Display, Formatter};
+
+/// The type of a binary file, detected from the leading "magic" bytes of the file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum FileType {
+	/// A BMP image.
+	Bmp,
+	/// A bzip2 compressed file.
+	Bzip2,
+	/// An ELF executable or library.
+	Elf,
+	/// A GIF image.
+	Gif,
+	/// A gzip compressed file.
+	Gzip,
+	/// A Windows icon.
+	Ico,
+	/// A JPEG image.
+	Jpeg,
+	/// A Mach-O executable or library.
+	MachO,
+	/// A PDF document.
+	Pdf,
+	/// A PNG image.
+	Png,
+	/// A binary PGM or PPM image.
+	Pnm,
+	/// A Windows portable executable.
+	PortableExecutable,
+	/// A 7-Zip archive.
+	SevenZip,
+	/// A `SQLite` database.
+	Sqlite,
+	/// A tar archive.
+	Tar,
+	/// A TIFF image.
+	Tiff,
+	/// A WebAssembly module.
+	Wasm,
+	/// A WebP image.
+	WebP,
+	/// An xz compressed file.
+	Xz,
+	/// A zip archive, including formats based on zip.
+	Zip,
+	/// A file of unknown type.
+	Unknown,
+}
+
+impl FileType {
+	/// Detect the type of file from the contents of the file.
+	#[must_use]
+	pub(crate) fn detect(data: &[u8]) -> Self {
+		match data {
+			[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Self::Png,
+			[0xFF, 0xD8, 0xFF, ..] => Self::Jpeg,
+			[b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Self::Gif,
+			[b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Self::WebP,
+			[b'B', b'M', ..] => Self::Bmp,
+			[0x00, 0x00, 0x01, 0x00, ..] => Self::Ico,
+			[b'I', b'I', 0x2A, 0x00, ..] | [b'M', b'M', 0x00, 0x2A, ..] => Self::Tiff,
+			[b'P', b'5' | b'6', b' ' | b'\t' | b'\r' | b'\n', ..] => Self::Pnm,
+			[b'%', b'P', b'D', b'F', b'-', ..] => Self::Pdf,
+			[b'P', b'K', 0x03 | 0x05 | 0x07, 0x04 | 0x06 | 0x08, ..] => Self::Zip,
+			[0x1F, 0x8B, ..] => Self::Gzip,
+			[b'B', b'Z', b'h', ..] => Self::Bzip2,
+			[0xFD, b'7', b'z', b'X', b'Z', 0x00, ..] => Self::Xz,
+			[b'7', b'z', 0xBC, 0xAF, 0x27, 0x1C, ..] => Self::SevenZip,
+			[0x7F, b'E', b'L', b'F', ..] => Self::Elf,
+			[0xFE, 0xED, 0xFA, 0xCE | 0xCF, ..]
+			| [0xCE | 0xCF, 0xFA, 0xED, 0xFE, ..]
+			| [0xCA, 0xFE, 0xBA, 0xBE, ..] => Self::MachO,
+			[b'M', b'Z', ..] => Self::PortableExecutable,
+			[0x00, b'a', b's', b'm', ..] => Self::Wasm,
+			[
+				b'S',
+				b'Q',
+				b'L',
+				b'i',
+				b't',
+				b'e',
+				b' ',
+				b'f',
+				b'o',
+				b'r',
+				b'm',
+				b'a',
+				b't',
+				b' ',
+				b'3',
+				0x00,
+				..,
+			] => Self::Sqlite,
+			_ if data.len() > 262 && &data[257..262] == b"ustar" => Self::Tar,
+			_ => Self::Unknown,
+		}
+	}
+
+	/// Is the file type an image.
+	#[must_use]
+	pub(crate) const fn is_image(self) -> bool {
+		matches!(
+			self,
+			Self::Bmp | Self::Gif | Self::Ico | Self::Jpeg | Self::Png | Self::Pnm | Self::Tiff | Self::WebP
+		)
+	}
+}
+
+impl Display for FileType {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", match *self {
+			Self::Bmp => "BMP image",
+			Self::Bzip2 => "bzip2 compressed data",
+			Self::Elf => "ELF executable",
+			Self::Gif => "GIF image",
+			Self::Gzip => "gzip compressed data",
+			Self::Ico => "icon image",
+			Self::Jpeg => "JPEG image",
+			Self::MachO => "Mach-O executable",
+			Self::Pdf => "PDF document",
+			Self::Png => "PNG image",
+			Self::Pnm => "PNM image",
+			Self::PortableExecutable => "PE executable",
+			Self::SevenZip => "7-zip archive",
+			Self::Sqlite => "SQLite database",
+			Self::Tar => "tar archive",
+			Self::Tiff => "TIFF image",
+			Self::Wasm => "WebAssembly module",
+			Self::WebP => "WebP image",
+			Self::Xz => "xz compressed data",
+			Self::Zip => "zip archive",
+			Self::Unknown => "binary data",
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::bmp(b"BM\x00\x00", FileType::Bmp)]
+	#[case::bzip2(b"BZh91AY", FileType::Bzip2)]
+	#[case::elf(b"\x7FELF\x02", FileType::Elf)]
+	#[case::gif87(b"GIF87a\x01", FileType::Gif)]
+	#[case::gif89(b"GIF89a\x01", FileType::Gif)]
+	#[case::gzip(b"\x1F\x8B\x08", FileType::Gzip)]
+	#[case::ico(b"\x00\x00\x01\x00\x01", FileType::Ico)]
+	#[case::jpeg(b"\xFF\xD8\xFF\xE0", FileType::Jpeg)]
+	#[case::mach_o_32(b"\xFE\xED\xFA\xCE", FileType::MachO)]
+	#[case::mach_o_64_le(b"\xCF\xFA\xED\xFE", FileType::MachO)]
+	#[case::mach_o_universal(b"\xCA\xFE\xBA\xBE", FileType::MachO)]
+	#[case::pdf(b"%PDF-1.7", FileType::Pdf)]
+	#[case::png(b"\x89PNG\x0D\x0A\x1A\x0A\x00", FileType::Png)]
+	#[case::pgm(b"P5 1 1 255 \x00", FileType::Pnm)]
+	#[case::ppm(b"P6\n1 1\n255\n", FileType::Pnm)]
+	#[case::portable_executable(b"MZ\x90\x00", FileType::PortableExecutable)]
+	#[case::seven_zip(b"7z\xBC\xAF\x27\x1C\x00", FileType::SevenZip)]
+	#[case::sqlite(b"SQLite format 3\x00\x10", FileType::Sqlite)]
+	#[case::tiff_le(b"II\x2A\x00", FileType::Tiff)]
+	#[case::tiff_be(b"MM\x00\x2A", FileType::Tiff)]
+	#[case::wasm(b"\x00asm\x01", FileType::Wasm)]
+	#[case::webp(b"RIFF\x00\x00\x00\x00WEBPVP8", FileType::WebP)]
+	#[case::xz(b"\xFD7zXZ\x00", FileType::Xz)]
+	#[case::zip(b"PK\x03\x04", FileType::Zip)]
+	#[case::zip_empty(b"PK\x05\x06", FileType::Zip)]
+	#[case::empty(b"", FileType::Unknown)]
+	#[case::unknown(b"\x00\x01\x02\x03", FileType::Unknown)]
+	fn detect(#[case] data: &[u8], #[case] expected: FileType) {
+		assert_eq!(FileType::detect(data), expected);
+	}
+
+	#[test]
+	fn detect_tar() {
+		let mut data = vec![0; 512];
+		data[257..262].copy_from_slice(b"ustar");
+		assert_eq!(FileType::detect(&data), FileType::Tar);
+	}
+
+	#[rstest]
+	#[case::image(FileType::Png, true)]
+	#[case::not_image(FileType::Zip, false)]
+	#[case::unknown(FileType::Unknown, false)]
+	fn is_image(#[case] file_type: FileType, #[case] expected: bool) {
+		assert_eq!(file_type.is_image(), expected);
+	}
+
+	#[rstest]
+	#[case::png(FileType::Png, "PNG image")]
+	#[case::zip(FileType::Zip, "zip archive")]
+	#[case::unknown(FileType::Unknown, "binary data")]
+	fn display(#[case] file_type: FileType, #[case] expected: &str) {
+		assert_eq!(file_type.to_string(), expected);
+	}
+}
@@ -0,0 +1,538 @@
+use miniz_oxide::inflate::decompress_to_vec_zlib_with_limit;
+
+use crate::diff::FileType;
+
+// characters used for the preview, from darkest to lightest
+const SHADES: &[u8] = b" .:-=+*#%@";
+const MAX_PREVIEW_WIDTH: usize = 32;
+// only small images are decoded, larger images do not produce a useful text preview
+const MAX_IMAGE_DIMENSION: usize = 512;
+
+struct Pixels {
+	width: usize,
+	height: usize,
+	// the brightness of each pixel, with transparent pixels shown as dark
+	values: Vec<u8>,
+}
+
+impl Pixels {
+	fn new(width: usize, height: usize) -> Option<Self> {
+		if width == 0 || height == 0 || width > MAX_IMAGE_DIMENSION || height > MAX_IMAGE_DIMENSION {
+			return None;
+		}
+		Some(Self {
+			width,
+			height,
+			values: Vec::with_capacity(width.checked_mul(height)?),
+		})
+	}
+
+	#[expect(clippy::integer_division, reason = "Truncation desired")]
+	fn push(&mut self, red: u8, green: u8, blue: u8, alpha: u8) {
+		let luma = (299 * u32::from(red) + 587 * u32::from(green) + 114 * u32::from(blue)) / 1000;
+		self.values.push((luma * u32::from(alpha) / 255) as u8);
+	}
+}
+
+#[expect(clippy::little_endian_bytes, reason = "BMP values are little endian")]
+fn read_u16_le(data: &[u8], offset: usize) -> Option<u16> {
+	Some(u16::from_le_bytes(data.get(offset..offset + 2)?.try_into().ok()?))
+}
+
+#[expect(clippy::little_endian_bytes, reason = "BMP values are little endian")]
+fn read_u32_le(data: &[u8], offset: usize) -> Option<u32> {
+	Some(u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+#[expect(clippy::big_endian_bytes, reason = "PNG values are big endian")]
+fn read_u32_be(data: &[u8], offset: usize) -> Option<u32> {
+	Some(u32::from_be_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+fn paeth(left: u8, up: u8, up_left: u8) -> u8 {
+	let estimate = i16::from(left) + i16::from(up) - i16::from(up_left);
+	let distance_left = (estimate - i16::from(left)).abs();
+	let distance_up = (estimate - i16::from(up)).abs();
+	let distance_up_left = (estimate - i16::from(up_left)).abs();
+	if distance_left <= distance_up && distance_left <= distance_up_left {
+		left
+	}
+	else if distance_up <= distance_up_left {
+		up
+	}
+	else {
+		up_left
+	}
+}
+
+#[expect(clippy::integer_division, reason = "Truncation desired")]
+fn png_sample(row: &[u8], index: usize, depth: usize) -> u8 {
+	match depth {
+		8 => row[index],
+		// only the high byte is needed for a preview
+		16 => row[index * 2],
+		_ => {
+			let bit = index * depth;
+			let mask = u8::MAX >> (8 - depth);
+			(row[bit / 8] >> (8 - depth - bit % 8)) & mask
+		},
+	}
+}
+
+#[expect(clippy::integer_division, reason = "Truncation desired")]
+fn decode_png(data: &[u8]) -> Option<Pixels> {
+	let mut header = None;
+	let mut palette: &[u8] = &[];
+	let mut transparency: &[u8] = &[];
+	let mut compressed = vec![];
+
+	let mut position = 8;
+	while position + 8 <= data.len() {
+		let length = read_u32_be(data, position)? as usize;
+		let chunk = data.get(position + 8..position + 8 + length)?;
+		match data.get(position + 4..position + 8)? {
+			b"IHDR" => header = Some(chunk),
+			b"PLTE" => palette = chunk,
+			b"tRNS" => transparency = chunk,
+			b"IDAT" => compressed.extend_from_slice(chunk),
+			b"IEND" => break,
+			_ => {},
+		}
+		// length, type and crc
+		position += length + 12;
+	}
+
+	let header = header?;
+	let mut pixels = Pixels::new(read_u32_be(header, 0)? as usize, read_u32_be(header, 4)? as usize)?;
+	let depth = usize::from(*header.get(8)?);
+	let color_type = *header.get(9)?;
+	// interlaced images are not supported
+	if *header.get(12)? != 0 || !matches!(depth, 1 | 2 | 4 | 8 | 16) {
+		return None;
+	}
+	let channels = match color_type {
+		0 | 3 => 1,
+		2 => 3,
+		4 => 2,
+		6 => 4,
+		_ => return None,
+	};
+	let bits_per_pixel = channels * depth;
+	let stride = pixels.width.checked_mul(bits_per_pixel)?.div_ceil(8);
+	let bytes_per_pixel = (bits_per_pixel / 8).max(1);
+
+	// each row starts with the filter type, and any data past the rows is never used
+	let raw_length = (stride + 1).checked_mul(pixels.height)?;
+	let raw = decompress_to_vec_zlib_with_limit(&compressed, raw_length).ok()?;
+	if raw.len() < raw_length {
+		return None;
+	}
+
+	let mut previous = vec![0; stride];
+	let max_value = u8::MAX >> (8 - depth.min(8));
+	for filtered in raw.chunks_exact(stride + 1).take(pixels.height) {
+		let mut row = filtered[1..].to_vec();
+		for i in 0..stride {
+			let left = if i >= bytes_per_pixel {
+				row[i - bytes_per_pixel]
+			}
+			else {
+				0
+			};
+			let up = previous[i];
+			let up_left = if i >= bytes_per_pixel {
+				previous[i - bytes_per_pixel]
+			}
+			else {
+				0
+			};
+			row[i] = row[i].wrapping_add(match filtered[0] {
+				0 => 0,
+				1 => left,
+				2 => up,
+				3 => left.midpoint(up),
+				4 => paeth(left, up, up_left),
+				_ => return None,
+			});
+		}
+
+		for x in 0..pixels.width {
+			let sample = |channel: usize| {
+				let value = png_sample(&row, x * channels + channel, depth);
+				if color_type == 3 || depth >= 8 {
+					value
+				}
+				else {
+					(u16::from(value) * 255 / u16::from(max_value)) as u8
+				}
+			};
+			match color_type {
+				0 => pixels.push(sample(0), sample(0), sample(0), 255),
+				2 => pixels.push(sample(0), sample(1), sample(2), 255),
+				3 => {
+					let index = usize::from(sample(0));
+					let &[red, green, blue] = palette.get(index * 3..index * 3 + 3)?
+					else {
+						return None;
+					};
+					pixels.push(red, green, blue, transparency.get(index).copied().unwrap_or(255));
+				},
+				4 => pixels.push(sample(0), sample(0), sample(0), sample(1)),
+				_ => pixels.push(sample(0), sample(1), sample(2), sample(3)),
+			}
+		}
+		previous = row;
+	}
+	Some(pixels)
+}
+
+#[expect(clippy::integer_division, reason = "Truncation desired")]
+fn decode_bmp(data: &[u8]) -> Option<Pixels> {
+	let offset = read_u32_le(data, 10)? as usize;
+	let width = read_u32_le(data, 18)?.cast_signed();
+	let height = read_u32_le(data, 22)?.cast_signed();
+	let bits_per_pixel = usize::from(read_u16_le(data, 28)?);
+	let compression = read_u32_le(data, 30)?;
+	// only uncompressed, or 32-bit bit field, true color images are supported
+	if width <= 0 || !(bits_per_pixel == 24 || bits_per_pixel == 32) || !(compression == 0 || compression == 3) {
+		return None;
+	}
+
+	let mut pixels = Pixels::new(width.unsigned_abs() as usize, height.unsigned_abs() as usize)?;
+	let bytes_per_pixel = bits_per_pixel / 8;
+	let stride = (pixels.width * bytes_per_pixel).next_multiple_of(4);
+	for y in 0..pixels.height {
+		// rows are stored bottom to top, unless the height is negative
+		let row_index = if height < 0 { y } else { pixels.height - 1 - y };
+		let row = data.get(offset + row_index * stride..offset + (row_index + 1) * stride)?;
+		for pixel in row.chunks_exact(bytes_per_pixel).take(pixels.width) {
+			pixels.push(pixel[2], pixel[1], pixel[0], 255);
+		}
+	}
+	Some(pixels)
+}
+
+#[expect(clippy::integer_division, reason = "Truncation desired")]
+fn decode_pnm(data: &[u8]) -> Option<Pixels> {
+	let mut values = [0; 3];
+	let mut position = 2;
+	for value in &mut values {
+		loop {
+			match data.get(position)? {
+				b'#' => {
+					while *data.get(position)? != b'\n' {
+						position += 1;
+					}
+				},
+				c if c.is_ascii_whitespace() => position += 1,
+				_ => break,
+			}
+		}
+		let start = position;
+		while data.get(position)?.is_ascii_digit() {
+			position += 1;
+		}
+		*value = std::str::from_utf8(&data[start..position])
+			.ok()?
+			.parse::<usize>()
+			.ok()?;
+	}
+	let [width, height, max_value] = values;
+	if max_value == 0 || max_value > 255 {
+		return None;
+	}
+
+	let channels = if data[1] == b'6' { 3 } else { 1 };
+	let mut pixels = Pixels::new(width, height)?;
+	// a single whitespace character separates the header from the image data
+	let image_data = data.get(position + 1..position + 1 + width * height * channels)?;
+	let scale = |value: u8| (usize::from(value) * 255 / max_value) as u8;
+	for pixel in image_data.chunks_exact(channels) {
+		match *pixel {
+			[red, green, blue] => pixels.push(scale(red), scale(green), scale(blue), 255),
+			[value] => pixels.push(scale(value), scale(value), scale(value), 255),
+			_ => {},
+		}
+	}
+	Some(pixels)
+}
+
+/// A small text preview of an image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ImagePreview {
+	width: usize,
+	height: usize,
+	lines: Vec<String>,
+}
+
+impl ImagePreview {
+	/// Create a preview of an image, if the image is of a supported type and is small enough to preview.
+	#[must_use]
+	#[expect(clippy::integer_division, reason = "Truncation desired")]
+	pub(crate) fn new(data: &[u8]) -> Option<Self> {
+		let pixels = match FileType::detect(data) {
+			FileType::Png => decode_png(data),
+			FileType::Bmp => decode_bmp(data),
+			FileType::Pnm => decode_pnm(data),
+			_ => None,
+		}?;
+
+		let columns = pixels.width.min(MAX_PREVIEW_WIDTH);
+		// terminal cells are roughly twice as tall as they are wide
+		let rows = (pixels.height * columns).div_ceil(pixels.width * 2);
+		let mut lines = Vec::with_capacity(rows);
+		for row in 0..rows {
+			let y_start = row * pixels.height / rows;
+			let y_end = ((row + 1) * pixels.height / rows).max(y_start + 1);
+			let mut line = String::with_capacity(columns);
+			for column in 0..columns {
+				let x_start = column * pixels.width / columns;
+				let x_end = ((column + 1) * pixels.width / columns).max(x_start + 1);
+				let mut total = 0;
+				for y in y_start..y_end {
+					for x in x_start..x_end {
+						total += usize::from(pixels.values[y * pixels.width + x]);
+					}
+				}
+				let average = total / ((y_end - y_start) * (x_end - x_start));
+				line.push(char::from(SHADES[average * (SHADES.len() - 1) / 255]));
+			}
+			lines.push(line);
+		}
+
+		Some(Self {
+			width: pixels.width,
+			height: pixels.height,
+			lines,
+		})
+	}
+
+	/// Get the width of the image in pixels.
+	#[must_use]
+	pub(crate) const fn width(&self) -> usize {
+		self.width
+	}
+
+	/// Get the height of the image in pixels.
+	#[must_use]
+	pub(crate) const fn height(&self) -> usize {
+		self.height
+	}
+
+	/// Get the lines of the preview.
+	#[must_use]
+	pub(crate) fn lines(&self) -> &[String] {
+		&self.lines
+	}
+}
+
+#[cfg(test)]
+#[expect(
+	clippy::big_endian_bytes,
+	clippy::little_endian_bytes,
+	reason = "Image formats use fixed byte orders"
+)]
+mod tests {
+	use claims::assert_none;
+	use miniz_oxide::deflate::compress_to_vec_zlib;
+
+	use super::*;
+
+	fn png_chunk(kind: &[u8], data: &[u8]) -> Vec<u8> {
+		let mut chunk = vec![];
+		chunk.extend_from_slice(&u32::try_from(data.len()).unwrap().to_be_bytes());
+		chunk.extend_from_slice(kind);
+		chunk.extend_from_slice(data);
+		// the crc is not validated
+		chunk.extend_from_slice(&[0, 0, 0, 0]);
+		chunk
+	}
+
+	fn png(width: u32, height: u32, depth: u8, color_type: u8, rows: &[Vec<u8>], extra: &[Vec<u8>]) -> Vec<u8> {
+		let mut header = vec![];
+		header.extend_from_slice(&width.to_be_bytes());
+		header.extend_from_slice(&height.to_be_bytes());
+		header.extend_from_slice(&[depth, color_type, 0, 0, 0]);
+		let raw: Vec<u8> = rows.concat();
+
+		let mut data = b"\x89PNG\x0D\x0A\x1A\x0A".to_vec();
+		data.extend(png_chunk(b"IHDR", &header));
+		for chunk in extra {
+			data.extend_from_slice(chunk);
+		}
+		data.extend(png_chunk(b"IDAT", &compress_to_vec_zlib(&raw, 6)));
+		data.extend(png_chunk(b"IEND", &[]));
+		data
+	}
+
+	#[test]
+	fn png_grayscale() {
+		let data = png(4, 2, 8, 0, &[vec![0, 0, 0, 255, 255], vec![0, 255, 255, 0, 0]], &[]);
+		let preview = ImagePreview::new(&data).unwrap();
+		assert_eq!(preview.width(), 4);
+		assert_eq!(preview.height(), 2);
+		assert_eq!(preview.lines(), &["===="]);
+	}
+
+	#[test]
+	fn png_rgb_with_filters() {
+		// sub filter, where the second pixel wraps to black, then up filter
+		let data = png(
+			2,
+			2,
+			8,
+			2,
+			&[vec![1, 255, 255, 255, 1, 1, 1], vec![2, 0, 0, 0, 0, 0, 0]],
+			&[],
+		);
+		let preview = ImagePreview::new(&data).unwrap();
+		assert_eq!(preview.lines(), &["@ "]);
+	}
+
+	#[test]
+	fn png_average_and_paeth_filters() {
+		// average filter gives 200 and 100, then paeth filter takes the value from above
+		let data = png(2, 2, 8, 0, &[vec![3, 200, 0], vec![4, 0, 0]], &[]);
+		let preview = ImagePreview::new(&data).unwrap();
+		assert_eq!(preview.lines(), &["#-"]);
+	}
+
+	#[test]
+	fn png_rgba_transparent() {
+		let data = png(1, 2, 8, 6, &[vec![0, 255, 255, 255, 0], vec![0, 255, 255, 255, 0]], &[]);
+		let preview = ImagePreview::new(&data).unwrap();
+		assert_eq!(preview.lines(), &[" "]);
+	}
+
+	#[test]
+	fn png_palette_low_bit_depth() {
+		let palette = png_chunk(b"PLTE", &[0, 0, 0, 255, 255, 255]);
+		let transparency = png_chunk(b"tRNS", &[255, 255]);
+		let data = png(8, 2, 1, 3, &[vec![0, 0b1111_0000], vec![0, 0b1111_0000]], &[
+			palette,
+			transparency,
+		]);
+		let preview = ImagePreview::new(&data).unwrap();
+		assert_eq!(preview.lines(), &["@@@@    "]);
+	}
+
+	#[test]
+	fn png_grayscale_low_bit_depth() {
+		let data = png(4, 2, 2, 0, &[vec![0, 0b1111_0000], vec![0, 0b1111_0000]], &[]);
+		let preview = ImagePreview::new(&data).unwrap();
+		assert_eq!(preview.lines(), &["@@  "]);
+	}
+
+	#[test]
+	fn png_gray_alpha_sixteen_bit() {
+		let data = png(
+			1,
+			2,
+			16,
+			4,
+			&[vec![0, 255, 255, 255, 255], vec![0, 255, 255, 255, 255]],
+			&[],
+		);
+		let preview = ImagePreview::new(&data).unwrap();
+		assert_eq!(preview.lines(), &["@"]);
+	}
+
+	#[test]
+	fn png_interlaced() {
+		let mut data = png(1, 1, 8, 0, &[vec![0, 0]], &[]);
+		// interlace method is the last byte of the header, 8 byte signature, 8 byte chunk header
+		data[8 + 8 + 12] = 1;
+		assert_none!(ImagePreview::new(&data));
+	}
+
+	#[test]
+	fn png_truncated_data() {
+		let data = png(4, 4, 8, 0, &[vec![0, 0, 0, 0, 0]], &[]);
+		assert_none!(ImagePreview::new(&data));
+	}
+
+	#[test]
+	fn png_decompressed_data_past_rows() {
+		// a small image that inflates to far more data than the rows of the image
+		let data = png(1, 1, 8, 0, &[vec![0, 0], vec![0; 1024 * 1024]], &[]);
+		assert_none!(ImagePreview::new(&data));
+	}
+
+	#[test]
+	fn png_too_large() {
+		let data = png(1024, 1, 8, 0, &[vec![0; 1025]], &[]);
+		assert_none!(ImagePreview::new(&data));
+	}
+
+	#[test]
+	fn png_downscaled() {
+		let rows = vec![[vec![0], vec![255; 64]].concat(); 4];
+		let data = png(64, 4, 8, 0, &rows, &[]);
+		let preview = ImagePreview::new(&data).unwrap();
+		assert_eq!(preview.lines(), &["@".repeat(32)]);
+	}
+
+	fn bmp(width: i32, height: i32, bits_per_pixel: u16, pixel_data: &[u8]) -> Vec<u8> {
+		let mut data = b"BM".to_vec();
+		data.extend_from_slice(&[0; 8]);
+		data.extend_from_slice(&u32::to_le_bytes(54));
+		data.extend_from_slice(&u32::to_le_bytes(40));
+		data.extend_from_slice(&width.to_le_bytes());
+		data.extend_from_slice(&height.to_le_bytes());
+		data.extend_from_slice(&u16::to_le_bytes(1));
+		data.extend_from_slice(&bits_per_pixel.to_le_bytes());
+		data.extend_from_slice(&[0; 24]);
+		data.extend_from_slice(pixel_data);
+		data
+	}
+
+	#[test]
+	fn bmp_bottom_up() {
+		// each row is padded to 4 bytes, with the bottom row first
+		let data = bmp(1, 4, 24, &[0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 0, 255, 255, 255, 0]);
+		let preview = ImagePreview::new(&data).unwrap();
+		assert_eq!(preview.width(), 1);
+		assert_eq!(preview.height(), 4);
+		assert_eq!(preview.lines(), &["@", " "]);
+	}
+
+	#[test]
+	fn bmp_top_down_32_bit() {
+		let data = bmp(1, -2, 32, &[255, 255, 255, 0, 255, 255, 255, 0]);
+		let preview = ImagePreview::new(&data).unwrap();
+		assert_eq!(preview.lines(), &["@"]);
+	}
+
+	#[test]
+	fn bmp_unsupported_bit_depth() {
+		let data = bmp(1, 1, 8, &[0, 0, 0, 0]);
+		assert_none!(ImagePreview::new(&data));
+	}
+
+	#[test]
+	fn pnm_gray() {
+		let preview = ImagePreview::new(b"P5\n# comment\n2 2\n255\n\xFF\x00\xFF\x00").unwrap();
+		assert_eq!(preview.lines(), &["@ "]);
+	}
+
+	#[test]
+	fn pnm_color_scaled() {
+		let preview = ImagePreview::new(b"P6 1 2 15 \x0F\x0F\x0F\x0F\x0F\x0F").unwrap();
+		assert_eq!(preview.lines(), &["@"]);
+	}
+
+	#[test]
+	fn pnm_invalid_max_value() {
+		assert_none!(ImagePreview::new(b"P5 1 1 65535 \x00\x00"));
+	}
+
+	#[test]
+	fn pnm_truncated() {
+		assert_none!(ImagePreview::new(b"P5 2 2 255 \x00"));
+	}
+
+	#[test]
+	fn unsupported_type() {
+		assert_none!(ImagePreview::new(b"GIF89a\x01\x00\x01\x00"));
+	}
+}
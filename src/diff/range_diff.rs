@@ -0,0 +1,358 @@
+use git2::{DiffOptions, Oid, Repository};
+
+use crate::{
+	diff::{RangeDiffCommit, RangeDiffEntry, RangeDiffStatus},
+	git::GitError,
+};
+
+struct PatchInfo {
+	hash: String,
+	commit_id: Oid,
+	patch_id: Oid,
+	summary: String,
+}
+
+impl PatchInfo {
+	fn load(repository: &Repository, hash: &str) -> Result<Self, GitError> {
+		let oid = repository
+			.revparse_single(hash)
+			.map_err(|e| GitError::CommitLoad { cause: e })?
+			.id();
+		let commit = repository
+			.find_commit(oid)
+			.map_err(|e| GitError::CommitLoad { cause: e })?;
+
+		let commit_tree = commit.tree().map_err(|e| GitError::DiffLoad { cause: e })?;
+		let parent_tree = match commit.parents().next() {
+			Some(parent) => Some(parent.tree().map_err(|e| GitError::DiffLoad { cause: e })?),
+			None => None,
+		};
+		let patch_id = repository
+			.diff_tree_to_tree(
+				parent_tree.as_ref(),
+				Some(&commit_tree),
+				Some(DiffOptions::new().ignore_filemode(false)),
+			)
+			.and_then(|diff| diff.patchid(None))
+			.map_err(|e| GitError::DiffLoad { cause: e })?;
+
+		Ok(Self {
+			hash: String::from(hash),
+			commit_id: commit.id(),
+			patch_id,
+			summary: String::from(commit.summary().unwrap_or("")),
+		})
+	}
+}
+
+/// A comparison of two commit series, in the style of `git range-diff`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct RangeDiff {
+	entries: Vec<RangeDiffEntry>,
+}
+
+impl RangeDiff {
+	/// Create a new empty instance.
+	#[must_use]
+	pub(crate) const fn new() -> Self {
+		Self { entries: vec![] }
+	}
+
+	/// Compare the `original` series of commits against the `edited` series of commits.
+	///
+	/// Commits are matched first by commit, and then by patch-id, so that a commit with a different
+	/// hash but the same patch is still considered the same.
+	///
+	/// # Errors
+	///
+	/// Returns an error if any of the commits cannot be loaded, or if a patch-id cannot be generated.
+	pub(crate) fn load(
+		repository: &Repository,
+		original: &[String],
+		edited: &[RangeDiffCommit],
+	) -> Result<Self, GitError> {
+		let original_patches = original
+			.iter()
+			.map(|hash| PatchInfo::load(repository, hash.as_str()))
+			.collect::<Result<Vec<PatchInfo>, GitError>>()?;
+		let edited_patches = edited
+			.iter()
+			.map(|commit| PatchInfo::load(repository, commit.hash()))
+			.collect::<Result<Vec<PatchInfo>, GitError>>()?;
+
+		// match all the edited commits before building the entries, since a reordered commit may be
+		// matched to an original commit that appears later in the original series
+		let mut matched = vec![false; original_patches.len()];
+		let mut edited_positions = Vec::with_capacity(edited_patches.len());
+		for patch in &edited_patches {
+			let position = original_patches
+				.iter()
+				.enumerate()
+				.position(|(i, o)| !matched[i] && o.commit_id == patch.commit_id)
+				.or_else(|| {
+					original_patches
+						.iter()
+						.enumerate()
+						.position(|(i, o)| !matched[i] && o.patch_id == patch.patch_id)
+				});
+			if let Some(p) = position {
+				matched[p] = true;
+			}
+			edited_positions.push(position);
+		}
+
+		let mut entries = vec![];
+		let mut next_unmatched = 0;
+		for (edited_position, (commit, patch)) in edited.iter().zip(edited_patches.iter()).enumerate() {
+			let Some(original_position) = edited_positions[edited_position]
+			else {
+				entries.push(RangeDiffEntry::new(
+					RangeDiffStatus::Added,
+					None,
+					Some((edited_position, patch.hash.as_str())),
+					patch.summary.as_str(),
+				));
+				continue;
+			};
+
+			// dropped commits are shown before the first edited commit that came after them
+			while next_unmatched < original_position {
+				if !matched[next_unmatched] {
+					let dropped = &original_patches[next_unmatched];
+					entries.push(RangeDiffEntry::new(
+						RangeDiffStatus::Dropped,
+						Some((next_unmatched, dropped.hash.as_str())),
+						None,
+						dropped.summary.as_str(),
+					));
+				}
+				next_unmatched += 1;
+			}
+
+			let status = if commit.is_modified() {
+				RangeDiffStatus::Modified
+			}
+			else {
+				RangeDiffStatus::Unchanged
+			};
+			entries.push(RangeDiffEntry::new(
+				status,
+				Some((original_position, original_patches[original_position].hash.as_str())),
+				Some((edited_position, patch.hash.as_str())),
+				patch.summary.as_str(),
+			));
+		}
+
+		for (position, dropped) in original_patches.iter().enumerate().skip(next_unmatched) {
+			if !matched[position] {
+				entries.push(RangeDiffEntry::new(
+					RangeDiffStatus::Dropped,
+					Some((position, dropped.hash.as_str())),
+					None,
+					dropped.summary.as_str(),
+				));
+			}
+		}
+
+		Ok(Self { entries })
+	}
+
+	/// Get the entries of the range diff.
+	#[must_use]
+	pub(crate) fn entries(&self) -> &[RangeDiffEntry] {
+		&self.entries
+	}
+
+	/// Is the range diff empty.
+	#[must_use]
+	pub(crate) fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+}
+
+impl From<Vec<RangeDiffEntry>> for RangeDiff {
+	fn from(entries: Vec<RangeDiffEntry>) -> Self {
+		Self { entries }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{fs::File, io::Write as _, path::Path};
+
+	use claims::assert_err;
+
+	use super::*;
+	use crate::test_helpers::{CreateCommitOptions, create_commit, with_temp_repository};
+
+	fn commit_file(repository: &Repository, contents: &str, message: &str) -> String {
+		let root = repository.path().parent().unwrap();
+		let mut file = File::create(root.join("file.txt")).unwrap();
+		write!(file, "{contents}").unwrap();
+		let mut index = repository.index().unwrap();
+		index.add_path(Path::new("file.txt")).unwrap();
+		index.write().unwrap();
+		create_commit(repository, Some(CreateCommitOptions::new().message(message)))
+			.hash()
+			.to_owned()
+	}
+
+	fn statuses(range_diff: &RangeDiff) -> Vec<(RangeDiffStatus, Option<usize>, Option<usize>)> {
+		range_diff
+			.entries()
+			.iter()
+			.map(|e| (e.status(), e.original_position(), e.edited_position()))
+			.collect()
+	}
+
+	#[test]
+	fn new() {
+		assert!(RangeDiff::new().is_empty());
+	}
+
+	#[test]
+	fn unchanged() {
+		with_temp_repository(|repository| {
+			let a = commit_file(&repository, "a", "A");
+			let b = commit_file(&repository, "b", "B");
+			let range_diff = RangeDiff::load(&repository, &[a.clone(), b.clone()], &[
+				RangeDiffCommit::new(a.as_str()),
+				RangeDiffCommit::new(b.as_str()),
+			])
+			.unwrap();
+			assert_eq!(statuses(&range_diff), vec![
+				(RangeDiffStatus::Unchanged, Some(0), Some(0)),
+				(RangeDiffStatus::Unchanged, Some(1), Some(1)),
+			]);
+			assert_eq!(range_diff.entries()[0].summary(), "A");
+			assert_eq!(range_diff.entries()[1].original_hash(), Some(b.as_str()));
+			assert_eq!(range_diff.entries()[1].edited_hash(), Some(b.as_str()));
+		});
+	}
+
+	#[test]
+	fn reordered() {
+		with_temp_repository(|repository| {
+			let a = commit_file(&repository, "a", "A");
+			let b = commit_file(&repository, "b", "B");
+			let range_diff = RangeDiff::load(&repository, &[a.clone(), b.clone()], &[
+				RangeDiffCommit::new(b.as_str()),
+				RangeDiffCommit::new(a.as_str()),
+			])
+			.unwrap();
+			assert_eq!(statuses(&range_diff), vec![
+				(RangeDiffStatus::Unchanged, Some(1), Some(0)),
+				(RangeDiffStatus::Unchanged, Some(0), Some(1)),
+			]);
+		});
+	}
+
+	#[test]
+	fn dropped() {
+		with_temp_repository(|repository| {
+			let a = commit_file(&repository, "a", "A");
+			let b = commit_file(&repository, "b", "B");
+			let c = commit_file(&repository, "c", "C");
+			let range_diff = RangeDiff::load(&repository, &[a.clone(), b.clone(), c.clone()], &[
+				RangeDiffCommit::new(c.as_str()),
+			])
+			.unwrap();
+			assert_eq!(statuses(&range_diff), vec![
+				(RangeDiffStatus::Dropped, Some(0), None),
+				(RangeDiffStatus::Dropped, Some(1), None),
+				(RangeDiffStatus::Unchanged, Some(2), Some(0)),
+			]);
+			assert_eq!(range_diff.entries()[1].summary(), "B");
+			assert_eq!(range_diff.entries()[1].edited_hash(), None);
+		});
+	}
+
+	#[test]
+	fn dropped_at_end() {
+		with_temp_repository(|repository| {
+			let a = commit_file(&repository, "a", "A");
+			let b = commit_file(&repository, "b", "B");
+			let range_diff = RangeDiff::load(&repository, &[a.clone(), b.clone()], &[RangeDiffCommit::new(
+				a.as_str(),
+			)])
+			.unwrap();
+			assert_eq!(statuses(&range_diff), vec![
+				(RangeDiffStatus::Unchanged, Some(0), Some(0)),
+				(RangeDiffStatus::Dropped, Some(1), None),
+			]);
+		});
+	}
+
+	#[test]
+	fn modified_with_squashed_commit() {
+		with_temp_repository(|repository| {
+			let a = commit_file(&repository, "a", "A");
+			let b = commit_file(&repository, "b", "B");
+			let mut commit = RangeDiffCommit::new(a.as_str());
+			commit.push_squashed(b.as_str());
+			let range_diff = RangeDiff::load(&repository, &[a.clone(), b.clone()], &[commit]).unwrap();
+			assert_eq!(statuses(&range_diff), vec![
+				(RangeDiffStatus::Modified, Some(0), Some(0)),
+				(RangeDiffStatus::Dropped, Some(1), None),
+			]);
+		});
+	}
+
+	#[test]
+	fn modified_with_reword() {
+		with_temp_repository(|repository| {
+			let a = commit_file(&repository, "a", "A");
+			let mut commit = RangeDiffCommit::new(a.as_str());
+			commit.set_reworded();
+			let range_diff = RangeDiff::load(&repository, &[a], &[commit]).unwrap();
+			assert_eq!(statuses(&range_diff), vec![(
+				RangeDiffStatus::Modified,
+				Some(0),
+				Some(0)
+			)]);
+		});
+	}
+
+	#[test]
+	fn added() {
+		with_temp_repository(|repository| {
+			let a = commit_file(&repository, "a", "A");
+			let b = commit_file(&repository, "b", "B");
+			let edited = [RangeDiffCommit::new(a.as_str()), RangeDiffCommit::new(b.as_str())];
+			let range_diff = RangeDiff::load(&repository, &[a], &edited).unwrap();
+			assert_eq!(statuses(&range_diff), vec![
+				(RangeDiffStatus::Unchanged, Some(0), Some(0)),
+				(RangeDiffStatus::Added, None, Some(1)),
+			]);
+		});
+	}
+
+	#[test]
+	fn matched_by_patch_id() {
+		with_temp_repository(|repository| {
+			let base = commit_file(&repository, "1", "Base");
+			let change = commit_file(&repository, "2", "Change");
+			let revert = commit_file(&repository, "1", "Revert");
+			let same_change = commit_file(&repository, "2", "Same change");
+			let range_diff = RangeDiff::load(&repository, &[base.clone(), change.clone(), revert.clone()], &[
+				RangeDiffCommit::new(base.as_str()),
+				RangeDiffCommit::new(same_change.as_str()),
+			])
+			.unwrap();
+			assert_eq!(statuses(&range_diff), vec![
+				(RangeDiffStatus::Unchanged, Some(0), Some(0)),
+				(RangeDiffStatus::Unchanged, Some(1), Some(1)),
+				(RangeDiffStatus::Dropped, Some(2), None),
+			]);
+			assert_eq!(range_diff.entries()[1].original_hash(), Some(change.as_str()));
+			assert_eq!(range_diff.entries()[1].edited_hash(), Some(same_change.as_str()));
+		});
+	}
+
+	#[test]
+	fn invalid_commit() {
+		with_temp_repository(|repository| {
+			assert_err!(RangeDiff::load(&repository, &[String::from("aaa")], &[]));
+		});
+	}
+}
@@ -0,0 +1,68 @@
+/// Represents a commit in an edited commit series, along with the commits folded into it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RangeDiffCommit {
+	hash: String,
+	squashed: Vec<String>,
+	reworded: bool,
+}
+
+impl RangeDiffCommit {
+	/// Create a new instance.
+	#[must_use]
+	pub(crate) fn new(hash: &str) -> Self {
+		Self {
+			hash: String::from(hash),
+			squashed: vec![],
+			reworded: false,
+		}
+	}
+
+	/// Add a commit that is squashed, or fixed-up, into this commit.
+	pub(crate) fn push_squashed(&mut self, hash: &str) {
+		self.squashed.push(String::from(hash));
+	}
+
+	/// Mark the commit message as changed.
+	pub(crate) fn set_reworded(&mut self) {
+		self.reworded = true;
+	}
+
+	/// Get the hash of the commit.
+	#[must_use]
+	pub(crate) fn hash(&self) -> &str {
+		self.hash.as_str()
+	}
+
+	/// Will the resulting commit differ from the commit it was created from.
+	#[must_use]
+	pub(crate) fn is_modified(&self) -> bool {
+		self.reworded || !self.squashed.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn new() {
+		let commit = RangeDiffCommit::new("abc123");
+		assert_eq!(commit.hash(), "abc123");
+		assert!(!commit.is_modified());
+	}
+
+	#[test]
+	fn push_squashed() {
+		let mut commit = RangeDiffCommit::new("abc123");
+		commit.push_squashed("def456");
+		assert_eq!(commit.squashed, vec![String::from("def456")]);
+		assert!(commit.is_modified());
+	}
+
+	#[test]
+	fn set_reworded() {
+		let mut commit = RangeDiffCommit::new("abc123");
+		commit.set_reworded();
+		assert!(commit.is_modified());
+	}
+}
@@ -0,0 +1,95 @@
+use crate::diff::RangeDiffStatus;
+
+/// Represents a single patch in a range diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RangeDiffEntry {
+	status: RangeDiffStatus,
+	original: Option<(usize, String)>,
+	edited: Option<(usize, String)>,
+	summary: String,
+}
+
+impl RangeDiffEntry {
+	/// Create a new instance, where `original` and `edited` are the position and hash of the commit
+	/// in the respective series.
+	#[must_use]
+	pub(crate) fn new(
+		status: RangeDiffStatus,
+		original: Option<(usize, &str)>,
+		edited: Option<(usize, &str)>,
+		summary: &str,
+	) -> Self {
+		Self {
+			status,
+			original: original.map(|(position, hash)| (position, String::from(hash))),
+			edited: edited.map(|(position, hash)| (position, String::from(hash))),
+			summary: String::from(summary),
+		}
+	}
+
+	/// Get the status of the patch.
+	#[must_use]
+	pub(crate) const fn status(&self) -> RangeDiffStatus {
+		self.status
+	}
+
+	/// Get the position of the commit in the original series, if it exists in the original series.
+	#[must_use]
+	pub(crate) fn original_position(&self) -> Option<usize> {
+		self.original.as_ref().map(|&(position, _)| position)
+	}
+
+	/// Get the hash of the commit in the original series, if it exists in the original series.
+	#[must_use]
+	pub(crate) fn original_hash(&self) -> Option<&str> {
+		self.original.as_ref().map(|(_, hash)| hash.as_str())
+	}
+
+	/// Get the position of the commit in the edited series, if it exists in the edited series.
+	#[must_use]
+	pub(crate) fn edited_position(&self) -> Option<usize> {
+		self.edited.as_ref().map(|&(position, _)| position)
+	}
+
+	/// Get the hash of the commit in the edited series, if it exists in the edited series.
+	#[must_use]
+	pub(crate) fn edited_hash(&self) -> Option<&str> {
+		self.edited.as_ref().map(|(_, hash)| hash.as_str())
+	}
+
+	/// Get the summary line of the commit message.
+	#[must_use]
+	pub(crate) fn summary(&self) -> &str {
+		self.summary.as_str()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn new_with_both() {
+		let entry = RangeDiffEntry::new(
+			RangeDiffStatus::Unchanged,
+			Some((1, "abc123")),
+			Some((2, "def456")),
+			"Summary",
+		);
+		assert_eq!(entry.status(), RangeDiffStatus::Unchanged);
+		assert_eq!(entry.original_position(), Some(1));
+		assert_eq!(entry.original_hash(), Some("abc123"));
+		assert_eq!(entry.edited_position(), Some(2));
+		assert_eq!(entry.edited_hash(), Some("def456"));
+		assert_eq!(entry.summary(), "Summary");
+	}
+
+	#[test]
+	fn new_with_none() {
+		let entry = RangeDiffEntry::new(RangeDiffStatus::Dropped, None, None, "");
+		assert_eq!(entry.original_position(), None);
+		assert_eq!(entry.original_hash(), None);
+		assert_eq!(entry.edited_position(), None);
+		assert_eq!(entry.edited_hash(), None);
+	}
+}
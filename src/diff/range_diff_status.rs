@@ -0,0 +1,41 @@
+/// Represents the status of a patch when comparing two commit series.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum RangeDiffStatus {
+	/// The patch exists in both series, without changes.
+	Unchanged,
+	/// The patch exists in both series, but has been changed.
+	Modified,
+	/// The patch only exists in the original series.
+	Dropped,
+	/// The patch only exists in the edited series.
+	Added,
+}
+
+impl RangeDiffStatus {
+	/// Get the symbol used by `git range-diff` for the status.
+	#[must_use]
+	pub(crate) const fn symbol(self) -> char {
+		match self {
+			Self::Unchanged => '=',
+			Self::Modified => '!',
+			Self::Dropped => '<',
+			Self::Added => '>',
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::unchanged(RangeDiffStatus::Unchanged, '=')]
+	#[case::modified(RangeDiffStatus::Modified, '!')]
+	#[case::dropped(RangeDiffStatus::Dropped, '<')]
+	#[case::added(RangeDiffStatus::Added, '>')]
+	fn symbol(#[case] status: RangeDiffStatus, #[case] expected: char) {
+		assert_eq!(status.symbol(), expected);
+	}
+}
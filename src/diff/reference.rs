@@ -25,14 +25,12 @@ impl Reference {
 
 	/// Get the oid of the reference
 	#[must_use]
-	#[expect(unused, reason = "Available for future use")]
 	pub(crate) fn hash(&self) -> &str {
 		self.hash.as_str()
 	}
 
 	/// Get the name of the reference
 	#[must_use]
-	#[expect(unused, reason = "Available for future use")]
 	pub(crate) fn name(&self) -> &str {
 		self.name.as_str()
 	}
@@ -0,0 +1,248 @@
+use std::{
+	collections::HashMap,
+	fs::{self, File},
+	path::Path,
+	process::{Command, Stdio},
+	thread::sleep,
+	time::{Duration, Instant},
+};
+
+use git2::{AttrCheckFlags, AttrValue, Repository};
+use uuid::Uuid;
+
+/// The time a textconv program can run, before the program is killed and the file is diffed without it.
+const TEXTCONV_TIMEOUT: Duration = Duration::from_secs(10);
+const TEXTCONV_POLL_RATE: Duration = Duration::from_millis(25);
+
+/// A textconv program, configured on a diff driver, that converts the contents of a file to text before
+/// the file is diffed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TextConv {
+	command: String,
+}
+
+impl TextConv {
+	/// Create a new instance from the shell command of the program.
+	#[must_use]
+	pub(crate) fn new(command: &str) -> Self {
+		Self {
+			command: String::from(command),
+		}
+	}
+
+	/// Convert the contents of a file, returning `None` if the program could not be run, failed, did not finish
+	/// before the timeout, or if `is_cancelled` returns `true` while the program is running.
+	///
+	/// Like Git, the contents are written to a temporary file, and the path to the file is passed as the
+	/// last argument of the command, which is run using the shell.
+	#[must_use]
+	pub(crate) fn convert(&self, content: &[u8], is_cancelled: &impl Fn() -> bool) -> Option<Vec<u8>> {
+		self.convert_with_timeout(content, TEXTCONV_TIMEOUT, is_cancelled)
+	}
+
+	fn convert_with_timeout(
+		&self,
+		content: &[u8],
+		timeout: Duration,
+		is_cancelled: &impl Fn() -> bool,
+	) -> Option<Vec<u8>> {
+		let path = std::env::temp_dir().join(format!("interactive-rebase-tool-textconv-{}", Uuid::new_v4()));
+		let output_path = path.with_extension("out");
+		let output = self.run(path.as_path(), output_path.as_path(), content, timeout, is_cancelled);
+		// failing to remove the temporary files is not fatal
+		drop(fs::remove_file(path.as_path()));
+		drop(fs::remove_file(output_path.as_path()));
+		output
+	}
+
+	fn run(
+		&self,
+		path: &Path,
+		output_path: &Path,
+		content: &[u8],
+		timeout: Duration,
+		is_cancelled: &impl Fn() -> bool,
+	) -> Option<Vec<u8>> {
+		fs::write(path, content).ok()?;
+		// the output is written to a file, so the program does not block on a full pipe while it is polled
+		let output = File::create(output_path).ok()?;
+		let mut child = Command::new("sh")
+			.arg("-c")
+			.arg(format!("{} \"$@\"", self.command))
+			.arg(self.command.as_str())
+			.arg(path)
+			.stdin(Stdio::null())
+			.stdout(output)
+			.stderr(Stdio::null())
+			.spawn()
+			.ok()?;
+
+		let deadline = Instant::now() + timeout;
+		loop {
+			match child.try_wait() {
+				Ok(Some(status)) => return status.success().then(|| fs::read(output_path).ok()).flatten(),
+				Ok(None) if Instant::now() < deadline && !is_cancelled() => sleep(TEXTCONV_POLL_RATE),
+				_ => {
+					drop(child.kill());
+					drop(child.wait());
+					return None;
+				},
+			}
+		}
+	}
+}
+
+/// The textconv programs of the diff drivers in the Git config, read once for each loaded diff.
+#[derive(Debug, Default)]
+pub(crate) struct TextConvDrivers {
+	drivers: HashMap<String, TextConv>,
+}
+
+impl TextConvDrivers {
+	/// Read the `diff.<driver>.textconv` settings of the repository.
+	#[must_use]
+	pub(crate) fn load(repository: &Repository) -> Self {
+		let mut drivers = HashMap::new();
+		let Ok(config) = repository.config().and_then(|mut config| config.snapshot())
+		else {
+			return Self { drivers };
+		};
+		let Ok(mut entries) = config.entries(Some(r"^diff\..*\.textconv$"))
+		else {
+			return Self { drivers };
+		};
+		while let Some(Ok(entry)) = entries.next() {
+			let driver = entry
+				.name()
+				.and_then(|name| name.strip_prefix("diff."))
+				.and_then(|name| name.strip_suffix(".textconv"));
+			if let (Some(driver), Some(command)) = (driver, entry.value()) {
+				// later entries have a higher priority, so replace the earlier entries
+				drop(drivers.insert(String::from(driver), TextConv::new(command)));
+			}
+		}
+		Self { drivers }
+	}
+
+	/// Find the textconv program for a path, from the diff driver set by the `diff` attribute of the path. The
+	/// attributes are not read when there are no textconv programs.
+	#[must_use]
+	pub(crate) fn for_path(&self, repository: &Repository, path: &Path) -> Option<&TextConv> {
+		if self.drivers.is_empty() {
+			return None;
+		}
+		let value = repository
+			.get_attr(path, "diff", AttrCheckFlags::FILE_THEN_INDEX)
+			.ok()??;
+		// `diff`, `-diff` and unset attributes do not name a driver
+		let AttrValue::String(driver) = AttrValue::from_string(Some(value))
+		else {
+			return None;
+		};
+		self.drivers.get(driver)
+	}
+}
+
+#[cfg(all(unix, test))]
+mod tests {
+	use std::{cell::Cell, io::Write as _};
+
+	use claims::{assert_none, assert_some_eq};
+
+	use super::*;
+	use crate::test_helpers::with_temp_repository;
+
+	fn write_file(repository: &Repository, name: &str, content: &str) {
+		let mut file = File::create(repository.workdir().unwrap().join(name)).unwrap();
+		write!(file, "{content}").unwrap();
+	}
+
+	#[test]
+	fn convert() {
+		assert_some_eq!(
+			TextConv::new("tr a-z A-Z <").convert(b"abc", &|| false),
+			b"ABC".to_vec()
+		);
+	}
+
+	#[test]
+	fn convert_large_output() {
+		assert_some_eq!(
+			TextConv::new("head -c 1000000 /dev/zero; true").convert(b"abc", &|| false),
+			vec![0; 1_000_000]
+		);
+	}
+
+	#[test]
+	fn convert_command_failure() {
+		assert_none!(TextConv::new("false").convert(b"abc", &|| false));
+	}
+
+	#[test]
+	fn convert_timeout() {
+		let start = Instant::now();
+		assert_none!(TextConv::new("sleep 5; true").convert_with_timeout(b"abc", Duration::from_millis(50), &|| false));
+		assert!(start.elapsed() < Duration::from_secs(5));
+	}
+
+	#[test]
+	fn convert_cancelled() {
+		let start = Instant::now();
+		let checks = Cell::new(0);
+		assert_none!(TextConv::new("sleep 5; true").convert(b"abc", &|| {
+			checks.set(checks.get() + 1);
+			checks.get() > 1
+		}));
+		assert!(start.elapsed() < Duration::from_secs(5));
+	}
+
+	#[test]
+	fn drivers_for_path_with_driver() {
+		with_temp_repository(|repository| {
+			write_file(&repository, ".gitattributes", "*.lock diff=lock\n");
+			repository
+				.config()
+				.unwrap()
+				.set_str("diff.lock.textconv", "sort")
+				.unwrap();
+			assert_some_eq!(
+				TextConvDrivers::load(&repository).for_path(&repository, Path::new("Cargo.lock")),
+				&TextConv::new("sort")
+			);
+		});
+	}
+
+	#[test]
+	fn drivers_for_path_driver_without_textconv() {
+		with_temp_repository(|repository| {
+			write_file(&repository, ".gitattributes", "*.lock diff=lock\n*.txt diff=text\n");
+			repository
+				.config()
+				.unwrap()
+				.set_str("diff.text.textconv", "sort")
+				.unwrap();
+			assert_none!(TextConvDrivers::load(&repository).for_path(&repository, Path::new("Cargo.lock")));
+		});
+	}
+
+	#[test]
+	fn drivers_for_path_without_driver() {
+		with_temp_repository(|repository| {
+			write_file(&repository, ".gitattributes", "*.lock -diff\n");
+			repository
+				.config()
+				.unwrap()
+				.set_str("diff.lock.textconv", "sort")
+				.unwrap();
+			assert_none!(TextConvDrivers::load(&repository).for_path(&repository, Path::new("Cargo.lock")));
+		});
+	}
+
+	#[test]
+	fn drivers_without_textconv() {
+		with_temp_repository(|repository| {
+			write_file(&repository, ".gitattributes", "*.lock diff=lock\n");
+			assert_none!(TextConvDrivers::load(&repository).for_path(&repository, Path::new("Cargo.lock")));
+		});
+	}
+}
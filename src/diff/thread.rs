@@ -1,5 +1,6 @@
 mod action;
 mod load_status;
+mod range_diff_load_status;
 mod state;
 mod update_handler;
 
@@ -8,7 +9,13 @@ use std::sync::Arc;
 use captur::capture;
 use parking_lot::Mutex;
 
-pub(crate) use self::{action::Action, load_status::LoadStatus, state::State, update_handler::UpdateHandlerFn};
+pub(crate) use self::{
+	action::Action,
+	load_status::LoadStatus,
+	range_diff_load_status::RangeDiffLoadStatus,
+	state::State,
+	update_handler::UpdateHandlerFn,
+};
 use crate::{
 	diff::CommitDiffLoader,
 	runtime::{Installer, Threadable},
@@ -66,6 +73,25 @@ where UpdateHandler: UpdateHandlerFn + 'static
 								update_handler();
 							}
 						},
+						Action::LoadRangeDiff(original, edited) => {
+							state.set_range_diff_load_status(RangeDiffLoadStatus::Loading);
+							update_handler();
+							let loader = commit_diff_loader.lock();
+							match loader.load_range_diff(&original, &edited) {
+								Ok(range_diff) => {
+									*state.range_diff().write() = range_diff;
+									state.set_range_diff_load_status(RangeDiffLoadStatus::Complete);
+								},
+								Err(e) => {
+									state.set_range_diff_load_status(RangeDiffLoadStatus::Error {
+										msg: e.to_string(),
+										code: e.code(),
+									});
+								},
+							}
+							update_handler();
+						},
+						Action::SetOptions(options) => commit_diff_loader.lock().set_options(options),
 						Action::StatusChange => {},
 					}
 				}
@@ -189,4 +215,59 @@ mod tests {
 			tester.wait_for_status(&Status::Ended);
 		});
 	}
+
+	#[test]
+	fn range_diff_load_error() {
+		with_temp_repository(|repository| {
+			let thread = Thread::new(CommitDiffLoader::new(repository, CommitDiffLoaderOptions::new()), || {});
+
+			let state = thread.state();
+			let tester = testers::Threadable::new();
+			tester.start_threadable(&thread, THREAD_NAME);
+			tester.wait_for_status(&Status::Waiting);
+
+			state.start_range_diff_load(vec![String::from("abc123")], vec![]);
+
+			let mut pass = false;
+			for _ in 0..10 {
+				if let RangeDiffLoadStatus::Error { .. } = state.range_diff_load_status() {
+					pass = true;
+					break;
+				}
+				sleep(Duration::from_millis(10));
+			}
+			assert!(pass);
+
+			state.end();
+			tester.wait_for_status(&Status::Ended);
+		});
+	}
+
+	#[test]
+	fn range_diff_load_complete() {
+		with_temp_repository(|repository| {
+			let thread = Thread::new(CommitDiffLoader::new(repository, CommitDiffLoaderOptions::new()), || {});
+
+			let state = thread.state();
+			let tester = testers::Threadable::new();
+			tester.start_threadable(&thread, THREAD_NAME);
+			tester.wait_for_status(&Status::Waiting);
+
+			state.start_range_diff_load(vec![], vec![]);
+
+			let mut pass = false;
+			for _ in 0..10 {
+				if state.range_diff_load_status() == RangeDiffLoadStatus::Complete {
+					pass = true;
+					break;
+				}
+				sleep(Duration::from_millis(10));
+			}
+			assert!(pass);
+			assert!(state.range_diff().read().is_empty());
+
+			state.end();
+			tester.wait_for_status(&Status::Ended);
+		});
+	}
 }
@@ -1,9 +1,13 @@
 use std::fmt::{Debug, Formatter};
 
+use crate::diff::{CommitDiffLoaderOptions, RangeDiffCommit};
+
 #[derive(PartialEq)]
 pub(crate) enum Action {
 	StatusChange,
 	Load(String),
+	LoadRangeDiff(Vec<String>, Vec<RangeDiffCommit>),
+	SetOptions(CommitDiffLoaderOptions),
 }
 
 impl Debug for Action {
@@ -11,6 +15,10 @@ impl Debug for Action {
 		match *self {
 			Self::StatusChange => write!(f, "StatusChange"),
 			Self::Load(ref hash) => write!(f, "Load({hash})"),
+			Self::LoadRangeDiff(ref original, ref edited) => {
+				write!(f, "LoadRangeDiff({}, {})", original.len(), edited.len())
+			},
+			Self::SetOptions(_) => write!(f, "SetOptions"),
 		}
 	}
 }
@@ -23,6 +31,11 @@ mod tests {
 	#[rstest]
 	#[case::status_change(Action::StatusChange, "StatusChange")]
 	#[case::cont(Action::Load(String::from("abc123")), "Load(abc123)")]
+	#[case::load_range_diff(
+		Action::LoadRangeDiff(vec![String::from("abc123")], vec![]),
+		"LoadRangeDiff(1, 0)"
+	)]
+	#[case::set_options(Action::SetOptions(CommitDiffLoaderOptions::new()), "SetOptions")]
 	fn debug(#[case] action: Action, #[case] expected: &str) {
 		assert_eq!(format!("{action:?}"), expected);
 	}
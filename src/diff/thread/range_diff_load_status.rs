@@ -0,0 +1,9 @@
+use git2::ErrorCode;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum RangeDiffLoadStatus {
+	New,
+	Loading,
+	Complete,
+	Error { msg: String, code: ErrorCode },
+}
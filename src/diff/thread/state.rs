@@ -7,13 +7,18 @@ use parking_lot::RwLock;
 
 use crate::diff::{
 	CommitDiff,
-	thread::{Action, LoadStatus},
+	CommitDiffLoaderOptions,
+	RangeDiff,
+	RangeDiffCommit,
+	thread::{Action, LoadStatus, RangeDiffLoadStatus},
 };
 
 #[derive(Clone, Debug)]
 pub(crate) struct State {
 	load_status: Arc<RwLock<LoadStatus>>,
 	diff: Arc<RwLock<CommitDiff>>,
+	range_diff_load_status: Arc<RwLock<RangeDiffLoadStatus>>,
+	range_diff: Arc<RwLock<RangeDiff>>,
 	ended: Arc<AtomicBool>,
 	cancelled: Arc<AtomicBool>,
 	update_receiver: crossbeam_channel::Receiver<Action>,
@@ -26,6 +31,8 @@ impl State {
 		Self {
 			load_status: Arc::new(RwLock::new(LoadStatus::New)),
 			diff,
+			range_diff_load_status: Arc::new(RwLock::new(RangeDiffLoadStatus::New)),
+			range_diff: Arc::new(RwLock::new(RangeDiff::new())),
 			ended: Arc::new(AtomicBool::from(false)),
 			cancelled: Arc::new(AtomicBool::from(false)),
 			update_receiver,
@@ -46,6 +53,19 @@ impl State {
 		Arc::clone(&self.diff)
 	}
 
+	pub(crate) fn range_diff_load_status(&self) -> RangeDiffLoadStatus {
+		self.range_diff_load_status.read().clone()
+	}
+
+	pub(crate) fn set_range_diff_load_status(&self, status: RangeDiffLoadStatus) {
+		let mut range_diff_load_status = self.range_diff_load_status.write();
+		*range_diff_load_status = status;
+	}
+
+	pub(crate) fn range_diff(&self) -> Arc<RwLock<RangeDiff>> {
+		Arc::clone(&self.range_diff)
+	}
+
 	pub(crate) fn receive_update(&self) -> Action {
 		self.update_receiver.recv().unwrap_or(Action::StatusChange)
 	}
@@ -54,6 +74,17 @@ impl State {
 		self.send_update(Action::Load(String::from(term)));
 	}
 
+	pub(crate) fn start_range_diff_load(&self, original: Vec<String>, edited: Vec<RangeDiffCommit>) {
+		self.send_update(Action::LoadRangeDiff(original, edited));
+	}
+
+	/// Change the options used to load a diff. The loaded diff is cleared, so that it is loaded again with the new
+	/// options.
+	pub(crate) fn set_options(&self, options: CommitDiffLoaderOptions) {
+		self.diff.write().clear();
+		self.send_update(Action::SetOptions(options));
+	}
+
 	pub(crate) fn is_cancelled(&self) -> bool {
 		self.cancelled.load(Ordering::Acquire) || self.ended.load(Ordering::Acquire)
 	}
@@ -119,6 +150,38 @@ mod tests {
 		assert_eq!(state.receive_update(), Action::Load(String::from("term")));
 	}
 
+	#[test]
+	fn set_options() {
+		let state = create_state();
+		let options = CommitDiffLoaderOptions::new().context_lines(5);
+		state.set_options(options.clone());
+		assert_eq!(state.receive_update(), Action::SetOptions(options));
+	}
+
+	#[test]
+	fn range_diff_load_status() {
+		let state = create_state();
+		assert_eq!(state.range_diff_load_status(), RangeDiffLoadStatus::New);
+		state.set_range_diff_load_status(RangeDiffLoadStatus::Complete);
+		assert_eq!(state.range_diff_load_status(), RangeDiffLoadStatus::Complete);
+	}
+
+	#[test]
+	fn start_range_diff_load() {
+		let state = create_state();
+		state.start_range_diff_load(vec![String::from("abc123")], vec![]);
+		assert_eq!(
+			state.receive_update(),
+			Action::LoadRangeDiff(vec![String::from("abc123")], vec![])
+		);
+	}
+
+	#[test]
+	fn range_diff() {
+		let state = create_state();
+		assert!(state.range_diff().read().is_empty());
+	}
+
 	#[test]
 	fn diff() {
 		// not much to test here
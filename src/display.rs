@@ -42,6 +42,7 @@ pub(crate) struct Display<T: Tui> {
 	action_merge: (Colors, Colors),
 	action_pick: (Colors, Colors),
 	action_reset: (Colors, Colors),
+	action_revert: (Colors, Colors),
 	action_reword: (Colors, Colors),
 	action_squash: (Colors, Colors),
 	action_update_ref: (Colors, Colors),
@@ -49,153 +50,185 @@ pub(crate) struct Display<T: Tui> {
 	diff_add: (Colors, Colors),
 	diff_change: (Colors, Colors),
 	diff_context: (Colors, Colors),
+	diff_hunk: (Colors, Colors),
 	diff_remove: (Colors, Colors),
 	diff_whitespace: (Colors, Colors),
 	indicator: (Colors, Colors),
 	normal: (Colors, Colors),
+	search_match: (Colors, Colors),
 }
 
 impl<T: Tui> Display<T> {
 	/// Create a new display instance.
 	pub(crate) fn new(tui: T, theme: &Theme) -> Self {
-		let color_mode = tui.get_color_mode();
-		let normal = register_selectable_color_pairs(
+		let unset = (
+			Colors::new(Color::Reset, Color::Reset),
+			Colors::new(Color::Reset, Color::Reset),
+		);
+		let mut display = Self {
+			action_break: unset,
+			action_drop: unset,
+			action_edit: unset,
+			action_exec: unset,
+			action_fixup: unset,
+			action_label: unset,
+			action_merge: unset,
+			action_pick: unset,
+			action_reset: unset,
+			action_revert: unset,
+			action_reword: unset,
+			action_squash: unset,
+			action_update_ref: unset,
+			tui,
+			diff_add: unset,
+			diff_change: unset,
+			diff_context: unset,
+			diff_hunk: unset,
+			diff_remove: unset,
+			diff_whitespace: unset,
+			indicator: unset,
+			normal: unset,
+			search_match: unset,
+		};
+		display.set_theme(theme);
+		display
+	}
+
+	/// Register the colors of a theme, replacing any previously registered colors.
+	pub(crate) fn set_theme(&mut self, theme: &Theme) {
+		let color_mode = self.tui.get_color_mode();
+		self.normal = register_selectable_color_pairs(
 			color_mode,
 			theme.color_foreground,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let indicator = register_selectable_color_pairs(
+		self.indicator = register_selectable_color_pairs(
 			color_mode,
 			theme.color_indicator,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_break = register_selectable_color_pairs(
+		self.search_match = register_selectable_color_pairs(
+			color_mode,
+			theme.color_search_match,
+			theme.color_background,
+			theme.color_selected_background,
+		);
+		self.action_break = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_break,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_drop = register_selectable_color_pairs(
+		self.action_drop = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_drop,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_edit = register_selectable_color_pairs(
+		self.action_edit = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_edit,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_exec = register_selectable_color_pairs(
+		self.action_exec = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_exec,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_fixup = register_selectable_color_pairs(
+		self.action_fixup = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_fixup,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_pick = register_selectable_color_pairs(
+		self.action_pick = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_pick,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_reword = register_selectable_color_pairs(
+		self.action_revert = register_selectable_color_pairs(
+			color_mode,
+			theme.color_action_revert,
+			theme.color_background,
+			theme.color_selected_background,
+		);
+		self.action_reword = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_reword,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_squash = register_selectable_color_pairs(
+		self.action_squash = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_squash,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_label = register_selectable_color_pairs(
+		self.action_label = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_label,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_reset = register_selectable_color_pairs(
+		self.action_reset = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_reset,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_merge = register_selectable_color_pairs(
+		self.action_merge = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_merge,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let action_update_ref = register_selectable_color_pairs(
+		self.action_update_ref = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_update_ref,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let diff_add = register_selectable_color_pairs(
+		self.diff_add = register_selectable_color_pairs(
 			color_mode,
 			theme.color_diff_add,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let diff_change = register_selectable_color_pairs(
+		self.diff_change = register_selectable_color_pairs(
 			color_mode,
 			theme.color_diff_change,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let diff_remove = register_selectable_color_pairs(
+		self.diff_remove = register_selectable_color_pairs(
 			color_mode,
 			theme.color_diff_remove,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let diff_context = register_selectable_color_pairs(
+		self.diff_context = register_selectable_color_pairs(
 			color_mode,
 			theme.color_diff_context,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-		let diff_whitespace = register_selectable_color_pairs(
+		self.diff_whitespace = register_selectable_color_pairs(
 			color_mode,
 			theme.color_diff_whitespace,
 			theme.color_background,
 			theme.color_selected_background,
 		);
-
-		Self {
-			action_break,
-			action_drop,
-			action_edit,
-			action_exec,
-			action_fixup,
-			action_label,
-			action_merge,
-			action_pick,
-			action_reset,
-			action_reword,
-			action_squash,
-			action_update_ref,
-			tui,
-			diff_add,
-			diff_change,
-			diff_context,
-			diff_remove,
-			diff_whitespace,
-			indicator,
-			normal,
-		}
+		self.diff_hunk = register_selectable_color_pairs(
+			color_mode,
+			theme.color_diff_hunk,
+			theme.color_background,
+			theme.color_selected_background,
+		);
 	}
 
 	/// Draws a string of text to the terminal interface.
@@ -241,6 +274,7 @@ impl<T: Tui> Display<T> {
 					DisplayColor::ActionExec => self.action_exec.1,
 					DisplayColor::ActionFixup => self.action_fixup.1,
 					DisplayColor::ActionPick => self.action_pick.1,
+					DisplayColor::ActionRevert => self.action_revert.1,
 					DisplayColor::ActionReword => self.action_reword.1,
 					DisplayColor::ActionSquash => self.action_squash.1,
 					DisplayColor::ActionLabel => self.action_label.1,
@@ -249,11 +283,13 @@ impl<T: Tui> Display<T> {
 					DisplayColor::ActionUpdateRef => self.action_update_ref.1,
 					DisplayColor::Normal => self.normal.1,
 					DisplayColor::IndicatorColor => self.indicator.1,
+					DisplayColor::SearchMatchColor => self.search_match.1,
 					DisplayColor::DiffAddColor => self.diff_add.1,
 					DisplayColor::DiffRemoveColor => self.diff_remove.1,
 					DisplayColor::DiffChangeColor => self.diff_change.1,
 					DisplayColor::DiffContextColor => self.diff_context.1,
 					DisplayColor::DiffWhitespaceColor => self.diff_whitespace.1,
+					DisplayColor::DiffHunkColor => self.diff_hunk.1,
 				}
 			}
 			else {
@@ -264,6 +300,7 @@ impl<T: Tui> Display<T> {
 					DisplayColor::ActionExec => self.action_exec.0,
 					DisplayColor::ActionFixup => self.action_fixup.0,
 					DisplayColor::ActionPick => self.action_pick.0,
+					DisplayColor::ActionRevert => self.action_revert.0,
 					DisplayColor::ActionReword => self.action_reword.0,
 					DisplayColor::ActionSquash => self.action_squash.0,
 					DisplayColor::ActionLabel => self.action_label.0,
@@ -272,11 +309,13 @@ impl<T: Tui> Display<T> {
 					DisplayColor::ActionUpdateRef => self.action_update_ref.0,
 					DisplayColor::Normal => self.normal.0,
 					DisplayColor::IndicatorColor => self.indicator.0,
+					DisplayColor::SearchMatchColor => self.search_match.0,
 					DisplayColor::DiffAddColor => self.diff_add.0,
 					DisplayColor::DiffRemoveColor => self.diff_remove.0,
 					DisplayColor::DiffChangeColor => self.diff_change.0,
 					DisplayColor::DiffContextColor => self.diff_context.0,
 					DisplayColor::DiffWhitespaceColor => self.diff_whitespace.0,
+					DisplayColor::DiffHunkColor => self.diff_hunk.0,
 				}
 			},
 		)
@@ -403,71 +442,48 @@ mod tests {
 
 	#[rstest]
 	#[case::action_break(DisplayColor::ActionBreak, false, CrosstermColor::White, CrosstermColor::Reset)]
-	#[case::action_break_selected(
-		DisplayColor::ActionBreak,
-		true,
-		CrosstermColor::White,
-		CrosstermColor::AnsiValue(237)
-	)]
+	#[case::action_break_selected(DisplayColor::ActionBreak, true, CrosstermColor::White, CrosstermColor::DarkGrey)]
 	#[case::action_drop(DisplayColor::ActionDrop, false, CrosstermColor::Red, CrosstermColor::Reset)]
-	#[case::action_drop_selected(DisplayColor::ActionDrop, true, CrosstermColor::Red, CrosstermColor::AnsiValue(237))]
+	#[case::action_drop_selected(DisplayColor::ActionDrop, true, CrosstermColor::Red, CrosstermColor::DarkGrey)]
 	#[case::action_edit(DisplayColor::ActionEdit, false, CrosstermColor::Blue, CrosstermColor::Reset)]
-	#[case::action_edit_selected(DisplayColor::ActionEdit, true, CrosstermColor::Blue, CrosstermColor::AnsiValue(237))]
+	#[case::action_edit_selected(DisplayColor::ActionEdit, true, CrosstermColor::Blue, CrosstermColor::DarkGrey)]
 	#[case::action_exec(DisplayColor::ActionExec, false, CrosstermColor::White, CrosstermColor::Reset)]
-	#[case::action_exec_selected(
-		DisplayColor::ActionExec,
-		true,
-		CrosstermColor::White,
-		CrosstermColor::AnsiValue(237)
-	)]
+	#[case::action_exec_selected(DisplayColor::ActionExec, true, CrosstermColor::White, CrosstermColor::DarkGrey)]
 	#[case::action_fixup(DisplayColor::ActionFixup, false, CrosstermColor::Magenta, CrosstermColor::Reset)]
-	#[case::action_fixup_selected(
-		DisplayColor::ActionFixup,
-		true,
-		CrosstermColor::Magenta,
-		CrosstermColor::AnsiValue(237)
-	)]
+	#[case::action_fixup_selected(DisplayColor::ActionFixup, true, CrosstermColor::Magenta, CrosstermColor::DarkGrey)]
 	#[case::action_pick(DisplayColor::ActionPick, false, CrosstermColor::Green, CrosstermColor::Reset)]
-	#[case::action_pick_selected(
-		DisplayColor::ActionPick,
+	#[case::action_pick_selected(DisplayColor::ActionPick, true, CrosstermColor::Green, CrosstermColor::DarkGrey)]
+	#[case::action_revert(DisplayColor::ActionRevert, false, CrosstermColor::DarkCyan, CrosstermColor::Reset)]
+	#[case::action_revert_selected(
+		DisplayColor::ActionRevert,
 		true,
-		CrosstermColor::Green,
-		CrosstermColor::AnsiValue(237)
+		CrosstermColor::DarkCyan,
+		CrosstermColor::DarkGrey
 	)]
 	#[case::action_reword(DisplayColor::ActionReword, false, CrosstermColor::Yellow, CrosstermColor::Reset)]
-	#[case::action_reword_selected(
-		DisplayColor::ActionReword,
-		true,
-		CrosstermColor::Yellow,
-		CrosstermColor::AnsiValue(237)
-	)]
+	#[case::action_reword_selected(DisplayColor::ActionReword, true, CrosstermColor::Yellow, CrosstermColor::DarkGrey)]
 	#[case::action_squash(DisplayColor::ActionSquash, false, CrosstermColor::Cyan, CrosstermColor::Reset)]
-	#[case::action_squash_selected(
-		DisplayColor::ActionSquash,
-		true,
-		CrosstermColor::Cyan,
-		CrosstermColor::AnsiValue(237)
-	)]
+	#[case::action_squash_selected(DisplayColor::ActionSquash, true, CrosstermColor::Cyan, CrosstermColor::DarkGrey)]
 	#[case::action_label(DisplayColor::ActionLabel, false, CrosstermColor::DarkYellow, CrosstermColor::Reset)]
 	#[case::action_label_selected(
 		DisplayColor::ActionLabel,
 		true,
 		CrosstermColor::DarkYellow,
-		CrosstermColor::AnsiValue(237)
+		CrosstermColor::DarkGrey
 	)]
 	#[case::action_reset(DisplayColor::ActionReset, false, CrosstermColor::DarkYellow, CrosstermColor::Reset)]
 	#[case::action_reset_selected(
 		DisplayColor::ActionReset,
 		true,
 		CrosstermColor::DarkYellow,
-		CrosstermColor::AnsiValue(237)
+		CrosstermColor::DarkGrey
 	)]
 	#[case::action_merge(DisplayColor::ActionMerge, false, CrosstermColor::DarkYellow, CrosstermColor::Reset)]
 	#[case::action_merge_selected(
 		DisplayColor::ActionMerge,
 		true,
 		CrosstermColor::DarkYellow,
-		CrosstermColor::AnsiValue(237)
+		CrosstermColor::DarkGrey
 	)]
 	#[case::action_update_ref(
 		DisplayColor::ActionUpdateRef,
@@ -479,44 +495,33 @@ mod tests {
 		DisplayColor::ActionUpdateRef,
 		true,
 		CrosstermColor::DarkMagenta,
-		CrosstermColor::AnsiValue(237)
+		CrosstermColor::DarkGrey
 	)]
 	#[case::normal(DisplayColor::Normal, false, CrosstermColor::Reset, CrosstermColor::Reset)]
-	#[case::normal_selected(DisplayColor::Normal, true, CrosstermColor::Reset, CrosstermColor::AnsiValue(237))]
+	#[case::normal_selected(DisplayColor::Normal, true, CrosstermColor::Reset, CrosstermColor::DarkGrey)]
 	#[case::indicator(DisplayColor::IndicatorColor, false, CrosstermColor::Cyan, CrosstermColor::Reset)]
-	#[case::indicator_selected(
-		DisplayColor::IndicatorColor,
-		true,
-		CrosstermColor::Cyan,
-		CrosstermColor::AnsiValue(237)
-	)]
+	#[case::indicator_selected(DisplayColor::IndicatorColor, true, CrosstermColor::Cyan, CrosstermColor::DarkGrey)]
+	#[case::search_match(DisplayColor::SearchMatchColor, false, CrosstermColor::Cyan, CrosstermColor::Reset)]
+	#[case::search_match_selected(DisplayColor::SearchMatchColor, true, CrosstermColor::Cyan, CrosstermColor::DarkGrey)]
+	#[case::diff_hunk(DisplayColor::DiffHunkColor, false, CrosstermColor::Reset, CrosstermColor::Reset)]
+	#[case::diff_hunk_selected(DisplayColor::DiffHunkColor, true, CrosstermColor::Reset, CrosstermColor::DarkGrey)]
 	#[case::diff_add(DisplayColor::DiffAddColor, false, CrosstermColor::Green, CrosstermColor::Reset)]
-	#[case::diff_add_selected(
-		DisplayColor::DiffAddColor,
-		true,
-		CrosstermColor::Green,
-		CrosstermColor::AnsiValue(237)
-	)]
+	#[case::diff_add_selected(DisplayColor::DiffAddColor, true, CrosstermColor::Green, CrosstermColor::DarkGrey)]
 	#[case::diff_remove(DisplayColor::DiffRemoveColor, false, CrosstermColor::Red, CrosstermColor::Reset)]
-	#[case::diff_remove_selected(
-		DisplayColor::DiffRemoveColor,
-		true,
-		CrosstermColor::Red,
-		CrosstermColor::AnsiValue(237)
-	)]
+	#[case::diff_remove_selected(DisplayColor::DiffRemoveColor, true, CrosstermColor::Red, CrosstermColor::DarkGrey)]
 	#[case::diff_change(DisplayColor::DiffChangeColor, false, CrosstermColor::Yellow, CrosstermColor::Reset)]
 	#[case::diff_change_selected(
 		DisplayColor::DiffChangeColor,
 		true,
 		CrosstermColor::Yellow,
-		CrosstermColor::AnsiValue(237)
+		CrosstermColor::DarkGrey
 	)]
 	#[case::diff_context(DisplayColor::DiffContextColor, false, CrosstermColor::White, CrosstermColor::Reset)]
 	#[case::diff_context_selected(
 		DisplayColor::DiffContextColor,
 		true,
 		CrosstermColor::White,
-		CrosstermColor::AnsiValue(237)
+		CrosstermColor::DarkGrey
 	)]
 	#[case::diff_whitespace(
 		DisplayColor::DiffWhitespaceColor,
@@ -528,7 +533,7 @@ mod tests {
 		DisplayColor::DiffWhitespaceColor,
 		true,
 		CrosstermColor::DarkGrey,
-		CrosstermColor::AnsiValue(237)
+		CrosstermColor::DarkGrey
 	)]
 	fn color(
 		#[case] display_color: DisplayColor,
@@ -545,6 +550,20 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn set_theme() {
+		let mut display = Display::new(mocks::CrossTerm::new(), &create_theme());
+		let mut theme = create_theme();
+		theme.color_action_pick = crate::config::Color::LightRed;
+		display.set_theme(&theme);
+		display.color(DisplayColor::ActionPick, false).unwrap();
+		assert!(
+			display
+				.tui
+				.is_colors_enabled(Colors::new(CrosstermColor::Red, CrosstermColor::Reset))
+		);
+	}
+
 	#[rstest]
 	#[case::all_off(false, false, false)]
 	#[case::reverse(false, false, true)]
@@ -13,6 +13,8 @@ pub(crate) enum DisplayColor {
 	ActionFixup,
 	/// The color for the pick action.
 	ActionPick,
+	/// The color for the revert action.
+	ActionRevert,
 	/// The color for the reword action.
 	ActionReword,
 	/// The color for the squash action.
@@ -31,12 +33,16 @@ pub(crate) enum DisplayColor {
 	DiffChangeColor,
 	/// The color for removed lines in a diff.
 	DiffRemoveColor,
+	/// The color for the markers of hunk headers in a diff.
+	DiffHunkColor,
 	/// The color for context lines in a diff.
 	DiffContextColor,
 	/// The color for whitespace characters in a diff.
 	DiffWhitespaceColor,
 	/// The color for indicator text.
 	IndicatorColor,
+	/// The color for text matching the search term.
+	SearchMatchColor,
 	/// The color for the standard text.
 	Normal,
 }
@@ -75,6 +75,65 @@ pub(super) fn register_selectable_color_pairs(
 	}
 }
 
+// The standard 16 colors, with the RGB values used by xterm
+const FOUR_BIT_PALETTE: [(CrosstermColor, (u8, u8, u8)); 16] = [
+	(CrosstermColor::Black, (0, 0, 0)),
+	(CrosstermColor::DarkRed, (205, 0, 0)),
+	(CrosstermColor::DarkGreen, (0, 205, 0)),
+	(CrosstermColor::DarkYellow, (205, 205, 0)),
+	(CrosstermColor::DarkBlue, (0, 0, 238)),
+	(CrosstermColor::DarkMagenta, (205, 0, 205)),
+	(CrosstermColor::DarkCyan, (0, 205, 205)),
+	(CrosstermColor::Grey, (229, 229, 229)),
+	(CrosstermColor::DarkGrey, (127, 127, 127)),
+	(CrosstermColor::Red, (255, 0, 0)),
+	(CrosstermColor::Green, (0, 255, 0)),
+	(CrosstermColor::Yellow, (255, 255, 0)),
+	(CrosstermColor::Blue, (92, 92, 255)),
+	(CrosstermColor::Magenta, (255, 0, 255)),
+	(CrosstermColor::Cyan, (0, 255, 255)),
+	(CrosstermColor::White, (255, 255, 255)),
+];
+
+/// Get the RGB value of a color in the 256 color ANSI palette.
+#[expect(
+	clippy::integer_division,
+	reason = "This contains low level math, the depends on integer division"
+)]
+fn ansi_rgb(index: u8) -> (u8, u8, u8) {
+	match index {
+		0..=15 => FOUR_BIT_PALETTE[usize::from(index)].1,
+		16..=231 => {
+			// the 6x6x6 color cube, where each level after the first is 55 + 40 * level
+			let level = |value: u8| if value == 0 { 0 } else { 55 + 40 * value };
+			let cube_index = index - 16;
+			(level(cube_index / 36), level(cube_index / 6 % 6), level(cube_index % 6))
+		},
+		_ => {
+			let shade = 8 + 10 * (index - 232);
+			(shade, shade, shade)
+		},
+	}
+}
+
+/// Find the standard color that is closest to a RGB color.
+fn nearest_four_bit_color(red: u8, green: u8, blue: u8) -> CrosstermColor {
+	let distance = |(r, g, b): (u8, u8, u8)| {
+		let dr = i32::from(red) - i32::from(r);
+		let dg = i32::from(green) - i32::from(g);
+		let db = i32::from(blue) - i32::from(b);
+		dr * dr + dg * dg + db * db
+	};
+	// black is usually the background color, so only nearly black colors use it, to keep dark colors, such as
+	// the selected background, visible
+	let is_nearly_black = red.max(green).max(blue) < 48;
+	FOUR_BIT_PALETTE
+		.iter()
+		.filter(|&&(color, _)| is_nearly_black || color != CrosstermColor::Black)
+		.min_by_key(|&&(_, rgb)| distance(rgb))
+		.map_or(CrosstermColor::Reset, |&(color, _)| color)
+}
+
 // Modified version from gyscos/cursive (https://github.com/gyscos/cursive)
 // Copyright (c) 2015 Alexandre Bury - MIT License
 #[expect(
@@ -162,9 +221,19 @@ fn find_color(color_mode: ColorMode, color: Color) -> CrosstermColor {
 				CrosstermColor::Grey
 			}
 		},
-		// for indexed colored we assume 8bit color
-		Color::Index(i) => CrosstermColor::AnsiValue(i),
+		Color::Index(i) if color_mode == ColorMode::EightBit || color_mode.has_true_color() => {
+			CrosstermColor::AnsiValue(i)
+		},
+		Color::Index(i) if color_mode == ColorMode::FourBit && i < 16 => CrosstermColor::AnsiValue(i),
+		// the bright half of the 16 standard colors is not available with 3 bit color
+		Color::Index(i) if i < 8 => CrosstermColor::AnsiValue(i),
+		Color::Index(i) if i < 16 => CrosstermColor::AnsiValue(i - 8),
+		Color::Index(i) => {
+			let (red, green, blue) = ansi_rgb(i);
+			find_color(color_mode, Color::Rgb { red, green, blue })
+		},
 		Color::Rgb { red, green, blue } if color_mode.has_true_color() => CrosstermColor::from((red, green, blue)),
+		Color::Rgb { red, green, blue } if color_mode == ColorMode::FourBit => nearest_four_bit_color(red, green, blue),
 		Color::Rgb { red, green, blue } if color_mode.has_minimum_four_bit_color() => {
 			// If red, green and blue are equal then we assume a grey scale color
 			// shades less than 8 should go to pure black, while shades greater than 247 should go to pure white
@@ -638,14 +707,46 @@ mod tests {
 	#[case::sample(0, 0, 127, 18)]
 	#[case::sample(127, 0, 127, 90)]
 	#[case::sample(255, 95, 0, 208)]
-	fn find_color_four_bit_rgb(#[case] red: u8, #[case] green: u8, #[case] blue: u8, #[case] expected_index: u8) {
+	fn find_color_eight_bit_rgb(#[case] red: u8, #[case] green: u8, #[case] blue: u8, #[case] expected_index: u8) {
 		let color = Color::Rgb { red, green, blue };
 		assert_eq!(
-			find_color(ColorMode::FourBit, color),
+			find_color(ColorMode::EightBit, color),
 			CrosstermColor::AnsiValue(expected_index)
 		);
 	}
 
+	#[rstest]
+	#[case::black(0, 0, 0, CrosstermColor::Black)]
+	#[case::dark_grey(100, 100, 100, CrosstermColor::DarkGrey)]
+	#[case::white(250, 250, 250, CrosstermColor::White)]
+	#[case::red(255, 10, 10, CrosstermColor::Red)]
+	#[case::dark_red(180, 20, 10, CrosstermColor::DarkRed)]
+	#[case::green(133, 153, 0, CrosstermColor::DarkYellow)]
+	#[case::blue(38, 139, 210, CrosstermColor::DarkCyan)]
+	#[case::magenta(211, 54, 130, CrosstermColor::DarkMagenta)]
+	fn find_color_four_bit_rgb(#[case] red: u8, #[case] green: u8, #[case] blue: u8, #[case] expected: CrosstermColor) {
+		assert_eq!(
+			find_color(ColorMode::FourBit, Color::Rgb { red, green, blue }),
+			expected
+		);
+	}
+
+	#[rstest]
+	#[case::two_tone(ColorMode::TwoTone, 196, CrosstermColor::Reset)]
+	#[case::three_bit_standard(ColorMode::ThreeBit, 3, CrosstermColor::AnsiValue(3))]
+	#[case::three_bit_bright(ColorMode::ThreeBit, 11, CrosstermColor::AnsiValue(3))]
+	#[case::three_bit_cube(ColorMode::ThreeBit, 196, CrosstermColor::AnsiValue(1))]
+	#[case::four_bit_standard(ColorMode::FourBit, 11, CrosstermColor::AnsiValue(11))]
+	#[case::four_bit_cube(ColorMode::FourBit, 196, CrosstermColor::Red)]
+	#[case::four_bit_grayscale(ColorMode::FourBit, 237, CrosstermColor::DarkGrey)]
+	#[case::four_bit_black(ColorMode::FourBit, 233, CrosstermColor::Black)]
+	#[case::four_bit_light_grayscale(ColorMode::FourBit, 250, CrosstermColor::Grey)]
+	#[case::eight_bit(ColorMode::EightBit, 196, CrosstermColor::AnsiValue(196))]
+	#[case::true_color(ColorMode::TrueColor, 196, CrosstermColor::AnsiValue(196))]
+	fn find_color_index(#[case] color_mode: ColorMode, #[case] index: u8, #[case] expected: CrosstermColor) {
+		assert_eq!(find_color(color_mode, Color::Index(index)), expected);
+	}
+
 	#[rstest]
 	#[case::dark_black(Color::DarkBlack, CrosstermColor::Black)]
 	#[case::dark_blue(Color::DarkBlue, CrosstermColor::DarkBlue)]
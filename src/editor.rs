@@ -1,3 +1,7 @@
+use std::env;
+
+use chrono::DateTime;
+
 #[cfg(not(test))]
 use crate::display::CrossTerm;
 #[cfg(test)]
@@ -6,21 +10,83 @@ use crate::{
 	application::Application,
 	arguments::Args,
 	exit::Exit,
+	git::open_repository_from_env,
 	input::read_event,
 	module::{ExitStatus, Modules},
+	summary::{SUMMARY_JSON_ENV, write_summary},
+	todo_file::{CommitMetadata, Line, TodoFile, TodoFileOptions},
 };
 
+fn load_commit_metadata(repository: &git2::Repository, line: &Line) -> Option<CommitMetadata> {
+	if !line.has_reference() {
+		return None;
+	}
+	let commit = repository
+		.revparse_single(line.get_hash())
+		.ok()?
+		.peel_to_commit()
+		.ok()?;
+	let author = commit.author();
+	Some(CommitMetadata {
+		hash: commit.id().to_string(),
+		author_name: author.name().map(String::from),
+		author_email: author.email().map(String::from),
+		author_date: DateTime::from_timestamp(author.when().seconds(), 0).map(|date| date.to_rfc3339()),
+		summary: commit.summary().map(String::from),
+	})
+}
+
+/// Write the JSON export of the todo file, including commit metadata when the repository can be opened.
+fn write_json_export(path: &str, todo_file: &TodoFile) -> Result<(), Exit> {
+	let repository = open_repository_from_env().ok();
+	todo_file
+		.write_json_file(path, |line| load_commit_metadata(repository.as_ref()?, line))
+		.map_err(|err| Exit::new(ExitStatus::FileWriteError, err.to_string().as_str()))
+}
+
+/// Write the summary of the changes, when a path is provided, returning the exit of the summary when it fails and
+/// the run was otherwise successful.
+fn write_summary_for_exit(args: &Args, todo_file: &TodoFile, exit: Exit) -> Exit {
+	let summary_json_path = args
+		.summary_json_path()
+		.map(String::from)
+		.or_else(|| env::var(SUMMARY_JSON_ENV).ok());
+	if let Some(path) = summary_json_path {
+		if let Err(summary_exit) = write_summary(path.as_str(), todo_file, *exit.get_status()) {
+			// an error from the application takes precedence over the summary error
+			if exit.get_status() == &ExitStatus::Good {
+				return summary_exit;
+			}
+		}
+	}
+	exit
+}
+
 #[cfg(not(tarpaulin_include))]
 pub(crate) fn run(args: &Args) -> Exit {
 	let mut application: Application<Modules> = match Application::new(args, read_event, CrossTerm::new()) {
 		Ok(app) => app,
-		Err(exit) => return exit,
+		Err(exit) => {
+			// the todo file was not changed, so the summary has no lines
+			let todo_file = TodoFile::new(args.todo_file_path().unwrap_or_default(), TodoFileOptions::new(1, "#"));
+			return write_summary_for_exit(args, &todo_file, exit);
+		},
 	};
 
-	match application.run_until_finished() {
+	let exit = match application.run_until_finished() {
 		Ok(..) => Exit::from(ExitStatus::Good),
 		Err(exit) => exit,
+	};
+
+	if let Some(path) = args.export_json_path() {
+		if let Err(export_exit) = write_json_export(path, &application.todo_file().lock()) {
+			if exit.get_status() == &ExitStatus::Good {
+				return export_exit;
+			}
+		}
 	}
+
+	write_summary_for_exit(args, &application.todo_file().lock(), exit)
 }
 
 #[cfg(test)]
@@ -45,6 +111,37 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn write_json_export_with_metadata() {
+		with_git_directory("fixtures/simple", |_| {
+			let directory = tempfile::tempdir().unwrap();
+			let export_path = directory.path().join("export.json");
+			let mut todo_file = TodoFile::new("git-rebase-todo", TodoFileOptions::new(1, "#"));
+			todo_file.set_lines(vec![
+				Line::parse("pick aed0fd1 c1").unwrap(),
+				Line::parse("exec make").unwrap(),
+			]);
+			write_json_export(export_path.to_str().unwrap(), &todo_file).unwrap();
+			let export = std::fs::read_to_string(export_path).unwrap();
+			assert!(export.contains(
+				"\"commit\": {\n        \"hash\": \"aed0fd1db3e73c0e568677ae8903a11c5fbc5659\",\n        \
+				 \"authorName\": \"Tim Oram\",\n        \"authorEmail\": \"dev@mitmaro.ca\",\n        \"authorDate\": \
+				 \"2020-01-28T01:28:53+00:00\",\n        \"summary\": \"File status - moved\"\n      }"
+			));
+			assert_eq!(export.matches("\"commit\"").count(), 1);
+		});
+	}
+
+	#[test]
+	fn write_json_export_error() {
+		with_git_directory("fixtures/simple", |_| {
+			let directory = tempfile::tempdir().unwrap();
+			let todo_file = TodoFile::new("git-rebase-todo", TodoFileOptions::new(1, "#"));
+			let exit = write_json_export(directory.path().to_str().unwrap(), &todo_file).unwrap_err();
+			assert_eq!(exit.get_status(), &ExitStatus::FileWriteError);
+		});
+	}
+
 	#[test]
 	fn error_on_application_create() {
 		with_git_directory("fixtures/simple", |path| {
@@ -55,4 +152,22 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn error_on_application_create_writes_summary() {
+		with_git_directory("fixtures/invalid-config", |path| {
+			let directory = tempfile::tempdir().unwrap();
+			let summary_path = directory.path().join("summary.json");
+			let todo_file = Path::new(path).join("rebase-todo");
+			let exit = run(&args(&[
+				"--summary-json",
+				summary_path.to_str().unwrap(),
+				todo_file.to_str().unwrap(),
+			]));
+			assert_eq!(exit.get_status(), &ExitStatus::ConfigError);
+			let summary = std::fs::read_to_string(summary_path).unwrap();
+			assert!(summary.contains("\"exitStatus\": \"configError\""));
+			assert!(summary.contains("\"aborted\": true"));
+		});
+	}
 }
@@ -5,15 +5,26 @@ Git Interactive Rebase Editor ({{VERSION}})
 Full feature terminal based sequence editor for git interactive rebase.
 
 USAGE:
-  interactive-rebase-tool [FLAGS] [REBASE-TODO-FILE]
+  interactive-rebase-tool [FLAGS] [OPTIONS] [REBASE-TODO-FILE]
+  interactive-rebase-tool rebase [--rebase-merges] [--autosquash] <UPSTREAM>
 
 FLAGS:
-  -v, --version       Prints versioning information
-  -h, --help          Prints help information
-  --license           Prints Open Source Software licensing
+  -v, --version         Prints versioning information
+  -h, --help            Prints help information
+  --license             Prints Open Source Software licensing
+  --print-keybindings   Prints the effective key bindings of each view
+  --check               Checks the todo file for problems, without the interface
+  --commit-message      Edits the file as a commit message, the default for COMMIT_EDITMSG and MERGE_MSG
+
+OPTIONS:
+  --script <FILE>       Applies the commands in the script file to the todo file, without the interface
+  --import-json <FILE>  Loads the todo lines from a JSON file, in place of the todo file
+  --export-json <FILE>  Writes the edited todo lines to a JSON file on exit
+  --summary-json <FILE> Writes a JSON summary of the changes made to the todo file on exit
 
 ARGS:
-  <REBASE-TODO-FILE>  The path to the Git rebase todo file
+  <REBASE-TODO-FILE>    The path to the Git rebase todo file
+  <UPSTREAM>            The upstream branch, or commit, to rebase the current branch onto
 "#;
 
 pub(crate) fn build_help(message: Option<String>) -> String {
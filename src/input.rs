@@ -14,11 +14,12 @@ mod event_provider;
 mod input_options;
 mod key_bindings;
 mod key_event;
+mod key_sequence;
 mod map_keybindings;
 mod standard_event;
 mod thread;
 
-pub(crate) use crossterm::event::{KeyCode, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind};
+pub(crate) use crossterm::event::{KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
 pub(crate) use self::{
 	event::Event,
@@ -27,6 +28,7 @@ pub(crate) use self::{
 	input_options::InputOptions,
 	key_bindings::KeyBindings,
 	key_event::KeyEvent,
+	key_sequence::KeySequence,
 	map_keybindings::map_keybindings,
 	standard_event::StandardEvent,
 	thread::{State, THREAD_NAME, Thread},
@@ -1,10 +1,12 @@
-use crate::input::{KeyCode, KeyEvent, MouseEvent, StandardEvent};
+use crate::input::{KeyCode, KeyEvent, KeySequence, MouseEvent, StandardEvent};
 
 /// An event, either from an input device, system change or action event.
 #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy)]
 pub(crate) enum Event {
 	/// A keyboard event.
 	Key(KeyEvent),
+	/// A completed sequence of keyboard events.
+	KeySequence(KeySequence),
 	/// An action event.
 	Standard(StandardEvent),
 	/// A mouse event.
@@ -35,6 +37,12 @@ impl From<KeyEvent> for Event {
 	}
 }
 
+impl From<KeySequence> for Event {
+	fn from(key_sequence: KeySequence) -> Self {
+		Self::KeySequence(key_sequence)
+	}
+}
+
 impl From<MouseEvent> for Event {
 	fn from(mouse_event: MouseEvent) -> Self {
 		Self::Mouse(mouse_event)
@@ -75,6 +83,13 @@ mod tests {
 		assert_eq!(event, Event::Key(key_event));
 	}
 
+	#[test]
+	fn from_key_sequence() {
+		let key_sequence = KeySequence::new(&[KeyEvent::from(KeyCode::Char('g')), KeyEvent::from(KeyCode::Char('g'))]);
+		let event = Event::from(key_sequence);
+		assert_eq!(event, Event::KeySequence(key_sequence));
+	}
+
 	#[test]
 	fn from_crossterm_key_event() {
 		let event = Event::from(ct_event::Event::Key(ct_event::KeyEvent::new(
@@ -1,22 +1,56 @@
-use crate::input::{Event, InputOptions, KeyBindings, KeyCode, KeyEvent, KeyModifiers, StandardEvent};
+use std::{
+	collections::VecDeque,
+	mem,
+	time::{Duration, Instant},
+};
+
+use crate::input::{Event, InputOptions, KeyBindings, KeyCode, KeyEvent, KeyModifiers, KeySequence, StandardEvent};
+
+const KEY_SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
 
 /// A handler for reading and processing events.
 #[derive(Debug)]
 pub(crate) struct EventHandler {
 	key_bindings: KeyBindings,
+	key_sequences: Vec<KeySequence>,
+	key_sequence_timeout: Duration,
+	pending_keys: Vec<KeyEvent>,
+	pending_keys_time: Instant,
+	queued_events: VecDeque<Event>,
 }
 
 impl EventHandler {
 	/// Create a new instance of the `EventHandler`.
 	#[must_use]
-	pub(crate) const fn new(key_bindings: KeyBindings) -> Self {
-		Self { key_bindings }
+	pub(crate) fn new(key_bindings: KeyBindings) -> Self {
+		Self {
+			key_sequences: key_bindings.key_sequences(),
+			key_bindings,
+			key_sequence_timeout: KEY_SEQUENCE_TIMEOUT,
+			pending_keys: vec![],
+			pending_keys_time: Instant::now(),
+			queued_events: VecDeque::new(),
+		}
+	}
+
+	/// Get the keys of a key sequence that has been started, but not yet completed.
+	#[must_use]
+	pub(crate) fn pending_keys(&self) -> String {
+		self.pending_keys.iter().map(|key| key.code.to_string()).collect()
+	}
+
+	/// Are there events, from a key sequence that was not completed, waiting to be read.
+	#[must_use]
+	pub(crate) fn has_queued_events(&self) -> bool {
+		!self.queued_events.is_empty()
 	}
 
 	/// Read and handle an event.
 	#[expect(clippy::trivially_copy_pass_by_ref, reason = "Legacy, needs refactor.")]
-	pub(crate) fn read_event<F>(&self, event: Event, input_options: &InputOptions, callback: F) -> Event
+	pub(crate) fn read_event<F>(&mut self, event: Event, input_options: &InputOptions, callback: F) -> Event
 	where F: FnOnce(Event, &KeyBindings) -> Event {
+		let event = self.read_key_sequence(event, input_options.contains(InputOptions::KEY_SEQUENCES));
+
 		if event == Event::None {
 			return event;
 		}
@@ -61,6 +95,63 @@ impl EventHandler {
 		callback(event, &self.key_bindings)
 	}
 
+	fn read_key_sequence(&mut self, event: Event, key_sequences_enabled: bool) -> Event {
+		if event != Event::None {
+			self.queued_events.push_back(event);
+		}
+
+		while let Some(queued_event) = self.queued_events.pop_front() {
+			let Event::Key(key) = queued_event
+			else {
+				return queued_event;
+			};
+
+			if !key_sequences_enabled {
+				if self.pending_keys.is_empty() {
+					return queued_event;
+				}
+				self.queued_events.push_front(queued_event);
+				return self.replay_pending_keys();
+			}
+
+			self.pending_keys.push(key);
+			if let Some(key_sequence) = self
+				.key_sequences
+				.iter()
+				.find(|key_sequence| key_sequence.keys() == self.pending_keys.as_slice())
+			{
+				self.pending_keys.clear();
+				return Event::KeySequence(*key_sequence);
+			}
+
+			if self
+				.key_sequences
+				.iter()
+				.any(|key_sequence| key_sequence.is_prefixed_by(self.pending_keys.as_slice()))
+			{
+				self.pending_keys_time = Instant::now();
+				continue;
+			}
+
+			return self.replay_pending_keys();
+		}
+
+		if !self.pending_keys.is_empty() && self.pending_keys_time.elapsed() >= self.key_sequence_timeout {
+			return self.replay_pending_keys();
+		}
+		Event::None
+	}
+
+	// the first pending key is read as a single key, and the remaining keys are queued to be read again
+	fn replay_pending_keys(&mut self) -> Event {
+		let mut keys = mem::take(&mut self.pending_keys).into_iter();
+		let first_key = keys.next();
+		for key in keys.rev() {
+			self.queued_events.push_front(Event::Key(key));
+		}
+		first_key.map_or(Event::None, Event::Key)
+	}
+
 	#[expect(clippy::wildcard_enum_match_arm, reason = "Pattern makes sense in this case")]
 	fn handle_standard_inputs(event: Event) -> Option<Event> {
 		match event {
@@ -177,7 +268,7 @@ mod tests {
 	}), false)]
 	#[case::other(Event::from('a'), false)]
 	fn read_event_options_disabled(#[case] event: Event, #[case] handled: bool) {
-		let event_handler = EventHandler::new(create_test_keybindings());
+		let mut event_handler = EventHandler::new(create_test_keybindings());
 		let result = event_handler.read_event(event, &InputOptions::empty(), |_, _| Event::from(KeyCode::Null));
 
 		if handled {
@@ -201,7 +292,7 @@ mod tests {
 	}), true)]
 	#[case::other(Event::from('a'), false)]
 	fn read_event_enabled(#[case] event: Event, #[case] handled: bool) {
-		let event_handler = EventHandler::new(create_test_keybindings());
+		let mut event_handler = EventHandler::new(create_test_keybindings());
 		let result = event_handler.read_event(event, &InputOptions::all(), |_, _| Event::from(KeyCode::Null));
 
 		if handled {
@@ -214,7 +305,7 @@ mod tests {
 
 	#[test]
 	fn none_event() {
-		let event_handler = EventHandler::new(create_test_keybindings());
+		let mut event_handler = EventHandler::new(create_test_keybindings());
 		let result = event_handler.read_event(Event::None, &InputOptions::empty(), |_, _| Event::from(KeyCode::Null));
 		assert_eq!(result, Event::None);
 	}
@@ -226,7 +317,7 @@ mod tests {
 	}), Event::from(StandardEvent::Kill))]
 	#[case::other(Event::from('a'), Event::from(KeyCode::Null))]
 	fn standard_inputs(#[case] event: Event, #[case] expected: Event) {
-		let event_handler = EventHandler::new(create_test_keybindings());
+		let mut event_handler = EventHandler::new(create_test_keybindings());
 		let result = event_handler.read_event(event, &InputOptions::empty(), |_, _| Event::from(KeyCode::Null));
 		assert_eq!(result, expected);
 	}
@@ -242,7 +333,7 @@ mod tests {
 	#[case::standard(Event::from(KeyCode::End), Event::from(StandardEvent::ScrollBottom))]
 	#[case::other(Event::from('a'), Event::from(KeyCode::Null))]
 	fn movement_inputs(#[case] event: Event, #[case] expected: Event) {
-		let event_handler = EventHandler::new(create_test_keybindings());
+		let mut event_handler = EventHandler::new(create_test_keybindings());
 		let result = event_handler.read_event(event, &InputOptions::MOVEMENT, |_, _| Event::from(KeyCode::Null));
 		assert_eq!(result, expected);
 	}
@@ -267,7 +358,7 @@ mod tests {
 		bindings.scroll_up = map_keybindings(&[String::from("x")]);
 		bindings.scroll_step_down = map_keybindings(&[String::from("x")]);
 		bindings.scroll_step_up = map_keybindings(&[String::from("x")]);
-		let event_handler = EventHandler::new(bindings);
+		let mut event_handler = EventHandler::new(bindings);
 		let result = event_handler.read_event(event, &InputOptions::MOVEMENT, |_, _| Event::from(KeyCode::Null));
 		assert_eq!(result, expected);
 	}
@@ -280,7 +371,7 @@ mod tests {
 	#[case::enter(Event::from(KeyCode::Enter), Event::from(StandardEvent::SearchFinish))]
 	#[case::other(Event::from('a'), Event::from(KeyCode::Null))]
 	fn search_inputs(#[case] event: Event, #[case] expected: Event) {
-		let event_handler = EventHandler::new(create_test_keybindings());
+		let mut event_handler = EventHandler::new(create_test_keybindings());
 		let result = event_handler.read_event(event, &InputOptions::SEARCH, |_, _| Event::from(KeyCode::Null));
 		assert_eq!(result, expected);
 	}
@@ -289,14 +380,14 @@ mod tests {
 	#[case::search_start(Event::from('/'), Event::from(StandardEvent::SearchStart))]
 	#[case::other(Event::from('a'), Event::from(KeyCode::Null))]
 	fn search_start(#[case] event: Event, #[case] expected: Event) {
-		let event_handler = EventHandler::new(create_test_keybindings());
+		let mut event_handler = EventHandler::new(create_test_keybindings());
 		let result = event_handler.read_event(event, &InputOptions::SEARCH_START, |_, _| Event::from(KeyCode::Null));
 		assert_eq!(result, expected);
 	}
 
 	#[test]
 	fn help_event() {
-		let event_handler = EventHandler::new(create_test_keybindings());
+		let mut event_handler = EventHandler::new(create_test_keybindings());
 		let result = event_handler.read_event(Event::from('?'), &InputOptions::HELP, |_, _| Event::from(KeyCode::Null));
 		assert_eq!(result, Event::from(StandardEvent::Help));
 	}
@@ -312,8 +403,145 @@ mod tests {
 	}), Event::from(StandardEvent::Redo))]
 	#[case::other(Event::from('a'), Event::from(KeyCode::Null))]
 	fn undo_redo_inputs(#[case] event: Event, #[case] expected: Event) {
-		let event_handler = EventHandler::new(create_test_keybindings());
+		let mut event_handler = EventHandler::new(create_test_keybindings());
 		let result = event_handler.read_event(event, &InputOptions::UNDO_REDO, |_, _| Event::from(KeyCode::Null));
 		assert_eq!(result, expected);
 	}
+
+	fn create_key_sequence_event_handler() -> EventHandler {
+		let mut bindings = create_test_keybindings();
+		bindings.scroll_home = map_keybindings(&[String::from("g g")]);
+		bindings.scroll_end = map_keybindings(&[String::from("g e x")]);
+		EventHandler::new(bindings)
+	}
+
+	#[test]
+	fn key_sequence_complete() {
+		let mut event_handler = create_key_sequence_event_handler();
+		let options = InputOptions::MOVEMENT | InputOptions::KEY_SEQUENCES;
+		assert_eq!(
+			event_handler.read_event(Event::from('g'), &options, |e, _| e),
+			Event::None
+		);
+		assert_eq!(event_handler.pending_keys(), "g");
+		assert_eq!(
+			event_handler.read_event(Event::from('g'), &options, |e, _| e),
+			Event::from(StandardEvent::ScrollTop)
+		);
+		assert_eq!(event_handler.pending_keys(), "");
+	}
+
+	#[test]
+	fn key_sequence_mismatch() {
+		let mut event_handler = create_key_sequence_event_handler();
+		let options = InputOptions::MOVEMENT | InputOptions::KEY_SEQUENCES;
+		assert_eq!(
+			event_handler.read_event(Event::from('g'), &options, |e, _| e),
+			Event::None
+		);
+		assert_eq!(
+			event_handler.read_event(Event::from('e'), &options, |e, _| e),
+			Event::None
+		);
+		assert_eq!(event_handler.pending_keys(), "ge");
+		assert_eq!(
+			event_handler.read_event(Event::from('a'), &options, |e, _| e),
+			Event::from('g')
+		);
+		assert!(event_handler.has_queued_events());
+		assert_eq!(
+			event_handler.read_event(Event::None, &options, |e, _| e),
+			Event::from('e')
+		);
+		assert_eq!(
+			event_handler.read_event(Event::None, &options, |e, _| e),
+			Event::from('a')
+		);
+		assert!(!event_handler.has_queued_events());
+		assert_eq!(event_handler.pending_keys(), "");
+	}
+
+	#[test]
+	fn key_sequence_mismatch_starts_new_sequence() {
+		let mut event_handler = create_key_sequence_event_handler();
+		let options = InputOptions::MOVEMENT | InputOptions::KEY_SEQUENCES;
+		assert_eq!(
+			event_handler.read_event(Event::from('g'), &options, |e, _| e),
+			Event::None
+		);
+		assert_eq!(
+			event_handler.read_event(Event::from('e'), &options, |e, _| e),
+			Event::None
+		);
+		assert_eq!(
+			event_handler.read_event(Event::from('g'), &options, |e, _| e),
+			Event::from('g')
+		);
+		assert_eq!(
+			event_handler.read_event(Event::None, &options, |e, _| e),
+			Event::from('e')
+		);
+		assert_eq!(event_handler.read_event(Event::None, &options, |e, _| e), Event::None);
+		assert_eq!(event_handler.pending_keys(), "g");
+	}
+
+	#[test]
+	fn key_sequence_timeout() {
+		let mut event_handler = create_key_sequence_event_handler();
+		let options = InputOptions::MOVEMENT | InputOptions::KEY_SEQUENCES;
+		assert_eq!(
+			event_handler.read_event(Event::from('g'), &options, |e, _| e),
+			Event::None
+		);
+		assert_eq!(event_handler.read_event(Event::None, &options, |e, _| e), Event::None);
+		event_handler.key_sequence_timeout = Duration::ZERO;
+		assert_eq!(
+			event_handler.read_event(Event::None, &options, |e, _| e),
+			Event::from('g')
+		);
+		assert_eq!(event_handler.pending_keys(), "");
+	}
+
+	#[test]
+	fn key_sequence_other_event_while_pending() {
+		let mut event_handler = create_key_sequence_event_handler();
+		let options = InputOptions::RESIZE | InputOptions::MOVEMENT | InputOptions::KEY_SEQUENCES;
+		assert_eq!(
+			event_handler.read_event(Event::from('g'), &options, |e, _| e),
+			Event::None
+		);
+		assert_eq!(
+			event_handler.read_event(Event::Resize(10, 10), &options, |e, _| e),
+			Event::Resize(10, 10)
+		);
+		assert_eq!(event_handler.pending_keys(), "g");
+	}
+
+	#[test]
+	fn key_sequence_disabled() {
+		let mut event_handler = create_key_sequence_event_handler();
+		assert_eq!(
+			event_handler.read_event(Event::from('g'), &InputOptions::MOVEMENT, |e, _| e),
+			Event::from('g')
+		);
+		assert_eq!(event_handler.pending_keys(), "");
+	}
+
+	#[test]
+	fn key_sequence_disabled_while_pending() {
+		let mut event_handler = create_key_sequence_event_handler();
+		let options = InputOptions::MOVEMENT | InputOptions::KEY_SEQUENCES;
+		assert_eq!(
+			event_handler.read_event(Event::from('g'), &options, |e, _| e),
+			Event::None
+		);
+		assert_eq!(
+			event_handler.read_event(Event::from('a'), &InputOptions::MOVEMENT, |e, _| e),
+			Event::from('g')
+		);
+		assert_eq!(
+			event_handler.read_event(Event::None, &InputOptions::MOVEMENT, |e, _| e),
+			Event::from('a')
+		);
+	}
 }
@@ -31,7 +31,12 @@ pub(crate) fn read_event() -> Result<Option<Event>> {
 						..
 					})
 					| Event::Mouse(MouseEvent {
-						kind: MouseEventKind::Down(_) | MouseEventKind::ScrollDown | MouseEventKind::ScrollUp,
+						kind:
+							MouseEventKind::Down(_)
+							| MouseEventKind::Drag(_)
+							| MouseEventKind::Up(_)
+							| MouseEventKind::ScrollDown
+							| MouseEventKind::ScrollUp,
 						..
 					})
 					| Event::Resize(..)) => Some(e),
@@ -229,6 +234,60 @@ mod tests {
 		);
 	}
 
+	#[test]
+	#[serial_test::serial]
+	fn read_event_read_mouse_drag() {
+		let mut lock = read_event_mocks::NEXT_EVENT.lock();
+		*lock = Ok(Event::Mouse(MouseEvent {
+			kind: MouseEventKind::Drag(MouseButton::Left),
+			column: 0,
+			row: 0,
+			modifiers: KeyModifiers::NONE,
+		}));
+		drop(lock);
+
+		let mut lock = read_event_mocks::HAS_POLLED_EVENT.lock();
+		*lock = Ok(true);
+		drop(lock);
+
+		assert_eq!(
+			read_event().unwrap(),
+			Some(Event::Mouse(MouseEvent {
+				kind: MouseEventKind::Drag(MouseButton::Left),
+				column: 0,
+				row: 0,
+				modifiers: KeyModifiers::NONE
+			}))
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn read_event_read_mouse_up() {
+		let mut lock = read_event_mocks::NEXT_EVENT.lock();
+		*lock = Ok(Event::Mouse(MouseEvent {
+			kind: MouseEventKind::Up(MouseButton::Left),
+			column: 0,
+			row: 0,
+			modifiers: KeyModifiers::NONE,
+		}));
+		drop(lock);
+
+		let mut lock = read_event_mocks::HAS_POLLED_EVENT.lock();
+		*lock = Ok(true);
+		drop(lock);
+
+		assert_eq!(
+			read_event().unwrap(),
+			Some(Event::Mouse(MouseEvent {
+				kind: MouseEventKind::Up(MouseButton::Left),
+				column: 0,
+				row: 0,
+				modifiers: KeyModifiers::NONE
+			}))
+		);
+	}
+
 	#[test]
 	#[serial_test::serial]
 	fn read_event_read_resize() {
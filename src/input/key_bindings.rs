@@ -1,4 +1,4 @@
-use crate::input::{Event, map_keybindings};
+use crate::input::{Event, KeySequence, map_keybindings};
 
 /// Represents a mapping between an input event and an action.
 #[derive(Debug)]
@@ -48,12 +48,24 @@ pub(crate) struct KeyBindings {
 	pub(crate) action_fixup: Vec<Event>,
 	/// Key bindings for the pick action.
 	pub(crate) action_pick: Vec<Event>,
+	/// Key bindings for the revert action.
+	pub(crate) action_revert: Vec<Event>,
 	/// Key bindings for the reword action.
 	pub(crate) action_reword: Vec<Event>,
 	/// Key bindings for the squash action.
 	pub(crate) action_squash: Vec<Event>,
 	/// Key bindings for positive confirmation.
 	pub(crate) confirm_yes: Vec<Event>,
+	/// Key bindings for aborting the commit in the commit message editor.
+	pub(crate) commit_message_abort: Vec<Event>,
+	/// Key bindings for showing the help in the commit message editor.
+	pub(crate) commit_message_help: Vec<Event>,
+	/// Key bindings for previewing the message in the commit message editor.
+	pub(crate) commit_message_preview: Vec<Event>,
+	/// Key bindings for saving the message in the commit message editor.
+	pub(crate) commit_message_save: Vec<Event>,
+	/// Key bindings for adding a trailer in the commit message editor.
+	pub(crate) commit_message_trailer: Vec<Event>,
 	/// Key bindings for editing.
 	pub(crate) edit: Vec<Event>,
 	/// Key bindings for forcing an abort.
@@ -88,18 +100,26 @@ pub(crate) struct KeyBindings {
 	pub(crate) open_in_external_editor: Vec<Event>,
 	/// Key bindings for rebasing.
 	pub(crate) rebase: Vec<Event>,
+	/// Key bindings for reloading the configuration.
+	pub(crate) reload_config: Vec<Event>,
 	/// Key bindings for removing a line.
 	pub(crate) remove_line: Vec<Event>,
 	/// Key bindings for showing a commit.
 	pub(crate) show_commit: Vec<Event>,
 	/// Key bindings for showing a diff.
 	pub(crate) show_diff: Vec<Event>,
+	/// Key bindings for showing the range diff.
+	pub(crate) show_range_diff: Vec<Event>,
+	/// Key bindings for toggling the excluded files in a diff.
+	pub(crate) toggle_excluded_files: Vec<Event>,
 	/// Key bindings for toggling visual mode.
 	pub(crate) toggle_visual_mode: Vec<Event>,
 	/// Key bindings for the fixup specific action to toggle the c option.
 	pub(crate) fixup_keep_message: Vec<Event>,
 	/// Key biding for the fixup specific action to toggle the C option.
 	pub(crate) fixup_keep_message_with_editor: Vec<Event>,
+	/// Key bindings for the user defined commands, in the order of the commands.
+	pub(crate) custom_commands: Vec<Vec<Event>>,
 }
 
 impl KeyBindings {
@@ -127,6 +147,7 @@ impl KeyBindings {
 			action_edit: map_keybindings(&key_bindings.action_edit),
 			action_fixup: map_keybindings(&key_bindings.action_fixup),
 			action_pick: map_keybindings(&key_bindings.action_pick),
+			action_revert: map_keybindings(&key_bindings.action_revert),
 			action_reword: map_keybindings(&key_bindings.action_reword),
 			action_squash: map_keybindings(&key_bindings.action_squash),
 			edit: map_keybindings(&key_bindings.edit),
@@ -146,15 +167,97 @@ impl KeyBindings {
 			move_up_step: map_keybindings(&key_bindings.move_up_step),
 			open_in_external_editor: map_keybindings(&key_bindings.open_in_external_editor),
 			rebase: map_keybindings(&key_bindings.rebase),
+			reload_config: map_keybindings(&key_bindings.reload_config),
 			remove_line: map_keybindings(&key_bindings.remove_line),
 			show_commit: map_keybindings(&key_bindings.show_commit),
 			show_diff: map_keybindings(&key_bindings.show_diff),
+			show_range_diff: map_keybindings(&key_bindings.show_range_diff),
+			toggle_excluded_files: map_keybindings(&key_bindings.toggle_excluded_files),
 			toggle_visual_mode: map_keybindings(&key_bindings.toggle_visual_mode),
 			confirm_yes: map_keybindings(&key_bindings.confirm_yes),
+			commit_message_abort: map_keybindings(&key_bindings.commit_message_abort),
+			commit_message_help: map_keybindings(&key_bindings.commit_message_help),
+			commit_message_preview: map_keybindings(&key_bindings.commit_message_preview),
+			commit_message_save: map_keybindings(&key_bindings.commit_message_save),
+			commit_message_trailer: map_keybindings(&key_bindings.commit_message_trailer),
 			fixup_keep_message: map_keybindings(&key_bindings.fixup_keep_message),
 			fixup_keep_message_with_editor: map_keybindings(&key_bindings.fixup_keep_message_with_editor),
+			custom_commands: key_bindings
+				.custom_commands
+				.iter()
+				.map(|custom_command| map_keybindings(&custom_command.key))
+				.collect(),
 		}
 	}
+
+	/// Get the key sequences of all key bindings.
+	#[must_use]
+	pub(crate) fn key_sequences(&self) -> Vec<KeySequence> {
+		[
+			&self.redo,
+			&self.undo,
+			&self.scroll_down,
+			&self.scroll_end,
+			&self.scroll_home,
+			&self.scroll_left,
+			&self.scroll_right,
+			&self.scroll_up,
+			&self.scroll_step_down,
+			&self.scroll_step_up,
+			&self.help,
+			&self.search_start,
+			&self.search_next,
+			&self.search_previous,
+			&self.abort,
+			&self.action_break,
+			&self.action_drop,
+			&self.action_edit,
+			&self.action_fixup,
+			&self.action_pick,
+			&self.action_revert,
+			&self.action_reword,
+			&self.action_squash,
+			&self.confirm_yes,
+			&self.edit,
+			&self.force_abort,
+			&self.force_rebase,
+			&self.insert_line,
+			&self.duplicate_line,
+			&self.move_down,
+			&self.move_down_step,
+			&self.move_end,
+			&self.move_home,
+			&self.move_left,
+			&self.move_right,
+			&self.move_selection_down,
+			&self.move_selection_up,
+			&self.move_up,
+			&self.move_up_step,
+			&self.open_in_external_editor,
+			&self.rebase,
+			&self.reload_config,
+			&self.remove_line,
+			&self.show_commit,
+			&self.show_diff,
+			&self.show_range_diff,
+			&self.toggle_excluded_files,
+			&self.toggle_visual_mode,
+			&self.fixup_keep_message,
+			&self.fixup_keep_message_with_editor,
+		]
+		.into_iter()
+		.chain(&self.custom_commands)
+		.flatten()
+		.filter_map(|event| {
+			if let Event::KeySequence(key_sequence) = *event {
+				Some(key_sequence)
+			}
+			else {
+				None
+			}
+		})
+		.collect()
+	}
 }
 
 #[cfg(test)]
@@ -163,7 +266,7 @@ mod tests {
 	use rstest::rstest;
 
 	use super::*;
-	use crate::input::KeyEvent;
+	use crate::{input::KeyEvent, test_helpers::create_test_keybindings};
 
 	#[test]
 	fn map_keybindings_with_modifiers() {
@@ -175,6 +278,29 @@ mod tests {
 		)]);
 	}
 
+	#[test]
+	fn map_keybindings_key_sequence() {
+		assert_eq!(map_keybindings(&[String::from("g Controlg")]), vec![
+			Event::KeySequence(KeySequence::new(&[
+				KeyEvent::from(KeyCode::Char('g')),
+				KeyEvent::new(KeyCode::Char('g'), KeyModifiers::CONTROL)
+			]))
+		]);
+	}
+
+	#[test]
+	fn key_sequences() {
+		let mut key_bindings = create_test_keybindings();
+		key_bindings.scroll_home = map_keybindings(&[String::from("Home"), String::from("g g")]);
+		key_bindings.action_drop = map_keybindings(&[String::from("d d")]);
+		key_bindings.custom_commands = vec![map_keybindings(&[String::from("t t")])];
+		assert_eq!(key_bindings.key_sequences(), vec![
+			KeySequence::new(&[KeyEvent::from(KeyCode::Char('g')), KeyEvent::from(KeyCode::Char('g'))]),
+			KeySequence::new(&[KeyEvent::from(KeyCode::Char('d')), KeyEvent::from(KeyCode::Char('d'))]),
+			KeySequence::new(&[KeyEvent::from(KeyCode::Char('t')), KeyEvent::from(KeyCode::Char('t'))]),
+		]);
+	}
+
 	#[rstest]
 	#[case::backspace("Backspace", KeyCode::Backspace)]
 	#[case::back_tab("BackTab", KeyCode::BackTab)]
@@ -0,0 +1,77 @@
+use crate::input::{KeyCode, KeyEvent};
+
+/// The maximum number of keys in a key sequence.
+const MAXIMUM_LENGTH: usize = 4;
+
+/// A sequence of keys, pressed one after another, that is bound as a single key binding.
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy)]
+pub(crate) struct KeySequence {
+	keys: [KeyEvent; MAXIMUM_LENGTH],
+	length: usize,
+}
+
+impl KeySequence {
+	/// Create a new instance from a list of keys. Keys past the maximum length of a sequence are ignored.
+	#[must_use]
+	pub(crate) fn new(keys: &[KeyEvent]) -> Self {
+		let mut sequence = [KeyEvent::from(KeyCode::Null); MAXIMUM_LENGTH];
+		for (slot, key) in sequence.iter_mut().zip(keys) {
+			*slot = *key;
+		}
+		Self {
+			keys: sequence,
+			length: keys.len().min(MAXIMUM_LENGTH),
+		}
+	}
+
+	/// Get the keys of the sequence.
+	#[must_use]
+	pub(crate) fn keys(&self) -> &[KeyEvent] {
+		&self.keys[..self.length]
+	}
+
+	/// Is the list of keys the start, but not the entirety, of this sequence.
+	#[must_use]
+	pub(crate) fn is_prefixed_by(&self, keys: &[KeyEvent]) -> bool {
+		keys.len() < self.length && self.keys().starts_with(keys)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn keys(chars: &str) -> Vec<KeyEvent> {
+		chars.chars().map(|c| KeyEvent::from(KeyCode::Char(c))).collect()
+	}
+
+	#[test]
+	fn new() {
+		assert_eq!(KeySequence::new(&keys("gg")).keys(), keys("gg").as_slice());
+	}
+
+	#[test]
+	fn new_too_long() {
+		assert_eq!(KeySequence::new(&keys("abcdef")).keys(), keys("abcd").as_slice());
+	}
+
+	#[test]
+	fn is_prefixed_by_start() {
+		assert!(KeySequence::new(&keys("abc")).is_prefixed_by(&keys("ab")));
+	}
+
+	#[test]
+	fn is_prefixed_by_full_sequence() {
+		assert!(!KeySequence::new(&keys("abc")).is_prefixed_by(&keys("abc")));
+	}
+
+	#[test]
+	fn is_prefixed_by_other() {
+		assert!(!KeySequence::new(&keys("abc")).is_prefixed_by(&keys("ac")));
+	}
+
+	#[test]
+	fn is_prefixed_by_empty() {
+		assert!(KeySequence::new(&keys("abc")).is_prefixed_by(&[]));
+	}
+}
@@ -57,6 +57,8 @@ pub(crate) enum StandardEvent {
 	ActionFixup,
 	/// The pick action meta event.
 	ActionPick,
+	/// The revert action meta event.
+	ActionRevert,
 	/// The reword action meta event.
 	ActionReword,
 	/// The squash action meta event.
@@ -83,24 +85,42 @@ pub(crate) enum StandardEvent {
 	Edit,
 	/// The open in editor meta event.
 	OpenInEditor,
+	/// The reload configuration meta event.
+	ReloadConfig,
 	/// The show commit meta event.
 	ShowCommit,
 	/// The show diff meta event.
 	ShowDiff,
+	/// The show range diff meta event.
+	ShowRangeDiff,
 	/// The swap selection down meta event.
 	SwapSelectedDown,
 	/// The swap selection up meta event.
 	SwapSelectedUp,
+	/// The toggle excluded files meta event.
+	ToggleExcludedFiles,
 	/// The toggle visual mode meta event.
 	ToggleVisualMode,
 	/// The insert line meta event.
 	InsertLine,
 	/// The duplicate line meta event.
 	DuplicateLine,
+	/// Run the user defined command at the index.
+	CustomCommand(usize),
+	/// Run the plugin command at the index.
+	PluginCommand(usize),
 	/// Fixup specific action to toggle the c option.
 	FixupKeepMessage,
 	/// Fixup specific action to toggle the C option.
 	FixupKeepMessageWithEditor,
+	/// The abort commit meta event, of the commit message editor.
+	CommitMessageAbort,
+	/// The preview commit message meta event, of the commit message editor.
+	CommitMessagePreview,
+	/// The save commit message meta event, of the commit message editor.
+	CommitMessageSave,
+	/// The add trailer meta event, of the commit message editor.
+	CommitMessageTrailer,
 	/// The no meta event.
 	No,
 	/// The yes meta event.
@@ -113,4 +133,6 @@ pub(crate) enum StandardEvent {
 	SearchUpdate,
 	/// Diff was updated
 	DiffUpdate,
+	/// A plugin sent an update.
+	PluginUpdate,
 }
@@ -40,6 +40,8 @@
 
 mod application;
 mod arguments;
+mod check;
+mod commit_message;
 mod components;
 mod config;
 mod diff;
@@ -52,20 +54,26 @@ mod input;
 mod license;
 mod module;
 mod modules;
+mod plugin;
+mod print_key_bindings;
 mod process;
+mod rebase;
 mod runtime;
+mod script;
 mod search;
+mod summary;
 #[cfg(test)]
 mod test_helpers;
 #[cfg(test)]
 mod tests;
-mod todo_file;
 mod util;
 mod version;
 mod view;
 
 use std::{env, ffi::OsString, process::Termination};
 
+use girt_todo_file as todo_file;
+
 use crate::{
 	arguments::{Args, Mode},
 	exit::Exit,
@@ -80,6 +88,11 @@ fn run(os_args: Vec<OsString>) -> Exit {
 				Mode::Help => help::run(),
 				Mode::Version => version::run(),
 				Mode::License => license::run(),
+				Mode::PrintKeyBindings => print_key_bindings::run(),
+				Mode::Script => script::run(&args),
+				Mode::Check => check::run(&args),
+				Mode::Rebase => rebase::run(&args),
+				Mode::CommitMessage => commit_message::run(&args),
 				Mode::Editor => editor::run(&args),
 			}
 		},
@@ -18,6 +18,7 @@ pub(crate) use self::{
 	state::State,
 };
 use crate::{
+	application::AppData,
 	input::{Event, InputOptions, KeyBindings},
 	process::Results,
 	view::{RenderContext, ViewData},
@@ -54,4 +55,7 @@ pub(crate) trait Module: Send {
 	fn handle_error(&mut self, _error: &Error) -> Results {
 		Results::new()
 	}
+
+	/// Update the module from a reloaded configuration, keeping the state of the module.
+	fn reload(&mut self, _app_data: &AppData) {}
 }
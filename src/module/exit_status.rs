@@ -10,6 +10,7 @@ pub(crate) enum ExitStatus {
 	Good,
 	StateError,
 	Kill,
+	CheckFailed,
 }
 
 impl ExitStatus {
@@ -22,6 +23,7 @@ impl ExitStatus {
 			Self::None | Self::Good => 0,
 			Self::StateError => 4,
 			Self::Kill => 6,
+			Self::CheckFailed => 7,
 		}
 	}
 }
@@ -47,6 +49,7 @@ mod tests {
 	#[case::good(ExitStatus::Good, 0)]
 	#[case::state_error(ExitStatus::StateError, 4)]
 	#[case::kill(ExitStatus::Kill, 6)]
+	#[case::check_failed(ExitStatus::CheckFailed, 7)]
 	fn to_code(#[case] input: ExitStatus, #[case] expected: u8) {
 		assert_eq!(ExitStatus::to_code(input), expected);
 	}
@@ -1,5 +1,6 @@
 use super::State;
 use crate::{
+	application::AppData,
 	input::{Event, EventHandler},
 	process::Results,
 	view::{RenderContext, ViewData},
@@ -18,6 +19,11 @@ impl<ModuleProvider: crate::module::ModuleProvider> ModuleHandler<ModuleProvider
 		}
 	}
 
+	pub(crate) fn reload(&mut self, event_handler: EventHandler, app_data: &AppData) {
+		self.event_handler = event_handler;
+		self.module_provider.reload(app_data);
+	}
+
 	pub(crate) fn activate(&mut self, state: State, previous_state: State) -> Results {
 		self.module_provider.get_mut_module(state).activate(previous_state)
 	}
@@ -48,6 +54,14 @@ impl<ModuleProvider: crate::module::ModuleProvider> ModuleHandler<ModuleProvider
 		})
 	}
 
+	pub(crate) fn pending_keys(&self) -> String {
+		self.event_handler.pending_keys()
+	}
+
+	pub(crate) fn has_queued_events(&self) -> bool {
+		self.event_handler.has_queued_events()
+	}
+
 	pub(crate) fn error(&mut self, state: State, error: &anyhow::Error) -> Results {
 		self.module_provider.get_mut_module(state).handle_error(error)
 	}
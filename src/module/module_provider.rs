@@ -9,4 +9,7 @@ pub(crate) trait ModuleProvider {
 	fn get_mut_module(&mut self, _state: State) -> &mut dyn Module;
 
 	fn get_module(&self, _state: State) -> &dyn Module;
+
+	/// Update all modules from a reloaded configuration, keeping the state of the modules.
+	fn reload(&mut self, app_data: &AppData);
 }
@@ -1,16 +1,29 @@
 use crate::{
 	application::AppData,
 	module::{Module, ModuleProvider, State},
-	modules::{ConfirmAbort, ConfirmRebase, Error, ExternalEditor, Insert, List, ShowCommit, WindowSizeError},
+	modules::{
+		CommitMessageEditor,
+		ConfirmAbort,
+		ConfirmRebase,
+		Error,
+		ExternalEditor,
+		Insert,
+		List,
+		RangeDiff,
+		ShowCommit,
+		WindowSizeError,
+	},
 };
 
 pub(crate) struct Modules {
+	commit_message_editor: CommitMessageEditor,
 	confirm_abort: ConfirmAbort,
 	confirm_rebase: ConfirmRebase,
 	error: Error,
 	external_editor: ExternalEditor,
 	insert: Insert,
 	list: List,
+	range_diff: RangeDiff,
 	show_commit: ShowCommit,
 	window_size_error: WindowSizeError,
 }
@@ -18,8 +31,10 @@ pub(crate) struct Modules {
 impl ModuleProvider for Modules {
 	fn new(app_data: &AppData) -> Self {
 		Self {
+			commit_message_editor: CommitMessageEditor::new(app_data),
 			error: Error::new(app_data),
 			list: List::new(app_data),
+			range_diff: RangeDiff::new(app_data),
 			show_commit: ShowCommit::new(app_data),
 			window_size_error: WindowSizeError::new(),
 			confirm_abort: ConfirmAbort::new(app_data),
@@ -31,12 +46,14 @@ impl ModuleProvider for Modules {
 
 	fn get_mut_module(&mut self, state: State) -> &mut dyn Module {
 		match state {
+			State::CommitMessageEditor => &mut self.commit_message_editor,
 			State::ConfirmAbort => &mut self.confirm_abort,
 			State::ConfirmRebase => &mut self.confirm_rebase,
 			State::Error => &mut self.error,
 			State::ExternalEditor => &mut self.external_editor,
 			State::Insert => &mut self.insert,
 			State::List => &mut self.list,
+			State::RangeDiff => &mut self.range_diff,
 			State::ShowCommit => &mut self.show_commit,
 			State::WindowSizeError => &mut self.window_size_error,
 		}
@@ -44,16 +61,31 @@ impl ModuleProvider for Modules {
 
 	fn get_module(&self, state: State) -> &dyn Module {
 		match state {
+			State::CommitMessageEditor => &self.commit_message_editor,
 			State::ConfirmAbort => &self.confirm_abort,
 			State::ConfirmRebase => &self.confirm_rebase,
 			State::Error => &self.error,
 			State::ExternalEditor => &self.external_editor,
 			State::Insert => &self.insert,
 			State::List => &self.list,
+			State::RangeDiff => &self.range_diff,
 			State::ShowCommit => &self.show_commit,
 			State::WindowSizeError => &self.window_size_error,
 		}
 	}
+
+	fn reload(&mut self, app_data: &AppData) {
+		self.commit_message_editor.reload(app_data);
+		self.confirm_abort.reload(app_data);
+		self.confirm_rebase.reload(app_data);
+		self.error.reload(app_data);
+		self.external_editor.reload(app_data);
+		self.insert.reload(app_data);
+		self.list.reload(app_data);
+		self.range_diff.reload(app_data);
+		self.show_commit.reload(app_data);
+		self.window_size_error.reload(app_data);
+	}
 }
 
 #[cfg(test)]
@@ -96,12 +128,14 @@ mod tests {
 	#[test]
 	fn get_mut_module() {
 		modules_test(|mut modules| {
+			_ = modules.get_mut_module(State::CommitMessageEditor);
 			_ = modules.get_mut_module(State::ConfirmAbort);
 			_ = modules.get_mut_module(State::ConfirmRebase);
 			_ = modules.get_mut_module(State::Error);
 			_ = modules.get_mut_module(State::ExternalEditor);
 			_ = modules.get_mut_module(State::Insert);
 			_ = modules.get_mut_module(State::List);
+			_ = modules.get_mut_module(State::RangeDiff);
 			_ = modules.get_mut_module(State::ShowCommit);
 			_ = modules.get_mut_module(State::WindowSizeError);
 		});
@@ -110,12 +144,14 @@ mod tests {
 	#[test]
 	fn get_module() {
 		modules_test(|modules| {
+			_ = modules.get_module(State::CommitMessageEditor);
 			_ = modules.get_module(State::ConfirmAbort);
 			_ = modules.get_module(State::ConfirmRebase);
 			_ = modules.get_module(State::Error);
 			_ = modules.get_module(State::ExternalEditor);
 			_ = modules.get_module(State::Insert);
 			_ = modules.get_module(State::List);
+			_ = modules.get_module(State::RangeDiff);
 			_ = modules.get_module(State::ShowCommit);
 			_ = modules.get_module(State::WindowSizeError);
 		});
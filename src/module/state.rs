@@ -1,11 +1,13 @@
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub(crate) enum State {
+	CommitMessageEditor,
 	ConfirmAbort,
 	ConfirmRebase,
 	Error,
 	ExternalEditor,
 	List,
 	Insert,
+	RangeDiff,
 	ShowCommit,
 	WindowSizeError,
 }
@@ -1,19 +1,23 @@
+mod commit_message_editor;
 mod confirm_abort;
 mod confirm_rebase;
 mod error;
 mod external_editor;
 mod insert;
 mod list;
+mod range_diff;
 mod show_commit;
 mod window_size_error;
 
 pub(crate) use self::{
+	commit_message_editor::CommitMessageEditor,
 	confirm_abort::ConfirmAbort,
 	confirm_rebase::ConfirmRebase,
 	error::Error,
 	external_editor::ExternalEditor,
 	insert::Insert,
 	list::List,
+	range_diff::RangeDiff,
 	show_commit::ShowCommit,
 	window_size_error::WindowSizeError,
 };
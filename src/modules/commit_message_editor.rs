@@ -0,0 +1,383 @@
+mod editor_state;
+mod trailer;
+
+#[cfg(test)]
+mod tests;
+
+use std::sync::{Arc, LazyLock};
+
+use parking_lot::Mutex;
+use unicode_segmentation::UnicodeSegmentation as _;
+
+use self::{editor_state::EditorState, trailer::Trailer};
+use crate::{
+	application::AppData,
+	commit_message::CommitMessage,
+	components::{
+		choice::{Choice, INPUT_OPTIONS as CHOICE_INPUT_OPTIONS},
+		confirm::{Confirm, Confirmed, INPUT_OPTIONS as CONFIRM_INPUT_OPTIONS},
+		edit::{EditAction, EditableText, INPUT_OPTIONS as EDIT_INPUT_OPTIONS},
+		help::Help,
+	},
+	config::{self, format_key_binding},
+	display::DisplayColor,
+	input::{Event, InputOptions, KeyBindings, StandardEvent},
+	module::{ExitStatus, Module},
+	process::Results,
+	select,
+	util::handle_view_data_scroll,
+	view::{self, LineSegment, LineSegmentOptions, RenderContext, ViewData, ViewLine, ViewLines},
+};
+
+static PREVIEW_INPUT_OPTIONS: LazyLock<InputOptions> = LazyLock::new(|| InputOptions::RESIZE | InputOptions::MOVEMENT);
+
+/// The length of the subject, after which the subject is highlighted, from the conventions of git.
+const SUBJECT_LIMIT: usize = 50;
+/// The length of the lines of the body, after which the line is highlighted, from the conventions of git.
+const BODY_LIMIT: usize = 72;
+
+fn get_commit_message_help_lines(key_bindings: &config::KeyBindings) -> Vec<(Vec<String>, String)> {
+	vec![
+		(
+			key_bindings.commit_message_save.clone(),
+			String::from("Save the message and exit"),
+		),
+		(
+			key_bindings.commit_message_abort.clone(),
+			String::from("Abort the commit, by saving an empty message"),
+		),
+		(
+			key_bindings.commit_message_preview.clone(),
+			String::from("Preview the message"),
+		),
+		(
+			key_bindings.commit_message_trailer.clone(),
+			String::from("Add a trailer"),
+		),
+		(key_bindings.commit_message_help.clone(), String::from("Show help")),
+	]
+}
+
+fn get_footer(key_bindings: &config::KeyBindings) -> String {
+	[
+		(&key_bindings.commit_message_save, "save"),
+		(&key_bindings.commit_message_abort, "abort"),
+		(&key_bindings.commit_message_preview, "preview"),
+		(&key_bindings.commit_message_trailer, "add a trailer"),
+		(&key_bindings.commit_message_help, "show help"),
+	]
+	.into_iter()
+	.filter_map(|(keys, action)| {
+		keys.first()
+			.map(|key| format!("{} to {action}", format_key_binding(key)))
+	})
+	.collect::<Vec<_>>()
+	.join(", ")
+}
+
+pub(crate) struct CommitMessageEditor {
+	commit_message: Option<Arc<Mutex<CommitMessage>>>,
+	confirm_abort: Confirm,
+	editable_text: EditableText,
+	footer: String,
+	help: Help,
+	preview_view_data: ViewData,
+	state: EditorState,
+	trailer_choices: Choice<Trailer>,
+	view_data: ViewData,
+	view_state: view::State,
+}
+
+impl Module for CommitMessageEditor {
+	fn build_view_data(&mut self, _: &RenderContext) -> &ViewData {
+		if self.help.is_active() {
+			return self.help.get_view_data();
+		}
+		match self.state {
+			EditorState::ConfirmAbort => self.confirm_abort.get_view_data(),
+			EditorState::Edit => self.build_edit_view_data(),
+			EditorState::Preview => self.build_preview_view_data(),
+			EditorState::Trailer => self.trailer_choices.get_view_data(),
+		}
+	}
+
+	fn input_options(&self) -> &InputOptions {
+		select!(
+			default match self.state {
+				EditorState::ConfirmAbort => &CONFIRM_INPUT_OPTIONS,
+				EditorState::Edit => &EDIT_INPUT_OPTIONS,
+				EditorState::Preview => &PREVIEW_INPUT_OPTIONS,
+				EditorState::Trailer => &CHOICE_INPUT_OPTIONS,
+			},
+			self.help.input_options()
+		)
+	}
+
+	fn read_event(&self, event: Event, key_bindings: &KeyBindings) -> Event {
+		select!(
+			default match self.state {
+				EditorState::ConfirmAbort => Confirm::read_event(event, key_bindings),
+				EditorState::Edit => Self::read_edit_event(event, key_bindings),
+				EditorState::Preview | EditorState::Trailer => event,
+			},
+			self.help.read_event(event)
+		)
+	}
+
+	fn handle_event(&mut self, event: Event) -> Results {
+		if let Some(results) = self.help.handle_event(event, &self.view_state) {
+			return results;
+		}
+		match self.state {
+			EditorState::ConfirmAbort => self.handle_confirm_abort_event(event),
+			EditorState::Edit => self.handle_edit_event(event),
+			EditorState::Preview => {
+				select!(
+					default {
+						if let Event::Key(_) = event {
+							self.state = EditorState::Edit;
+						}
+						Results::new()
+					},
+					handle_view_data_scroll(event, &self.view_state)
+				)
+			},
+			EditorState::Trailer => {
+				let choice = self.trailer_choices.handle_event(event, &self.view_state).cloned();
+				if let Some(trailer) = choice {
+					self.state = EditorState::Edit;
+					if trailer != Trailer::Cancel {
+						self.add_trailer(&trailer);
+					}
+				}
+				Results::new()
+			},
+		}
+	}
+
+	fn reload(&mut self, app_data: &AppData) {
+		let key_bindings = &app_data.config().key_bindings;
+		self.confirm_abort = Self::create_confirm_abort(key_bindings);
+		self.footer = get_footer(key_bindings);
+		self.help = Help::new_from_keybindings(&get_commit_message_help_lines(key_bindings));
+	}
+}
+
+impl CommitMessageEditor {
+	pub(crate) fn new(app_data: &AppData) -> Self {
+		let commit_message = app_data.commit_message();
+		let mut editable_text = EditableText::new();
+		if let Some(message) = commit_message.as_ref() {
+			editable_text.set_content(message.lock().lines());
+		}
+
+		let mut trailer_choices = Choice::new(vec![
+			(Trailer::SignedOffBy, 's', Trailer::SignedOffBy.to_string()),
+			(Trailer::CoAuthoredBy, 'c', Trailer::CoAuthoredBy.to_string()),
+			(Trailer::ReviewedBy, 'r', Trailer::ReviewedBy.to_string()),
+			(Trailer::AckedBy, 'a', Trailer::AckedBy.to_string()),
+			(Trailer::TestedBy, 't', Trailer::TestedBy.to_string()),
+			(Trailer::Cancel, 'q', String::from("Cancel add trailer")),
+		]);
+		trailer_choices.set_prompt(ViewLines::from([ViewLine::from("Select the trailer to add:")]));
+
+		let key_bindings = &app_data.config().key_bindings;
+		Self {
+			commit_message,
+			confirm_abort: Self::create_confirm_abort(key_bindings),
+			editable_text,
+			footer: get_footer(key_bindings),
+			help: Help::new_from_keybindings(&get_commit_message_help_lines(key_bindings)),
+			preview_view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+				updater.set_retain_scroll_position(false);
+			}),
+			state: EditorState::Edit,
+			trailer_choices,
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+			}),
+			view_state: app_data.view_state(),
+		}
+	}
+
+	fn create_confirm_abort(key_bindings: &config::KeyBindings) -> Confirm {
+		Confirm::new(
+			"Are you sure you want to abort",
+			&key_bindings.confirm_yes,
+			&key_bindings.confirm_no,
+		)
+	}
+
+	fn read_edit_event(event: Event, key_bindings: &KeyBindings) -> Event {
+		match event {
+			e if key_bindings.commit_message_abort.contains(&e) => Event::from(StandardEvent::CommitMessageAbort),
+			e if key_bindings.commit_message_help.contains(&e) => Event::from(StandardEvent::Help),
+			e if key_bindings.commit_message_preview.contains(&e) => Event::from(StandardEvent::CommitMessagePreview),
+			e if key_bindings.commit_message_save.contains(&e) => Event::from(StandardEvent::CommitMessageSave),
+			e if key_bindings.commit_message_trailer.contains(&e) => Event::from(StandardEvent::CommitMessageTrailer),
+			_ => event,
+		}
+	}
+
+	fn handle_edit_event(&mut self, event: Event) -> Results {
+		let mut results = Results::new();
+		match event {
+			Event::Standard(StandardEvent::CommitMessageSave) => {
+				self.update_commit_message();
+				results.exit_status(ExitStatus::Good);
+			},
+			Event::Standard(StandardEvent::CommitMessageAbort) => self.state = EditorState::ConfirmAbort,
+			Event::Standard(StandardEvent::CommitMessagePreview) => self.state = EditorState::Preview,
+			Event::Standard(StandardEvent::CommitMessageTrailer) => self.state = EditorState::Trailer,
+			Event::Standard(StandardEvent::Help) => self.help.set_active(),
+			_ => {
+				if self.editable_text.handle_event(event) == EditAction::ContentUpdate {
+					self.update_commit_message();
+				}
+			},
+		}
+		results
+	}
+
+	fn handle_confirm_abort_event(&mut self, event: Event) -> Results {
+		let mut results = Results::new();
+		match self.confirm_abort.handle_event(event) {
+			Confirmed::Yes => {
+				// git aborts the commit when the message is empty
+				if let Some(commit_message) = self.commit_message.as_ref() {
+					commit_message.lock().set_lines(vec![]);
+				}
+				results.exit_status(ExitStatus::Good);
+			},
+			Confirmed::No => self.state = EditorState::Edit,
+			Confirmed::Other => {},
+		}
+		results
+	}
+
+	fn update_commit_message(&self) {
+		if let Some(commit_message) = self.commit_message.as_ref() {
+			commit_message.lock().set_lines(self.editable_text.get_content());
+		}
+	}
+
+	fn add_trailer(&mut self, trailer: &Trailer) {
+		let Some(commit_message) = self.commit_message.as_ref()
+		else {
+			return;
+		};
+		let mut commit_message = commit_message.lock();
+		let value = match *trailer {
+			Trailer::SignedOffBy => String::from(commit_message.signature().unwrap_or_default()),
+			_ => String::new(),
+		};
+		let index = commit_message.add_trailer(format!("{trailer}: {value}").as_str());
+		self.editable_text.set_content(commit_message.lines());
+		self.editable_text.set_cursor(index, usize::MAX);
+	}
+
+	fn build_edit_view_data(&mut self) -> &ViewData {
+		let commit_message = self.commit_message.as_ref().map(|commit_message| commit_message.lock());
+		let subject_index = commit_message.as_ref().and_then(|message| message.subject_index());
+		let stripped_index = commit_message.as_ref().and_then(|message| message.scissors_index());
+		let editable_text = &self.editable_text;
+		let footer = self.footer.as_str();
+		let subject_length = subject_index
+			.and_then(|index| editable_text.get_line(index))
+			.map_or(0, |subject| subject.graphemes(true).count());
+
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.push_leading_line(ViewLine::new_pinned(vec![
+				LineSegment::new_with_color(
+					format!(
+						"{}|{}|",
+						"-".repeat(SUBJECT_LIMIT - 1),
+						"-".repeat(BODY_LIMIT - SUBJECT_LIMIT - 1)
+					)
+					.as_str(),
+					DisplayColor::IndicatorColor,
+				),
+				LineSegment::new_with_color(
+					format!(" Subject {subject_length}/{SUBJECT_LIMIT}").as_str(),
+					if subject_length > SUBJECT_LIMIT {
+						DisplayColor::DiffRemoveColor
+					}
+					else {
+						DisplayColor::IndicatorColor
+					},
+				),
+			]));
+
+			for index in 0..editable_text.line_count() {
+				if index == editable_text.line_index() {
+					updater.push_line(ViewLine::from(editable_text.line_segments(index)));
+					continue;
+				}
+				let line = editable_text.get_line(index).unwrap_or_default();
+				let is_stripped = stripped_index.is_some_and(|stripped| index >= stripped)
+					|| commit_message.as_ref().is_some_and(|message| message.is_comment(line));
+				if is_stripped {
+					updater.push_line(ViewLine::from(vec![LineSegment::new_with_color_and_style(
+						line,
+						DisplayColor::Normal,
+						LineSegmentOptions::DIMMED,
+					)]));
+					continue;
+				}
+				let limit = if Some(index) == subject_index {
+					SUBJECT_LIMIT
+				}
+				else {
+					BODY_LIMIT
+				};
+				let start = line.graphemes(true).take(limit).collect::<String>();
+				let end = line.graphemes(true).skip(limit).collect::<String>();
+				let mut segments = vec![LineSegment::new(start.as_str())];
+				if !end.is_empty() {
+					segments.push(LineSegment::new_with_color(end.as_str(), DisplayColor::DiffRemoveColor));
+				}
+				updater.push_line(ViewLine::from(segments));
+			}
+
+			updater.push_trailing_line(ViewLine::new_pinned(vec![LineSegment::new_with_color(
+				footer,
+				DisplayColor::IndicatorColor,
+			)]));
+			updater.ensure_column_visible(editable_text.cursor_position());
+			updater.ensure_line_visible(editable_text.line_index());
+		});
+		&self.view_data
+	}
+
+	fn build_preview_view_data(&mut self) -> &ViewData {
+		let lines = self
+			.commit_message
+			.as_ref()
+			.map(|commit_message| commit_message.lock().cleaned_lines())
+			.unwrap_or_default();
+		self.preview_view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+				"The commit message, with the comments removed:",
+				DisplayColor::IndicatorColor,
+			)));
+			updater.push_leading_line(ViewLine::new_empty_line());
+			if lines.is_empty() {
+				updater.push_line(ViewLine::from(LineSegment::new_with_color(
+					"The commit message is empty, and the commit will be aborted",
+					DisplayColor::IndicatorColor,
+				)));
+			}
+			for line in &lines {
+				updater.push_line(ViewLine::from(line.as_str()));
+			}
+			updater.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+				"Press any key to continue",
+				DisplayColor::IndicatorColor,
+			)));
+		});
+		&self.preview_view_data
+	}
+}
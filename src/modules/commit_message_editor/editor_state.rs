@@ -0,0 +1,7 @@
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum EditorState {
+	ConfirmAbort,
+	Edit,
+	Preview,
+	Trailer,
+}
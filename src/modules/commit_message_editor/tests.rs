@@ -0,0 +1,373 @@
+use super::*;
+use crate::{
+	assert_rendered_output,
+	assert_results,
+	input::{KeyCode, KeyEvent, KeyModifiers, map_keybindings},
+	process::Artifact,
+	test_helpers::{
+		assertions::assert_rendered_output::AssertRenderOptions,
+		create_config,
+		create_test_keybindings,
+		testers,
+	},
+};
+
+const RULER: &str = "-------------------------------------------------|---------------------|";
+const FOOTER: &str =
+	"Control+s to save, Control+q to abort, Control+p to preview, Control+t to add a trailer, F1 to show help";
+
+fn create_module(test_context: &testers::ModuleTestContext, lines: &[&str]) -> CommitMessageEditor {
+	let mut commit_message = CommitMessage::new("COMMIT_EDITMSG", "#");
+	commit_message.set_lines(lines.iter().map(|line| String::from(*line)).collect());
+	commit_message.set_signature("Tim Oram <dev@mitmaro.ca>");
+	CommitMessageEditor::new(&test_context.app_data().with_commit_message(commit_message))
+}
+
+fn commit_message_lines(module: &CommitMessageEditor) -> Vec<String> {
+	module.commit_message.as_ref().unwrap().lock().lines().to_vec()
+}
+
+fn control_key(c: char) -> Event {
+	Event::from(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL))
+}
+
+#[test]
+fn render_edit() {
+	testers::module(&[], &[], None, |test_context| {
+		let mut module = create_module(&test_context, &["Subject", "", "Body", "# comment"]);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			format!("{RULER} Subject 7/50"),
+			"{BODY}",
+			"Subject",
+			"",
+			"Body",
+			"# comment",
+			"{TRAILING}",
+			FOOTER
+		);
+	});
+}
+
+#[test]
+fn render_edit_style() {
+	testers::module(&[], &[], None, |test_context| {
+		let long_subject = "s".repeat(52);
+		let long_body = "b".repeat(73);
+		let mut module = create_module(&test_context, &[
+			"",
+			long_subject.as_str(),
+			"",
+			long_body.as_str(),
+			"# comment",
+			"# ------------------------ >8 ------------------------",
+			"diff",
+		]);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Options AssertRenderOptions::INCLUDE_STYLE,
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			format!("{{IndicatorColor}}{RULER}{{DiffRemoveColor}} Subject 52/50"),
+			"{BODY}",
+			"{Normal,Underline}",
+			format!("{{Normal}}{}{{DiffRemoveColor}}ss", "s".repeat(50)),
+			"{Normal}",
+			format!("{{Normal}}{}{{DiffRemoveColor}}b", "b".repeat(72)),
+			"{Normal,Dimmed}# comment",
+			"{Normal,Dimmed}# ------------------------ >8 ------------------------",
+			"{Normal,Dimmed}diff",
+			"{TRAILING}",
+			format!("{{IndicatorColor}}{FOOTER}")
+		);
+	});
+}
+
+#[test]
+fn edit_updates_commit_message() {
+	testers::module(
+		&[],
+		&[
+			Event::from(KeyCode::End),
+			Event::from('!'),
+			Event::from(KeyCode::Enter),
+			Event::from(KeyCode::Enter),
+			Event::from('b'),
+		],
+		None,
+		|mut test_context| {
+			let mut module = create_module(&test_context, &["Subject"]);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(commit_message_lines(&module), vec!["Subject!", "", "b"]);
+		},
+	);
+}
+
+#[test]
+fn finish() {
+	testers::module(&[], &[control_key('s')], None, |mut test_context| {
+		let mut module = create_module(&test_context, &["Subject"]);
+		assert_results!(
+			test_context.handle_event(&mut module),
+			Artifact::Event(Event::from(StandardEvent::CommitMessageSave)),
+			Artifact::ExitStatus(ExitStatus::Good)
+		);
+		assert_eq!(commit_message_lines(&module), vec!["Subject"]);
+	});
+}
+
+#[test]
+fn render_confirm_abort() {
+	testers::module(&[], &[control_key('q')], None, |mut test_context| {
+		let mut module = create_module(&test_context, &["Subject"]);
+		assert_results!(
+			test_context.handle_event(&mut module),
+			Artifact::Event(Event::from(StandardEvent::CommitMessageAbort))
+		);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(view_data, "{TITLE}", "{BODY}", "Are you sure you want to abort (y/n)?");
+		assert_eq!(commit_message_lines(&module), vec!["Subject"]);
+	});
+}
+
+#[test]
+fn abort_confirm_yes() {
+	testers::module(&[], &[control_key('q'), Event::from('y')], None, |mut test_context| {
+		let mut module = create_module(&test_context, &["Subject"]);
+		_ = test_context.handle_event(&mut module);
+		assert_results!(
+			test_context.handle_event(&mut module),
+			Artifact::Event(Event::from(StandardEvent::Yes)),
+			Artifact::ExitStatus(ExitStatus::Good)
+		);
+		assert!(commit_message_lines(&module).is_empty());
+	});
+}
+
+#[test]
+fn abort_confirm_no() {
+	testers::module(&[], &[control_key('q'), Event::from('n')], None, |mut test_context| {
+		let mut module = create_module(&test_context, &["Subject"]);
+		_ = test_context.handle_event(&mut module);
+		assert_results!(
+			test_context.handle_event(&mut module),
+			Artifact::Event(Event::from(StandardEvent::No))
+		);
+		assert_eq!(module.state, EditorState::Edit);
+		assert_eq!(commit_message_lines(&module), vec!["Subject"]);
+	});
+}
+
+#[test]
+fn read_event_custom_key_bindings() {
+	testers::module(&[], &[], None, |test_context| {
+		let module = create_module(&test_context, &["Subject"]);
+		let mut key_bindings = create_test_keybindings();
+		key_bindings.commit_message_save = map_keybindings(&[String::from("F2")]);
+		assert_eq!(
+			module.read_event(Event::from(KeyCode::F(2)), &key_bindings),
+			Event::from(StandardEvent::CommitMessageSave)
+		);
+		assert_eq!(module.read_event(control_key('s'), &key_bindings), control_key('s'));
+	});
+}
+
+#[test]
+fn render_footer_custom_key_bindings() {
+	let mut config = create_config();
+	config.key_bindings.commit_message_save = vec![String::from("F2")];
+	config.key_bindings.commit_message_help = vec![];
+	testers::module(&[], &[], Some(config), |test_context| {
+		let mut module = create_module(&test_context, &["Subject"]);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Skip 6,
+			view_data,
+			"F2 to save, Control+q to abort, Control+p to preview, Control+t to add a trailer"
+		);
+	});
+}
+
+#[test]
+fn help() {
+	testers::module(
+		&[],
+		&[Event::from(KeyCode::F(1)), Event::from('x')],
+		None,
+		|mut test_context| {
+			let mut module = create_module(&test_context, &["Subject"]);
+			_ = test_context.handle_event(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Body view_data,
+				" Controls|Save the message and exit",
+				" Controlq|Abort the commit, by saving an empty message",
+				" Controlp|Preview the message",
+				" Controlt|Add a trailer",
+				" F1      |Show help"
+			);
+			_ = test_context.handle_event(&mut module);
+			assert!(!module.help.is_active());
+			assert_eq!(commit_message_lines(&module), vec!["Subject"]);
+		},
+	);
+}
+
+#[test]
+fn render_preview() {
+	testers::module(&[], &[control_key('p')], None, |mut test_context| {
+		let mut module = create_module(&test_context, &["Subject  ", "# comment", "", "", "Body", ""]);
+		_ = test_context.handle_event(&mut module);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"The commit message, with the comments removed:",
+			"",
+			"{BODY}",
+			"Subject",
+			"",
+			"Body",
+			"{TRAILING}",
+			"Press any key to continue"
+		);
+	});
+}
+
+#[test]
+fn render_preview_empty() {
+	testers::module(&[], &[control_key('p')], None, |mut test_context| {
+		let mut module = create_module(&test_context, &["# comment"]);
+		_ = test_context.handle_event(&mut module);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Body view_data,
+			"The commit message is empty, and the commit will be aborted"
+		);
+	});
+}
+
+#[test]
+fn preview_scroll_and_return() {
+	testers::module(
+		&[],
+		&[
+			control_key('p'),
+			Event::from(StandardEvent::ScrollDown),
+			Event::from('x'),
+			Event::from('y'),
+		],
+		None,
+		|mut test_context| {
+			let mut module = create_module(&test_context, &["Subject"]);
+			_ = test_context.handle_n_events(&mut module, 2);
+			assert_eq!(module.state, EditorState::Preview);
+			_ = test_context.handle_event(&mut module);
+			assert_eq!(module.state, EditorState::Edit);
+			_ = test_context.handle_event(&mut module);
+			assert_eq!(commit_message_lines(&module), vec!["ySubject"]);
+		},
+	);
+}
+
+#[test]
+fn render_trailer_prompt() {
+	testers::module(&[], &[control_key('t')], None, |mut test_context| {
+		let mut module = create_module(&test_context, &["Subject"]);
+		_ = test_context.handle_event(&mut module);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"Select the trailer to add:",
+			"",
+			"{BODY}",
+			"s) Signed-off-by",
+			"c) Co-authored-by",
+			"r) Reviewed-by",
+			"a) Acked-by",
+			"t) Tested-by",
+			"q) Cancel add trailer",
+			"",
+			"Please choose an option."
+		);
+	});
+}
+
+#[test]
+fn add_signed_off_by_trailer() {
+	testers::module(&[], &[control_key('t'), Event::from('s')], None, |mut test_context| {
+		let mut module = create_module(&test_context, &["Subject", "# comment"]);
+		_ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.state, EditorState::Edit);
+		assert_eq!(commit_message_lines(&module), vec![
+			"Subject",
+			"",
+			"Signed-off-by: Tim Oram <dev@mitmaro.ca>",
+			"# comment"
+		]);
+		assert_eq!(module.editable_text.line_index(), 2);
+	});
+}
+
+#[test]
+fn add_trailer_and_type_value() {
+	testers::module(
+		&[],
+		&[control_key('t'), Event::from('r'), Event::from('M'), Event::from('e')],
+		None,
+		|mut test_context| {
+			let mut module = create_module(&test_context, &["Subject", "", "Fixes: 123"]);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(commit_message_lines(&module), vec![
+				"Subject",
+				"",
+				"Fixes: 123",
+				"Reviewed-by: Me"
+			]);
+		},
+	);
+}
+
+#[test]
+fn add_trailer_cancel() {
+	testers::module(&[], &[control_key('t'), Event::from('q')], None, |mut test_context| {
+		let mut module = create_module(&test_context, &["Subject"]);
+		_ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.state, EditorState::Edit);
+		assert_eq!(commit_message_lines(&module), vec!["Subject"]);
+	});
+}
+
+#[test]
+fn without_commit_message() {
+	testers::module(
+		&[],
+		&[
+			Event::from('a'),
+			control_key('t'),
+			Event::from('s'),
+			control_key('q'),
+			Event::from('y'),
+		],
+		None,
+		|mut test_context| {
+			let mut module = CommitMessageEditor::new(&test_context.app_data());
+			_ = test_context.handle_n_events(&mut module, 3);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(Body view_data, "a");
+			_ = test_context.handle_event(&mut module);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::Yes)),
+				Artifact::ExitStatus(ExitStatus::Good)
+			);
+		},
+	);
+}
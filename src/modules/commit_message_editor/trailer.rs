@@ -0,0 +1,42 @@
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Trailer {
+	Cancel,
+	AckedBy,
+	CoAuthoredBy,
+	ReviewedBy,
+	SignedOffBy,
+	TestedBy,
+}
+
+impl Display for Trailer {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::Cancel => write!(f, "<cancel>"),
+			Self::AckedBy => write!(f, "Acked-by"),
+			Self::CoAuthoredBy => write!(f, "Co-authored-by"),
+			Self::ReviewedBy => write!(f, "Reviewed-by"),
+			Self::SignedOffBy => write!(f, "Signed-off-by"),
+			Self::TestedBy => write!(f, "Tested-by"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::cancel(&Trailer::Cancel, "<cancel>")]
+	#[case::acked_by(&Trailer::AckedBy, "Acked-by")]
+	#[case::co_authored_by(&Trailer::CoAuthoredBy, "Co-authored-by")]
+	#[case::reviewed_by(&Trailer::ReviewedBy, "Reviewed-by")]
+	#[case::signed_off_by(&Trailer::SignedOffBy, "Signed-off-by")]
+	#[case::tested_by(&Trailer::TestedBy, "Tested-by")]
+	fn to_string(#[case] trailer: &Trailer, #[case] expected: &str) {
+		assert_eq!(trailer.to_string(), String::from(expected));
+	}
+}
@@ -45,20 +45,28 @@ impl Module for ConfirmAbort {
 		}
 		results
 	}
+
+	fn reload(&mut self, app_data: &AppData) {
+		self.dialog = Self::create_dialog(app_data);
+	}
 }
 
 impl ConfirmAbort {
 	pub(crate) fn new(app_data: &AppData) -> Self {
-		let config = app_data.config();
 		Self {
-			dialog: Confirm::new(
-				"Are you sure you want to abort",
-				&config.key_bindings.confirm_yes,
-				&config.key_bindings.confirm_no,
-			),
+			dialog: Self::create_dialog(app_data),
 			todo_file: app_data.todo_file(),
 		}
 	}
+
+	fn create_dialog(app_data: &AppData) -> Confirm {
+		let config = app_data.config();
+		Confirm::new(
+			"Are you sure you want to abort",
+			&config.key_bindings.confirm_yes,
+			&config.key_bindings.confirm_no,
+		)
+	}
 }
 
 #[cfg(test)]
@@ -38,19 +38,27 @@ impl Module for ConfirmRebase {
 		}
 		results
 	}
+
+	fn reload(&mut self, app_data: &AppData) {
+		self.dialog = Self::create_dialog(app_data);
+	}
 }
 
 impl ConfirmRebase {
 	pub(crate) fn new(app_data: &AppData) -> Self {
-		let config = app_data.config();
 		Self {
-			dialog: Confirm::new(
-				"Are you sure you want to rebase",
-				&config.key_bindings.confirm_yes,
-				&config.key_bindings.confirm_no,
-			),
+			dialog: Self::create_dialog(app_data),
 		}
 	}
+
+	fn create_dialog(app_data: &AppData) -> Confirm {
+		let config = app_data.config();
+		Confirm::new(
+			"Are you sure you want to rebase",
+			&config.key_bindings.confirm_yes,
+			&config.key_bindings.confirm_no,
+		)
+	}
 }
 #[cfg(test)]
 mod tests {
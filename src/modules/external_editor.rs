@@ -166,6 +166,10 @@ impl Module for ExternalEditor {
 		}
 		results
 	}
+
+	fn reload(&mut self, app_data: &AppData) {
+		self.editor = String::from(app_data.config().git.editor.as_str());
+	}
 }
 
 impl ExternalEditor {
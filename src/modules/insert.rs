@@ -19,7 +19,7 @@ use crate::{
 	input::{Event, InputOptions},
 	module::{Module, State},
 	process::Results,
-	todo_file::{Line, TodoFile},
+	todo_file::{Action, Line, TodoFile},
 	view::{self, LineSegment, RenderContext, ViewData, ViewDataUpdater, ViewLine, ViewLines},
 };
 
@@ -35,6 +35,7 @@ pub(crate) struct Insert {
 impl Module for Insert {
 	fn activate(&mut self, _: State) -> Results {
 		self.state = InsertState::Prompt;
+		self.action_choices = Self::create_action_choices(&self.todo_file.lock());
 		self.edit.reset();
 		Results::new()
 	}
@@ -90,6 +91,7 @@ impl Module for Insert {
 						let line = match self.line_type {
 							LineType::Exec => Line::new_exec(content),
 							LineType::Pick => Line::new_pick(content),
+							LineType::Revert => Line::new_revert(content),
 							LineType::Label => Line::new_label(content),
 							LineType::Reset => Line::new_reset(content),
 							LineType::Merge => Line::new_merge(content),
@@ -111,20 +113,7 @@ impl Module for Insert {
 
 impl Insert {
 	pub(crate) fn new(app_data: &AppData) -> Self {
-		let mut action_choices = Choice::new(vec![
-			(LineType::Exec, 'e', String::from("exec <command>")),
-			(LineType::Pick, 'p', String::from("pick <hash>")),
-			(LineType::Label, 'l', String::from("label <label>")),
-			(LineType::Reset, 'r', String::from("reset <label>")),
-			(
-				LineType::Merge,
-				'm',
-				String::from("merge [-C <commit> | -c <commit>] <label> [# <oneline>]"),
-			),
-			(LineType::UpdateRef, 'u', String::from("update-ref <reference>")),
-			(LineType::Cancel, 'q', String::from("Cancel add line")),
-		]);
-		action_choices.set_prompt(ViewLines::from([ViewLine::from("Select the type of line to insert:")]));
+		let action_choices = Self::create_action_choices(&app_data.todo_file().lock());
 
 		Self {
 			action_choices,
@@ -135,4 +124,33 @@ impl Insert {
 			view_state: app_data.view_state(),
 		}
 	}
+
+	/// Create the choices of the types of line, limited to the actions supported by the todo file, since a
+	/// cherry-pick or revert sequence only supports the command of the sequence.
+	fn create_action_choices(todo_file: &TodoFile) -> Choice<LineType> {
+		let choices = [
+			(LineType::Exec, Action::Exec, 'e', "exec <command>"),
+			(LineType::Pick, Action::Pick, 'p', "pick <hash>"),
+			(LineType::Revert, Action::Revert, 'v', "revert <hash>"),
+			(LineType::Label, Action::Label, 'l', "label <label>"),
+			(LineType::Reset, Action::Reset, 'r', "reset <label>"),
+			(
+				LineType::Merge,
+				Action::Merge,
+				'm',
+				"merge [-C <commit> | -c <commit>] <label> [# <oneline>]",
+			),
+			(LineType::UpdateRef, Action::UpdateRef, 'u', "update-ref <reference>"),
+		];
+		let mut action_choices = Choice::new(
+			choices
+				.into_iter()
+				.filter(|&(_, action, ..)| todo_file.supports_action(action))
+				.map(|(line_type, _, key, description)| (line_type, key, String::from(description)))
+				.chain([(LineType::Cancel, 'q', String::from("Cancel add line"))])
+				.collect(),
+		);
+		action_choices.set_prompt(ViewLines::from([ViewLine::from("Select the type of line to insert:")]));
+		action_choices
+	}
 }
@@ -4,6 +4,7 @@ use std::fmt::Display;
 pub(crate) enum LineType {
 	Cancel,
 	Pick,
+	Revert,
 	Exec,
 	Label,
 	Merge,
@@ -16,6 +17,7 @@ impl Display for LineType {
 		match *self {
 			Self::Cancel => write!(f, "<cancel>"),
 			Self::Pick => write!(f, "pick"),
+			Self::Revert => write!(f, "revert"),
 			Self::Exec => write!(f, "exec"),
 			Self::Label => write!(f, "label"),
 			Self::Merge => write!(f, "merge"),
@@ -34,6 +36,7 @@ mod tests {
 	#[rstest]
 	#[case::cancel(&LineType::Cancel, "<cancel>")]
 	#[case::pick(&LineType::Pick, "pick")]
+	#[case::revert(&LineType::Revert, "revert")]
 	#[case::exec(&LineType::Exec, "exec")]
 	#[case::label(&LineType::Label, "label")]
 	#[case::merge(&LineType::Merge, "merge")]
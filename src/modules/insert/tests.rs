@@ -1,5 +1,18 @@
 use super::*;
-use crate::{assert_rendered_output, assert_results, input::KeyCode, process::Artifact, test_helpers::testers};
+use crate::{
+	assert_rendered_output,
+	assert_results,
+	input::KeyCode,
+	process::Artifact,
+	test_helpers::testers,
+	todo_file::TodoFileOptions,
+};
+
+fn set_sequencer_lines(test_context: &testers::ModuleTestContext, lines: &[&str]) {
+	let mut todo_file = TodoFile::new("sequencer/todo", TodoFileOptions::new(10, "#"));
+	todo_file.set_lines(lines.iter().map(|line| Line::parse(line).unwrap()).collect());
+	*test_context.app_data().todo_file().lock() = todo_file;
+}
 
 #[test]
 fn activate() {
@@ -34,6 +47,61 @@ fn render_prompt() {
 	});
 }
 
+#[test]
+fn render_prompt_cherry_pick_sequencer() {
+	testers::module(&[], &[], None, |test_context| {
+		set_sequencer_lines(&test_context, &["pick aaa c1"]);
+		let mut module = Insert::new(&test_context.app_data());
+		_ = test_context.activate(&mut module, State::List);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Body view_data,
+			"p) pick <hash>",
+			"q) Cancel add line",
+			"",
+			"Please choose an option."
+		);
+	});
+}
+
+#[test]
+fn render_prompt_revert_sequencer() {
+	testers::module(&[], &[], None, |test_context| {
+		set_sequencer_lines(&test_context, &["revert aaa c1"]);
+		let mut module = Insert::new(&test_context.app_data());
+		_ = test_context.activate(&mut module, State::List);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Body view_data,
+			"v) revert <hash>",
+			"q) Cancel add line",
+			"",
+			"Please choose an option."
+		);
+	});
+}
+
+#[test]
+fn edit_revert_sequencer() {
+	testers::module(
+		&[],
+		&[
+			Event::from('v'),
+			Event::from('a'),
+			Event::from('b'),
+			Event::from('c'),
+			Event::from(KeyCode::Enter),
+		],
+		None,
+		|mut test_context| {
+			set_sequencer_lines(&test_context, &["revert aaa c1"]);
+			let mut module = Insert::new(&test_context.app_data());
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_line(1).unwrap().to_text(), "revert abc ");
+		},
+	);
+}
+
 #[test]
 fn prompt_cancel() {
 	testers::module(&[], &[Event::from('q')], None, |mut test_context| {
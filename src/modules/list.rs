@@ -3,17 +3,29 @@ mod search;
 mod tests;
 mod utils;
 
-use std::{cmp::min, sync::Arc};
+use std::{
+	cmp::{Ordering, min},
+	collections::HashMap,
+	ops::Range,
+	sync::Arc,
+};
 
+use anyhow::anyhow;
 use captur::capture;
 use parking_lot::Mutex;
+use version_track::Version;
 
 use self::{
 	search::Search,
 	utils::{
+		FORMAT_HASH_WIDTH,
 		TodoLineSegmentsOptions,
+		get_done_line_segments,
+		get_formatted_todo_line_segments,
 		get_list_normal_mode_help_lines,
 		get_list_visual_mode_help_lines,
+		get_plugin_command_help_lines,
+		get_segment_columns,
 		get_todo_line_segments,
 	},
 };
@@ -25,10 +37,12 @@ use crate::{
 		search_bar::{SearchBar, SearchBarAction},
 		spin_indicator::SpinIndicator,
 	},
+	config::{CustomCommand, LineFormat},
 	display::DisplayColor,
-	input::{Event, InputOptions, KeyBindings, MouseEventKind, StandardEvent},
+	input::{Event, InputOptions, KeyBindings, KeyModifiers, MouseButton, MouseEvent, MouseEventKind, StandardEvent},
 	module::{ExitStatus, Module, State},
 	modules::list::utils::get_line_action_maximum_width,
+	plugin::{self, PluginEdit, PluginLine},
 	process::Results,
 	search::Searchable,
 	select,
@@ -41,7 +55,8 @@ use crate::{
 const INPUT_OPTIONS: InputOptions = InputOptions::UNDO_REDO
 	.union(InputOptions::RESIZE)
 	.union(InputOptions::HELP)
-	.union(InputOptions::SEARCH_START);
+	.union(InputOptions::SEARCH_START)
+	.union(InputOptions::KEY_SEQUENCES);
 
 #[derive(Debug, PartialEq, Eq)]
 enum ListState {
@@ -59,10 +74,22 @@ enum CursorUpdate {
 }
 
 pub(crate) struct List {
+	// the columns of the action of each todo line, as rendered
+	action_columns: Vec<Range<usize>>,
 	auto_select_next: bool,
+	commit_authors: Arc<HashMap<String, String>>,
+	custom_commands: Vec<CustomCommand>,
+	dragging: bool,
 	edit: Edit,
 	height: usize,
+	line_format: Option<LineFormat>,
 	normal_mode_help: Help,
+	normal_mode_help_lines: Vec<(Vec<String>, String)>,
+	plugin_commands_version: usize,
+	plugin_selected_index: Option<usize>,
+	plugin_state: plugin::State,
+	plugin_todo_version: Option<Version>,
+	running_custom_command: Option<usize>,
 	search: Search,
 	search_bar: SearchBar,
 	selected_line_action: Option<Action>,
@@ -83,6 +110,8 @@ impl Module for List {
 		if let Some(term) = self.search_bar.search_value() {
 			results.search_term(term);
 		}
+		self.apply_plugin_updates(&mut results);
+		self.notify_plugins();
 		results
 	}
 
@@ -111,7 +140,13 @@ impl Module for List {
 	}
 
 	fn handle_event(&mut self, event: Event) -> Results {
-		select!(
+		// a drag is interrupted by any input, so that the moves of the drag are not mixed with other changes
+		if matches!(event, Event::Key(_) | Event::KeySequence(_))
+			|| matches!(event, Event::Standard(e) if e != StandardEvent::PluginUpdate)
+		{
+			self.end_drag();
+		}
+		let results = select!(
 			default {
 				match self.state {
 					ListState::Normal => self.handle_normal_mode_event(event),
@@ -122,7 +157,9 @@ impl Module for List {
 			self.normal_mode_help.handle_event(event, &self.view_state),
 			self.visual_mode_help.handle_event(event, &self.view_state),
 			self.handle_search_input(event)
-		)
+		);
+		self.notify_plugins();
+		results
 	}
 
 	fn input_options(&self) -> &InputOptions {
@@ -144,6 +181,17 @@ impl Module for List {
 			self.search_bar.read_event(event)
 		)
 	}
+
+	fn reload(&mut self, app_data: &AppData) {
+		let config = app_data.config();
+		self.auto_select_next = config.auto_select_next;
+		self.commit_authors = app_data.commit_authors();
+		self.custom_commands.clone_from(&config.key_bindings.custom_commands);
+		self.line_format.clone_from(&config.list_line_format);
+		self.normal_mode_help_lines = get_list_normal_mode_help_lines(&config.key_bindings);
+		self.normal_mode_help = Help::new_from_keybindings(&self.normal_mode_help_lines);
+		self.visual_mode_help = Help::new_from_keybindings(&get_list_visual_mode_help_lines(&config.key_bindings));
+	}
 }
 
 impl List {
@@ -154,12 +202,24 @@ impl List {
 		});
 
 		let config = app_data.config();
+		let normal_mode_help_lines = get_list_normal_mode_help_lines(&config.key_bindings);
 
 		Self {
+			action_columns: vec![],
 			auto_select_next: config.auto_select_next,
+			commit_authors: app_data.commit_authors(),
+			custom_commands: config.key_bindings.custom_commands.clone(),
+			dragging: false,
 			edit: Edit::new(),
 			height: 0,
-			normal_mode_help: Help::new_from_keybindings(&get_list_normal_mode_help_lines(&config.key_bindings)),
+			line_format: config.list_line_format.clone(),
+			normal_mode_help: Help::new_from_keybindings(&normal_mode_help_lines),
+			normal_mode_help_lines,
+			plugin_commands_version: 0,
+			plugin_selected_index: None,
+			plugin_state: app_data.plugin_state(),
+			plugin_todo_version: None,
+			running_custom_command: None,
 			search: Search::new(app_data.todo_file()),
 			search_bar: SearchBar::new(),
 			selected_line_action: None,
@@ -173,6 +233,12 @@ impl List {
 		}
 	}
 
+	fn done_lines_count(todo_file: &TodoFile) -> usize {
+		todo_file
+			.rebase_progress()
+			.map_or(0, |rebase_progress| rebase_progress.done_lines().len())
+	}
+
 	fn update_cursor(&mut self, cursor_update: CursorUpdate) -> usize {
 		let mut todo_file = self.todo_file.lock();
 		let new_selected_line_index = match cursor_update {
@@ -247,6 +313,9 @@ impl List {
 
 	fn set_selected_line_action(&mut self, action: Action) {
 		let mut todo_file = self.todo_file.lock();
+		if !todo_file.supports_action(action) {
+			return;
+		}
 		let start_index = todo_file.get_selected_line_index();
 		let end_index = self.visual_index_start.unwrap_or(start_index);
 
@@ -358,15 +427,19 @@ impl List {
 
 	fn show_commit(&mut self, results: &mut Results) {
 		let todo_file = self.todo_file.lock();
-		if let Some(selected_line) = todo_file.get_selected_line() {
-			if selected_line.has_reference() {
-				results.state(State::ShowCommit);
-			}
+		if todo_file
+			.get_line_revision(todo_file.get_selected_line_index())
+			.is_some()
+		{
+			results.state(State::ShowCommit);
 		}
 	}
 
 	fn action_break(&mut self) {
 		let mut todo_file = self.todo_file.lock();
+		if !todo_file.supports_action(Action::Break) {
+			return;
+		}
 		let selected_line_index = todo_file.get_selected_line_index();
 		let next_action_is_break = todo_file
 			.get_line(selected_line_index + 1)
@@ -433,6 +506,274 @@ impl List {
 		}
 	}
 
+	fn run_custom_command(&mut self, index: usize, results: &mut Results) {
+		let Some(custom_command) = self.custom_commands.get(index)
+		else {
+			return;
+		};
+		let todo_file = self.todo_file.lock();
+		let Some(selected_line) = todo_file.get_selected_line()
+		else {
+			return;
+		};
+
+		// the todo file must be current for a command that may change it
+		if custom_command.reload {
+			if let Err(err) = todo_file.write_file() {
+				results.error(err.into());
+				return;
+			}
+		}
+
+		// `env` sets the details of the selected line in the environment of the command
+		results.external_command(String::from("env"), vec![
+			format!("INTERACTIVE_REBASE_TOOL_HASH={}", selected_line.get_hash()),
+			format!("INTERACTIVE_REBASE_TOOL_ACTION={}", selected_line.get_action()),
+			format!("INTERACTIVE_REBASE_TOOL_CONTENT={}", selected_line.get_content()),
+			String::from("sh"),
+			String::from("-c"),
+			custom_command.run.clone(),
+		]);
+		drop(todo_file);
+		self.running_custom_command = Some(index);
+	}
+
+	fn finish_custom_command(&mut self, successful: bool, results: &mut Results) {
+		let Some(custom_command) = self
+			.running_custom_command
+			.take()
+			.and_then(|index| self.custom_commands.get(index))
+		else {
+			return;
+		};
+
+		if custom_command.reload {
+			if let Err(err) = self.todo_file.lock().load_file() {
+				results.error(err.into());
+				return;
+			}
+		}
+
+		if !successful {
+			results.error(anyhow!(
+				"Command \"{}\" returned a non-zero exit status",
+				custom_command.name
+			));
+		}
+	}
+
+	fn run_plugin_command(&self, index: usize) {
+		let Some(command) = self.plugin_state.command(index)
+		else {
+			return;
+		};
+		let todo_file = self.todo_file.lock();
+		let Some(selected_line) = todo_file.get_selected_line()
+		else {
+			return;
+		};
+		self.plugin_state.send_update(plugin::Action::RunCommand {
+			plugin: command.plugin,
+			command: command.name,
+			index: todo_file.get_selected_line_index(),
+			line: PluginLine::from(selected_line),
+		});
+	}
+
+	/// Apply the edits proposed by the plugins, and show the errors of the plugins.
+	fn apply_plugin_updates(&mut self, results: &mut Results) {
+		let mut errors = self.plugin_state.take_errors();
+		let edits = self.plugin_state.take_edits();
+		if !edits.is_empty() {
+			let mut todo_file = self.todo_file.lock();
+			for edit in edits {
+				if let Err(err) = Self::apply_plugin_edit(&mut todo_file, &edit) {
+					errors.push(format!(
+						"Plugin \"{}\": {err}",
+						self.plugin_state.plugin_name(edit.plugin)
+					));
+				}
+			}
+		}
+
+		let commands_version = self.plugin_state.commands_version();
+		if commands_version != self.plugin_commands_version {
+			self.plugin_commands_version = commands_version;
+			let mut help_lines = self.normal_mode_help_lines.clone();
+			help_lines.extend(get_plugin_command_help_lines(&self.plugin_state.commands()));
+			self.normal_mode_help = Help::new_from_keybindings(&help_lines);
+		}
+
+		if !errors.is_empty() {
+			results.error(anyhow!(errors.join("\n")));
+		}
+	}
+
+	/// Apply an edit proposed by a plugin, through the history of the todo file, so it can be undone.
+	fn apply_plugin_edit(todo_file: &mut TodoFile, edit: &PluginEdit) -> Result<(), String> {
+		let Some(line) = todo_file
+			.get_line(edit.index)
+			.filter(|line| line.get_hash() == edit.hash)
+		else {
+			return Err(format!(
+				"The line at index {} does not have the hash \"{}\"",
+				edit.index, edit.hash
+			));
+		};
+
+		let mut edit_context = EditContext::new();
+		if let Some(action) = edit.action {
+			if line.get_action().is_static() || action.is_static() || !todo_file.supports_action(action) {
+				return Err(format!(
+					"The action of the line at index {} cannot be changed to {action}",
+					edit.index
+				));
+			}
+			edit_context = edit_context.action(action);
+		}
+		if let Some(content) = edit.content.as_deref() {
+			if !line.is_editable() {
+				return Err(format!(
+					"The content of the line at index {} cannot be changed",
+					edit.index
+				));
+			}
+			edit_context = edit_context.content(content);
+		}
+
+		todo_file.update_range(edit.index, edit.index, &edit_context);
+		Ok(())
+	}
+
+	/// Notify the plugins of changes to the todo file, and to the selected line.
+	fn notify_plugins(&mut self) {
+		if !self.plugin_state.has_plugins() {
+			return;
+		}
+
+		let todo_file = self.todo_file.lock();
+		let version = *todo_file.version();
+		let todo_changed = self.plugin_todo_version != Some(version);
+		if todo_changed {
+			self.plugin_todo_version = Some(version);
+			self.plugin_state.send_update(plugin::Action::TodoChanged(
+				todo_file.lines_iter().map(PluginLine::from).collect(),
+			));
+		}
+
+		let selected_index = todo_file.get_selected_line_index();
+		if todo_changed || self.plugin_selected_index != Some(selected_index) {
+			self.plugin_selected_index = Some(selected_index);
+			if let Some(line) = todo_file.get_selected_line() {
+				self.plugin_state.send_update(plugin::Action::SelectionChanged {
+					index: selected_index,
+					line: PluginLine::from(line),
+				});
+			}
+		}
+	}
+
+	fn select_line(&mut self, index: usize) {
+		self.state = ListState::Normal;
+		self.visual_index_start = None;
+		_ = self.update_cursor(CursorUpdate::Set(index));
+	}
+
+	fn select_range_to(&mut self, index: usize) {
+		if self.state != ListState::Visual {
+			self.state = ListState::Visual;
+			self.visual_index_start = Some(self.todo_file.lock().get_selected_line_index());
+		}
+		_ = self.update_cursor(CursorUpdate::Set(index));
+	}
+
+	fn cycle_line_action(&mut self, index: usize) {
+		let mut todo_file = self.todo_file.lock();
+		if let Some(line) = todo_file.get_line(index) {
+			let action = *line.get_action();
+			let next_action = if todo_file.is_sequencer() {
+				// a sequencer line is toggled between dropped and the action it was loaded with
+				if action == Action::Drop {
+					line.original().map_or(Action::Pick, |original| *original.get_action())
+				}
+				else {
+					Action::Drop
+				}
+			}
+			else {
+				action.next()
+			};
+			if !action.is_static() && todo_file.supports_action(next_action) {
+				todo_file.update_range(index, index, &EditContext::new().action(next_action));
+			}
+		}
+		drop(todo_file);
+		self.select_line(index);
+	}
+
+	fn drag_selected_line_to(&mut self, index: usize) {
+		loop {
+			let selected_index = self.todo_file.lock().get_selected_line_index();
+			match selected_index.cmp(&index) {
+				Ordering::Less => self.swap_selected_down(),
+				Ordering::Greater => self.swap_selected_up(),
+				Ordering::Equal => break,
+			}
+			// the line could not be moved any further
+			if self.todo_file.lock().get_selected_line_index() == selected_index {
+				break;
+			}
+		}
+	}
+
+	fn end_drag(&mut self) {
+		if self.dragging {
+			self.dragging = false;
+			self.todo_file.lock().end_history_group();
+		}
+	}
+
+	fn handle_mouse_event(&mut self, mouse_event: MouseEvent) {
+		// the applied lines of the rebase are read-only, so rows containing them do not map to a todo line
+		let done_lines_count = Self::done_lines_count(&self.todo_file.lock());
+		let line_index = self
+			.view_state
+			.line_index_at_row(mouse_event.row)
+			.and_then(|index| index.checked_sub(done_lines_count));
+		match mouse_event.kind {
+			MouseEventKind::Down(MouseButton::Left) => {
+				self.end_drag();
+				let Some(index) = line_index
+				else {
+					return;
+				};
+				let is_action_column = self
+					.action_columns
+					.get(index)
+					.is_some_and(|columns| columns.contains(&(mouse_event.column as usize)));
+				if mouse_event.modifiers.contains(KeyModifiers::SHIFT) {
+					self.select_range_to(index);
+				}
+				else if is_action_column {
+					self.cycle_line_action(index);
+				}
+				else {
+					self.select_line(index);
+					// the moves of a drag are undone together
+					self.todo_file.lock().start_history_group();
+					self.dragging = true;
+				}
+			},
+			MouseEventKind::Drag(MouseButton::Left) => {
+				if let Some(index) = line_index.filter(|_| self.dragging) {
+					self.drag_selected_line_to(index);
+				}
+			},
+			MouseEventKind::Up(MouseButton::Left) => self.end_drag(),
+			_ => {},
+		}
+	}
+
 	fn update_list_view_data(&mut self, context: &RenderContext) -> &ViewData {
 		let todo_file = self.todo_file.lock();
 		let is_visual_mode = self.state == ListState::Visual;
@@ -445,10 +786,54 @@ impl List {
 		let search_index = self.search.current_match();
 		let search_active = self.search.is_active();
 		let spin_indicator = self.spin_indicator.indicator();
+		let maximum_action_width = get_line_action_maximum_width(&todo_file);
+		// the lines already applied by the rebase are rendered before the todo lines
+		let done_lines_count = Self::done_lines_count(&todo_file);
+
+		let line_format = self.line_format.as_ref().filter(|_| context.is_full_width());
+
+		// the action segment is pinned, so the action columns do not change when scrolled
+		let mut action_columns = vec![];
 
 		self.view_data.update_view_data(|updater| {
 			capture!(todo_file);
 			updater.clear();
+			if let Some(rebase_progress) = todo_file.rebase_progress() {
+				let mut progress = match rebase_progress.position() {
+					Some((current, total)) => format!("Rebase progress: {current}/{total}"),
+					None => String::from("Rebase in progress"),
+				};
+				if let Some(hash) = rebase_progress.stopped_hash() {
+					progress.push_str(
+						format!(
+							", stopped at {}",
+							hash.chars().take(FORMAT_HASH_WIDTH).collect::<String>()
+						)
+						.as_str(),
+					);
+				}
+				updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+					progress.as_str(),
+					DisplayColor::IndicatorColor,
+				)));
+				let done_line_segment_options = if context.is_full_width() {
+					TodoLineSegmentsOptions::FULL_WIDTH
+				}
+				else {
+					TodoLineSegmentsOptions::empty()
+				};
+				for line in rebase_progress.done_lines() {
+					let (segments, pinned_segments) = get_done_line_segments(
+						line,
+						self.commit_authors.get(line.get_hash()).map(String::as_str),
+						line_format,
+						rebase_progress.is_stopped_line(line),
+						done_line_segment_options,
+						maximum_action_width,
+					);
+					updater.push_line(ViewLine::new_with_pinned_segments(segments, pinned_segments));
+				}
+			}
 			if todo_file.is_empty() {
 				updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
 					"Rebase todo file is empty",
@@ -456,7 +841,6 @@ impl List {
 				)));
 			}
 			else {
-				let maximum_action_width = get_line_action_maximum_width(&todo_file);
 				for (index, line) in todo_file.lines_iter().enumerate() {
 					let search_match = self.search.match_at_index(index);
 					let selected_line = is_visual_mode
@@ -475,17 +859,49 @@ impl List {
 					if search_index.is_some_and(|v| v.index() == index) {
 						todo_line_segment_options.insert(TodoLineSegmentsOptions::SEARCH_LINE);
 					}
-					let mut view_line = ViewLine::new_with_pinned_segments(
-						get_todo_line_segments(
+					let (mut segments, pinned_segments) = if let Some(format) = line_format {
+						get_formatted_todo_line_segments(
 							line,
+							self.commit_authors.get(line.get_hash()).map(String::as_str),
+							format,
 							search_term,
 							search_match,
 							todo_line_segment_options,
 							maximum_action_width,
-						),
-						if line.has_reference() { 2 } else { 3 },
-					)
-					.set_selected(selected_index == index || selected_line);
+						)
+					}
+					else {
+						(
+							get_todo_line_segments(
+								line,
+								search_term,
+								search_match,
+								todo_line_segment_options,
+								maximum_action_width,
+							),
+							if line.has_reference() { 2 } else { 3 },
+						)
+					};
+					// the action is the last pinned segment of a formatted line, when the format has an action
+					let action_index = if line_format.is_some() { pinned_segments - 1 } else { 1 };
+					action_columns.push(
+						if action_index == 0 {
+							0..0
+						}
+						else {
+							get_segment_columns(&segments, action_index)
+						},
+					);
+					if line.has_reference() {
+						for badge in self.plugin_state.badges(line.get_hash()) {
+							segments.push(LineSegment::new_with_color(
+								format!(" [{badge}]").as_str(),
+								DisplayColor::IndicatorColor,
+							));
+						}
+					}
+					let mut view_line = ViewLine::new_with_pinned_segments(segments, pinned_segments)
+						.set_selected(selected_index == index || selected_line);
 
 					if selected_index == index || selected_line {
 						view_line = view_line.set_selected(true).set_padding(' ');
@@ -514,11 +930,16 @@ impl List {
 					updater.push_trailing_line(ViewLine::from(search_line_segments));
 				}
 			}
+			// show as many of the applied lines as possible, when the first todo line is selected
+			if selected_index == 0 && done_lines_count > 0 {
+				updater.ensure_line_visible(0);
+			}
 			if visual_index != selected_index {
-				updater.ensure_line_visible(visual_index);
+				updater.ensure_line_visible(done_lines_count + visual_index);
 			}
-			updater.ensure_line_visible(selected_index);
+			updater.ensure_line_visible(done_lines_count + selected_index);
 		});
+		self.action_columns = action_columns;
 		&self.view_data
 	}
 
@@ -564,6 +985,7 @@ impl List {
 			e if key_bindings.action_edit.contains(&e) => Event::from(StandardEvent::ActionEdit),
 			e if key_bindings.action_fixup.contains(&e) => Event::from(StandardEvent::ActionFixup),
 			e if key_bindings.action_pick.contains(&e) => Event::from(StandardEvent::ActionPick),
+			e if key_bindings.action_revert.contains(&e) => Event::from(StandardEvent::ActionRevert),
 			e if key_bindings.action_reword.contains(&e) => Event::from(StandardEvent::ActionReword),
 			e if key_bindings.action_squash.contains(&e) => Event::from(StandardEvent::ActionSquash),
 			e if key_bindings.edit.contains(&e) => Event::from(StandardEvent::Edit),
@@ -583,8 +1005,10 @@ impl List {
 			e if key_bindings.move_up_step.contains(&e) => Event::from(StandardEvent::MoveCursorPageUp),
 			e if key_bindings.open_in_external_editor.contains(&e) => Event::from(StandardEvent::OpenInEditor),
 			e if key_bindings.rebase.contains(&e) => Event::from(StandardEvent::Rebase),
+			e if key_bindings.reload_config.contains(&e) => Event::from(StandardEvent::ReloadConfig),
 			e if key_bindings.remove_line.contains(&e) => Event::from(StandardEvent::Delete),
 			e if key_bindings.show_commit.contains(&e) => Event::from(StandardEvent::ShowCommit),
+			e if key_bindings.show_range_diff.contains(&e) => Event::from(StandardEvent::ShowRangeDiff),
 			e if key_bindings.toggle_visual_mode.contains(&e) => Event::from(StandardEvent::ToggleVisualMode),
 			Event::Mouse(mouse_event) => {
 				match mouse_event.kind {
@@ -593,7 +1017,19 @@ impl List {
 					_ => event,
 				}
 			},
-			_ => event,
+			_ => {
+				key_bindings
+					.custom_commands
+					.iter()
+					.position(|custom_command| custom_command.contains(&event))
+					.map(|index| Event::from(StandardEvent::CustomCommand(index)))
+					.or_else(|| {
+						self.plugin_state
+							.command_index(&event)
+							.map(|index| Event::from(StandardEvent::PluginCommand(index)))
+					})
+					.unwrap_or(event)
+			},
 		}
 	}
 
@@ -653,6 +1089,7 @@ impl List {
 					StandardEvent::ActionEdit => self.set_selected_line_action(Action::Edit),
 					StandardEvent::ActionFixup => self.set_selected_line_action(Action::Fixup),
 					StandardEvent::ActionPick => self.set_selected_line_action(Action::Pick),
+					StandardEvent::ActionRevert => self.set_selected_line_action(Action::Revert),
 					StandardEvent::ActionReword => self.set_selected_line_action(Action::Reword),
 					StandardEvent::ActionSquash => self.set_selected_line_action(Action::Squash),
 					StandardEvent::Delete => self.delete(),
@@ -687,9 +1124,11 @@ impl List {
 					StandardEvent::Redo => self.redo(),
 					StandardEvent::Undo => self.undo(),
 					StandardEvent::SearchStart => self.search_start(),
+					StandardEvent::PluginUpdate => self.apply_plugin_updates(&mut results),
 					_ => return None,
 				}
 			},
+			Event::Mouse(mouse_event) => self.handle_mouse_event(mouse_event),
 			Event::Resize(_, height) => self.resize(height),
 			_ => {},
 		}
@@ -710,6 +1149,12 @@ impl List {
 					StandardEvent::InsertLine => self.insert_line(&mut results),
 					StandardEvent::DuplicateLine => self.duplicate_line(),
 					StandardEvent::ShowCommit => self.show_commit(&mut results),
+					StandardEvent::ShowRangeDiff => results.state(State::RangeDiff),
+					StandardEvent::ReloadConfig => results.reload_config(),
+					StandardEvent::CustomCommand(index) => self.run_custom_command(index, &mut results),
+					StandardEvent::PluginCommand(index) => self.run_plugin_command(index),
+					StandardEvent::ExternalCommandSuccess => self.finish_custom_command(true, &mut results),
+					StandardEvent::ExternalCommandError => self.finish_custom_command(false, &mut results),
 					StandardEvent::FixupKeepMessage => self.toggle_option("-C"),
 					StandardEvent::FixupKeepMessageWithEditor => self.toggle_option("-c"),
 					_ => {},
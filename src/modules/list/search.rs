@@ -63,6 +63,7 @@ impl Searchable for Search {
 				| Action::Edit
 				| Action::Fixup
 				| Action::Pick
+				| Action::Revert
 				| Action::Reword
 				| Action::Squash
 				| Action::UpdateRef => line.get_hash().starts_with(term),
@@ -73,6 +74,7 @@ impl Searchable for Search {
 				| Action::Edit
 				| Action::Fixup
 				| Action::Pick
+				| Action::Revert
 				| Action::Reword
 				| Action::Squash
 				| Action::UpdateRef
@@ -1,13 +1,16 @@
 mod abort_and_rebase;
 mod activate;
 mod change_action;
+mod custom_command;
 mod duplicate_line;
 mod edit_mode;
 mod external_editor;
 mod help;
 mod insert_line;
+mod mouse;
 mod movement;
 mod normal_mode;
+mod plugin;
 mod read_event;
 mod remove_lines;
 mod render;
@@ -20,7 +23,44 @@ mod undo_redo;
 mod visual_mode;
 
 use super::*;
-use crate::test_helpers::{create_config, testers};
+use crate::{
+	test_helpers::{create_config, testers},
+	todo_file::TodoFileOptions,
+};
+
+fn set_sequencer_lines(test_context: &testers::ModuleTestContext, lines: &[&str]) {
+	let mut todo_file = TodoFile::new("sequencer/todo", TodoFileOptions::new(10, "#"));
+	todo_file.set_lines(lines.iter().map(|line| Line::parse(line).unwrap()).collect());
+	*test_context.app_data().todo_file().lock() = todo_file;
+}
+
+// the returned directory contains the rebase state files, and must be kept until the test completes
+fn set_rebase_progress_lines(
+	test_context: &testers::ModuleTestContext,
+	state_files: &[(&str, &str)],
+	lines: &[&str],
+) -> tempfile::TempDir {
+	let directory = tempfile::tempdir().unwrap();
+	for &(name, content) in state_files {
+		std::fs::write(directory.path().join(name), content).unwrap();
+	}
+	let todo_path = directory.path().join("git-rebase-todo");
+	std::fs::write(todo_path.as_path(), lines.join("\n")).unwrap();
+	let mut todo_file = TodoFile::new(todo_path, TodoFileOptions::new(10, "#"));
+	todo_file.load_file().unwrap();
+	*test_context.app_data().todo_file().lock() = todo_file;
+	directory
+}
+
+fn todo_lines(test_context: &testers::ModuleTestContext) -> Vec<String> {
+	test_context
+		.app_data()
+		.todo_file()
+		.lock()
+		.lines_iter()
+		.map(Line::to_text)
+		.collect()
+}
 
 #[test]
 fn resize() {
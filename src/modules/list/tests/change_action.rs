@@ -308,3 +308,99 @@ fn visual_mode_action_change_to_squash() {
 		},
 	);
 }
+
+#[test]
+fn normal_mode_action_change_to_revert_in_rebase() {
+	testers::module(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(StandardEvent::ActionRevert)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context), vec!["pick aaa c1", "pick bbb c2"]);
+		},
+	);
+}
+
+#[test]
+fn sequencer_supported_actions() {
+	testers::module(
+		&[],
+		&[
+			Event::from(StandardEvent::ActionDrop),
+			Event::from(StandardEvent::MoveCursorDown),
+			Event::from(StandardEvent::ActionDrop),
+			Event::from(StandardEvent::MoveCursorUp),
+			Event::from(StandardEvent::ActionPick),
+		],
+		None,
+		|mut test_context| {
+			set_sequencer_lines(&test_context, &["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context), vec![
+				"pick aaa c1",
+				"drop bbb c2",
+				"pick ccc c3"
+			]);
+		},
+	);
+}
+
+#[test]
+fn cherry_pick_sequencer_revert_unsupported() {
+	testers::module(
+		&[],
+		&[Event::from(StandardEvent::ActionRevert)],
+		None,
+		|mut test_context| {
+			set_sequencer_lines(&test_context, &["pick aaa c1", "pick bbb c2"]);
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context), vec!["pick aaa c1", "pick bbb c2"]);
+		},
+	);
+}
+
+#[test]
+fn revert_sequencer_pick_unsupported() {
+	testers::module(
+		&[],
+		&[
+			Event::from(StandardEvent::ActionPick),
+			Event::from(StandardEvent::MoveCursorDown),
+			Event::from(StandardEvent::ActionDrop),
+			Event::from(StandardEvent::ActionRevert),
+		],
+		None,
+		|mut test_context| {
+			set_sequencer_lines(&test_context, &["revert aaa c1", "revert bbb c2"]);
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context), vec!["revert aaa c1", "revert bbb c2"]);
+		},
+	);
+}
+
+#[test]
+fn sequencer_unsupported_actions() {
+	testers::module(
+		&[],
+		&[
+			Event::from(StandardEvent::ActionSquash),
+			Event::from(StandardEvent::ActionFixup),
+			Event::from(StandardEvent::ActionEdit),
+			Event::from(StandardEvent::ActionReword),
+			Event::from(StandardEvent::ActionBreak),
+		],
+		None,
+		|mut test_context| {
+			set_sequencer_lines(&test_context, &["pick aaa c1", "pick bbb c2"]);
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context), vec!["pick aaa c1", "pick bbb c2"]);
+			assert_eq!(test_context.app_data().todo_file().lock().get_selected_line_index(), 0);
+		},
+	);
+}
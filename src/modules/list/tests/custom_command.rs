@@ -0,0 +1,234 @@
+use claims::assert_none;
+
+use super::*;
+use crate::{
+	assert_rendered_output,
+	assert_results,
+	config::Config,
+	input::map_keybindings,
+	process::Artifact,
+	test_helpers::create_test_keybindings,
+};
+
+fn create_custom_command(reload: bool) -> CustomCommand {
+	CustomCommand {
+		name: String::from("review"),
+		key: vec![String::from("t")],
+		run: String::from("open-review"),
+		reload,
+	}
+}
+
+fn create_custom_command_config(reload: bool) -> Config {
+	let mut config = create_config();
+	config.key_bindings.custom_commands = vec![create_custom_command(reload)];
+	config
+}
+
+#[test]
+fn read_event() {
+	testers::module(&["pick aaa c1"], &[], None, |test_context| {
+		let module = List::new(&test_context.app_data());
+		let mut key_bindings = create_test_keybindings();
+		key_bindings.custom_commands = vec![map_keybindings(&[String::from("t")])];
+		assert_eq!(
+			module.read_event(Event::from('t'), &key_bindings),
+			Event::from(StandardEvent::CustomCommand(0))
+		);
+	});
+}
+
+#[test]
+fn run() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::CustomCommand(0))],
+		Some(create_custom_command_config(false)),
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::CustomCommand(0))),
+				Artifact::ExternalCommand((String::from("env"), vec![
+					String::from("INTERACTIVE_REBASE_TOOL_HASH=aaa"),
+					String::from("INTERACTIVE_REBASE_TOOL_ACTION=pick"),
+					String::from("INTERACTIVE_REBASE_TOOL_CONTENT=c1"),
+					String::from("sh"),
+					String::from("-c"),
+					String::from("open-review"),
+				]))
+			);
+		},
+	);
+}
+
+#[test]
+fn run_unknown_command() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::CustomCommand(1))],
+		Some(create_custom_command_config(false)),
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::CustomCommand(1)))
+			);
+			assert_none!(module.running_custom_command);
+		},
+	);
+}
+
+#[test]
+fn run_without_selected_line() {
+	testers::module(
+		&[],
+		&[Event::from(StandardEvent::CustomCommand(0))],
+		Some(create_custom_command_config(false)),
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::CustomCommand(0)))
+			);
+			assert_none!(module.running_custom_command);
+		},
+	);
+}
+
+#[test]
+fn success() {
+	testers::module(
+		&["pick aaa c1"],
+		&[
+			Event::from(StandardEvent::CustomCommand(0)),
+			Event::from(StandardEvent::ExternalCommandSuccess),
+		],
+		Some(create_custom_command_config(false)),
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_event(&mut module);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ExternalCommandSuccess))
+			);
+			assert_none!(module.running_custom_command);
+		},
+	);
+}
+
+#[test]
+fn success_with_reload() {
+	testers::module(
+		&["pick aaa c1"],
+		&[
+			Event::from(StandardEvent::CustomCommand(0)),
+			Event::from(StandardEvent::ActionDrop),
+			Event::from(StandardEvent::ExternalCommandSuccess),
+		],
+		Some(create_custom_command_config(true)),
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(
+				module.todo_file.lock().get_selected_line().unwrap().get_action(),
+				&Action::Pick
+			);
+		},
+	);
+}
+
+#[test]
+fn error() {
+	testers::module(
+		&["pick aaa c1"],
+		&[
+			Event::from(StandardEvent::CustomCommand(0)),
+			Event::from(StandardEvent::ExternalCommandError),
+		],
+		Some(create_custom_command_config(false)),
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_event(&mut module);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ExternalCommandError)),
+				Artifact::Error(anyhow!("Command \"review\" returned a non-zero exit status"), None)
+			);
+		},
+	);
+}
+
+#[test]
+fn external_command_event_without_custom_command() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::ExternalCommandError)],
+		Some(create_custom_command_config(false)),
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ExternalCommandError))
+			);
+		},
+	);
+}
+
+#[test]
+fn help() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::Help)],
+		Some(create_custom_command_config(false)),
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				" Key      Action{Pad( )}",
+				"{BODY}",
+				" Up      |Move selection up",
+				" Down    |Move selection down",
+				" PageUp  |Move selection up half a page",
+				" PageDown|Move selection down half a page",
+				" Home    |Move selection to top of the list",
+				" End     |Move selection to end of the list",
+				" Left    |Scroll content to the left",
+				" Right   |Scroll content to the right",
+				" q       |Abort interactive rebase",
+				" Q       |Immediately abort interactive rebase",
+				" w       |Write interactive rebase file",
+				" W       |Immediately write interactive rebase file",
+				" ?       |Show help",
+				" j       |Move selected lines down",
+				" k       |Move selected lines up",
+				" c       |Show commit information",
+				" R       |Show range diff of the original and edited commits",
+				" F5      |Reload the configuration",
+				" b       |Toggle break action",
+				" p       |Set selected commits to be picked",
+				" r       |Set selected commits to be reworded",
+				" e       |Set selected commits to be edited",
+				" s       |Set selected commits to be squashed",
+				" f       |Set selected commits to be fixed-up",
+				" d       |Set selected commits to be dropped",
+				" V       |Set selected commits to be reverted",
+				" E       |Edit an exec, label, reset or merge action's content",
+				" I       |Insert a new line",
+				" Controld|Duplicate selected line",
+				" Delete  |Completely remove the selected lines",
+				" Controlz|Undo the last change",
+				" Controly|Redo the previous undone change",
+				" !       |Open the todo file in the default editor",
+				" v       |Enter visual selection mode",
+				" t       |Run review",
+				"{TRAILING}",
+				"Press any key to close"
+			);
+		},
+	);
+}
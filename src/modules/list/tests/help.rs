@@ -34,6 +34,8 @@ fn normal_mode_help() {
 				" j       |Move selected lines down",
 				" k       |Move selected lines up",
 				" c       |Show commit information",
+				" R       |Show range diff of the original and edited commits",
+				" F5      |Reload the configuration",
 				" b       |Toggle break action",
 				" p       |Set selected commits to be picked",
 				" r       |Set selected commits to be reworded",
@@ -41,6 +43,7 @@ fn normal_mode_help() {
 				" s       |Set selected commits to be squashed",
 				" f       |Set selected commits to be fixed-up",
 				" d       |Set selected commits to be dropped",
+				" V       |Set selected commits to be reverted",
 				" E       |Edit an exec, label, reset or merge action's content",
 				" I       |Insert a new line",
 				" Controld|Duplicate selected line",
@@ -109,6 +112,7 @@ fn visual_mode_help() {
 				" s       |Set selected commits to be squashed",
 				" f       |Set selected commits to be fixed-up",
 				" d       |Set selected commits to be dropped",
+				" V       |Set selected commits to be reverted",
 				" Delete  |Completely remove the selected lines",
 				" Controlz|Undo the last change",
 				" Controly|Redo the previous undone change",
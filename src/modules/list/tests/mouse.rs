@@ -0,0 +1,381 @@
+use claims::{assert_none, assert_some_eq};
+
+use super::*;
+use crate::{action_line, assert_rendered_output, test_helpers::testers::ModuleTestContext};
+
+fn mouse_event(kind: MouseEventKind, column: u16, row: u16, modifiers: KeyModifiers) -> Event {
+	Event::Mouse(MouseEvent {
+		kind,
+		column,
+		row,
+		modifiers,
+	})
+}
+
+fn left_click(column: u16, row: u16) -> Event {
+	mouse_event(MouseEventKind::Down(MouseButton::Left), column, row, KeyModifiers::NONE)
+}
+
+// the title is rendered in the first row, so the first line is in the second row
+fn render(test_context: &ModuleTestContext, module: &mut List) {
+	let view_state = test_context.app_data().view_state();
+	view_state.resize(300, 120);
+	view_state.render(test_context.build_view_data(module));
+}
+
+#[test]
+fn click_selects_line() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[left_click(20, 3)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 2);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+fn click_outside_lines() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[left_click(20, 0), left_click(20, 10)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_event(&mut module);
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 0);
+		},
+	);
+}
+
+#[test]
+fn click_ends_visual_mode() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[Event::from(StandardEvent::ToggleVisualMode), left_click(20, 2)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 1);
+			assert_eq!(module.state, ListState::Normal);
+			assert_none!(module.visual_index_start);
+		},
+	);
+}
+
+#[test]
+fn shift_click_selects_range() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			left_click(20, 2),
+			mouse_event(MouseEventKind::Down(MouseButton::Left), 20, 3, KeyModifiers::SHIFT),
+		],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 2);
+			assert_eq!(module.state, ListState::Visual);
+			assert_some_eq!(module.visual_index_start, 1);
+		},
+	);
+}
+
+#[test]
+fn shift_click_extends_visual_range() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			Event::from(StandardEvent::ToggleVisualMode),
+			mouse_event(MouseEventKind::Down(MouseButton::Left), 20, 2, KeyModifiers::SHIFT),
+			mouse_event(MouseEventKind::Down(MouseButton::Left), 20, 3, KeyModifiers::SHIFT),
+		],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 2);
+			assert_some_eq!(module.visual_index_start, 0);
+		},
+	);
+}
+
+#[test]
+fn click_action_column_cycles_action() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2"],
+		&[left_click(5, 2), left_click(5, 2)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				Body test_context.build_view_data(&mut module),
+				action_line!(Pick "aaa", "c1"),
+				action_line!(Selected Edit "aaa", "c2")
+			);
+		},
+	);
+}
+
+#[test]
+fn click_action_column_static_action() {
+	testers::module(
+		&["pick aaa c1", "break"],
+		&[left_click(5, 2)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				Body test_context.build_view_data(&mut module),
+				action_line!(Pick "aaa", "c1"),
+				action_line!(Selected Break)
+			);
+		},
+	);
+}
+
+#[test]
+fn click_action_column_sequencer() {
+	testers::module(
+		&[],
+		&[left_click(5, 2), left_click(5, 2), left_click(5, 2)],
+		None,
+		|mut test_context| {
+			set_sequencer_lines(&test_context, &["revert aaa c1", "revert bbb c2"]);
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(todo_lines(&test_context), vec!["revert aaa c1", "drop bbb c2"]);
+		},
+	);
+}
+
+#[test]
+fn click_with_rebase_progress() {
+	testers::module(
+		&[],
+		&[left_click(20, 4), left_click(20, 2)],
+		None,
+		|mut test_context| {
+			let _directory = set_rebase_progress_lines(&test_context, &[("done", "pick aaa c1\n")], &[
+				"pick bbb c2",
+				"pick ccc c3",
+			]);
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_event(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 1);
+			// the second click is on the applied line, which cannot be selected
+			_ = test_context.handle_event(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+fn drag_moves_line() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			left_click(20, 1),
+			mouse_event(MouseEventKind::Drag(MouseButton::Left), 20, 3, KeyModifiers::NONE),
+			mouse_event(MouseEventKind::Up(MouseButton::Left), 20, 3, KeyModifiers::NONE),
+		],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert!(!module.dragging);
+			assert_rendered_output!(
+				Body test_context.build_view_data(&mut module),
+				action_line!(Pick "aaa", "c2"),
+				action_line!(Pick "aaa", "c3"),
+				action_line!(Selected Pick "aaa", "c1")
+			);
+		},
+	);
+}
+
+#[test]
+fn drag_moves_line_up() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			left_click(20, 3),
+			mouse_event(MouseEventKind::Drag(MouseButton::Left), 20, 2, KeyModifiers::NONE),
+		],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				Body test_context.build_view_data(&mut module),
+				action_line!(Pick "aaa", "c1"),
+				action_line!(Selected Pick "aaa", "c3"),
+				action_line!(Pick "aaa", "c2")
+			);
+		},
+	);
+}
+
+#[test]
+fn drag_without_click() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[mouse_event(
+			MouseEventKind::Drag(MouseButton::Left),
+			20,
+			3,
+			KeyModifiers::NONE,
+		)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				Body test_context.build_view_data(&mut module),
+				action_line!(Selected Pick "aaa", "c1"),
+				action_line!(Pick "aaa", "c2"),
+				action_line!(Pick "aaa", "c3")
+			);
+		},
+	);
+}
+
+#[test]
+fn other_mouse_button() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[mouse_event(
+			MouseEventKind::Down(MouseButton::Right),
+			20,
+			3,
+			KeyModifiers::NONE,
+		)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 0);
+		},
+	);
+}
+
+#[test]
+fn click_action_column_with_line_format() {
+	let mut config = create_config();
+	config.list_line_format = Some(LineFormat::parse("{hash:3} {action} {subject}").unwrap());
+	testers::module(
+		&["pick aaa c1", "pick aaa c2"],
+		&[left_click(2, 2), left_click(9, 2)],
+		Some(config),
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 1);
+			assert_eq!(
+				*module.todo_file.lock().get_line(1).unwrap().get_action(),
+				Action::Reword
+			);
+		},
+	);
+}
+
+#[test]
+fn click_action_column_with_variable_width_line_format() {
+	let mut config = create_config();
+	config.list_line_format = Some(LineFormat::parse("{author} {action} {subject}").unwrap());
+	testers::module(
+		&["pick aaa c1", "pick bbb c2"],
+		&[left_click(16, 2), left_click(5, 1)],
+		Some(config),
+		|mut test_context| {
+			let app_data = test_context.app_data().with_commit_authors(HashMap::from([
+				(String::from("aaa"), String::from("Al")),
+				(String::from("bbb"), String::from("Bartholomew")),
+			]));
+			let mut module = List::new(&app_data);
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 0);
+			assert_eq!(*module.todo_file.lock().get_line(0).unwrap().get_action(), Action::Pick);
+			assert_eq!(
+				*module.todo_file.lock().get_line(1).unwrap().get_action(),
+				Action::Reword
+			);
+		},
+	);
+}
+
+#[test]
+fn drag_undone_as_single_change() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			left_click(20, 1),
+			mouse_event(MouseEventKind::Drag(MouseButton::Left), 20, 2, KeyModifiers::NONE),
+			mouse_event(MouseEventKind::Drag(MouseButton::Left), 20, 3, KeyModifiers::NONE),
+			mouse_event(MouseEventKind::Up(MouseButton::Left), 20, 3, KeyModifiers::NONE),
+			Event::from(StandardEvent::Undo),
+		],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				Body test_context.build_view_data(&mut module),
+				action_line!(Selected Pick "aaa", "c1"),
+				action_line!(Selected Pick "aaa", "c2"),
+				action_line!(Selected Pick "aaa", "c3")
+			);
+		},
+	);
+}
+
+#[test]
+fn drag_ended_by_key() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			left_click(20, 1),
+			mouse_event(MouseEventKind::Drag(MouseButton::Left), 20, 2, KeyModifiers::NONE),
+			Event::from(StandardEvent::Undo),
+			mouse_event(MouseEventKind::Drag(MouseButton::Left), 20, 3, KeyModifiers::NONE),
+		],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert!(!module.dragging);
+			assert_rendered_output!(
+				Body test_context.build_view_data(&mut module),
+				action_line!(Selected Pick "aaa", "c1"),
+				action_line!(Selected Pick "aaa", "c2"),
+				action_line!(Pick "aaa", "c3")
+			);
+		},
+	);
+}
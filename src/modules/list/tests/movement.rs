@@ -1,9 +1,5 @@
 use super::*;
-use crate::{
-	action_line,
-	assert_rendered_output,
-	input::{KeyModifiers, MouseEvent},
-};
+use crate::{action_line, assert_rendered_output};
 
 #[test]
 fn move_down_1() {
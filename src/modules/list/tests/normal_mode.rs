@@ -40,6 +40,48 @@ fn toggle_visual_mode() {
 	);
 }
 
+#[test]
+fn reload_config() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::ReloadConfig)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ReloadConfig)),
+				Artifact::ReloadConfig
+			);
+		},
+	);
+}
+
+#[test]
+fn reload_keeps_state() {
+	testers::module(
+		&["pick aaa c1", "pick aaa c2"],
+		&[
+			Event::from(StandardEvent::MoveCursorDown),
+			Event::from(StandardEvent::ToggleVisualMode),
+		],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_all_events(&mut module);
+			let app_data = test_context.app_data();
+			let mut config = create_config();
+			config.auto_select_next = true;
+			app_data.set_config(config);
+			module.reload(&app_data);
+			assert!(module.auto_select_next);
+			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(module.visual_index_start, Some(1));
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 1);
+		},
+	);
+}
+
 #[test]
 fn other_event() {
 	testers::module(
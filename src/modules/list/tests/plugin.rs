@@ -0,0 +1,277 @@
+use anyhow::anyhow;
+
+use super::*;
+use crate::{assert_rendered_output, assert_results, plugin, process::Artifact, test_helpers::create_test_keybindings};
+
+fn create_plugin_state() -> plugin::State {
+	plugin::State::new(vec![String::from("lint")])
+}
+
+fn send_message(state: &plugin::State, method: &str, params: &str) {
+	_ = state.handle_message(
+		0,
+		format!(r#"{{"jsonrpc":"2.0","method":"{method}","params":{params}}}"#).as_str(),
+	);
+}
+
+fn register_command(state: &plugin::State) {
+	send_message(
+		state,
+		"registerCommand",
+		r#"{"name":"check","key":"t","description":"Check the commit"}"#,
+	);
+}
+
+fn plugin_line(line: &str) -> PluginLine {
+	PluginLine::from(&Line::parse(line).unwrap())
+}
+
+#[test]
+fn read_event() {
+	testers::module(&["pick aaa c1"], &[], None, |test_context| {
+		let state = create_plugin_state();
+		register_command(&state);
+		let module = List::new(&test_context.app_data().with_plugin_state(state));
+		assert_eq!(
+			module.read_event(Event::from('t'), &create_test_keybindings()),
+			Event::from(StandardEvent::PluginCommand(0))
+		);
+	});
+}
+
+#[test]
+fn read_event_custom_command_before_plugin_command() {
+	testers::module(&["pick aaa c1"], &[], None, |test_context| {
+		let state = create_plugin_state();
+		register_command(&state);
+		let module = List::new(&test_context.app_data().with_plugin_state(state));
+		let mut key_bindings = create_test_keybindings();
+		key_bindings.custom_commands = vec![crate::input::map_keybindings(&[String::from("t")])];
+		assert_eq!(
+			module.read_event(Event::from('t'), &key_bindings),
+			Event::from(StandardEvent::CustomCommand(0))
+		);
+	});
+}
+
+#[test]
+fn activate_notifies_plugins() {
+	testers::module(&["pick aaa c1", "pick bbb c2"], &[], None, |test_context| {
+		let state = create_plugin_state();
+		let mut module = List::new(&test_context.app_data().with_plugin_state(state.clone()));
+		_ = test_context.activate(&mut module, State::List);
+		assert_eq!(
+			state.receive_update(),
+			plugin::Action::TodoChanged(vec![plugin_line("pick aaa c1"), plugin_line("pick bbb c2")])
+		);
+		assert_eq!(state.receive_update(), plugin::Action::SelectionChanged {
+			index: 0,
+			line: plugin_line("pick aaa c1"),
+		});
+	});
+}
+
+#[test]
+fn selection_change_notifies_plugins() {
+	testers::module(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(StandardEvent::MoveCursorDown)],
+		None,
+		|mut test_context| {
+			let state = create_plugin_state();
+			let mut module = List::new(&test_context.app_data().with_plugin_state(state.clone()));
+			_ = test_context.activate(&mut module, State::List);
+			_ = state.receive_update();
+			_ = state.receive_update();
+			_ = test_context.handle_event(&mut module);
+			assert_eq!(state.receive_update(), plugin::Action::SelectionChanged {
+				index: 1,
+				line: plugin_line("pick bbb c2"),
+			});
+		},
+	);
+}
+
+#[test]
+fn run_command() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::PluginCommand(0))],
+		None,
+		|mut test_context| {
+			let state = create_plugin_state();
+			register_command(&state);
+			let mut module = List::new(&test_context.app_data().with_plugin_state(state.clone()));
+			_ = test_context.activate(&mut module, State::List);
+			_ = state.receive_update();
+			_ = state.receive_update();
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::PluginCommand(0)))
+			);
+			assert_eq!(state.receive_update(), plugin::Action::RunCommand {
+				plugin: 0,
+				command: String::from("check"),
+				index: 0,
+				line: plugin_line("pick aaa c1"),
+			});
+		},
+	);
+}
+
+#[test]
+fn apply_edit() {
+	testers::module(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(StandardEvent::PluginUpdate)],
+		None,
+		|mut test_context| {
+			let state = create_plugin_state();
+			send_message(&state, "proposeEdit", r#"{"index":1,"hash":"bbb","action":"fixup"}"#);
+			let mut module = List::new(&test_context.app_data().with_plugin_state(state));
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::PluginUpdate))
+			);
+			assert_eq!(todo_lines(&test_context), vec!["pick aaa c1", "fixup bbb c2"]);
+		},
+	);
+}
+
+#[test]
+fn apply_edit_content() {
+	testers::module(
+		&["exec echo foo"],
+		&[Event::from(StandardEvent::PluginUpdate)],
+		None,
+		|mut test_context| {
+			let state = create_plugin_state();
+			send_message(&state, "proposeEdit", r#"{"index":0,"hash":"","content":"echo bar"}"#);
+			let mut module = List::new(&test_context.app_data().with_plugin_state(state));
+			_ = test_context.handle_event(&mut module);
+			assert_eq!(todo_lines(&test_context), vec!["exec echo bar"]);
+		},
+	);
+}
+
+#[test]
+fn apply_edit_undo() {
+	testers::module(
+		&["pick aaa c1"],
+		&[
+			Event::from(StandardEvent::PluginUpdate),
+			Event::from(StandardEvent::Undo),
+		],
+		None,
+		|mut test_context| {
+			let state = create_plugin_state();
+			send_message(&state, "proposeEdit", r#"{"index":0,"hash":"aaa","action":"drop"}"#);
+			let mut module = List::new(&test_context.app_data().with_plugin_state(state));
+			_ = test_context.handle_event(&mut module);
+			assert_eq!(todo_lines(&test_context), vec!["drop aaa c1"]);
+			_ = test_context.handle_event(&mut module);
+			assert_eq!(todo_lines(&test_context), vec!["pick aaa c1"]);
+		},
+	);
+}
+
+#[test]
+fn apply_edit_hash_mismatch() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::PluginUpdate)],
+		None,
+		|mut test_context| {
+			let state = create_plugin_state();
+			send_message(&state, "proposeEdit", r#"{"index":0,"hash":"bbb","action":"drop"}"#);
+			let mut module = List::new(&test_context.app_data().with_plugin_state(state));
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::PluginUpdate)),
+				Artifact::Error(
+					anyhow!("Plugin \"lint\": The line at index 0 does not have the hash \"bbb\""),
+					None
+				)
+			);
+			assert_eq!(todo_lines(&test_context), vec!["pick aaa c1"]);
+		},
+	);
+}
+
+#[test]
+fn apply_edit_static_action() {
+	testers::module(
+		&["break"],
+		&[Event::from(StandardEvent::PluginUpdate)],
+		None,
+		|mut test_context| {
+			let state = create_plugin_state();
+			send_message(&state, "proposeEdit", r#"{"index":0,"hash":"","action":"drop"}"#);
+			let mut module = List::new(&test_context.app_data().with_plugin_state(state));
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::PluginUpdate)),
+				Artifact::Error(
+					anyhow!("Plugin \"lint\": The action of the line at index 0 cannot be changed to drop"),
+					None
+				)
+			);
+		},
+	);
+}
+
+#[test]
+fn apply_edit_content_not_editable() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::PluginUpdate)],
+		None,
+		|mut test_context| {
+			let state = create_plugin_state();
+			send_message(&state, "proposeEdit", r#"{"index":0,"hash":"aaa","content":"new"}"#);
+			let mut module = List::new(&test_context.app_data().with_plugin_state(state));
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::PluginUpdate)),
+				Artifact::Error(
+					anyhow!("Plugin \"lint\": The content of the line at index 0 cannot be changed"),
+					None
+				)
+			);
+		},
+	);
+}
+
+#[test]
+fn plugin_error() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::PluginUpdate)],
+		None,
+		|mut test_context| {
+			let state = create_plugin_state();
+			state.push_error(0, "The plugin exited");
+			let mut module = List::new(&test_context.app_data().with_plugin_state(state));
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::PluginUpdate)),
+				Artifact::Error(anyhow!("Plugin \"lint\": The plugin exited"), None)
+			);
+		},
+	);
+}
+
+#[test]
+fn render_badges() {
+	testers::module(&["pick aaa c1", "exec aaa"], &[], None, |test_context| {
+		let state = create_plugin_state();
+		send_message(&state, "annotate", r#"{"hash":"aaa","badge":"ok"}"#);
+		let mut module = List::new(&test_context.app_data().with_plugin_state(state));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Body view_data,
+			"{Selected} > pick   aaa      c1 [ok]{Pad( )}",
+			"   exec   aaa"
+		);
+	});
+}
@@ -1,7 +1,7 @@
 use rstest::rstest;
 
 use super::*;
-use crate::input::{KeyCode, KeyModifiers, MouseEvent};
+use crate::input::KeyCode;
 
 #[test]
 fn edit_mode_passthrough_event() {
@@ -46,6 +46,7 @@ fn search() {
 #[case::actionedit('e', StandardEvent::ActionEdit)]
 #[case::actionfixup('f', StandardEvent::ActionFixup)]
 #[case::actionpick('p', StandardEvent::ActionPick)]
+#[case::actionrevert('V', StandardEvent::ActionRevert)]
 #[case::actionreword('r', StandardEvent::ActionReword)]
 #[case::actionsquash('s', StandardEvent::ActionSquash)]
 #[case::edit('E', StandardEvent::Edit)]
@@ -75,6 +76,7 @@ fn default_events_single_char(#[case] binding: char, #[case] expected: StandardE
 #[case::movecursorup(KeyCode::Up, StandardEvent::MoveCursorUp)]
 #[case::movecursorpageup(KeyCode::PageUp, StandardEvent::MoveCursorPageUp)]
 #[case::delete(KeyCode::Delete, StandardEvent::Delete)]
+#[case::reloadconfig(KeyCode::F(5), StandardEvent::ReloadConfig)]
 fn default_events_special(#[case] code: KeyCode, #[case] expected: StandardEvent) {
 	testers::read_event(Event::from(code), None, |mut test_context| {
 		let module = List::new(&test_context.app_data());
@@ -186,3 +186,136 @@ fn full_with_short_actions() {
 		);
 	});
 }
+
+#[test]
+fn line_format() {
+	let mut config = create_config();
+	config.list_line_format = Some(LineFormat::parse("{action:>8} | {hash:4} {author:6}|{subject}").unwrap());
+	testers::module(
+		&[
+			"pick aaaaaaaa comment 1",
+			"fixup -c bbbbbbbb comment 2",
+			"exec echo 'foo'",
+			"break",
+		],
+		&[],
+		Some(config),
+		|test_context| {
+			let app_data = test_context
+				.app_data()
+				.with_commit_authors(HashMap::from([(String::from("aaaaaaaa"), String::from("Tim Smith"))]));
+			let mut module = List::new(&app_data);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Options AssertRenderOptions::INCLUDE_PINNED | AssertRenderOptions::INCLUDE_STYLE,
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Pin(2)}{Selected}{Normal} > {ActionPick}    pick{Normal} | aaaa Tim Sm|comment 1{Pad( )}",
+				"{Pin(2)}{Normal}   {ActionFixup}fixup -c{Normal} | bbbb       |comment 2",
+				"{Pin(2)}{Normal}   {ActionExec}    exec{Normal} |            |echo 'foo'",
+				"{Pin(2)}{Normal}   {ActionBreak}   break{Normal} |            |"
+			);
+		},
+	);
+}
+
+#[test]
+fn line_format_compact() {
+	let mut config = create_config();
+	config.list_line_format = Some(LineFormat::parse("{subject}").unwrap());
+	testers::module(&["pick aaaaaaaa comment 1"], &[], Some(config), |mut test_context| {
+		test_context.render_context.update(30, 300);
+		let mut module = List::new(&test_context.app_data());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Style view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{Selected}{Normal}>{ActionPick}p {Normal}aaa comment 1{Pad( )}"
+		);
+	});
+}
+
+#[test]
+fn line_format_without_action() {
+	let mut config = create_config();
+	config.list_line_format = Some(LineFormat::parse("{subject}").unwrap());
+	testers::module(&["pick aaaaaaaa comment 1"], &[], Some(config), |test_context| {
+		let mut module = List::new(&test_context.app_data());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Options AssertRenderOptions::INCLUDE_PINNED,
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{Pin(1)}{Selected} > comment 1{Pad( )}"
+		);
+	});
+}
+
+#[test]
+fn revert_action() {
+	testers::module(
+		&["pick aaaaaaaa comment 1", "revert bbbbbbbb comment 2"],
+		&[],
+		None,
+		|test_context| {
+			let mut module = List::new(&test_context.app_data());
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Style view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa comment 1{Pad( )}",
+				"{Normal}   {ActionRevert}revert {Normal}bbbbbbbb comment 2"
+			);
+		},
+	);
+}
+
+#[test]
+fn rebase_progress() {
+	testers::module(&[], &[], None, |test_context| {
+		let _directory = set_rebase_progress_lines(
+			&test_context,
+			&[
+				("done", "pick aaaaaaaa comment 1\nedit bbbbbbbb comment 2\n"),
+				("msgnum", "2"),
+				("end", "3"),
+				("stopped-sha", "bbbbbbbbcccccccc"),
+			],
+			&["pick cccccccc comment 3"],
+		);
+		let mut module = List::new(&test_context.app_data());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Style view_data,
+			"{TITLE}{HELP}",
+			"{LEADING}",
+			"{IndicatorColor}Rebase progress: 2/3, stopped at bbbbbbbb",
+			"{BODY}",
+			"{Normal}   {ActionPick,Dimmed}pick   {Normal,Dimmed}aaaaaaaa comment 1",
+			"{IndicatorColor} * {ActionEdit,Dimmed}edit   {Normal,Dimmed}bbbbbbbb comment 2",
+			"{Selected}{Normal} > {ActionPick}pick   {Normal}cccccccc comment 3{Pad( )}"
+		);
+	});
+}
+
+#[test]
+fn rebase_progress_without_position() {
+	testers::module(&[], &[], None, |test_context| {
+		let _directory = set_rebase_progress_lines(&test_context, &[("done", "exec make\n")], &["pick aaaaaaaa c1"]);
+		let mut module = List::new(&test_context.app_data());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Style view_data,
+			"{TITLE}{HELP}",
+			"{LEADING}",
+			"{IndicatorColor}Rebase in progress",
+			"{BODY}",
+			"{Normal}   {ActionExec,Dimmed}exec   {Normal,Dimmed}make",
+			"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa c1{Pad( )}"
+		);
+	});
+}
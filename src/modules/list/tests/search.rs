@@ -129,7 +129,7 @@ fn render_match_hash() {
 				Style test_context.build_view_data(),
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {IndicatorColor}aaaaaaaa{Normal} comment{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {SearchMatchColor}aaaaaaaa{Normal} comment{Pad( )}",
 				"{TRAILING}",
 				"{Normal}/aaa{Normal,Underline}"
 			);
@@ -147,7 +147,7 @@ fn render_match_content_start() {
 				Style test_context.build_view_data(),
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {IndicatorColor}com{Normal}ment{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {SearchMatchColor}com{Normal}ment{Pad( )}",
 				"{TRAILING}",
 				"{Normal}/com{Normal,Underline}"
 			);
@@ -165,7 +165,7 @@ fn render_match_content_middle() {
 				Style test_context.build_view_data(),
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa c{IndicatorColor}omm{Normal}ent{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa c{SearchMatchColor}omm{Normal}ent{Pad( )}",
 				"{TRAILING}",
 				"{Normal}/omm{Normal,Underline}"
 			);
@@ -183,7 +183,7 @@ fn render_match_content_end() {
 				Style test_context.build_view_data(),
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa comm{IndicatorColor}ent{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa comm{SearchMatchColor}ent{Normal}{Pad( )}",
 				"{TRAILING}",
 				"{Normal}/ent{Normal,Underline}"
 			);
@@ -201,7 +201,7 @@ fn render_match_content_full() {
 				Style test_context.build_view_data(),
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {IndicatorColor}comment{Normal}{Pad( )}",
+				"{Selected}{Normal} > {ActionPick}pick   {Normal}aaaaaaaa {SearchMatchColor}comment{Normal}{Pad( )}",
 				"{TRAILING}",
 				"{Normal}/comment{Normal,Underline}"
 			);
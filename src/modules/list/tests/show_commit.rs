@@ -49,3 +49,95 @@ fn do_not_when_hash_not_available() {
 		},
 	);
 }
+
+#[test]
+fn when_merge_with_commit() {
+	testers::module(
+		&["merge -C aaa label"],
+		&[Event::from(StandardEvent::ShowCommit)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ShowCommit)),
+				Artifact::ChangeState(State::ShowCommit)
+			);
+		},
+	);
+}
+
+#[test]
+fn do_not_when_merge_without_commit() {
+	testers::module(
+		&["merge label"],
+		&[Event::from(StandardEvent::ShowCommit)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ShowCommit))
+			);
+		},
+	);
+}
+
+#[test]
+fn when_update_ref() {
+	testers::module(
+		&["pick aaa c1", "update-ref refs/heads/branch"],
+		&[
+			Event::from(StandardEvent::MoveCursorDown),
+			Event::from(StandardEvent::ShowCommit),
+		],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_event(&mut module);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ShowCommit)),
+				Artifact::ChangeState(State::ShowCommit)
+			);
+		},
+	);
+}
+
+#[test]
+fn when_label_resolves() {
+	testers::module(
+		&["pick aaa c1", "label l1"],
+		&[
+			Event::from(StandardEvent::MoveCursorDown),
+			Event::from(StandardEvent::ShowCommit),
+		],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			_ = test_context.handle_event(&mut module);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ShowCommit)),
+				Artifact::ChangeState(State::ShowCommit)
+			);
+		},
+	);
+}
+
+#[test]
+fn show_range_diff() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::ShowRangeDiff)],
+		None,
+		|mut test_context| {
+			let mut module = List::new(&test_context.app_data());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ShowRangeDiff)),
+				Artifact::ChangeState(State::RangeDiff)
+			);
+		},
+	);
+}
@@ -1,12 +1,13 @@
-use std::cmp;
+use std::{cmp, ops::Range};
 
 use bitflags::bitflags;
 use if_chain::if_chain;
 
 use crate::{
-	config::KeyBindings,
+	config::{KeyBindings, LineFormat, LineFormatField, LineFormatPart, align_value},
 	display::DisplayColor,
 	modules::list::search::LineMatch,
+	plugin::PluginCommand,
 	todo_file::{Action, Line, TodoFile},
 	view::{LineSegment, LineSegmentOptions},
 };
@@ -92,6 +93,16 @@ fn build_help_lines(key_bindings: &KeyBindings, selector: HelpLinesSelector) ->
 			"Show commit information",
 			HelpLinesSelector::Normal,
 		),
+		(
+			&key_bindings.show_range_diff,
+			"Show range diff of the original and edited commits",
+			HelpLinesSelector::Normal,
+		),
+		(
+			&key_bindings.reload_config,
+			"Reload the configuration",
+			HelpLinesSelector::Normal,
+		),
 		(
 			&key_bindings.action_break,
 			"Toggle break action",
@@ -127,6 +138,11 @@ fn build_help_lines(key_bindings: &KeyBindings, selector: HelpLinesSelector) ->
 			"Set selected commits to be dropped",
 			HelpLinesSelector::Common,
 		),
+		(
+			&key_bindings.action_revert,
+			"Set selected commits to be reverted",
+			HelpLinesSelector::Common,
+		),
 		(
 			&key_bindings.edit,
 			"Edit an exec, label, reset or merge action's content",
@@ -180,13 +196,38 @@ fn build_help_lines(key_bindings: &KeyBindings, selector: HelpLinesSelector) ->
 }
 
 pub(super) fn get_list_normal_mode_help_lines(key_bindings: &KeyBindings) -> Vec<(Vec<String>, String)> {
-	build_help_lines(key_bindings, HelpLinesSelector::Normal)
+	let mut lines = build_help_lines(key_bindings, HelpLinesSelector::Normal);
+	lines.extend(
+		key_bindings
+			.custom_commands
+			.iter()
+			.map(|custom_command| (custom_command.key.clone(), format!("Run {}", custom_command.name))),
+	);
+	lines
+}
+
+pub(super) fn get_plugin_command_help_lines(commands: &[PluginCommand]) -> Vec<(Vec<String>, String)> {
+	commands
+		.iter()
+		.map(|command| (command.key.clone(), command.description.clone()))
+		.collect()
 }
 
 pub(super) fn get_list_visual_mode_help_lines(key_bindings: &KeyBindings) -> Vec<(Vec<String>, String)> {
 	build_help_lines(key_bindings, HelpLinesSelector::Visual)
 }
 
+/// The single character shown for an action in the compact layout. Git has no abbreviation for `revert`, so the
+/// character is only used for display, and is never written to the todo file.
+fn get_action_abbreviation(action: Action) -> String {
+	if action == Action::Revert {
+		String::from("v")
+	}
+	else {
+		action.to_abbreviation()
+	}
+}
+
 const fn get_action_color(action: Action) -> DisplayColor {
 	match action {
 		Action::Break => DisplayColor::ActionBreak,
@@ -195,6 +236,7 @@ const fn get_action_color(action: Action) -> DisplayColor {
 		Action::Exec => DisplayColor::ActionExec,
 		Action::Fixup => DisplayColor::ActionFixup,
 		Action::Pick => DisplayColor::ActionPick,
+		Action::Revert => DisplayColor::ActionRevert,
 		Action::Reword => DisplayColor::ActionReword,
 		Action::Squash => DisplayColor::ActionSquash,
 		Action::Label => DisplayColor::ActionLabel,
@@ -208,8 +250,11 @@ const fn get_action_color(action: Action) -> DisplayColor {
 
 pub(super) fn get_line_action_maximum_width(todo_file: &TodoFile) -> usize {
 	let mut max_width = 0;
+	let done_lines = todo_file
+		.rebase_progress()
+		.map_or(&[][..], |rebase_progress| rebase_progress.done_lines());
 
-	for line in todo_file.lines_iter() {
+	for line in todo_file.lines_iter().chain(done_lines) {
 		let action_length = match line.get_action() {
 			// allow these to overflow their bounds
 			&Action::Exec | &Action::UpdateRef => 0,
@@ -223,7 +268,7 @@ pub(super) fn get_line_action_maximum_width(todo_file: &TodoFile) -> usize {
 					5
 				}
 			},
-			&Action::Reword | &Action::Squash => 6,
+			&Action::Revert | &Action::Reword | &Action::Squash => 6,
 		};
 		if max_width < action_length {
 			max_width = action_length;
@@ -243,25 +288,12 @@ bitflags! {
 	}
 }
 
-#[expect(
-	clippy::string_slice,
-	reason = "Safe slice, as it is only on the hash, which is hexadecimal"
-)]
-pub(super) fn get_todo_line_segments(
-	line: &Line,
-	search_term: Option<&str>,
-	search_match: Option<LineMatch>,
-	options: TodoLineSegmentsOptions,
-	maximum_action_width: usize,
-) -> Vec<LineSegment> {
-	let mut segments: Vec<LineSegment> = vec![];
+pub(super) const FORMAT_HASH_WIDTH: usize = 8;
 
+fn get_indicator_segment(options: TodoLineSegmentsOptions) -> LineSegment {
 	let is_cursor_line = options.contains(TodoLineSegmentsOptions::CURSOR_LINE);
 	let selected = options.contains(TodoLineSegmentsOptions::SELECTED);
 	let is_full_width = options.contains(TodoLineSegmentsOptions::FULL_WIDTH);
-	let is_search_index = options.contains(TodoLineSegmentsOptions::SEARCH_LINE);
-
-	let action = line.get_action();
 
 	let indicator = if is_cursor_line || selected {
 		if is_full_width { " > " } else { ">" }
@@ -273,26 +305,102 @@ pub(super) fn get_todo_line_segments(
 		" "
 	};
 
-	segments.push(LineSegment::new_with_color_and_style(
+	LineSegment::new_with_color_and_style(
 		indicator,
 		DisplayColor::Normal,
 		LineSegmentOptions::conditional(!is_cursor_line && selected, LineSegmentOptions::DIMMED),
-	));
+	)
+}
 
-	let action_padding = cmp::max(maximum_action_width, 6);
+fn get_action_name(line: &Line) -> String {
+	if let Some(opt) = line.option() {
+		format!("{} {opt}", line.get_action())
+	}
+	else {
+		line.get_action().to_string()
+	}
+}
 
-	let action_name = if is_full_width {
-		if let Some(opt) = line.option() {
-			format!("{:action_padding$} ", format!("{action} {opt}"))
+fn get_hash_segment(hash: &str, search_match: Option<LineMatch>, options: TodoLineSegmentsOptions) -> LineSegment {
+	let search_hash_match = search_match.is_some_and(|m| m.hash());
+	LineSegment::new_with_color_and_style(
+		hash,
+		if search_hash_match {
+			DisplayColor::SearchMatchColor
 		}
 		else {
-			format!("{:action_padding$} ", action.to_string())
+			DisplayColor::Normal
+		},
+		LineSegmentOptions::conditional(
+			search_hash_match && options.contains(TodoLineSegmentsOptions::SEARCH_LINE),
+			LineSegmentOptions::UNDERLINED,
+		),
+	)
+}
+
+fn push_content_segments(
+	segments: &mut Vec<LineSegment>,
+	content: &str,
+	search_term: Option<&str>,
+	search_match: Option<LineMatch>,
+	options: TodoLineSegmentsOptions,
+) {
+	if content.is_empty() {
+		return;
+	}
+	let search_content_match = search_match.is_some_and(|m| m.content());
+	if_chain! {
+		if search_content_match;
+		if let Some(term) = search_term;
+		then {
+			let mut split_iter = content.split(term);
+			segments.push(LineSegment::new(split_iter.next().unwrap()));
+			for split in split_iter {
+				segments.push(LineSegment::new_with_color_and_style(
+					term,
+					DisplayColor::SearchMatchColor,
+					LineSegmentOptions::conditional(
+						options.contains(TodoLineSegmentsOptions::SEARCH_LINE),
+						LineSegmentOptions::UNDERLINED,
+					),
+				));
+				if !split.is_empty() {
+					segments.push(LineSegment::new(split));
+				}
+			}
+		}
+		else {
+			segments.push(LineSegment::new(content));
 		}
 	}
+}
+
+#[expect(
+	clippy::string_slice,
+	reason = "Safe slice, as it is only on the hash, which is hexadecimal"
+)]
+pub(super) fn get_todo_line_segments(
+	line: &Line,
+	search_term: Option<&str>,
+	search_match: Option<LineMatch>,
+	options: TodoLineSegmentsOptions,
+	maximum_action_width: usize,
+) -> Vec<LineSegment> {
+	let mut segments: Vec<LineSegment> = vec![get_indicator_segment(options)];
+
+	let is_full_width = options.contains(TodoLineSegmentsOptions::FULL_WIDTH);
+
+	let action = line.get_action();
+
+	let action_padding = cmp::max(maximum_action_width, 6);
+
+	let action_name = if is_full_width {
+		format!("{:action_padding$} ", get_action_name(line))
+	}
 	else {
 		format!(
 			"{:1}{}",
-			action.to_abbreviation(),
+			get_action_abbreviation(*action),
 			if line.option().is_some() { "*" } else { " " }
 		)
 	};
@@ -304,25 +412,25 @@ pub(super) fn get_todo_line_segments(
 
 	// render hash
 	match *action {
-		Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
+		Action::Drop
+		| Action::Edit
+		| Action::Fixup
+		| Action::Pick
+		| Action::Revert
+		| Action::Reword
+		| Action::Squash => {
 			let action_width = if is_full_width { 8 } else { 3 };
 			let max_index = cmp::min(line.get_hash().len(), action_width);
-			let search_hash_match = search_match.is_some_and(|m| m.hash());
 
-			segments.push(LineSegment::new_with_color_and_style(
+			segments.push(get_hash_segment(
 				format!(
 					"{:width$}",
 					line.get_hash()[0..max_index].to_string(), // safe slice, ascii only
 					width = action_width
 				)
 				.as_str(),
-				if search_hash_match {
-					DisplayColor::IndicatorColor
-				}
-				else {
-					DisplayColor::Normal
-				},
-				LineSegmentOptions::conditional(search_hash_match && is_search_index, LineSegmentOptions::UNDERLINED),
+				search_match,
+				options,
 			));
 			segments.push(LineSegment::new(" "));
 		},
@@ -335,30 +443,119 @@ pub(super) fn get_todo_line_segments(
 		| Action::UpdateRef => {},
 	}
 
-	let content = line.get_content();
-	if !content.is_empty() {
-		let search_content_match = search_match.is_some_and(|m| m.content());
-		if_chain! {
-			if search_content_match;
-			if let Some(term) = search_term;
-			then {
-				let mut split_iter = content.split(term);
-				segments.push(LineSegment::new(split_iter.next().unwrap()));
-				for split in split_iter {
-					segments.push(LineSegment::new_with_color_and_style(
-						term,
-						DisplayColor::IndicatorColor,
-						LineSegmentOptions::conditional(is_search_index, LineSegmentOptions::UNDERLINED),
-					));
-					if !split.is_empty() {
-						segments.push(LineSegment::new(split));
-					}
+	push_content_segments(&mut segments, line.get_content(), search_term, search_match, options);
+	segments
+}
+
+/// Get the columns of the segment at the index, from the width of the segments before it.
+pub(super) fn get_segment_columns(segments: &[LineSegment], index: usize) -> Range<usize> {
+	let start = segments.iter().take(index).map(LineSegment::get_length).sum();
+	start..start + segments.get(index).map_or(0, LineSegment::get_length)
+}
+
+/// Get the segments of a todo line, using a line format, and the number of leading segments that are pinned. The
+/// pinned segments are the indicator, and the segments up to and including the action.
+pub(super) fn get_formatted_todo_line_segments(
+	line: &Line,
+	author: Option<&str>,
+	line_format: &LineFormat,
+	search_term: Option<&str>,
+	search_match: Option<LineMatch>,
+	options: TodoLineSegmentsOptions,
+	maximum_action_width: usize,
+) -> (Vec<LineSegment>, usize) {
+	let mut segments: Vec<LineSegment> = vec![get_indicator_segment(options)];
+	let mut pinned_segments = 1;
+
+	for part in line_format.parts() {
+		match *part {
+			LineFormatPart::Text(ref text) => segments.push(LineSegment::new(text.as_str())),
+			LineFormatPart::Field {
+				field,
+				alignment,
+				width,
+			} => {
+				match field {
+					LineFormatField::Action => {
+						let width = width.unwrap_or_else(|| cmp::max(maximum_action_width, 6));
+						segments.push(LineSegment::new_with_color(
+							align_value(get_action_name(line).as_str(), alignment, width).as_str(),
+							get_action_color(*line.get_action()),
+						));
+						pinned_segments = segments.len();
+					},
+					LineFormatField::Hash => {
+						let width = width.unwrap_or(FORMAT_HASH_WIDTH);
+						segments.push(get_hash_segment(
+							align_value(line.get_hash(), alignment, width).as_str(),
+							search_match,
+							options,
+						));
+					},
+					LineFormatField::Author => {
+						let author = author.unwrap_or_default();
+						let author = match width {
+							Some(width) => align_value(author, alignment, width),
+							None => String::from(author),
+						};
+						if !author.is_empty() {
+							segments.push(LineSegment::new(author.as_str()));
+						}
+					},
+					LineFormatField::Subject => {
+						let content = match width {
+							Some(width) => align_value(line.get_content(), alignment, width),
+							None => String::from(line.get_content()),
+						};
+						push_content_segments(&mut segments, content.as_str(), search_term, search_match, options);
+					},
 				}
+			},
+		}
+	}
+	(segments, pinned_segments)
+}
+
+/// Get the segments of a line that has already been applied by the rebase, and the number of leading segments that
+/// are pinned. Applied lines are read-only, so are rendered dimmed, with the line the rebase stopped at marked.
+pub(super) fn get_done_line_segments(
+	line: &Line,
+	author: Option<&str>,
+	line_format: Option<&LineFormat>,
+	is_stopped: bool,
+	options: TodoLineSegmentsOptions,
+	maximum_action_width: usize,
+) -> (Vec<LineSegment>, usize) {
+	let (segments, pinned_segments) = if let Some(format) = line_format {
+		get_formatted_todo_line_segments(line, author, format, None, None, options, maximum_action_width)
+	}
+	else {
+		(
+			get_todo_line_segments(line, None, None, options, maximum_action_width),
+			if line.has_reference() { 2 } else { 3 },
+		)
+	};
+
+	let indicator = if !is_stopped {
+		LineSegment::new(
+			if options.contains(TodoLineSegmentsOptions::FULL_WIDTH) {
+				"   "
 			}
 			else {
-				segments.push(LineSegment::new(content));
-			}
-		}
+				" "
+			},
+		)
 	}
-	segments
+	else if options.contains(TodoLineSegmentsOptions::FULL_WIDTH) {
+		LineSegment::new_with_color(" * ", DisplayColor::IndicatorColor)
+	}
+	else {
+		LineSegment::new_with_color("*", DisplayColor::IndicatorColor)
+	};
+
+	let mut done_segments = vec![indicator];
+	done_segments.extend(segments.iter().skip(1).map(|segment| {
+		LineSegment::new_with_color_and_style(segment.get_content(), segment.get_color(), LineSegmentOptions::DIMMED)
+	}));
+	(done_segments, pinned_segments)
 }
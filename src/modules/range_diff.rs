@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests;
+mod util;
+
+use std::sync::Arc;
+
+use git2::ErrorCode;
+use parking_lot::Mutex;
+
+use self::util::{edited_series, get_range_diff_entry_line, get_range_diff_help_lines, original_series};
+use crate::{
+	application::AppData,
+	components::help::Help,
+	diff::{self, thread::RangeDiffLoadStatus},
+	display::DisplayColor,
+	input::{Event, InputOptions, KeyBindings, StandardEvent},
+	module::{Module, State},
+	process::Results,
+	select,
+	todo_file::TodoFile,
+	util::handle_view_data_scroll,
+	view::{self, LineSegment, RenderContext, ViewData, ViewLine},
+};
+
+// TODO Remove `union` call when bitflags/bitflags#180 is resolved
+const INPUT_OPTIONS: InputOptions = InputOptions::RESIZE
+	.union(InputOptions::MOVEMENT)
+	.union(InputOptions::HELP)
+	.union(InputOptions::KEY_SEQUENCES);
+
+pub(crate) struct RangeDiff {
+	diff_state: diff::thread::State,
+	help: Help,
+	todo_file: Arc<Mutex<TodoFile>>,
+	view_data: ViewData,
+	view_state: view::State,
+}
+
+impl Module for RangeDiff {
+	fn activate(&mut self, _: State) -> Results {
+		let mut results = Results::new();
+		let todo_file = self.todo_file.lock();
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+		});
+		self.diff_state.set_range_diff_load_status(RangeDiffLoadStatus::Loading);
+		results.load_range_diff(original_series(&todo_file), edited_series(&todo_file));
+		results
+	}
+
+	fn build_view_data(&mut self, _: &RenderContext) -> &ViewData {
+		if self.help.is_active() {
+			return self.help.get_view_data();
+		}
+
+		let load_status = self.diff_state.range_diff_load_status();
+		let range_diff_arc = self.diff_state.range_diff();
+		let range_diff = range_diff_arc.read();
+
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			match load_status {
+				RangeDiffLoadStatus::New | RangeDiffLoadStatus::Loading => {
+					updater.push_line(ViewLine::from("Loading Range Diff"));
+				},
+				RangeDiffLoadStatus::Error { code, msg } => {
+					updater.push_line(ViewLine::from(LineSegment::new_with_color(
+						"Error loading range diff. Press any key to return.",
+						DisplayColor::IndicatorColor,
+					)));
+					updater.push_line(ViewLine::from(""));
+					updater.push_line(ViewLine::from("Reason:"));
+					updater.push_line(ViewLine::from(match code {
+						ErrorCode::NotFound => "Commit not found",
+						_ => msg.as_str(),
+					}));
+				},
+				RangeDiffLoadStatus::Complete => {
+					if range_diff.is_empty() {
+						updater.push_line(ViewLine::from("No commits to compare"));
+						return;
+					}
+					let max_position = range_diff
+						.entries()
+						.iter()
+						.filter_map(|e| e.original_position().max(e.edited_position()))
+						.max()
+						.unwrap_or(0);
+					let position_width = (max_position + 1).to_string().len();
+					for entry in range_diff.entries() {
+						updater.push_line(get_range_diff_entry_line(entry, position_width));
+					}
+				},
+			}
+		});
+		&self.view_data
+	}
+
+	fn input_options(&self) -> &InputOptions {
+		select!(default & INPUT_OPTIONS, self.help.input_options())
+	}
+
+	fn read_event(&self, event: Event, _: &KeyBindings) -> Event {
+		select!(default event, self.help.read_event(event))
+	}
+
+	fn handle_event(&mut self, event: Event) -> Results {
+		select!(
+			default {
+				let mut results = Results::new();
+				match event {
+					Event::Standard(StandardEvent::Help) => self.help.set_active(),
+					Event::Key(_) => results.state(State::List),
+					_ => {},
+				}
+				results
+			},
+			self.help.handle_event(event, &self.view_state),
+			handle_view_data_scroll(event, &self.view_state)
+		)
+	}
+
+	fn reload(&mut self, app_data: &AppData) {
+		self.help = Help::new_from_keybindings(&get_range_diff_help_lines(&app_data.config().key_bindings));
+	}
+}
+
+impl RangeDiff {
+	pub(crate) fn new(app_data: &AppData) -> Self {
+		let config = app_data.config();
+		Self {
+			diff_state: app_data.diff_state(),
+			help: Help::new_from_keybindings(&get_range_diff_help_lines(&config.key_bindings)),
+			todo_file: app_data.todo_file(),
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+				updater.set_show_help(true);
+			}),
+			view_state: app_data.view_state(),
+		}
+	}
+}
@@ -0,0 +1,278 @@
+use super::*;
+use crate::{
+	assert_rendered_output,
+	assert_results,
+	diff::{RangeDiffCommit, RangeDiffEntry, RangeDiffStatus},
+	process::Artifact,
+	test_helpers::{assertions::assert_rendered_output::AssertRenderOptions, testers},
+	todo_file::{Action, EditContext},
+};
+
+fn set_range_diff(module: &RangeDiff, entries: Vec<RangeDiffEntry>) {
+	*module.diff_state.range_diff().write() = diff::RangeDiff::from(entries);
+	module
+		.diff_state
+		.set_range_diff_load_status(RangeDiffLoadStatus::Complete);
+}
+
+#[test]
+fn activate_loads_range_diff() {
+	testers::module(&["pick aaa c1", "pick bbb c2"], &[], None, |test_context| {
+		let mut module = RangeDiff::new(&test_context.app_data());
+		assert_results!(
+			test_context.activate(&mut module, State::List),
+			Artifact::LoadRangeDiff(vec![String::from("aaa"), String::from("bbb")], vec![
+				RangeDiffCommit::new("aaa"),
+				RangeDiffCommit::new("bbb")
+			])
+		);
+		assert_eq!(module.diff_state.range_diff_load_status(), RangeDiffLoadStatus::Loading);
+	});
+}
+
+#[test]
+fn activate_with_edited_lines() {
+	testers::module(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3", "exec make", "pick ddd c4"],
+		&[],
+		None,
+		|test_context| {
+			{
+				let todo_file = test_context.app_data().todo_file();
+				let mut todo_file = todo_file.lock();
+				todo_file.update_range(0, 0, &EditContext::new().action(Action::Reword));
+				todo_file.update_range(1, 1, &EditContext::new().action(Action::Fixup));
+				todo_file.update_range(2, 2, &EditContext::new().action(Action::Drop));
+				_ = todo_file.swap_range_up(4, 4);
+			}
+			let mut module = RangeDiff::new(&test_context.app_data());
+			let mut reworded = RangeDiffCommit::new("aaa");
+			reworded.set_reworded();
+			reworded.push_squashed("bbb");
+			assert_results!(
+				test_context.activate(&mut module, State::List),
+				Artifact::LoadRangeDiff(
+					vec![
+						String::from("aaa"),
+						String::from("bbb"),
+						String::from("ccc"),
+						String::from("ddd")
+					],
+					vec![reworded, RangeDiffCommit::new("ddd")]
+				)
+			);
+		},
+	);
+}
+
+#[test]
+fn activate_with_leading_fixup() {
+	testers::module(&["fixup aaa c1", "squash bbb c2"], &[], None, |test_context| {
+		let mut module = RangeDiff::new(&test_context.app_data());
+		let mut commit = RangeDiffCommit::new("aaa");
+		commit.push_squashed("bbb");
+		assert_results!(
+			test_context.activate(&mut module, State::List),
+			Artifact::LoadRangeDiff(vec![String::from("aaa"), String::from("bbb")], vec![commit])
+		);
+	});
+}
+
+#[test]
+fn render_loading() {
+	testers::module(&["pick aaa c1"], &[], None, |test_context| {
+		let mut module = RangeDiff::new(&test_context.app_data());
+		assert_rendered_output!(
+			Options AssertRenderOptions::BODY_ONLY,
+			test_context.build_view_data(&mut module),
+			"Loading Range Diff"
+		);
+	});
+}
+
+#[test]
+fn render_error() {
+	testers::module(&["pick aaa c1"], &[], None, |test_context| {
+		let mut module = RangeDiff::new(&test_context.app_data());
+		module
+			.diff_state
+			.set_range_diff_load_status(RangeDiffLoadStatus::Error {
+				msg: String::from("Something went wrong"),
+				code: ErrorCode::GenericError,
+			});
+		assert_rendered_output!(
+			Options AssertRenderOptions::BODY_ONLY,
+			test_context.build_view_data(&mut module),
+			"Error loading range diff. Press any key to return.",
+			"",
+			"Reason:",
+			"Something went wrong"
+		);
+	});
+}
+
+#[test]
+fn render_error_not_found() {
+	testers::module(&["pick aaa c1"], &[], None, |test_context| {
+		let mut module = RangeDiff::new(&test_context.app_data());
+		module
+			.diff_state
+			.set_range_diff_load_status(RangeDiffLoadStatus::Error {
+				msg: String::from("Something went wrong"),
+				code: ErrorCode::NotFound,
+			});
+		assert_rendered_output!(
+			Options AssertRenderOptions::BODY_ONLY,
+			test_context.build_view_data(&mut module),
+			"Error loading range diff. Press any key to return.",
+			"",
+			"Reason:",
+			"Commit not found"
+		);
+	});
+}
+
+#[test]
+fn render_empty() {
+	testers::module(&[], &[], None, |test_context| {
+		let mut module = RangeDiff::new(&test_context.app_data());
+		set_range_diff(&module, vec![]);
+		assert_rendered_output!(
+			Options AssertRenderOptions::BODY_ONLY,
+			test_context.build_view_data(&mut module),
+			"No commits to compare"
+		);
+	});
+}
+
+#[test]
+fn render_entries() {
+	testers::module(&["pick aaa c1"], &[], None, |test_context| {
+		let mut module = RangeDiff::new(&test_context.app_data());
+		set_range_diff(&module, vec![
+			RangeDiffEntry::new(
+				RangeDiffStatus::Unchanged,
+				Some((0, "0123456789abcdef")),
+				Some((0, "0123456789abcdef")),
+				"Unchanged",
+			),
+			RangeDiffEntry::new(RangeDiffStatus::Dropped, Some((1, "1123456789abcdef")), None, "Dropped"),
+			RangeDiffEntry::new(
+				RangeDiffStatus::Modified,
+				Some((2, "2123456789abcdef")),
+				Some((1, "2123456789abcdef")),
+				"Modified",
+			),
+			RangeDiffEntry::new(RangeDiffStatus::Added, None, Some((2, "3123456789abcdef")), "Added"),
+		]);
+		assert_rendered_output!(
+			Options AssertRenderOptions::INCLUDE_STYLE | AssertRenderOptions::BODY_ONLY,
+			test_context.build_view_data(&mut module),
+			"{Normal}1:  0123456 = 1:  0123456 Unchanged",
+			"{Normal}2:  1123456{DiffRemoveColor} < {Normal}-:  ------- {DiffRemoveColor}Dropped",
+			"{Normal}3:  2123456{DiffChangeColor} ! {Normal}2:  2123456 {DiffChangeColor}Modified",
+			"{Normal}-:  -------{DiffAddColor} > {Normal}3:  3123456 {DiffAddColor}Added"
+		);
+	});
+}
+
+#[test]
+fn render_entries_padded_positions() {
+	testers::module(&["pick aaa c1"], &[], None, |test_context| {
+		let mut module = RangeDiff::new(&test_context.app_data());
+		set_range_diff(&module, vec![RangeDiffEntry::new(
+			RangeDiffStatus::Unchanged,
+			Some((0, "aaa")),
+			Some((9, "aaa")),
+			"C1",
+		)]);
+		assert_rendered_output!(
+			Options AssertRenderOptions::BODY_ONLY,
+			test_context.build_view_data(&mut module),
+			" 1:  aaa     = 10:  aaa     C1"
+		);
+	});
+}
+
+#[test]
+fn handle_event_other_key() {
+	testers::module(&["pick aaa c1"], &[Event::from('a')], None, |mut test_context| {
+		let mut module = RangeDiff::new(&test_context.app_data());
+		assert_results!(
+			test_context.handle_event(&mut module),
+			Artifact::Event(Event::from('a')),
+			Artifact::ChangeState(State::List)
+		);
+	});
+}
+
+#[test]
+fn handle_event_resize() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::Resize(100, 100)],
+		None,
+		|mut test_context| {
+			let mut module = RangeDiff::new(&test_context.app_data());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::Resize(100, 100))
+			);
+		},
+	);
+}
+
+#[test]
+fn handle_event_scroll() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::ScrollDown)],
+		None,
+		|mut test_context| {
+			let mut module = RangeDiff::new(&test_context.app_data());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ScrollDown))
+			);
+		},
+	);
+}
+
+#[test]
+fn handle_help_event_show() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::Help)],
+		None,
+		|mut test_context| {
+			let mut module = RangeDiff::new(&test_context.app_data());
+			_ = test_context.handle_all_events(&mut module);
+			assert!(module.help.is_active());
+		},
+	);
+}
+
+#[test]
+fn render_help() {
+	testers::module(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::Help)],
+		None,
+		|mut test_context| {
+			let mut module = RangeDiff::new(&test_context.app_data());
+			_ = test_context.handle_all_events(&mut module);
+			assert_rendered_output!(
+				Body test_context.build_view_data(&mut module),
+				" Up      |Scroll up",
+				" Down    |Scroll down",
+				" PageUp  |Scroll up half a page",
+				" PageDown|Scroll down half a page",
+				" Home    |Scroll to the top",
+				" End     |Scroll to the bottom",
+				" Right   |Scroll right",
+				" Left    |Scroll left",
+				" ?       |Show help"
+			);
+		},
+	);
+}
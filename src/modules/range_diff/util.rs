@@ -0,0 +1,98 @@
+use crate::{
+	config::KeyBindings,
+	diff::{RangeDiffCommit, RangeDiffEntry, RangeDiffStatus},
+	display::DisplayColor,
+	todo_file::{Action, TodoFile},
+	view::{LineSegment, ViewLine},
+};
+
+const SHORT_HASH_LENGTH: usize = 7;
+
+pub(super) fn get_range_diff_help_lines(key_bindings: &KeyBindings) -> Vec<(Vec<String>, String)> {
+	vec![
+		(key_bindings.scroll_up.clone(), String::from("Scroll up")),
+		(key_bindings.scroll_down.clone(), String::from("Scroll down")),
+		(
+			key_bindings.scroll_step_up.clone(),
+			String::from("Scroll up half a page"),
+		),
+		(
+			key_bindings.scroll_step_down.clone(),
+			String::from("Scroll down half a page"),
+		),
+		(key_bindings.scroll_home.clone(), String::from("Scroll to the top")),
+		(key_bindings.scroll_end.clone(), String::from("Scroll to the bottom")),
+		(key_bindings.scroll_right.clone(), String::from("Scroll right")),
+		(key_bindings.scroll_left.clone(), String::from("Scroll left")),
+		(key_bindings.help.clone(), String::from("Show help")),
+	]
+}
+
+const fn is_commit_action(action: Action) -> bool {
+	matches!(
+		action,
+		Action::Pick | Action::Reword | Action::Edit | Action::Squash | Action::Fixup
+	)
+}
+
+pub(super) fn original_series(todo_file: &TodoFile) -> Vec<String> {
+	todo_file
+		.original_lines_iter()
+		.map(|line| line.original().unwrap_or(line))
+		.filter(|line| is_commit_action(*line.get_action()))
+		.map(|line| String::from(line.get_hash()))
+		.collect()
+}
+
+pub(super) fn edited_series(todo_file: &TodoFile) -> Vec<RangeDiffCommit> {
+	let mut series: Vec<RangeDiffCommit> = vec![];
+	for line in todo_file.lines_iter() {
+		match *line.get_action() {
+			Action::Squash | Action::Fixup if !series.is_empty() => {
+				if let Some(commit) = series.last_mut() {
+					commit.push_squashed(line.get_hash());
+				}
+			},
+			action if is_commit_action(action) => {
+				let mut commit = RangeDiffCommit::new(line.get_hash());
+				if action == Action::Reword {
+					commit.set_reworded();
+				}
+				series.push(commit);
+			},
+			_ => {},
+		}
+	}
+	series
+}
+
+fn format_side(position: Option<usize>, hash: Option<&str>, position_width: usize) -> String {
+	match (position, hash) {
+		(Some(p), Some(h)) => {
+			format!(
+				"{:>position_width$}:  {:SHORT_HASH_LENGTH$}",
+				p + 1,
+				h.chars().take(SHORT_HASH_LENGTH).collect::<String>()
+			)
+		},
+		_ => format!("{:>position_width$}:  {}", "-", "-".repeat(SHORT_HASH_LENGTH)),
+	}
+}
+
+pub(super) fn get_range_diff_entry_line(entry: &RangeDiffEntry, position_width: usize) -> ViewLine {
+	let status = entry.status();
+	let color = match status {
+		RangeDiffStatus::Unchanged => DisplayColor::Normal,
+		RangeDiffStatus::Modified => DisplayColor::DiffChangeColor,
+		RangeDiffStatus::Dropped => DisplayColor::DiffRemoveColor,
+		RangeDiffStatus::Added => DisplayColor::DiffAddColor,
+	};
+
+	ViewLine::from(vec![
+		LineSegment::new(format_side(entry.original_position(), entry.original_hash(), position_width).as_str()),
+		LineSegment::new_with_color(format!(" {} ", status.symbol()).as_str(), color),
+		LineSegment::new(format_side(entry.edited_position(), entry.edited_hash(), position_width).as_str()),
+		LineSegment::new(" "),
+		LineSegment::new_with_color(entry.summary(), color),
+	])
+}
@@ -5,7 +5,7 @@ mod view_builder;
 #[cfg(test)]
 mod tests;
 
-use std::sync::Arc;
+use std::{collections::HashSet, path::PathBuf, sync::Arc};
 
 use anyhow::anyhow;
 use captur::capture;
@@ -13,16 +13,16 @@ use parking_lot::Mutex;
 
 use self::{
 	show_commit_state::ShowCommitState,
-	util::get_show_commit_help_lines,
+	util::{get_show_commit_help_lines, get_todo_line_details, is_revision_loaded},
 	view_builder::{ViewBuilder, ViewBuilderOptions},
 };
 use crate::{
 	application::AppData,
 	components::help::Help,
-	config::DiffShowWhitespaceSetting,
+	config::{Config, DiffShowWhitespaceSetting},
 	diff,
 	diff::thread::LoadStatus,
-	input::{Event, InputOptions, KeyBindings, StandardEvent},
+	input::{Event, InputOptions, KeyBindings, MouseButton, MouseEvent, MouseEventKind, StandardEvent},
 	module::{Module, State},
 	process::Results,
 	select,
@@ -34,13 +34,16 @@ use crate::{
 // TODO Remove `union` call when bitflags/bitflags#180 is resolved
 const INPUT_OPTIONS: InputOptions = InputOptions::UNDO_REDO
 	.union(InputOptions::MOVEMENT)
-	.union(InputOptions::HELP);
+	.union(InputOptions::HELP)
+	.union(InputOptions::KEY_SEQUENCES);
 
 pub(crate) struct ShowCommit {
+	collapsed_files: HashSet<PathBuf>,
 	diff_state: diff::thread::State,
 	diff_view_data: ViewData,
 	help: Help,
 	overview_view_data: ViewData,
+	show_excluded: bool,
 	state: ShowCommitState,
 	view_state: view::State,
 	todo_file: Arc<Mutex<TodoFile>>,
@@ -50,12 +53,13 @@ pub(crate) struct ShowCommit {
 impl Module for ShowCommit {
 	fn activate(&mut self, _: State) -> Results {
 		let mut results = Results::new();
-		if let Some(selected_line) = self.todo_file.lock().get_selected_line() {
+		let todo_file = self.todo_file.lock();
+		if let Some(revision) = todo_file.get_line_revision(todo_file.get_selected_line_index()) {
 			{
 				// skip loading commit data if the currently loaded commit has not changed, this retains
 				// position after returning to the list view or help
 				let diff = self.diff_state.diff();
-				if diff.read().commit().hash() == selected_line.get_hash() {
+				if is_revision_loaded(diff.read().commit(), revision.as_str()) {
 					return results;
 				}
 			}
@@ -69,8 +73,9 @@ impl Module for ShowCommit {
 				updater.clear();
 				updater.reset_scroll_position();
 			});
+			self.collapsed_files.clear();
 
-			results.load_diff(selected_line.get_hash());
+			results.load_diff(revision.as_str());
 		}
 		else {
 			results.error_with_return(anyhow!("No valid commit to show"), State::List);
@@ -98,9 +103,11 @@ impl Module for ShowCommit {
 		// There is a small race condition where sometimes the diff loader is still in the process
 		// of cancelling the previous diff and still has that diff loaded. In that case, we want to
 		// show a general loading diff.
-		let todo_line = self.todo_file.lock();
-		let selected_line = todo_line.get_selected_line().map_or("", |l| l.get_hash());
-		if self.diff_state.is_cancelled() || selected_line.is_empty() || selected_line != diff.commit().hash() {
+		let todo_file = self.todo_file.lock();
+		let revision = todo_file
+			.get_line_revision(todo_file.get_selected_line_index())
+			.unwrap_or_default();
+		if self.diff_state.is_cancelled() || revision.is_empty() || !is_revision_loaded(diff.commit(), &revision) {
 			self.overview_view_data.update_view_data(|updater| {
 				updater.clear();
 				updater.push_line(ViewLine::from("Loading Diff"));
@@ -109,21 +116,38 @@ impl Module for ShowCommit {
 		}
 
 		let state = &self.state;
+		let show_excluded = self.show_excluded;
+		let collapsed_files = &self.collapsed_files;
 		let view_builder = &mut self.view_builder;
 		let is_full_width = context.is_full_width();
 
 		match *state {
 			ShowCommitState::Overview => {
+				let details = get_todo_line_details(&todo_file, diff.commit(), is_full_width);
 				self.overview_view_data.update_view_data(|updater| {
 					capture!(view_builder, diff);
-					view_builder.build_view_data_for_overview(updater, &diff, &load_status, is_full_width);
+					view_builder.build_view_data_for_overview(
+						updater,
+						&diff,
+						&load_status,
+						details,
+						show_excluded,
+						is_full_width,
+					);
 				});
 				&self.overview_view_data
 			},
 			ShowCommitState::Diff => {
 				self.diff_view_data.update_view_data(|updater| {
 					capture!(view_builder, diff);
-					view_builder.build_view_data_diff(updater, &diff, &load_status, is_full_width);
+					view_builder.build_view_data_diff(
+						updater,
+						&diff,
+						&load_status,
+						collapsed_files,
+						show_excluded,
+						is_full_width,
+					);
 				});
 				&self.diff_view_data
 			},
@@ -137,10 +161,12 @@ impl Module for ShowCommit {
 	fn read_event(&self, event: Event, key_bindings: &KeyBindings) -> Event {
 		select!(
 			default {
-				let has_event = key_bindings.show_diff.contains(&event);
-				if has_event {
+				if key_bindings.show_diff.contains(&event) {
 					Event::from(StandardEvent::ShowDiff)
 				}
+				else if key_bindings.toggle_excluded_files.contains(&event) {
+					Event::from(StandardEvent::ToggleExcludedFiles)
+				}
 				else {
 					event
 				}
@@ -161,7 +187,9 @@ impl Module for ShowCommit {
 							ShowCommitState::Diff => ShowCommitState::Overview,
 						}
 					},
+					Event::Standard(StandardEvent::ToggleExcludedFiles) => self.show_excluded = !self.show_excluded,
 					Event::Standard(StandardEvent::Help) => self.help.set_active(),
+					Event::Mouse(mouse_event) => self.handle_mouse_event(mouse_event),
 					Event::Key(_) => {
 						if self.state == ShowCommitState::Diff {
 							self.state = ShowCommitState::Overview;
@@ -179,6 +207,12 @@ impl Module for ShowCommit {
 			handle_view_data_scroll(event, &self.view_state)
 		)
 	}
+
+	fn reload(&mut self, app_data: &AppData) {
+		let config = app_data.config();
+		self.help = Help::new_from_keybindings(&get_show_commit_help_lines(&config.key_bindings));
+		self.view_builder = ViewBuilder::new(Self::view_builder_options(&config));
+	}
 }
 
 impl ShowCommit {
@@ -192,25 +226,50 @@ impl ShowCommit {
 			updater.set_show_help(true);
 		});
 		let config = app_data.config();
-		let view_builder_options = ViewBuilderOptions::new(
-			config.diff_tab_width as usize,
-			config.diff_tab_symbol.as_str(),
-			config.diff_space_symbol.as_str(),
-			config.diff_show_whitespace == DiffShowWhitespaceSetting::Both
-				|| config.diff_show_whitespace == DiffShowWhitespaceSetting::Leading,
-			config.diff_show_whitespace == DiffShowWhitespaceSetting::Both
-				|| config.diff_show_whitespace == DiffShowWhitespaceSetting::Trailing,
-		);
 
 		Self {
+			collapsed_files: HashSet::new(),
 			diff_state: app_data.diff_state(),
 			diff_view_data,
 			help: Help::new_from_keybindings(&get_show_commit_help_lines(&config.key_bindings)),
 			overview_view_data,
+			show_excluded: false,
 			state: ShowCommitState::Overview,
 			view_state: app_data.view_state(),
 			todo_file: app_data.todo_file(),
-			view_builder: ViewBuilder::new(view_builder_options),
+			view_builder: ViewBuilder::new(Self::view_builder_options(&config)),
+		}
+	}
+
+	fn view_builder_options(config: &Config) -> ViewBuilderOptions {
+		ViewBuilderOptions::new(
+			config.diff_tab_width as usize,
+			config.diff_tab_symbol.as_str(),
+			config.diff_space_symbol.as_str(),
+			config.diff_show_whitespace == DiffShowWhitespaceSetting::Both
+				|| config.diff_show_whitespace == DiffShowWhitespaceSetting::Leading,
+			config.diff_show_whitespace == DiffShowWhitespaceSetting::Both
+				|| config.diff_show_whitespace == DiffShowWhitespaceSetting::Trailing,
+		)
+	}
+
+	fn handle_mouse_event(&mut self, mouse_event: MouseEvent) {
+		match mouse_event.kind {
+			MouseEventKind::ScrollDown => self.view_state.scroll_down(),
+			MouseEventKind::ScrollUp => self.view_state.scroll_up(),
+			MouseEventKind::Down(MouseButton::Left) if self.state == ShowCommitState::Diff => {
+				let path = self
+					.view_state
+					.line_index_at_row(mouse_event.row)
+					.and_then(|index| self.view_builder.file_at_line(index))
+					.map(PathBuf::from);
+				if let Some(path) = path {
+					if !self.collapsed_files.remove(&path) {
+						_ = self.collapsed_files.insert(path);
+					}
+				}
+			},
+			_ => {},
 		}
 	}
 }
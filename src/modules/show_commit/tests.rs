@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use anyhow::anyhow;
 use git2::ErrorCode;
 use rstest::rstest;
@@ -6,16 +8,17 @@ use super::*;
 use crate::{
 	assert_rendered_output,
 	assert_results,
-	diff::{Commit, Delta, DiffLine, FileMode, Origin, Status, User},
-	input::StandardEvent,
+	diff::{BinaryDiff, Commit, Delta, DiffLine, FileMode, Origin, Status, User},
+	input::{KeyModifiers, StandardEvent},
 	process::Artifact,
 	render_line,
 	test_helpers::{
 		assertions::assert_rendered_output::AssertRenderOptions,
-		builders::{CommitBuilder, CommitDiffBuilder, FileStatusBuilder},
+		builders::{CommitBuilder, CommitDiffBuilder, FileStatusBuilder, ReferenceBuilder},
 		create_config,
-		testers,
+		testers::{self, ModuleTestContext},
 	},
+	view::RenderAction,
 };
 
 fn render_options() -> AssertRenderOptions {
@@ -60,6 +63,75 @@ fn cached_commit_in_activate() {
 	});
 }
 
+#[test]
+fn load_merge_commit_during_activate() {
+	testers::module(&["merge -C abcde12345 label # comment"], &[], None, |test_context| {
+		let mut module = ShowCommit::new(&test_context.app_data());
+		assert_results!(
+			test_context.activate(&mut module, State::List),
+			Artifact::LoadDiff(String::from("abcde12345"))
+		);
+	});
+}
+
+#[test]
+fn load_update_ref_during_activate() {
+	testers::module(
+		&["pick aaa c1", "update-ref refs/heads/main"],
+		&[],
+		None,
+		|test_context| {
+			_ = test_context.app_data().todo_file().lock().set_selected_line_index(1);
+			let mut module = ShowCommit::new(&test_context.app_data());
+			assert_results!(
+				test_context.activate(&mut module, State::List),
+				Artifact::LoadDiff(String::from("refs/heads/main"))
+			);
+		},
+	);
+}
+
+#[test]
+fn load_label_during_activate() {
+	testers::module(&["pick aaa c1", "label l1"], &[], None, |test_context| {
+		_ = test_context.app_data().todo_file().lock().set_selected_line_index(1);
+		let mut module = ShowCommit::new(&test_context.app_data());
+		assert_results!(
+			test_context.activate(&mut module, State::List),
+			Artifact::LoadDiff(String::from("aaa"))
+		);
+	});
+}
+
+#[test]
+fn cached_reference_in_activate() {
+	testers::module(&["update-ref refs/heads/main"], &[], None, |test_context| {
+		let mut module = ShowCommit::new(&test_context.app_data());
+		let diff = test_context.app_data().diff_state().diff();
+		{
+			let mut diff_lock = diff.write();
+			diff_lock.reset(
+				CommitBuilder::new("abcde12345")
+					.reference(ReferenceBuilder::new("abcde12345").build())
+					.build(),
+				None,
+			);
+		}
+		assert_results!(test_context.activate(&mut module, State::List));
+	});
+}
+
+#[test]
+fn no_revision_in_activate() {
+	testers::module(&["merge label"], &[], None, |test_context| {
+		let mut module = ShowCommit::new(&test_context.app_data());
+		assert_results!(
+			test_context.activate(&mut module, State::List),
+			Artifact::Error(anyhow!("No valid commit to show"), Some(State::List))
+		);
+	});
+}
+
 #[test]
 fn no_selected_line_in_activate() {
 	testers::module(&[], &[], None, |test_context| {
@@ -153,6 +225,100 @@ fn render_overview_minimal_commit_compact() {
 	);
 }
 
+#[test]
+fn render_overview_label() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef comment1", "label l1"],
+		&[],
+		None,
+		|test_context| {
+			_ = test_context.app_data().todo_file().lock().set_selected_line_index(1);
+			let commit = CommitBuilder::new("0123456789abcdef0123456789abcdef").build();
+			let commit_date = commit.committed_date().format("%c %z").to_string();
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(&mut module, CommitDiffBuilder::new(commit));
+
+			assert_rendered_output!(
+				Options render_options(),
+				test_context.build_view_data(&mut module),
+				"{IndicatorColor}Label: {Normal}l1",
+				format!("{{IndicatorColor}}Date: {{Normal}}{commit_date}"),
+				"{Normal}",
+				"{IndicatorColor}0{Normal} files with {DiffAddColor}0{Normal} insertions and \
+				 {DiffRemoveColor}0{Normal} deletions"
+			);
+		},
+	);
+}
+
+#[test]
+fn render_overview_update_ref() {
+	testers::module(
+		&[
+			"pick aaa c1",
+			"pick 0123456 c2",
+			"pick bbb c3",
+			"exec make",
+			"pick ccc c4",
+			"update-ref refs/heads/main",
+		],
+		&[],
+		None,
+		|test_context| {
+			_ = test_context.app_data().todo_file().lock().set_selected_line_index(5);
+			let commit = CommitBuilder::new("0123456789abcdef0123456789abcdef")
+				.reference(ReferenceBuilder::new("0123456789abcdef0123456789abcdef").build())
+				.build();
+			let commit_date = commit.committed_date().format("%c %z").to_string();
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(&mut module, CommitDiffBuilder::new(commit));
+
+			assert_rendered_output!(
+				Options render_options(),
+				test_context.build_view_data(&mut module),
+				"{IndicatorColor}Reference: {Normal}refs/heads/main",
+				"{IndicatorColor}Moves over: {Normal}2 commits",
+				"{IndicatorColor}  bbb {Normal}c3",
+				"{IndicatorColor}  ccc {Normal}c4",
+				format!("{{IndicatorColor}}Date: {{Normal}}{commit_date}"),
+				"{Normal}",
+				"{IndicatorColor}0{Normal} files with {DiffAddColor}0{Normal} insertions and \
+				 {DiffRemoveColor}0{Normal} deletions"
+			);
+		},
+	);
+}
+
+#[test]
+fn render_overview_update_ref_compact() {
+	testers::module(
+		&["reset onto", "pick aaa c1", "update-ref refs/heads/main"],
+		&[],
+		None,
+		|mut test_context| {
+			test_context.render_context.update(30, 300);
+			_ = test_context.app_data().todo_file().lock().set_selected_line_index(2);
+			let commit = CommitBuilder::new("0123456789abcdef0123456789abcdef")
+				.reference(ReferenceBuilder::new("0123456789abcdef0123456789abcdef").build())
+				.build();
+			let commit_date = commit.committed_date().format("%c %z").to_string();
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(&mut module, CommitDiffBuilder::new(commit));
+
+			assert_rendered_output!(
+				Options render_options(),
+				test_context.build_view_data(&mut module),
+				"{IndicatorColor}R: {Normal}refs/heads/main",
+				"{IndicatorColor}M: {Normal}1",
+				"{IndicatorColor}  aaa {Normal}c1",
+				format!("{{IndicatorColor}}D: {{Normal}}{commit_date}"),
+				"{Normal}",
+				"{IndicatorColor}0{Normal} / {DiffAddColor}0{Normal} / {DiffRemoveColor}0"
+			);
+		},
+	);
+}
+
 #[test]
 fn render_overview_with_author() {
 	testers::module(
@@ -644,6 +810,141 @@ fn render_overview_more_than_one_deletion() {
 	);
 }
 
+fn excluded_files_diff() -> CommitDiffBuilder {
+	CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+		.number_files_changed(2)
+		.number_insertions(5)
+		.number_deletions(3)
+		.excluded(1, 4, 2)
+		.file_statuses(vec![
+			FileStatusBuilder::new()
+				.source_path("file.rs")
+				.destination_path("file.rs")
+				.status(Status::Modified)
+				.build(),
+			FileStatusBuilder::new()
+				.source_path("Cargo.lock")
+				.destination_path("Cargo.lock")
+				.status(Status::Modified)
+				.excluded(true)
+				.build(),
+		])
+}
+
+#[test]
+fn render_overview_excluded_files_hidden() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef comment1"],
+		&[],
+		None,
+		|test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(&mut module, excluded_files_diff());
+
+			assert_rendered_output!(
+				Options render_options(),
+				Skip 2,
+				test_context.build_view_data(&mut module),
+				"{IndicatorColor}1{Normal} file with {DiffAddColor}1{Normal} insertion and \
+				 {DiffRemoveColor}1{Normal} deletion, {IndicatorColor}1{Normal} excluded file hidden",
+				"{DiffChangeColor}modified: file.rs"
+			);
+		},
+	);
+}
+
+#[test]
+fn render_overview_excluded_files_shown() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef comment1"],
+		&[],
+		None,
+		|test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			module.show_excluded = true;
+			update_diff(&mut module, excluded_files_diff());
+
+			assert_rendered_output!(
+				Options render_options(),
+				Skip 2,
+				test_context.build_view_data(&mut module),
+				"{IndicatorColor}2{Normal} files with {DiffAddColor}5{Normal} insertions and \
+				 {DiffRemoveColor}3{Normal} deletions, {IndicatorColor}1{Normal} excluded file shown",
+				"{DiffChangeColor}modified: file.rs",
+				"{DiffChangeColor}modified: Cargo.lock{IndicatorColor} (excluded)"
+			);
+		},
+	);
+}
+
+#[test]
+fn render_overview_excluded_files_compact() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef comment1"],
+		&[],
+		None,
+		|mut test_context| {
+			test_context.render_context.update(30, 300);
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(&mut module, excluded_files_diff());
+
+			assert_rendered_output!(
+				Options render_options(),
+				Skip 2,
+				test_context.build_view_data(&mut module),
+				"{IndicatorColor}1{Normal} / {DiffAddColor}1{Normal} / {DiffRemoveColor}1{Normal} \
+				 ({IndicatorColor}1{Normal} hidden)",
+				"{DiffChangeColor}M file.rs"
+			);
+		},
+	);
+}
+
+#[test]
+fn render_diff_excluded_files_hidden() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef comment1"],
+		&[],
+		None,
+		|test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(&mut module, excluded_files_diff());
+			module.state = ShowCommitState::Diff;
+
+			assert_rendered_output!(
+				Options render_options(),
+				test_context.build_view_data(&mut module),
+				"{Normal}{Pad(―)}",
+				"{DiffChangeColor}modified: file.rs"
+			);
+		},
+	);
+}
+
+#[test]
+fn render_diff_excluded_files_shown() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef comment1"],
+		&[],
+		None,
+		|test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			module.show_excluded = true;
+			update_diff(&mut module, excluded_files_diff());
+			module.state = ShowCommitState::Diff;
+
+			assert_rendered_output!(
+				Options render_options(),
+				test_context.build_view_data(&mut module),
+				"{Normal}{Pad(―)}",
+				"{DiffChangeColor}modified: file.rs",
+				"{Normal}{Pad(―)}",
+				"{DiffChangeColor}modified: Cargo.lock{IndicatorColor} (excluded)"
+			);
+		},
+	);
+}
+
 #[test]
 fn render_diff_minimal_commit() {
 	let mut config = create_config();
@@ -786,6 +1087,89 @@ fn render_diff_basic_file_stats() {
 	);
 }
 
+#[test]
+fn render_diff_binary_file() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef comment1"],
+		&[],
+		None,
+		|test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(
+				&mut module,
+				CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build()).file_statuses(
+					vec![
+						FileStatusBuilder::new()
+							.source_path("file.bin")
+							.destination_path("file.bin")
+							.status(Status::Modified)
+							.binary_diff(BinaryDiff::new(Some(b"PK\x03\x04abc"), Some(b"PK\x03\x04abXYZ")))
+							.build(),
+					],
+				),
+			);
+
+			module.state = ShowCommitState::Diff;
+			assert_rendered_output!(
+				Options AssertRenderOptions::INCLUDE_STYLE,
+				Skip 5,
+				test_context.build_view_data(&mut module),
+				"{Normal}{Pad(―)}",
+				"{DiffChangeColor}modified: file.bin",
+				"",
+				"{IndicatorColor}    size: {Normal}7 → 9 bytes{DiffAddColor} (+2)",
+				"{IndicatorColor}    type: {Normal}zip archive",
+				"{IndicatorColor} differs: {Normal}at byte 6 (0x6)",
+				"",
+				"{DiffRemoveColor}- 00000000  50 4b 03 04 61 62 63                              |PK..abc|",
+				"{DiffAddColor}+ 00000000  50 4b 03 04 61 62 58 59  5a                       |PK..abXYZ|"
+			);
+		},
+	);
+}
+
+#[test]
+fn render_diff_binary_image_added() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef comment1"],
+		&[],
+		None,
+		|test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(
+				&mut module,
+				CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build()).file_statuses(
+					vec![
+						FileStatusBuilder::new()
+							.source_path("image.pgm")
+							.destination_path("image.pgm")
+							.status(Status::Added)
+							.binary_diff(BinaryDiff::new(None, Some(b"P5 2 2 255 \xFF\x00\x00\xFF")))
+							.build(),
+					],
+				),
+			);
+
+			module.state = ShowCommitState::Diff;
+			assert_rendered_output!(
+				Options AssertRenderOptions::INCLUDE_STYLE,
+				Skip 5,
+				test_context.build_view_data(&mut module),
+				"{Normal}{Pad(―)}",
+				"{DiffAddColor}   added: image.pgm",
+				"",
+				"{IndicatorColor}    size: {Normal}15 bytes",
+				"{IndicatorColor}    type: {Normal}PNM image (2x2)",
+				"",
+				"{DiffAddColor}+ 00000000  50 35 20 32 20 32 20 32  35 35 20 ff 00 00 ff     |P5 2 2 255 ....|",
+				"",
+				"{IndicatorColor} preview: {Normal}new",
+				"{DiffAddColor}          =="
+			);
+		},
+	);
+}
+
 #[test]
 fn render_diff_end_new_line_missing() {
 	let mut config = create_config();
@@ -825,7 +1209,8 @@ fn render_diff_end_new_line_missing() {
 				"{Normal}{Pad(―)}",
 				"{DiffChangeColor}modified: file.txt",
 				"",
-				"{Normal,Dimmed}@@{DiffContextColor} -14,0 +14,1 {Normal,Dimmed}@@{DiffContextColor} context",
+				"{DiffHunkColor,Dimmed}@@{DiffContextColor} -14,0 +14,1 {DiffHunkColor,Dimmed}@@{DiffContextColor} \
+				 context",
 				"{Normal,Dimmed}{Pad(―)}",
 				"{Normal}   14| {DiffAddColor}new line",
 				"{Normal}       {DiffContextColor}\\ No newline at end of file"
@@ -872,7 +1257,8 @@ fn render_diff_add_line() {
 				"{Normal}{Pad(―)}",
 				"{DiffChangeColor}modified: file.txt",
 				"",
-				"{Normal,Dimmed}@@{DiffContextColor} -14,0 +14,1 {Normal,Dimmed}@@{DiffContextColor} context",
+				"{DiffHunkColor,Dimmed}@@{DiffContextColor} -14,0 +14,1 {DiffHunkColor,Dimmed}@@{DiffContextColor} \
+				 context",
 				"{Normal,Dimmed}{Pad(―)}",
 				"{Normal}   14| {DiffAddColor}new line"
 			);
@@ -918,7 +1304,8 @@ fn render_diff_delete_line() {
 				"{Normal}{Pad(―)}",
 				"{DiffChangeColor}modified: file.txt",
 				"",
-				"{Normal,Dimmed}@@{DiffContextColor} -14,0 +14,1 {Normal,Dimmed}@@{DiffContextColor} context",
+				"{DiffHunkColor,Dimmed}@@{DiffContextColor} -14,0 +14,1 {DiffHunkColor,Dimmed}@@{DiffContextColor} \
+				 context",
 				"{Normal,Dimmed}{Pad(―)}",
 				"{Normal}14   | {DiffRemoveColor}old line"
 			);
@@ -967,7 +1354,8 @@ fn render_diff_context_add_remove_lines() {
 				"{Normal}{Pad(―)}",
 				"{DiffChangeColor}modified: file.txt",
 				"",
-				"{Normal,Dimmed}@@{DiffContextColor} -14,0 +14,1 {Normal,Dimmed}@@{DiffContextColor} context",
+				"{DiffHunkColor,Dimmed}@@{DiffContextColor} -14,0 +14,1 {DiffHunkColor,Dimmed}@@{DiffContextColor} \
+				 context",
 				"{Normal,Dimmed}{Pad(―)}",
 				"{Normal}13 13| {DiffContextColor}context 1",
 				"{Normal}14   | {DiffRemoveColor}old line",
@@ -1387,6 +1775,28 @@ fn handle_event_toggle_overview_to_diff() {
 	);
 }
 
+#[test]
+fn handle_event_toggle_excluded_files() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef c1"],
+		&[Event::from('x'), Event::from('x')],
+		None,
+		|mut test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ToggleExcludedFiles))
+			);
+			assert!(module.show_excluded);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ToggleExcludedFiles))
+			);
+			assert!(!module.show_excluded);
+		},
+	);
+}
+
 #[test]
 fn handle_event_resize() {
 	testers::module(
@@ -1423,6 +1833,7 @@ fn render_help() {
 				" Right   |Scroll right",
 				" Left    |Scroll left",
 				" d       |Show full diff",
+				" x       |Toggle excluded files",
 				" ?       |Show help"
 			);
 		},
@@ -1509,3 +1920,163 @@ fn scroll_events(#[case] event: StandardEvent) {
 		);
 	});
 }
+
+fn mouse_event(kind: MouseEventKind, row: u16) -> Event {
+	Event::Mouse(MouseEvent {
+		kind,
+		column: 0,
+		row,
+		modifiers: KeyModifiers::NONE,
+	})
+}
+
+fn two_file_diff() -> CommitDiffBuilder {
+	let mut delta = Delta::new("@@ -14,2 +13,3 @@ context", 14, 14, 0, 1);
+	delta.add_line(DiffLine::new(Origin::Addition, "new line", None, Some(14), false));
+	CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build()).file_statuses(vec![
+		FileStatusBuilder::new()
+			.source_path("file1.txt")
+			.destination_path("file1.txt")
+			.status(Status::Modified)
+			.push_delta(delta.clone())
+			.build(),
+		FileStatusBuilder::new()
+			.source_path("file2.txt")
+			.destination_path("file2.txt")
+			.status(Status::Modified)
+			.push_delta(delta)
+			.build(),
+	])
+}
+
+// the title and the leading lines are rendered first, so the first file header is in the fifth row
+fn render(test_context: &ModuleTestContext, module: &mut ShowCommit) {
+	let view_state = test_context.app_data().view_state();
+	view_state.resize(300, 120);
+	view_state.render(test_context.build_view_data(module));
+}
+
+#[test]
+fn mouse_scroll_down() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef c1"],
+		&[mouse_event(MouseEventKind::ScrollDown, 0)],
+		None,
+		|mut test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			_ = test_context.handle_event(&mut module);
+			assert!(matches!(
+				module.view_state.render_slice().lock().get_actions().back(),
+				Some(&RenderAction::ScrollDown)
+			));
+		},
+	);
+}
+
+#[test]
+fn mouse_scroll_up() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef c1"],
+		&[mouse_event(MouseEventKind::ScrollUp, 0)],
+		None,
+		|mut test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			_ = test_context.handle_event(&mut module);
+			assert!(matches!(
+				module.view_state.render_slice().lock().get_actions().back(),
+				Some(&RenderAction::ScrollUp)
+			));
+		},
+	);
+}
+
+#[test]
+fn mouse_click_file_header_toggles_collapsed() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef c1"],
+		&[
+			mouse_event(MouseEventKind::Down(MouseButton::Left), 4),
+			mouse_event(MouseEventKind::Down(MouseButton::Left), 4),
+		],
+		None,
+		|mut test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(&mut module, two_file_diff());
+			module.state = ShowCommitState::Diff;
+			render(&test_context, &mut module);
+			_ = test_context.handle_event(&mut module);
+			assert!(module.collapsed_files.contains(Path::new("file1.txt")));
+			_ = test_context.handle_event(&mut module);
+			assert!(module.collapsed_files.is_empty());
+		},
+	);
+}
+
+#[test]
+fn mouse_click_other_line() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef c1"],
+		&[mouse_event(MouseEventKind::Down(MouseButton::Left), 5)],
+		None,
+		|mut test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(&mut module, two_file_diff());
+			module.state = ShowCommitState::Diff;
+			render(&test_context, &mut module);
+			_ = test_context.handle_event(&mut module);
+			assert!(module.collapsed_files.is_empty());
+		},
+	);
+}
+
+#[test]
+fn mouse_click_in_overview() {
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef c1"],
+		&[mouse_event(MouseEventKind::Down(MouseButton::Left), 4)],
+		None,
+		|mut test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(&mut module, two_file_diff());
+			module.state = ShowCommitState::Diff;
+			render(&test_context, &mut module);
+			module.state = ShowCommitState::Overview;
+			_ = test_context.handle_event(&mut module);
+			assert!(module.collapsed_files.is_empty());
+		},
+	);
+}
+
+#[test]
+fn render_diff_collapsed_file() {
+	let mut config = create_config();
+	config.diff_show_whitespace = DiffShowWhitespaceSetting::None;
+	testers::module(
+		&["pick 0123456789abcdef0123456789abcdef comment1"],
+		&[],
+		Some(config),
+		|test_context| {
+			let mut module = ShowCommit::new(&test_context.app_data());
+			update_diff(&mut module, two_file_diff());
+			_ = module.collapsed_files.insert(PathBuf::from("file1.txt"));
+			module.state = ShowCommitState::Diff;
+			assert_rendered_output!(
+				Options AssertRenderOptions::INCLUDE_STYLE,
+				Skip 3,
+				test_context.build_view_data(&mut module),
+				"{IndicatorColor}0{Normal} files with {DiffAddColor}0{Normal} insertions and \
+				 {DiffRemoveColor}0{Normal} deletions",
+				"{BODY}",
+				"{Normal}{Pad(―)}",
+				"{DiffChangeColor}modified: file1.txt{IndicatorColor} (collapsed)",
+				"{Normal}{Pad(―)}",
+				"{DiffChangeColor}modified: file2.txt",
+				"",
+				"{DiffHunkColor,Dimmed}@@{DiffContextColor} -14,0 +14,1 {DiffHunkColor,Dimmed}@@{DiffContextColor} \
+				 context",
+				"{Normal,Dimmed}{Pad(―)}",
+				"{Normal}   14| {DiffAddColor}new line"
+			);
+		},
+	);
+}
@@ -1,17 +1,20 @@
-use std::path::Path;
+use std::{cmp::Ordering, fmt::Write as _, path::Path};
 
 use num_format::{Locale, ToFormattedString as _};
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
 	config::KeyBindings,
-	diff::{CommitDiff, Status},
+	diff::{BinaryDiff, BinaryFile, Commit, CommitDiff, Reference, Status},
 	display::DisplayColor,
+	todo_file::{Action, TodoFile},
 	view::{LineSegment, ViewLine},
 };
 
 const TO_FILE_INDICATOR_LONG: &str = " \u{2192} "; // " → "
 const TO_FILE_INDICATOR_SHORT: &str = "\u{2192}"; // "→"
+const HEX_DUMP_WIDTH: usize = 16;
+const HEX_DUMP_GROUP_WIDTH: usize = 8;
 
 pub(super) fn get_show_commit_help_lines(key_bindings: &KeyBindings) -> Vec<(Vec<String>, String)> {
 	vec![
@@ -30,6 +33,10 @@ pub(super) fn get_show_commit_help_lines(key_bindings: &KeyBindings) -> Vec<(Vec
 		(key_bindings.scroll_right.clone(), String::from("Scroll right")),
 		(key_bindings.scroll_left.clone(), String::from("Scroll left")),
 		(key_bindings.show_diff.clone(), String::from("Show full diff")),
+		(
+			key_bindings.toggle_excluded_files.clone(),
+			String::from("Toggle excluded files"),
+		),
 		(key_bindings.help.clone(), String::from("Show help")),
 	]
 }
@@ -106,13 +113,27 @@ pub(super) fn get_stat_item_segments(
 	}
 }
 
-pub(super) fn get_files_changed_summary(diff: &CommitDiff, is_full_width: bool) -> ViewLine {
-	let files_changed = diff.number_files_changed();
-	let insertions = diff.number_insertions();
-	let deletions = diff.number_deletions();
+pub(super) fn get_files_changed_summary(diff: &CommitDiff, show_excluded: bool, is_full_width: bool) -> ViewLine {
+	let files_excluded = diff.number_files_excluded();
+	let (files_changed, insertions, deletions) = if show_excluded {
+		(
+			diff.number_files_changed(),
+			diff.number_insertions(),
+			diff.number_deletions(),
+		)
+	}
+	else {
+		(
+			diff.number_files_changed().saturating_sub(files_excluded),
+			diff.number_insertions()
+				.saturating_sub(diff.number_insertions_excluded()),
+			diff.number_deletions().saturating_sub(diff.number_deletions_excluded()),
+		)
+	};
+	let excluded_state = if show_excluded { "shown" } else { "hidden" };
 
-	if is_full_width {
-		ViewLine::from(vec![
+	let mut segments = if is_full_width {
+		vec![
 			LineSegment::new_with_color(
 				files_changed.to_formatted_string(&Locale::en).as_str(),
 				DisplayColor::IndicatorColor,
@@ -130,10 +151,10 @@ pub(super) fn get_files_changed_summary(diff: &CommitDiff, is_full_width: bool)
 				DisplayColor::DiffRemoveColor,
 			),
 			LineSegment::new(if deletions == 1 { " deletion" } else { " deletions" }),
-		])
+		]
 	}
 	else {
-		ViewLine::from(vec![
+		vec![
 			LineSegment::new_with_color(
 				files_changed.to_formatted_string(&Locale::en).as_str(),
 				DisplayColor::IndicatorColor,
@@ -148,10 +169,266 @@ pub(super) fn get_files_changed_summary(diff: &CommitDiff, is_full_width: bool)
 				deletions.to_formatted_string(&Locale::en).as_str(),
 				DisplayColor::DiffRemoveColor,
 			),
-		])
+		]
+	};
+
+	if files_excluded > 0 {
+		if is_full_width {
+			segments.push(LineSegment::new(", "));
+			segments.push(LineSegment::new_with_color(
+				files_excluded.to_formatted_string(&Locale::en).as_str(),
+				DisplayColor::IndicatorColor,
+			));
+			segments.push(LineSegment::new(
+				format!(
+					" excluded {} {excluded_state}",
+					if files_excluded == 1 { "file" } else { "files" }
+				)
+				.as_str(),
+			));
+		}
+		else {
+			segments.push(LineSegment::new(" ("));
+			segments.push(LineSegment::new_with_color(
+				files_excluded.to_formatted_string(&Locale::en).as_str(),
+				DisplayColor::IndicatorColor,
+			));
+			segments.push(LineSegment::new(format!(" {excluded_state})").as_str()));
+		}
+	}
+	ViewLine::from(segments)
+}
+
+pub(super) fn is_revision_loaded(commit: &Commit, revision: &str) -> bool {
+	commit.hash() == revision || commit.reference().is_some_and(|r| r.name() == revision)
+}
+
+fn get_detail_line(label: &str, short_label: &str, value: &str, is_full_width: bool) -> ViewLine {
+	ViewLine::from(vec![
+		LineSegment::new_with_color(
+			if is_full_width { label } else { short_label },
+			DisplayColor::IndicatorColor,
+		),
+		LineSegment::new(value),
+	])
+}
+
+pub(super) fn get_todo_line_details(todo_file: &TodoFile, commit: &Commit, is_full_width: bool) -> Vec<ViewLine> {
+	let index = todo_file.get_selected_line_index();
+	let Some(line) = todo_file.get_line(index)
+	else {
+		return vec![];
+	};
+	let name = line.get_content().split_whitespace().next().unwrap_or("");
+
+	match *line.get_action() {
+		Action::Label | Action::Reset => vec![get_detail_line("Label: ", "L: ", name, is_full_width)],
+		Action::UpdateRef => {
+			let target = commit.reference().map_or(commit.hash(), Reference::hash);
+			// the commits the reference moves over are the commits between the current target and the
+			// update-ref, stopping at the start of the branch
+			let mut moved_commits = vec![];
+			for l in todo_file.lines_iter().take(index).rev() {
+				match *l.get_action() {
+					Action::Reset => break,
+					Action::Edit | Action::Fixup | Action::Pick | Action::Revert | Action::Reword | Action::Squash => {
+						if target.starts_with(l.get_hash()) {
+							break;
+						}
+						moved_commits.push(l);
+					},
+					Action::Break
+					| Action::Drop
+					| Action::Exec
+					| Action::Label
+					| Action::Merge
+					| Action::Noop
+					| Action::UpdateRef => {},
+				}
+			}
+
+			let mut lines = vec![
+				get_detail_line("Reference: ", "R: ", name, is_full_width),
+				get_detail_line(
+					"Moves over: ",
+					"M: ",
+					if is_full_width {
+						format!(
+							"{} {}",
+							moved_commits.len(),
+							if moved_commits.len() == 1 { "commit" } else { "commits" }
+						)
+					}
+					else {
+						moved_commits.len().to_string()
+					}
+					.as_str(),
+					is_full_width,
+				),
+			];
+			for l in moved_commits.into_iter().rev() {
+				lines.push(ViewLine::from(vec![
+					LineSegment::new_with_color(format!("  {} ", l.get_hash()).as_str(), DisplayColor::IndicatorColor),
+					LineSegment::new(l.get_content()),
+				]));
+			}
+			lines
+		},
+		Action::Break
+		| Action::Drop
+		| Action::Edit
+		| Action::Exec
+		| Action::Fixup
+		| Action::Merge
+		| Action::Noop
+		| Action::Pick
+		| Action::Revert
+		| Action::Reword
+		| Action::Squash => vec![],
+	}
+}
+
+pub(super) fn get_hex_dump(offset: usize, bytes: &[u8]) -> String {
+	let mut hex = String::new();
+	for i in 0..HEX_DUMP_WIDTH {
+		if i == HEX_DUMP_GROUP_WIDTH {
+			hex.push(' ');
+		}
+		match bytes.get(i) {
+			Some(b) => _ = write!(hex, "{b:02x} "),
+			None => hex.push_str("   "),
+		}
+	}
+	let ascii = bytes
+		.iter()
+		.take(HEX_DUMP_WIDTH)
+		.map(|b| {
+			if b.is_ascii_graphic() || *b == b' ' {
+				char::from(*b)
+			}
+			else {
+				'.'
+			}
+		})
+		.collect::<String>();
+	format!("{offset:08x}  {hex} |{ascii}|")
+}
+
+fn get_binary_file_description(file: &BinaryFile) -> String {
+	match file.preview() {
+		Some(preview) => format!("{} ({}x{})", file.file_type(), preview.width(), preview.height()),
+		None => file.file_type().to_string(),
 	}
 }
 
+fn get_binary_label(label: &str) -> LineSegment {
+	LineSegment::new_with_color(format!("{label:>8}: ").as_str(), DisplayColor::IndicatorColor)
+}
+
+pub(super) fn get_binary_diff_lines(binary_diff: &BinaryDiff) -> Vec<ViewLine> {
+	let old = binary_diff.old();
+	let new = binary_diff.new_file();
+	let mut lines = vec![];
+
+	let mut size_segments = vec![get_binary_label("size")];
+	match (old, new) {
+		(Some(o), Some(n)) => {
+			size_segments.push(LineSegment::new(
+				format!(
+					"{} {TO_FILE_INDICATOR_SHORT} {} bytes",
+					o.size().to_formatted_string(&Locale::en),
+					n.size().to_formatted_string(&Locale::en)
+				)
+				.as_str(),
+			));
+			match n.size().cmp(&o.size()) {
+				Ordering::Greater => {
+					size_segments.push(LineSegment::new_with_color(
+						format!(" (+{})", (n.size() - o.size()).to_formatted_string(&Locale::en)).as_str(),
+						DisplayColor::DiffAddColor,
+					));
+				},
+				Ordering::Less => {
+					size_segments.push(LineSegment::new_with_color(
+						format!(" (-{})", (o.size() - n.size()).to_formatted_string(&Locale::en)).as_str(),
+						DisplayColor::DiffRemoveColor,
+					));
+				},
+				Ordering::Equal => {},
+			}
+		},
+		(Some(f), None) | (None, Some(f)) => {
+			size_segments.push(LineSegment::new(
+				format!("{} bytes", f.size().to_formatted_string(&Locale::en)).as_str(),
+			));
+		},
+		(None, None) => size_segments.push(LineSegment::new("unknown")),
+	}
+	lines.push(ViewLine::from(size_segments));
+
+	let old_description = old.map(get_binary_file_description);
+	let new_description = new.map(get_binary_file_description);
+	let description = match (old_description, new_description) {
+		(Some(o), Some(n)) if o != n => format!("{o} {TO_FILE_INDICATOR_SHORT} {n}"),
+		(Some(d), _) | (None, Some(d)) => d,
+		(None, None) => String::from("unknown"),
+	};
+	lines.push(ViewLine::from(vec![
+		get_binary_label("type"),
+		LineSegment::new(description.as_str()),
+	]));
+
+	let Some(first_difference) = binary_diff.first_difference()
+	else {
+		lines.push(ViewLine::from(vec![
+			get_binary_label("differs"),
+			LineSegment::new("no content change"),
+		]));
+		return lines;
+	};
+	if old.is_some() && new.is_some() {
+		lines.push(ViewLine::from(vec![
+			get_binary_label("differs"),
+			LineSegment::new(format!("at byte {first_difference} (0x{first_difference:x})").as_str()),
+		]));
+	}
+
+	lines.push(ViewLine::new_empty_line());
+	if let Some(o) = old {
+		lines.push(ViewLine::from(LineSegment::new_with_color(
+			format!("- {}", get_hex_dump(binary_diff.dump_offset(), o.dump())).as_str(),
+			DisplayColor::DiffRemoveColor,
+		)));
+	}
+	if let Some(n) = new {
+		lines.push(ViewLine::from(LineSegment::new_with_color(
+			format!("+ {}", get_hex_dump(binary_diff.dump_offset(), n.dump())).as_str(),
+			DisplayColor::DiffAddColor,
+		)));
+	}
+
+	for (label, file, color) in [
+		("old", old, DisplayColor::DiffRemoveColor),
+		("new", new, DisplayColor::DiffAddColor),
+	] {
+		if let Some(preview) = file.and_then(BinaryFile::preview) {
+			lines.push(ViewLine::new_empty_line());
+			lines.push(ViewLine::from(vec![
+				get_binary_label("preview"),
+				LineSegment::new(label),
+			]));
+			for preview_line in preview.lines() {
+				lines.push(ViewLine::from(LineSegment::new_with_color(
+					format!("{:10}{preview_line}", "").as_str(),
+					color,
+				)));
+			}
+		}
+	}
+
+	lines
+}
+
 pub(super) fn get_partition_index_on_whitespace_for_line(line: &str) -> (usize, usize) {
 	let graphemes = UnicodeSegmentation::graphemes(line, true);
 	let length = graphemes.clone().map(str::len).sum();
@@ -202,4 +479,16 @@ mod tests {
 	fn get_partition_index_on_whitespace_for_line_cases(#[case] s: &str, #[case] start: usize, #[case] end: usize) {
 		assert_eq!(get_partition_index_on_whitespace_for_line(s), (start, end));
 	}
+
+	#[rstest]
+	#[case::empty(0, b"", "00000000                                                    ||")]
+	#[case::partial(16, b"ab\x00", "00000010  61 62 00                                          |ab.|")]
+	#[case::full(
+		32,
+		b"0123456789abcdef",
+		"00000020  30 31 32 33 34 35 36 37  38 39 61 62 63 64 65 66  |0123456789abcdef|"
+	)]
+	fn get_hex_dump_cases(#[case] offset: usize, #[case] bytes: &[u8], #[case] expected: &str) {
+		assert_eq!(get_hex_dump(offset, bytes), expected);
+	}
 }
@@ -1,10 +1,16 @@
+use std::{
+	collections::HashSet,
+	path::{Path, PathBuf},
+};
+
 use git2::ErrorCode;
 
 use crate::{
 	components::spin_indicator::SpinIndicator,
-	diff::{Commit, CommitDiff, DiffLine, Origin, thread::LoadStatus},
+	diff::{Commit, CommitDiff, DiffLine, FileStatus, Origin, thread::LoadStatus},
 	display::DisplayColor,
 	modules::show_commit::util::{
+		get_binary_diff_lines,
 		get_files_changed_summary,
 		get_partition_index_on_whitespace_for_line,
 		get_stat_item_segments,
@@ -41,6 +47,7 @@ impl ViewBuilderOptions {
 }
 
 pub(super) struct ViewBuilder {
+	file_header_lines: Vec<(usize, PathBuf)>,
 	invisible_tab_string: String,
 	visible_tab_string: String,
 	visible_space_string: String,
@@ -52,6 +59,7 @@ pub(super) struct ViewBuilder {
 impl ViewBuilder {
 	pub(crate) fn new(options: ViewBuilderOptions) -> Self {
 		Self {
+			file_header_lines: vec![],
 			invisible_tab_string: " ".repeat(options.tab_width),
 			visible_tab_string: format!("{0:width$}", options.tab_character, width = options.tab_width),
 			visible_space_string: options.space_character,
@@ -61,6 +69,14 @@ impl ViewBuilder {
 		}
 	}
 
+	/// Get the path of the file with the header at a line index of the last built diff.
+	pub(super) fn file_at_line(&self, index: usize) -> Option<&Path> {
+		self.file_header_lines
+			.iter()
+			.find(|&&(line_index, _)| line_index == index)
+			.map(|(_, path)| path.as_path())
+	}
+
 	fn replace_whitespace(&self, value: &str, visible: bool) -> String {
 		if visible {
 			value
@@ -152,6 +168,8 @@ impl ViewBuilder {
 		updater: &mut ViewDataUpdater<'_>,
 		diff: &CommitDiff,
 		load_status: &LoadStatus,
+		details: Vec<ViewLine>,
+		show_excluded: bool,
 		is_full_width: bool,
 	) {
 		updater.clear();
@@ -161,6 +179,9 @@ impl ViewBuilder {
 
 		let commit = diff.commit();
 		updater.push_leading_line(Self::build_leading_summary(commit, is_full_width));
+		for line in details {
+			updater.push_line(line);
+		}
 		// TODO handle authored date
 		updater.push_line(ViewLine::from(vec![
 			LineSegment::new_with_color(
@@ -204,17 +225,34 @@ impl ViewBuilder {
 			updater.push_line(ViewLine::from(""));
 		}
 
-		updater.push_line(get_files_changed_summary(diff, is_full_width));
+		updater.push_line(get_files_changed_summary(diff, show_excluded, is_full_width));
 		for status in diff.file_statuses() {
-			updater.push_line(ViewLine::from(get_stat_item_segments(
-				status.status(),
-				status.destination_path(),
-				status.source_path(),
-				is_full_width,
-			)));
+			if status.is_excluded() && !show_excluded {
+				continue;
+			}
+			updater.push_line(Self::build_stat_item(status, is_full_width, false));
 		}
 	}
 
+	fn build_stat_item(status: &FileStatus, is_full_width: bool, is_collapsed: bool) -> ViewLine {
+		let mut segments = get_stat_item_segments(
+			status.status(),
+			status.destination_path(),
+			status.source_path(),
+			is_full_width,
+		);
+		if status.is_excluded() {
+			segments.push(LineSegment::new_with_color(" (excluded)", DisplayColor::IndicatorColor));
+		}
+		if is_collapsed {
+			segments.push(LineSegment::new_with_color(
+				" (collapsed)",
+				DisplayColor::IndicatorColor,
+			));
+		}
+		ViewLine::from(segments)
+	}
+
 	fn build_diff_line_line_segment(content: &str, origin: Origin) -> LineSegment {
 		LineSegment::new_with_color(content, match origin {
 			Origin::Addition => DisplayColor::DiffAddColor,
@@ -296,33 +334,56 @@ impl ViewBuilder {
 		updater: &mut ViewDataUpdater<'_>,
 		diff: &CommitDiff,
 		load_status: &LoadStatus,
+		collapsed_files: &HashSet<PathBuf>,
+		show_excluded: bool,
 		is_full_width: bool,
 	) {
 		updater.clear();
+		self.file_header_lines.clear();
 
 		if !self.build_loading_status(updater, load_status) {
 			return;
 		}
 
 		updater.push_leading_line(Self::build_leading_summary(diff.commit(), is_full_width));
-		updater.push_leading_line(get_files_changed_summary(diff, is_full_width));
+		updater.push_leading_line(get_files_changed_summary(diff, show_excluded, is_full_width));
 		updater.push_line(ViewLine::new_empty_line().set_padding(PADDING_CHARACTER));
 
-		let file_statuses = diff.file_statuses();
+		let file_statuses = diff
+			.file_statuses()
+			.iter()
+			.filter(|status| show_excluded || !status.is_excluded())
+			.collect::<Vec<_>>();
 		for (s_i, status) in file_statuses.iter().enumerate() {
-			updater.push_line(ViewLine::from(get_stat_item_segments(
-				status.status(),
-				status.destination_path(),
-				status.source_path(),
-				true,
-			)));
+			let is_collapsed = collapsed_files.contains(status.destination_path());
+			self.file_header_lines
+				.push((updater.lines_count(), status.destination_path().to_path_buf()));
+			updater.push_line(Self::build_stat_item(status, true, is_collapsed));
+
+			if is_collapsed {
+				if s_i + 1 != file_statuses.len() {
+					updater.push_line(ViewLine::new_empty_line().set_padding(PADDING_CHARACTER));
+				}
+				continue;
+			}
+
+			if let Some(binary_diff) = status.binary_diff() {
+				updater.push_line(ViewLine::new_empty_line());
+				for line in get_binary_diff_lines(binary_diff) {
+					updater.push_line(line);
+				}
+			}
 
 			let old_largest_line_number_length = status.last_old_line_number().to_string().len();
 			let new_largest_line_number_length = status.last_new_line_number().to_string().len();
 			for delta in status.deltas() {
 				updater.push_line(ViewLine::new_empty_line());
 				updater.push_line(ViewLine::from(vec![
-					LineSegment::new_with_color_and_style("@@", DisplayColor::Normal, LineSegmentOptions::DIMMED),
+					LineSegment::new_with_color_and_style(
+						"@@",
+						DisplayColor::DiffHunkColor,
+						LineSegmentOptions::DIMMED,
+					),
 					LineSegment::new_with_color(
 						format!(
 							" -{},{} +{},{} ",
@@ -334,7 +395,11 @@ impl ViewBuilder {
 						.as_str(),
 						DisplayColor::DiffContextColor,
 					),
-					LineSegment::new_with_color_and_style("@@", DisplayColor::Normal, LineSegmentOptions::DIMMED),
+					LineSegment::new_with_color_and_style(
+						"@@",
+						DisplayColor::DiffHunkColor,
+						LineSegmentOptions::DIMMED,
+					),
 					LineSegment::new_with_color(
 						format!(" {}", delta.context()).as_str(),
 						DisplayColor::DiffContextColor,
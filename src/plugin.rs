@@ -0,0 +1,25 @@
+//! Git Interactive Rebase Tool - Plugin Module.
+//!
+//! # Description
+//! This module is used to run the configured plugins. A plugin is an external program that communicates with the
+//! tool using JSON-RPC 2.0 messages, one message per line, over its stdin and stdout. Plugins are notified of
+//! changes to the todo file and the selected line, and can register commands, annotate lines with badges, and
+//! propose edits to the todo file.
+mod action;
+mod message;
+mod plugin_command;
+mod plugin_edit;
+mod plugin_process;
+mod state;
+mod thread;
+mod update_handler;
+
+pub(crate) use self::{
+	action::Action,
+	message::PluginLine,
+	plugin_command::PluginCommand,
+	plugin_edit::PluginEdit,
+	state::State,
+	thread::Thread,
+	update_handler::UpdateHandlerFn,
+};
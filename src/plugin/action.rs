@@ -0,0 +1,23 @@
+use crate::plugin::PluginLine;
+
+/// A message for the plugin thread.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Action {
+	/// Notify the plugins of the lines of the todo file, after a change.
+	TodoChanged(Vec<PluginLine>),
+	/// Notify the plugins of the selected line, after a change.
+	SelectionChanged { index: usize, line: PluginLine },
+	/// Notify a plugin that one of its commands was run, on the selected line.
+	RunCommand {
+		plugin: usize,
+		command: String,
+		index: usize,
+		line: PluginLine,
+	},
+	/// Send a response to a request made by a plugin.
+	Respond { plugin: usize, message: String },
+	/// Check for new messages.
+	Continue,
+	/// End the plugins.
+	End,
+}
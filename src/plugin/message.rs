@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::todo_file::Line;
+
+const JSON_RPC_VERSION: &str = "2.0";
+
+/// The JSON-RPC error code for a message that is not valid JSON.
+pub(crate) const PARSE_ERROR: i64 = -32700;
+/// The JSON-RPC error code for a message that is not a valid request.
+pub(crate) const INVALID_REQUEST: i64 = -32600;
+/// The JSON-RPC error code for an unknown method.
+pub(crate) const METHOD_NOT_FOUND: i64 = -32601;
+/// The JSON-RPC error code for invalid method parameters.
+pub(crate) const INVALID_PARAMS: i64 = -32602;
+
+/// A line of the todo file, as sent to the plugins.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct PluginLine {
+	action: String,
+	hash: String,
+	content: String,
+	option: Option<String>,
+}
+
+impl From<&Line> for PluginLine {
+	fn from(line: &Line) -> Self {
+		Self {
+			action: line.get_action().to_string(),
+			hash: String::from(line.get_hash()),
+			content: String::from(line.get_content()),
+			option: line.option().map(String::from),
+		}
+	}
+}
+
+/// An error in a message received from a plugin.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct MessageError {
+	pub(crate) code: i64,
+	pub(crate) message: String,
+}
+
+impl MessageError {
+	pub(crate) fn new(code: i64, message: &str) -> Self {
+		Self {
+			code,
+			message: String::from(message),
+		}
+	}
+}
+
+/// A request or notification received from a plugin.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Request {
+	jsonrpc: String,
+	/// The id of a request, or `None` for a notification.
+	#[serde(default)]
+	pub(crate) id: Option<Value>,
+	pub(crate) method: String,
+	#[serde(default)]
+	pub(crate) params: Value,
+}
+
+impl Request {
+	/// Parse a request from a line of input. A message with the id of the request is returned with the error, when
+	/// it is available.
+	pub(crate) fn parse(input: &str) -> Result<Self, (Option<Value>, MessageError)> {
+		let value = serde_json::from_str::<Value>(input)
+			.map_err(|err| (None, MessageError::new(PARSE_ERROR, err.to_string().as_str())))?;
+		let id = value.get("id").cloned().filter(|id| !id.is_null());
+		let request = serde_json::from_value::<Self>(value)
+			.map_err(|err| (id.clone(), MessageError::new(INVALID_REQUEST, err.to_string().as_str())))?;
+		if request.jsonrpc != JSON_RPC_VERSION {
+			return Err((
+				id,
+				MessageError::new(INVALID_REQUEST, "The jsonrpc version must be \"2.0\""),
+			));
+		}
+		Ok(request)
+	}
+
+	/// Parse the parameters of the request.
+	pub(crate) fn params<'de, Params: Deserialize<'de>>(&'de self) -> Result<Params, MessageError> {
+		Params::deserialize(&self.params).map_err(|err| MessageError::new(INVALID_PARAMS, err.to_string().as_str()))
+	}
+}
+
+/// The parameters of the `registerCommand` method.
+#[derive(Debug, Deserialize)]
+pub(crate) struct RegisterCommandParams {
+	pub(crate) name: String,
+	pub(crate) key: String,
+	#[serde(default)]
+	pub(crate) description: Option<String>,
+}
+
+/// The parameters of the `annotate` method.
+#[derive(Debug, Deserialize)]
+pub(crate) struct AnnotateParams {
+	pub(crate) hash: String,
+	#[serde(default)]
+	pub(crate) badge: Option<String>,
+}
+
+/// The parameters of the `proposeEdit` method.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ProposeEditParams {
+	pub(crate) index: usize,
+	pub(crate) hash: String,
+	#[serde(default)]
+	pub(crate) action: Option<String>,
+	#[serde(default)]
+	pub(crate) content: Option<String>,
+}
+
+/// Build a notification message, sent to a plugin.
+pub(crate) fn notification(method: &str, params: &Value) -> String {
+	json!({"jsonrpc": JSON_RPC_VERSION, "method": method, "params": params}).to_string()
+}
+
+/// Build the response to a successful request.
+pub(crate) fn result_response(id: &Value) -> String {
+	json!({"jsonrpc": JSON_RPC_VERSION, "id": id, "result": null}).to_string()
+}
+
+/// Build the response to a failed request, with a `null` id when the id of the request is not known.
+pub(crate) fn error_response(id: Option<&Value>, error: &MessageError) -> String {
+	json!({
+		"jsonrpc": JSON_RPC_VERSION,
+		"id": id,
+		"error": {"code": error.code, "message": error.message},
+	})
+	.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::assert_ok;
+	use rstest::rstest;
+
+	use super::*;
+
+	#[test]
+	fn plugin_line_from_line() {
+		let line = Line::parse("fixup -C aaa comment").unwrap();
+		assert_eq!(
+			serde_json::to_string(&PluginLine::from(&line)).unwrap(),
+			r#"{"action":"fixup","hash":"aaa","content":"comment","option":"-C"}"#
+		);
+	}
+
+	#[test]
+	fn parse_request() {
+		let request = assert_ok!(Request::parse(
+			r#"{"jsonrpc": "2.0", "id": 1, "method": "annotate", "params": {"hash": "aaa"}}"#
+		));
+		assert_eq!(request.id, Some(json!(1)));
+		assert_eq!(request.method, "annotate");
+		let params: AnnotateParams = request.params().unwrap();
+		assert_eq!(params.hash, "aaa");
+		assert_eq!(params.badge, None);
+	}
+
+	#[test]
+	fn parse_notification() {
+		let request = assert_ok!(Request::parse(r#"{"jsonrpc": "2.0", "method": "annotate"}"#));
+		assert_eq!(request.id, None);
+		assert_eq!(request.params, Value::Null);
+	}
+
+	#[rstest]
+	#[case::invalid_json("{", None, PARSE_ERROR)]
+	#[case::missing_method(r#"{"jsonrpc": "2.0", "id": 2}"#, Some(json!(2)), INVALID_REQUEST)]
+	#[case::invalid_version(r#"{"jsonrpc": "1.0", "id": "a", "method": "m"}"#, Some(json!("a")), INVALID_REQUEST)]
+	fn parse_request_error(#[case] input: &str, #[case] expected_id: Option<Value>, #[case] expected_code: i64) {
+		let (id, error) = Request::parse(input).unwrap_err();
+		assert_eq!(id, expected_id);
+		assert_eq!(error.code, expected_code);
+	}
+
+	#[test]
+	fn params_error() {
+		let request = Request::parse(r#"{"jsonrpc": "2.0", "method": "annotate", "params": {}}"#).unwrap();
+		let error = request.params::<AnnotateParams>().unwrap_err();
+		assert_eq!(error.code, INVALID_PARAMS);
+	}
+
+	#[test]
+	fn build_notification() {
+		assert_eq!(
+			notification("shutdown", &json!({})),
+			r#"{"jsonrpc":"2.0","method":"shutdown","params":{}}"#
+		);
+	}
+
+	#[test]
+	fn build_result_response() {
+		assert_eq!(result_response(&json!(1)), r#"{"id":1,"jsonrpc":"2.0","result":null}"#);
+	}
+
+	#[test]
+	fn build_error_response() {
+		assert_eq!(
+			error_response(None, &MessageError::new(PARSE_ERROR, "error")),
+			r#"{"error":{"code":-32700,"message":"error"},"id":null,"jsonrpc":"2.0"}"#
+		);
+	}
+}
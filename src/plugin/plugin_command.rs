@@ -0,0 +1,16 @@
+use crate::input::Event;
+
+/// A command registered by a plugin, run on the selected line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PluginCommand {
+	/// The index of the plugin that registered the command.
+	pub(crate) plugin: usize,
+	/// The name of the command, unique for the plugin.
+	pub(crate) name: String,
+	/// The key bindings of the command, as they are shown in the help.
+	pub(crate) key: Vec<String>,
+	/// The key binding events of the command.
+	pub(crate) events: Vec<Event>,
+	/// The description of the command, shown in the help.
+	pub(crate) description: String,
+}
@@ -0,0 +1,16 @@
+use crate::todo_file::Action;
+
+/// An edit of a line of the todo file, proposed by a plugin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PluginEdit {
+	/// The index of the plugin that proposed the edit.
+	pub(crate) plugin: usize,
+	/// The index of the line to edit.
+	pub(crate) index: usize,
+	/// The hash of the line to edit, used to ensure the line did not change since the edit was proposed.
+	pub(crate) hash: String,
+	/// The new action of the line.
+	pub(crate) action: Option<Action>,
+	/// The new content of the line.
+	pub(crate) content: Option<String>,
+}
@@ -0,0 +1,233 @@
+use std::{
+	io::{self, BufRead as _, BufReader, Write as _},
+	process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+	sync::Arc,
+	thread::{self, JoinHandle, sleep},
+	time::{Duration, Instant},
+};
+
+use crossbeam_channel::{Sender, TrySendError};
+
+use crate::{
+	config::PluginConfig,
+	plugin::{Action, State, UpdateHandlerFn},
+};
+
+const SHUTDOWN_POLL_RATE: Duration = Duration::from_millis(10);
+/// The number of messages that are queued for a plugin that is not reading its input, before messages are dropped.
+const WRITE_QUEUE_SIZE: usize = 64;
+/// The time to wait for the threads of a plugin to end, after the plugin has exited. A thread can outlive the plugin
+/// when the plugin started a process that holds its input or output open, in which case the thread is detached.
+const THREAD_END_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// A running plugin, with a thread that writes the messages to the plugin, and a thread that reads the messages from
+/// the plugin.
+#[derive(Debug)]
+pub(crate) struct PluginProcess {
+	child: Child,
+	sender: Option<Sender<String>>,
+	writer: Option<JoinHandle<()>>,
+	reader: Option<JoinHandle<()>>,
+	shutdown_timeout: Duration,
+}
+
+impl PluginProcess {
+	/// Start the plugin, and the thread that reads the messages from the plugin.
+	pub(crate) fn start<UpdateHandler>(
+		plugin: usize,
+		config: &PluginConfig,
+		state: &State,
+		update_handler: &Arc<UpdateHandler>,
+	) -> io::Result<Self>
+	where
+		UpdateHandler: UpdateHandlerFn + 'static,
+	{
+		// stderr is discarded, since output from a plugin would corrupt the interface
+		let mut child = Command::new("sh")
+			.args(["-c", config.run.as_str()])
+			.stdin(Stdio::piped())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::null())
+			.spawn()?;
+		let stdin = child.stdin.take();
+		let stdout = child.stdout.take();
+
+		let (sender, receiver) = crossbeam_channel::bounded::<String>(WRITE_QUEUE_SIZE);
+		let writer = match stdin {
+			Some(stdin) => {
+				let spawn_result = thread::Builder::new()
+					.name(format!("plugin-{}-writer", config.name))
+					.spawn(move || write_messages(stdin, &receiver));
+				match spawn_result {
+					Ok(writer) => Some(writer),
+					Err(err) => {
+						drop(child.kill());
+						drop(child.wait());
+						return Err(err);
+					},
+				}
+			},
+			None => None,
+		};
+
+		let reader = match stdout {
+			Some(stdout) => {
+				let state = state.clone();
+				let update_handler = Arc::clone(update_handler);
+				let spawn_result = thread::Builder::new()
+					.name(format!("plugin-{}", config.name))
+					.spawn(move || read_messages(plugin, stdout, &state, update_handler.as_ref()));
+				match spawn_result {
+					Ok(reader) => Some(reader),
+					Err(err) => {
+						drop(child.kill());
+						drop(child.wait());
+						return Err(err);
+					},
+				}
+			},
+			None => None,
+		};
+
+		Ok(Self {
+			child,
+			sender: writer.is_some().then_some(sender),
+			writer,
+			reader,
+			shutdown_timeout: Duration::from_millis(u64::from(config.shutdown_timeout)),
+		})
+	}
+
+	/// Queue a message to be written to the plugin. The message is dropped if the plugin is not reading its input, and
+	/// the queue is full, so a plugin cannot block the tool.
+	pub(crate) fn write(&self, message: &str) -> io::Result<()> {
+		let Some(sender) = self.sender.as_ref()
+		else {
+			return Err(io::Error::from(io::ErrorKind::BrokenPipe));
+		};
+		sender.try_send(String::from(message)).map_err(|err| {
+			match err {
+				TrySendError::Full(_) => io::Error::from(io::ErrorKind::WouldBlock),
+				TrySendError::Disconnected(_) => io::Error::from(io::ErrorKind::BrokenPipe),
+			}
+		})
+	}
+
+	/// Close the input of the plugin, once the queued messages are written, and wait for the plugin to exit, killing
+	/// the plugin if it does not exit before the shutdown timeout.
+	pub(crate) fn shutdown(mut self) {
+		drop(self.sender.take());
+		let deadline = Instant::now() + self.shutdown_timeout;
+		while matches!(self.child.try_wait(), Ok(None)) && Instant::now() < deadline {
+			sleep(SHUTDOWN_POLL_RATE);
+		}
+		if matches!(self.child.try_wait(), Ok(None)) {
+			drop(self.child.kill());
+		}
+		drop(self.child.wait());
+
+		let deadline = Instant::now() + THREAD_END_TIMEOUT;
+		for handle in [self.writer.take(), self.reader.take()].into_iter().flatten() {
+			join_before(handle, deadline);
+		}
+	}
+}
+
+/// Join a thread if it ends before the deadline, otherwise the thread is detached.
+fn join_before(handle: JoinHandle<()>, deadline: Instant) {
+	while !handle.is_finished() && Instant::now() < deadline {
+		sleep(SHUTDOWN_POLL_RATE);
+	}
+	if handle.is_finished() {
+		drop(handle.join());
+	}
+}
+
+fn write_messages(mut stdin: ChildStdin, receiver: &crossbeam_channel::Receiver<String>) {
+	for message in receiver {
+		// a failed write means the plugin exited, which is reported by the reader
+		if writeln!(stdin, "{message}").and_then(|()| stdin.flush()).is_err() {
+			break;
+		}
+	}
+}
+
+fn read_messages<UpdateHandler: UpdateHandlerFn>(
+	plugin: usize,
+	stdout: ChildStdout,
+	state: &State,
+	update_handler: &UpdateHandler,
+) {
+	for input in BufReader::new(stdout).lines() {
+		let Ok(input) = input
+		else {
+			break;
+		};
+		if input.trim().is_empty() {
+			continue;
+		}
+		if let Some(message) = state.handle_message(plugin, input.as_str()) {
+			state.send_update(Action::Respond { plugin, message });
+		}
+		update_handler();
+	}
+
+	// the output of the plugin is only closed before the tool ends when the plugin exits early
+	if !state.is_ended() {
+		state.push_error(plugin, "The plugin exited");
+		update_handler();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::assert_ok;
+
+	use super::*;
+
+	fn plugin_config(run: &str) -> PluginConfig {
+		PluginConfig {
+			name: String::from("name"),
+			run: String::from(run),
+			shutdown_timeout: 10,
+		}
+	}
+
+	#[test]
+	fn write_to_plugin_not_reading_input() {
+		let state = State::new(vec![String::from("name")]);
+		let process = assert_ok!(PluginProcess::start(
+			0,
+			&plugin_config("exec sleep 30"),
+			&state,
+			&Arc::new(|| {})
+		));
+		let message = "x".repeat(10_000);
+		let mut results = vec![];
+		for _ in 0..WRITE_QUEUE_SIZE * 2 {
+			results.push(process.write(message.as_str()));
+		}
+		assert!(results.iter().any(|result| {
+			result
+				.as_ref()
+				.is_err_and(|err| err.kind() == io::ErrorKind::WouldBlock)
+		}));
+		state.end();
+		process.shutdown();
+	}
+
+	#[test]
+	fn shutdown_with_process_holding_output() {
+		let state = State::new(vec![String::from("name")]);
+		let process = assert_ok!(PluginProcess::start(
+			0,
+			&plugin_config("sleep 5 & cat > /dev/null"),
+			&state,
+			&Arc::new(|| {})
+		));
+		state.end();
+		let start = Instant::now();
+		process.shutdown();
+		assert!(start.elapsed() < Duration::from_secs(5));
+	}
+}
@@ -0,0 +1,524 @@
+use std::{
+	collections::{BTreeMap, HashMap},
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::Duration,
+};
+
+use crossbeam_channel::RecvTimeoutError;
+use parking_lot::Mutex;
+
+use crate::{
+	config::parse_input,
+	input::{Event, map_keybindings},
+	plugin::{
+		Action,
+		PluginCommand,
+		PluginEdit,
+		message::{
+			AnnotateParams,
+			INVALID_PARAMS,
+			METHOD_NOT_FOUND,
+			MessageError,
+			ProposeEditParams,
+			RegisterCommandParams,
+			Request,
+			error_response,
+			result_response,
+		},
+	},
+	todo_file,
+};
+
+const RECEIVE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Check that a value from a plugin, that is shown in the interface or written to the todo file, does not contain a
+/// control character, such as a newline or a terminal escape sequence.
+fn check_text(name: &str, value: &str) -> Result<(), MessageError> {
+	if value.chars().any(char::is_control) {
+		return Err(MessageError::new(
+			INVALID_PARAMS,
+			format!("The {name} {value:?} contains a control character").as_str(),
+		));
+	}
+	Ok(())
+}
+
+#[derive(Debug, Default)]
+struct Data {
+	commands: Vec<PluginCommand>,
+	// badges of each hash, keyed by the index of the plugin, so badges are shown in the order of the plugins
+	badges: HashMap<String, BTreeMap<usize, String>>,
+	edits: Vec<PluginEdit>,
+	errors: Vec<String>,
+	commands_version: usize,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct State {
+	names: Arc<[String]>,
+	data: Arc<Mutex<Data>>,
+	ended: Arc<AtomicBool>,
+	update_receiver: crossbeam_channel::Receiver<Action>,
+	update_sender: crossbeam_channel::Sender<Action>,
+}
+
+impl State {
+	pub(crate) fn new(names: Vec<String>) -> Self {
+		let (update_sender, update_receiver) = crossbeam_channel::unbounded();
+		Self {
+			names: Arc::from(names),
+			data: Arc::new(Mutex::new(Data::default())),
+			ended: Arc::new(AtomicBool::from(false)),
+			update_receiver,
+			update_sender,
+		}
+	}
+
+	pub(crate) fn has_plugins(&self) -> bool {
+		!self.names.is_empty()
+	}
+
+	pub(crate) fn plugin_name(&self, plugin: usize) -> &str {
+		self.names.get(plugin).map_or("", String::as_str)
+	}
+
+	pub(crate) fn receive_update(&self) -> Action {
+		self.update_receiver
+			.recv_timeout(RECEIVE_TIMEOUT)
+			.unwrap_or_else(|e: RecvTimeoutError| {
+				match e {
+					RecvTimeoutError::Timeout => Action::Continue,
+					RecvTimeoutError::Disconnected => Action::End,
+				}
+			})
+	}
+
+	/// Send a message to the plugins, ignored when there are no plugins.
+	pub(crate) fn send_update(&self, action: Action) {
+		if self.has_plugins() {
+			let _result = self.update_sender.send(action);
+		}
+	}
+
+	pub(crate) fn is_ended(&self) -> bool {
+		self.ended.load(Ordering::Acquire)
+	}
+
+	pub(crate) fn end(&self) {
+		self.ended.store(true, Ordering::Release);
+	}
+
+	/// Get the registered commands.
+	pub(crate) fn commands(&self) -> Vec<PluginCommand> {
+		self.data.lock().commands.clone()
+	}
+
+	/// Get a version of the registered commands, that changes when a command is registered.
+	pub(crate) fn commands_version(&self) -> usize {
+		self.data.lock().commands_version
+	}
+
+	/// Get the index of the registered command with a key binding that matches the event.
+	pub(crate) fn command_index(&self, event: &Event) -> Option<usize> {
+		self.data
+			.lock()
+			.commands
+			.iter()
+			.position(|command| command.events.contains(event))
+	}
+
+	/// Get the registered command at the index.
+	pub(crate) fn command(&self, index: usize) -> Option<PluginCommand> {
+		self.data.lock().commands.get(index).cloned()
+	}
+
+	/// Get the badges for the lines with a hash, in the order of the plugins.
+	pub(crate) fn badges(&self, hash: &str) -> Vec<String> {
+		self.data
+			.lock()
+			.badges
+			.get(hash)
+			.map(|badges| badges.values().cloned().collect())
+			.unwrap_or_default()
+	}
+
+	/// Take the edits proposed by the plugins, in the order that they were proposed.
+	pub(crate) fn take_edits(&self) -> Vec<PluginEdit> {
+		self.data.lock().edits.drain(..).collect()
+	}
+
+	/// Take the errors of the plugins, in the order that they happened.
+	pub(crate) fn take_errors(&self) -> Vec<String> {
+		self.data.lock().errors.drain(..).collect()
+	}
+
+	pub(crate) fn push_error(&self, plugin: usize, message: &str) {
+		self.data
+			.lock()
+			.errors
+			.push(format!("Plugin \"{}\": {message}", self.plugin_name(plugin)));
+	}
+
+	/// Handle a line of input from a plugin, returning the response, when the input is a request.
+	pub(crate) fn handle_message(&self, plugin: usize, input: &str) -> Option<String> {
+		let (id, result) = match Request::parse(input) {
+			Ok(request) => {
+				let result = self.handle_request(plugin, &request);
+				(request.id, result)
+			},
+			Err((id, error)) => (id, Err(error)),
+		};
+
+		match (id, result) {
+			(Some(id), Ok(())) => Some(result_response(&id)),
+			(id, Err(error)) => {
+				// a notification has no response, so the error is shown instead
+				if id.is_none() {
+					self.push_error(plugin, error.message.as_str());
+				}
+				id.map(|id| error_response(Some(&id), &error))
+			},
+			(None, Ok(())) => None,
+		}
+	}
+
+	fn handle_request(&self, plugin: usize, request: &Request) -> Result<(), MessageError> {
+		match request.method.as_str() {
+			"registerCommand" => self.register_command(plugin, request.params()?),
+			"annotate" => self.annotate(plugin, request.params()?),
+			"proposeEdit" => self.propose_edit(plugin, request.params()?),
+			method => {
+				Err(MessageError::new(
+					METHOD_NOT_FOUND,
+					format!("Unknown method \"{method}\"").as_str(),
+				))
+			},
+		}
+	}
+
+	fn register_command(&self, plugin: usize, params: RegisterCommandParams) -> Result<(), MessageError> {
+		check_text("name", params.name.as_str())?;
+		if let Some(description) = params.description.as_deref() {
+			check_text("description", description)?;
+		}
+		let key = parse_input("key", params.key.as_str())
+			.map_err(|err| MessageError::new(INVALID_PARAMS, err.to_string().as_str()))?;
+		// key sequences are only supported when they are known at startup
+		if key.is_empty() || key.iter().any(|k| k.contains(' ')) {
+			return Err(MessageError::new(
+				INVALID_PARAMS,
+				format!("The key \"{}\" is not a single key binding", params.key).as_str(),
+			));
+		}
+		let command = PluginCommand {
+			plugin,
+			events: map_keybindings(&key),
+			key,
+			description: params.description.unwrap_or_else(|| format!("Run {}", params.name)),
+			name: params.name,
+		};
+
+		let mut data = self.data.lock();
+		if let Some(existing) = data
+			.commands
+			.iter_mut()
+			.find(|existing| existing.plugin == plugin && existing.name == command.name)
+		{
+			*existing = command;
+		}
+		else {
+			data.commands.push(command);
+		}
+		data.commands_version += 1;
+		Ok(())
+	}
+
+	fn annotate(&self, plugin: usize, params: AnnotateParams) -> Result<(), MessageError> {
+		if let Some(badge) = params.badge.as_deref() {
+			check_text("badge", badge)?;
+		}
+		let mut data = self.data.lock();
+		if let Some(badge) = params.badge {
+			drop(data.badges.entry(params.hash).or_default().insert(plugin, badge));
+		}
+		else if let Some(badges) = data.badges.get_mut(params.hash.as_str()) {
+			drop(badges.remove(&plugin));
+		}
+		Ok(())
+	}
+
+	fn propose_edit(&self, plugin: usize, params: ProposeEditParams) -> Result<(), MessageError> {
+		let action = params
+			.action
+			.as_deref()
+			.map(todo_file::Action::try_from)
+			.transpose()
+			.map_err(|err| MessageError::new(INVALID_PARAMS, err.to_string().as_str()))?;
+		if let Some(content) = params.content.as_deref() {
+			check_text("content", content)?;
+		}
+		if action.is_none() && params.content.is_none() {
+			return Err(MessageError::new(
+				INVALID_PARAMS,
+				"An edit must change the action or the content",
+			));
+		}
+		self.data.lock().edits.push(PluginEdit {
+			plugin,
+			index: params.index,
+			hash: params.hash,
+			action,
+			content: params.content,
+		});
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_none, assert_some_eq};
+
+	use super::*;
+
+	fn create_state() -> State {
+		State::new(vec![String::from("review"), String::from("ci")])
+	}
+
+	fn notification(method: &str, params: &str) -> String {
+		format!(r#"{{"jsonrpc":"2.0","method":"{method}","params":{params}}}"#)
+	}
+
+	fn request(method: &str, params: &str) -> String {
+		format!(r#"{{"jsonrpc":"2.0","id":1,"method":"{method}","params":{params}}}"#)
+	}
+
+	#[test]
+	fn send_recv_update() {
+		let state = create_state();
+		state.send_update(Action::TodoChanged(vec![]));
+		assert_eq!(state.receive_update(), Action::TodoChanged(vec![]));
+	}
+
+	#[test]
+	fn send_update_without_plugins() {
+		let state = State::new(vec![]);
+		state.send_update(Action::TodoChanged(vec![]));
+		assert_eq!(state.receive_update(), Action::Continue);
+	}
+
+	#[test]
+	fn send_recv_disconnect() {
+		let (update_sender, _update_receiver) = crossbeam_channel::unbounded();
+		let mut state = create_state();
+		state.update_sender = update_sender; // replace last reference to sender, to force a disconnect
+		assert_eq!(state.receive_update(), Action::End);
+	}
+
+	#[test]
+	fn ended() {
+		let state = create_state();
+		state.end();
+		assert!(state.is_ended());
+	}
+
+	#[test]
+	fn plugin_name() {
+		let state = create_state();
+		assert_eq!(state.plugin_name(1), "ci");
+		assert_eq!(state.plugin_name(2), "");
+	}
+
+	#[test]
+	fn register_command() {
+		let state = create_state();
+		assert_none!(state.handle_message(
+			0,
+			notification("registerCommand", r#"{"name":"approve","key":"Control+a"}"#).as_str()
+		));
+		assert_eq!(state.commands(), vec![PluginCommand {
+			plugin: 0,
+			name: String::from("approve"),
+			key: vec![String::from("Controla")],
+			events: map_keybindings(&[String::from("Controla")]),
+			description: String::from("Run approve"),
+		}]);
+		assert_eq!(state.commands_version(), 1);
+		assert_some_eq!(state.command_index(&map_keybindings(&[String::from("Controla")])[0]), 0);
+		assert_none!(state.command_index(&Event::from('a')));
+	}
+
+	#[test]
+	fn register_command_replaces_existing() {
+		let state = create_state();
+		_ = state.handle_message(
+			0,
+			notification("registerCommand", r#"{"name":"approve","key":"a"}"#).as_str(),
+		);
+		_ = state.handle_message(
+			1,
+			notification("registerCommand", r#"{"name":"approve","key":"b"}"#).as_str(),
+		);
+		_ = state.handle_message(
+			0,
+			notification(
+				"registerCommand",
+				r#"{"name":"approve","key":"c","description":"Approve"}"#,
+			)
+			.as_str(),
+		);
+		let commands = state.commands();
+		assert_eq!(commands.len(), 2);
+		assert_eq!(commands[0].key, vec![String::from("c")]);
+		assert_eq!(commands[0].description, "Approve");
+		assert_eq!(commands[1].plugin, 1);
+		assert_some_eq!(state.command(1).map(|command| command.key), vec![String::from("b")]);
+	}
+
+	#[test]
+	fn register_command_key_sequence() {
+		let state = create_state();
+		let response = state
+			.handle_message(0, request("registerCommand", r#"{"name":"a","key":"g,g"}"#).as_str())
+			.unwrap();
+		assert!(response.contains(r#""code":-32602,"message":"The key \"g,g\" is not a single key binding""#));
+		assert!(state.commands().is_empty());
+	}
+
+	#[test]
+	fn register_command_invalid_key() {
+		let state = create_state();
+		let response = state
+			.handle_message(
+				0,
+				request("registerCommand", r#"{"name":"a","key":"Control+invalid"}"#).as_str(),
+			)
+			.unwrap();
+		assert!(response.contains("-32602"));
+	}
+
+	#[test]
+	fn annotate() {
+		let state = create_state();
+		_ = state.handle_message(
+			1,
+			notification("annotate", r#"{"hash":"aaa","badge":"CI ok"}"#).as_str(),
+		);
+		_ = state.handle_message(
+			0,
+			notification("annotate", r#"{"hash":"aaa","badge":"approved"}"#).as_str(),
+		);
+		assert_eq!(state.badges("aaa"), vec![
+			String::from("approved"),
+			String::from("CI ok")
+		]);
+		_ = state.handle_message(0, notification("annotate", r#"{"hash":"aaa","badge":null}"#).as_str());
+		assert_eq!(state.badges("aaa"), vec![String::from("CI ok")]);
+		assert!(state.badges("bbb").is_empty());
+	}
+
+	#[test]
+	fn annotate_badge_with_control_character() {
+		let state = create_state();
+		assert_none!(state.handle_message(
+			0,
+			notification("annotate", r#"{"hash":"aaa","badge":"ok\u001b[2J"}"#).as_str()
+		));
+		assert!(state.badges("aaa").is_empty());
+		assert_eq!(state.take_errors(), vec![String::from(
+			"Plugin \"review\": The badge \"ok\\u{1b}[2J\" contains a control character"
+		)]);
+	}
+
+	#[test]
+	fn register_command_with_control_character() {
+		let state = create_state();
+		let response = state
+			.handle_message(
+				0,
+				request("registerCommand", r#"{"name":"a","key":"a","description":"Run\r"}"#).as_str(),
+			)
+			.unwrap();
+		assert!(response.contains("-32602"));
+		assert!(state.commands().is_empty());
+	}
+
+	#[test]
+	fn propose_edit() {
+		let state = create_state();
+		assert_some_eq!(
+			state.handle_message(
+				0,
+				request("proposeEdit", r#"{"index":1,"hash":"aaa","action":"fixup"}"#).as_str()
+			),
+			r#"{"id":1,"jsonrpc":"2.0","result":null}"#
+		);
+		assert_eq!(state.take_edits(), vec![PluginEdit {
+			plugin: 0,
+			index: 1,
+			hash: String::from("aaa"),
+			action: Some(todo_file::Action::Fixup),
+			content: None,
+		}]);
+		assert!(state.take_edits().is_empty());
+	}
+
+	#[test]
+	fn propose_edit_invalid_action() {
+		let state = create_state();
+		assert_none!(state.handle_message(
+			0,
+			notification("proposeEdit", r#"{"index":1,"hash":"aaa","action":"invalid"}"#).as_str()
+		));
+		assert!(state.take_edits().is_empty());
+		assert_eq!(state.take_errors(), vec![String::from(
+			"Plugin \"review\": The action `invalid` is not valid"
+		)]);
+	}
+
+	#[test]
+	fn propose_edit_content_with_control_character() {
+		let state = create_state();
+		let response = state
+			.handle_message(
+				0,
+				request(
+					"proposeEdit",
+					r#"{"index":1,"hash":"aaa","content":"c1\nexec rm -rf ~"}"#,
+				)
+				.as_str(),
+			)
+			.unwrap();
+		assert!(response.contains(r#""message":"The content \"c1\\nexec rm -rf ~\" contains a control character""#));
+		assert!(state.take_edits().is_empty());
+	}
+
+	#[test]
+	fn propose_edit_without_change() {
+		let state = create_state();
+		_ = state.handle_message(1, notification("proposeEdit", r#"{"index":1,"hash":"aaa"}"#).as_str());
+		assert_eq!(state.take_errors(), vec![String::from(
+			"Plugin \"ci\": An edit must change the action or the content"
+		)]);
+	}
+
+	#[test]
+	fn unknown_method() {
+		let state = create_state();
+		assert_some_eq!(
+			state.handle_message(0, r#"{"jsonrpc":"2.0","id":5,"method":"unknown"}"#),
+			r#"{"error":{"code":-32601,"message":"Unknown method \"unknown\""},"id":5,"jsonrpc":"2.0"}"#
+		);
+		assert!(state.take_errors().is_empty());
+	}
+
+	#[test]
+	fn invalid_message() {
+		let state = create_state();
+		assert_none!(state.handle_message(0, "not json"));
+		assert_eq!(state.take_errors().len(), 1);
+		assert!(state.take_errors().is_empty());
+	}
+}
@@ -0,0 +1,283 @@
+use std::sync::Arc;
+
+use captur::capture;
+use serde_json::json;
+
+use crate::{
+	config::PluginConfig,
+	plugin::{Action, State, UpdateHandlerFn, message::notification, plugin_process::PluginProcess},
+	runtime::{Installer, Threadable},
+	version::VERSION,
+};
+
+pub(crate) const THREAD_NAME: &str = "plugin";
+
+/// Build the messages for an action, with the index of the plugin the message is for, or `None` for all plugins.
+fn action_message(action: Action) -> Option<(Option<usize>, String)> {
+	match action {
+		Action::TodoChanged(lines) => Some((None, notification("todoChanged", &json!({ "lines": lines })))),
+		Action::SelectionChanged { index, line } => {
+			Some((
+				None,
+				notification("selectionChanged", &json!({ "index": index, "line": line })),
+			))
+		},
+		Action::RunCommand {
+			plugin,
+			command,
+			index,
+			line,
+		} => {
+			Some((
+				Some(plugin),
+				notification(
+					"commandRun",
+					&json!({ "command": command, "index": index, "line": line }),
+				),
+			))
+		},
+		Action::Respond { plugin, message } => Some((Some(plugin), message)),
+		Action::Continue | Action::End => None,
+	}
+}
+
+#[derive(Debug)]
+pub(crate) struct Thread<UpdateHandler: UpdateHandlerFn> {
+	plugins: Vec<PluginConfig>,
+	state: State,
+	update_handler: Arc<UpdateHandler>,
+}
+
+impl<UpdateHandler> Threadable for Thread<UpdateHandler>
+where UpdateHandler: UpdateHandlerFn + 'static
+{
+	fn install(&self, installer: &Installer) {
+		if self.plugins.is_empty() {
+			return;
+		}
+
+		let state = self.state();
+		let plugins = self.plugins.clone();
+		let update_handler = Arc::clone(&self.update_handler);
+
+		installer.spawn(THREAD_NAME, |notifier| {
+			move || {
+				capture!(notifier, state);
+				notifier.busy();
+				let processes = plugins
+					.iter()
+					.enumerate()
+					.map(|(index, plugin)| {
+						match PluginProcess::start(index, plugin, &state, &update_handler) {
+							Ok(process) => {
+								let message =
+									notification("initialize", &json!({ "name": plugin.name, "version": VERSION }));
+								// a failed write means the plugin exited, which is reported by the reader
+								drop(process.write(message.as_str()));
+								Some(process)
+							},
+							Err(err) => {
+								state.push_error(index, format!("Unable to start: {err}").as_str());
+								update_handler();
+								None
+							},
+						}
+					})
+					.collect::<Vec<Option<PluginProcess>>>();
+
+				loop {
+					notifier.wait();
+					if state.is_ended() {
+						break;
+					}
+					let action = state.receive_update();
+					if action == Action::End {
+						break;
+					}
+					notifier.busy();
+					let Some((target, message)) = action_message(action)
+					else {
+						continue;
+					};
+					for (index, process) in processes.iter().enumerate() {
+						if target.is_none_or(|target| target == index) {
+							if let Some(process) = process.as_ref() {
+								// the message is dropped when the plugin is not keeping up with its input
+								drop(process.write(message.as_str()));
+							}
+						}
+					}
+				}
+
+				// the state is ended first, so the exit of the plugins is not reported as an error
+				state.end();
+				let shutdown = notification("shutdown", &json!({}));
+				for process in processes.into_iter().flatten() {
+					drop(process.write(shutdown.as_str()));
+					process.shutdown();
+				}
+
+				notifier.request_end();
+				notifier.end();
+			}
+		});
+	}
+
+	fn end(&self) {
+		self.state.end();
+	}
+}
+
+impl<UpdateHandler> Thread<UpdateHandler>
+where UpdateHandler: UpdateHandlerFn
+{
+	pub(crate) fn new(plugins: Vec<PluginConfig>, update_handler: UpdateHandler) -> Self {
+		Self {
+			state: State::new(plugins.iter().map(|plugin| plugin.name.clone()).collect()),
+			plugins,
+			update_handler: Arc::new(update_handler),
+		}
+	}
+
+	pub(crate) fn state(&self) -> State {
+		self.state.clone()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{fs, thread::sleep, time::Duration};
+
+	use claims::assert_ok;
+	use tempfile::NamedTempFile;
+
+	use super::*;
+	use crate::{
+		plugin::PluginLine,
+		runtime::{Status, ThreadStatuses},
+		test_helpers::testers,
+		todo_file::Line,
+	};
+
+	fn plugin_config(name: &str, run: &str) -> PluginConfig {
+		PluginConfig {
+			name: String::from(name),
+			run: String::from(run),
+			shutdown_timeout: 1000,
+		}
+	}
+
+	fn wait_for<F: FnMut() -> bool>(mut condition: F) {
+		for _ in 0..100 {
+			if condition() {
+				return;
+			}
+			sleep(Duration::from_millis(50));
+		}
+		panic!("Timeout waiting for condition");
+	}
+
+	fn line() -> PluginLine {
+		PluginLine::from(&Line::parse("pick abc comment").unwrap())
+	}
+
+	#[test]
+	fn install_without_plugins() {
+		let thread = Thread::new(vec![], || {});
+		let (sender, _receiver) = crossbeam_channel::unbounded();
+		let installer = Installer::new(ThreadStatuses::new(), sender);
+		thread.install(&installer);
+		assert!(installer.into_ops().is_empty());
+	}
+
+	#[test]
+	fn set_end() {
+		let thread = Thread::new(vec![plugin_config("name", "cat")], || {});
+		let state = thread.state();
+		thread.end();
+		assert!(state.is_ended());
+	}
+
+	#[test]
+	fn messages_sent_to_plugin() {
+		let output = assert_ok!(NamedTempFile::new());
+		let path = output.path().to_string_lossy().to_string();
+		let thread = Thread::new(vec![plugin_config("record", format!("cat > '{path}'").as_str())], || {});
+		let state = thread.state();
+		let tester = testers::Threadable::new();
+		tester.start_threadable(&thread, THREAD_NAME);
+		tester.wait_for_status(&Status::Waiting);
+		state.send_update(Action::TodoChanged(vec![line()]));
+		state.send_update(Action::SelectionChanged { index: 0, line: line() });
+		state.send_update(Action::RunCommand {
+			plugin: 0,
+			command: String::from("cmd"),
+			index: 0,
+			line: line(),
+		});
+		state.send_update(Action::End);
+		tester.wait_for_status(&Status::Ended);
+
+		let messages = assert_ok!(fs::read_to_string(output.path()));
+		let methods = messages
+			.lines()
+			.map(|message| {
+				let value: serde_json::Value = assert_ok!(serde_json::from_str(message));
+				String::from(value["method"].as_str().unwrap())
+			})
+			.collect::<Vec<String>>();
+		assert_eq!(methods, vec![
+			"initialize",
+			"todoChanged",
+			"selectionChanged",
+			"commandRun",
+			"shutdown"
+		]);
+		assert!(messages.contains(r#""name":"record""#));
+		assert!(messages.contains(r#""command":"cmd""#));
+	}
+
+	#[test]
+	fn messages_received_from_plugin() {
+		let run =
+			r#"echo '{"jsonrpc":"2.0","method":"annotate","params":{"hash":"abc","badge":"ok"}}'; cat > /dev/null"#;
+		let thread = Thread::new(vec![plugin_config("annotate", run)], || {});
+		let state = thread.state();
+		let tester = testers::Threadable::new();
+		tester.start_threadable(&thread, THREAD_NAME);
+		wait_for(|| !state.badges("abc").is_empty());
+		assert_eq!(state.badges("abc"), vec![String::from("ok")]);
+		state.send_update(Action::End);
+		tester.wait_for_status(&Status::Ended);
+		assert!(state.take_errors().is_empty());
+	}
+
+	#[test]
+	fn plugin_exits_early() {
+		let thread = Thread::new(vec![plugin_config("early", "true")], || {});
+		let state = thread.state();
+		let tester = testers::Threadable::new();
+		tester.start_threadable(&thread, THREAD_NAME);
+		let mut errors = vec![];
+		wait_for(|| {
+			errors.extend(state.take_errors());
+			!errors.is_empty()
+		});
+		assert_eq!(errors, vec![String::from(r#"Plugin "early": The plugin exited"#)]);
+		state.send_update(Action::End);
+		tester.wait_for_status(&Status::Ended);
+	}
+
+	#[test]
+	fn plugin_killed_after_shutdown_timeout() {
+		let mut config = plugin_config("slow", "exec sleep 30");
+		config.shutdown_timeout = 10;
+		let thread = Thread::new(vec![config], || {});
+		let state = thread.state();
+		let tester = testers::Threadable::new();
+		tester.start_threadable(&thread, THREAD_NAME);
+		tester.wait_for_status(&Status::Waiting);
+		state.send_update(Action::End);
+		tester.wait_for_status(&Status::Ended);
+	}
+}
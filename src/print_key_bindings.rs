@@ -0,0 +1,150 @@
+use crate::{
+	config::{Config, ConfigLoader, KeyBindings, format_key_binding},
+	exit::Exit,
+	git::open_repository_from_env,
+	module::ExitStatus,
+};
+
+fn format_keys(keys: &[String]) -> String {
+	keys.iter()
+		.map(|key| {
+			let key = format_key_binding(key).replace('|', "\\|");
+			if key.contains('`') {
+				format!("`` {key} ``")
+			}
+			else {
+				format!("`{key}`")
+			}
+		})
+		.collect::<Vec<String>>()
+		.join(" ")
+}
+
+fn build_table(rows: &[(String, String)]) -> Vec<String> {
+	let header = ("Setting", "Keys");
+	let name_width = rows
+		.iter()
+		.map(|(name, _)| name.chars().count())
+		.max()
+		.unwrap_or(0)
+		.max(header.0.len());
+	let keys_width = rows
+		.iter()
+		.map(|(_, keys)| keys.chars().count())
+		.max()
+		.unwrap_or(0)
+		.max(header.1.len());
+
+	let mut lines = vec![
+		format!("| {:name_width$} | {:keys_width$} |", header.0, header.1),
+		format!("|{}|{}|", "-".repeat(name_width + 2), "-".repeat(keys_width + 2)),
+	];
+	for (name, keys) in rows {
+		lines.push(format!("| {name:name_width$} | {keys:keys_width$} |"));
+	}
+	lines
+}
+
+fn build_key_bindings(key_bindings: &KeyBindings) -> String {
+	let mut lines = vec![];
+	for view in key_bindings.views() {
+		let rows = view
+			.bindings
+			.iter()
+			.map(|&(ref name, keys)| (format!("`{name}`"), format_keys(keys)))
+			.collect::<Vec<(String, String)>>();
+		if !lines.is_empty() {
+			lines.push(String::new());
+		}
+		lines.push(format!("## {}", view.name));
+		lines.push(String::new());
+		lines.extend(build_table(&rows));
+	}
+	lines.join("\n")
+}
+
+pub(crate) fn run() -> Exit {
+	let repository = match open_repository_from_env() {
+		Ok(repository) => repository,
+		Err(err) => {
+			return Exit::new(
+				ExitStatus::StateError,
+				format!("Unable to load Git repository: {err}").as_str(),
+			);
+		},
+	};
+
+	match Config::try_from(&ConfigLoader::from(repository)) {
+		Ok(config) => Exit::from(build_key_bindings(&config.key_bindings)),
+		Err(err) => Exit::new(ExitStatus::ConfigError, format!("{err:#}").as_str()),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::{create_config, with_git_directory};
+
+	#[test]
+	fn run_success() {
+		with_git_directory("fixtures/simple", |_| {
+			let exit = run();
+			assert_eq!(exit.get_status(), &ExitStatus::Good);
+			assert!(exit.get_message().unwrap().starts_with("## List"));
+		});
+	}
+
+	#[test]
+	fn run_repository_error() {
+		with_git_directory("fixtures/not-a-repository", |_| {
+			assert_eq!(run().get_status(), &ExitStatus::StateError);
+		});
+	}
+
+	#[test]
+	fn run_config_error() {
+		with_git_directory("fixtures/invalid-config", |_| {
+			assert_eq!(run().get_status(), &ExitStatus::ConfigError);
+		});
+	}
+
+	#[test]
+	fn format_keys_single() {
+		assert_eq!(format_keys(&[String::from("Controlz")]), "`Control+z`");
+	}
+
+	#[test]
+	fn format_keys_multiple() {
+		assert_eq!(
+			format_keys(&[String::from("Down"), String::from("g g")]),
+			"`Down` `g,g`"
+		);
+	}
+
+	#[test]
+	fn format_keys_escaped() {
+		assert_eq!(format_keys(&[String::from("|"), String::from("`")]), "`\\|` `` ` ``");
+	}
+
+	#[test]
+	fn format_keys_empty() {
+		assert_eq!(format_keys(&[]), "");
+	}
+
+	#[test]
+	fn build_key_bindings_tables() {
+		let output = build_key_bindings(&create_config().key_bindings);
+		let lines = output.lines().collect::<Vec<&str>>();
+		assert_eq!(lines[0], "## List");
+		assert_eq!(lines[1], "");
+		assert_eq!(lines[2], "| Setting                      | Keys        |");
+		assert_eq!(lines[3], "|------------------------------|-------------|");
+		assert_eq!(lines[4], "| `inputAbort`                 | `q`         |");
+		assert!(output.contains("\n\n## Show Commit\n\n"));
+		assert!(output.contains("\n\n## Range Diff\n\n"));
+		assert!(output.contains("\n\n## Commit Message\n\n"));
+		assert!(output.contains("| `inputCommitMessageSave` "));
+		assert!(output.contains("\n\n## Confirm\n\n"));
+		assert!(output.contains("| `inputUndo` "));
+	}
+}
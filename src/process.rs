@@ -18,9 +18,12 @@ use parking_lot::Mutex;
 
 pub(crate) use self::{artifact::Artifact, results::Results, thread::Thread};
 use crate::{
-	application::AppData,
+	application::{AppData, commit_diff_loader_options, load_commit_authors_for_config, todo_file_options},
+	config::{Config, ConfigLoader},
+	diff::RangeDiffCommit,
 	display::Size,
-	input::{Event, StandardEvent},
+	git::open_repository_from_env,
+	input::{Event, EventHandler, KeyBindings, StandardEvent},
 	module::{self, ExitStatus, ModuleHandler, State},
 	runtime::ThreadStatuses,
 	search::{self, Action, Searchable},
@@ -29,6 +32,7 @@ use crate::{
 };
 
 pub(crate) struct Process<ModuleProvider: module::ModuleProvider> {
+	app_data: AppData,
 	ended: Arc<AtomicBool>,
 	exit_status: Arc<Mutex<ExitStatus>>,
 	input_state: crate::input::State,
@@ -46,6 +50,7 @@ pub(crate) struct Process<ModuleProvider: module::ModuleProvider> {
 impl<ModuleProvider: module::ModuleProvider> Clone for Process<ModuleProvider> {
 	fn clone(&self) -> Self {
 		Self {
+			app_data: self.app_data.clone(),
 			ended: Arc::clone(&self.ended),
 			exit_status: Arc::clone(&self.exit_status),
 			input_state: self.input_state.clone(),
@@ -70,6 +75,7 @@ impl<ModuleProvider: module::ModuleProvider> Process<ModuleProvider> {
 		thread_statuses: ThreadStatuses,
 	) -> Self {
 		Self {
+			app_data: app_data.clone(),
 			ended: Arc::new(AtomicBool::from(false)),
 			exit_status: Arc::new(Mutex::new(ExitStatus::None)),
 			input_state: app_data.input_state(),
@@ -137,19 +143,35 @@ impl<ModuleProvider: module::ModuleProvider> Process<ModuleProvider> {
 		self.view_state.render(view_data);
 	}
 
-	pub(crate) fn write_todo_file(&self) -> Result<()> {
+	/// Write the file being edited, the commit message when editing a commit message, otherwise the todo file.
+	pub(crate) fn write_file(&self) -> Result<()> {
+		if let Some(commit_message) = self.app_data.commit_message() {
+			return commit_message.lock().write_file().map_err(Error::from);
+		}
 		self.todo_file.lock().write_file().map_err(Error::from)
 	}
 
+	pub(crate) fn initial_module(&self) -> State {
+		self.app_data.initial_module()
+	}
+
 	fn deactivate(&self, state: State) -> Results {
 		let mut module_handler = self.module_handler.lock();
 		module_handler.deactivate(state)
 	}
 
 	pub(crate) fn handle_event(&self) -> Option<Results> {
-		self.module_handler
-			.lock()
-			.handle_event(self.state(), self.input_state.read_event())
+		let mut module_handler = self.module_handler.lock();
+		// keys from an incomplete key sequence are handled before waiting on new events
+		let event = if module_handler.has_queued_events() {
+			Event::None
+		}
+		else {
+			self.input_state.read_event()
+		};
+		let results = module_handler.handle_event(self.state(), event);
+		self.view_state.set_pending_keys(module_handler.pending_keys().as_str());
+		results
 	}
 
 	fn handle_event_artifact(&self, event: Event) -> Results {
@@ -297,6 +319,45 @@ impl<ModuleProvider: module::ModuleProvider> Process<ModuleProvider> {
 		Results::new()
 	}
 
+	fn handle_range_diff_load(&self, original: Vec<String>, edited: Vec<RangeDiffCommit>) -> Results {
+		self.diff_state.start_range_diff_load(original, edited);
+		Results::new()
+	}
+
+	fn handle_reload_config(&self) -> Results {
+		let loaded = open_repository_from_env().map_err(Error::from).and_then(|repository| {
+			let config_loader = ConfigLoader::from(repository);
+			let config = Config::try_from(&config_loader).map_err(Error::from)?;
+			Ok((config, config_loader.eject_repository()))
+		});
+
+		match loaded {
+			Ok((config, repository)) => {
+				self.view_state
+					.reconfigure(&config.theme, config.key_bindings.help_indicator_key());
+				let event_handler = EventHandler::new(KeyBindings::new(&config.key_bindings));
+				// the authors are only loaded when shown, so the line format may have changed to show them
+				self.app_data.set_commit_authors(load_commit_authors_for_config(
+					&config,
+					&repository,
+					&self.todo_file.lock(),
+				));
+				self.todo_file.lock().set_options(todo_file_options(&config));
+				// a diff that is loading or loaded used the previous options, so it is loaded again when shown
+				self.diff_state.cancel();
+				self.diff_state.set_options(commit_diff_loader_options(&config));
+				self.app_data.set_config(config);
+				self.module_handler.lock().reload(event_handler, &self.app_data);
+				self.activate(self.state())
+			},
+			Err(err) => {
+				let mut results = Results::new();
+				results.error_with_return(err.context("Unable to reload the configuration"), self.state());
+				results
+			},
+		}
+	}
+
 	fn handle_results(&self, mut results: Results) {
 		while let Some(artifact) = results.artifact() {
 			results.append(match artifact {
@@ -311,6 +372,8 @@ impl<ModuleProvider: module::ModuleProvider> Process<ModuleProvider> {
 				Artifact::Searchable(searchable) => self.handle_searchable(searchable),
 				Artifact::LoadDiff(hash) => self.handle_diff_load(hash.as_str()),
 				Artifact::CancelDiff => self.handle_diff_cancel(),
+				Artifact::LoadRangeDiff(original, edited) => self.handle_range_diff_load(original, edited),
+				Artifact::ReloadConfig => self.handle_reload_config(),
 			});
 		}
 	}
@@ -3,6 +3,7 @@ use std::fmt::{Debug, Formatter};
 use anyhow::Error;
 
 use crate::{
+	diff::RangeDiffCommit,
 	input::Event,
 	module::{ExitStatus, State},
 	search::Searchable,
@@ -20,6 +21,8 @@ pub(crate) enum Artifact {
 	Searchable(Box<dyn Searchable>),
 	LoadDiff(String),
 	CancelDiff,
+	LoadRangeDiff(Vec<String>, Vec<RangeDiffCommit>),
+	ReloadConfig,
 }
 
 impl Debug for Artifact {
@@ -36,6 +39,8 @@ impl Debug for Artifact {
 			Self::Searchable(_) => write!(f, "Searchable(dyn Searchable)"),
 			Self::LoadDiff(ref hash) => write!(f, "LoadDiff({hash:?})"),
 			Self::CancelDiff => write!(f, "CancelDiff"),
+			Self::LoadRangeDiff(ref original, ref edited) => write!(f, "LoadRangeDiff({original:?}, {edited:?})"),
+			Self::ReloadConfig => write!(f, "ReloadConfig"),
 		}
 	}
 }
@@ -63,6 +68,11 @@ mod tests {
 	)]
 	#[case::diff_load(Artifact::LoadDiff(String::from("hash")), "LoadDiff(\"hash\")")]
 	#[case::diff_cancel(Artifact::CancelDiff, "CancelDiff")]
+	#[case::range_diff_load(
+		Artifact::LoadRangeDiff(vec![String::from("hash")], vec![]),
+		"LoadRangeDiff([\"hash\"], [])"
+	)]
+	#[case::reload_config(Artifact::ReloadConfig, "ReloadConfig")]
 	fn debug(#[case] artifact: Artifact, #[case] expected: &str) {
 		assert_eq!(format!("{artifact:?}"), expected);
 	}
@@ -3,6 +3,7 @@ use std::collections::VecDeque;
 use anyhow::Error;
 
 use crate::{
+	diff::RangeDiffCommit,
 	input::Event,
 	module::{ExitStatus, State},
 	process::Artifact,
@@ -57,11 +58,19 @@ impl Results {
 		self.artifacts.push_back(Artifact::CancelDiff);
 	}
 
+	pub(crate) fn load_range_diff(&mut self, original: Vec<String>, edited: Vec<RangeDiffCommit>) {
+		self.artifacts.push_back(Artifact::LoadRangeDiff(original, edited));
+	}
+
 	pub(crate) fn external_command(&mut self, command: String, arguments: Vec<String>) {
 		self.artifacts
 			.push_back(Artifact::ExternalCommand((command, arguments)));
 	}
 
+	pub(crate) fn reload_config(&mut self) {
+		self.artifacts.push_back(Artifact::ReloadConfig);
+	}
+
 	pub(crate) fn enqueue_resize(&mut self) {
 		self.artifacts.push_back(Artifact::EnqueueResize);
 	}
@@ -202,6 +211,16 @@ mod tests {
 		assert_results!(results, Artifact::CancelDiff);
 	}
 
+	#[test]
+	fn load_range_diff() {
+		let mut results = Results::new();
+		results.load_range_diff(vec![String::from("abc")], vec![RangeDiffCommit::new("def")]);
+		assert_results!(
+			results,
+			Artifact::LoadRangeDiff(vec![String::from("abc")], vec![RangeDiffCommit::new("def")])
+		);
+	}
+
 	#[test]
 	fn external_command() {
 		let mut results = Results::new();
@@ -212,6 +231,13 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn reload_config() {
+		let mut results = Results::new();
+		results.reload_config();
+		assert_results!(results, Artifact::ReloadConfig);
+	}
+
 	#[test]
 	fn enqueue_resize() {
 		let mut results = Results::new();
@@ -5,11 +5,24 @@ use anyhow::anyhow;
 use super::*;
 use crate::{
 	assert_results,
-	input::{InputOptions, KeyBindings},
+	commit_message::CommitMessage,
+	diff::thread::Action as DiffAction,
+	input::InputOptions,
 	module::{DEFAULT_INPUT_OPTIONS, DEFAULT_VIEW_DATA, Module},
 	runtime::Status,
-	test_helpers::{create_default_test_module_handler, create_test_module_handler, mocks, testers},
-	todo_file::Line,
+	test_helpers::{
+		CreateCommitOptions,
+		EnvVarAction,
+		create_commit,
+		create_default_test_module_handler,
+		create_test_module_handler,
+		mocks,
+		testers,
+		with_env_var,
+		with_git_directory,
+		with_temp_repository,
+	},
+	todo_file::{Action as TodoAction, EditContext, Line},
 	view::{REFRESH_THREAD_NAME, ViewData},
 };
 
@@ -66,6 +79,10 @@ impl Module for TestModule {
 		self.trace.lock().push(format!("handle_error(error = {error})"));
 		Results::new()
 	}
+
+	fn reload(&mut self, _app_data: &AppData) {
+		self.trace.lock().push(String::from("reload"));
+	}
 }
 
 #[test]
@@ -191,7 +208,7 @@ fn write_todo_file() {
 				.todo_file
 				.lock()
 				.set_lines(vec![Line::parse("fixup ddd comment").unwrap()]);
-			process.write_todo_file().unwrap();
+			process.write_file().unwrap();
 			process.todo_file.lock().load_file().unwrap();
 			assert_eq!(
 				process.todo_file.lock().get_line(0).unwrap(),
@@ -201,6 +218,29 @@ fn write_todo_file() {
 	);
 }
 
+#[test]
+fn write_commit_message() {
+	testers::process(
+		create_default_test_module_handler(),
+		|testers::ProcessTestContext { app_data, .. }| {
+			let directory = tempfile::tempdir().unwrap();
+			let path = directory.path().join("COMMIT_EDITMSG");
+			let mut commit_message = CommitMessage::new(path.as_path(), "#");
+			commit_message.set_lines(vec![String::from("Subject")]);
+			let app_data = app_data.with_commit_message(commit_message);
+			let process = Process::new(
+				&app_data,
+				Size::new(300, 120),
+				create_default_test_module_handler(),
+				ThreadStatuses::new(),
+			);
+			assert_eq!(process.initial_module(), State::CommitMessageEditor);
+			process.write_file().unwrap();
+			assert_eq!(std::fs::read_to_string(path).unwrap(), "Subject\n");
+		},
+	);
+}
+
 #[test]
 fn deactivate() {
 	let module = TestModule::new();
@@ -649,6 +689,24 @@ fn handle_diff_load() {
 	);
 }
 
+#[test]
+fn handle_range_diff_load() {
+	let module = TestModule::new();
+	testers::process(
+		create_test_module_handler(module),
+		|testers::ProcessTestContext { process, app_data, .. }| {
+			let mut results = Results::new();
+			results.load_range_diff(vec![String::from("abc")], vec![]);
+			process.handle_results(results);
+
+			assert_eq!(
+				app_data.diff_state().receive_update(),
+				crate::diff::thread::Action::LoadRangeDiff(vec![String::from("abc")], vec![])
+			);
+		},
+	);
+}
+
 #[test]
 fn handle_diff_cancel() {
 	let module = TestModule::new();
@@ -663,3 +721,120 @@ fn handle_diff_cancel() {
 		},
 	);
 }
+
+#[test]
+fn handle_reload_config() {
+	let module = TestModule::new();
+	with_git_directory("fixtures/simple", |_| {
+		testers::process(
+			create_test_module_handler(module.clone()),
+			|testers::ProcessTestContext {
+			     process, view_context, ..
+			 }| {
+				process.set_state(State::List);
+				let mut results = Results::new();
+				results.reload_config();
+				process.handle_results(results);
+				assert_eq!(process.state(), State::List);
+				view_context.assert_sent_messages(vec!["Reconfigure"]);
+				module.assert_trace(&["reload", "activate(state = List)"]);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_reload_config_loads_commit_authors() {
+	with_temp_repository(|repository| {
+		let commit = create_commit(&repository, Some(CreateCommitOptions::new().author("Tim Smith")));
+		repository
+			.config()
+			.unwrap()
+			.set_str(
+				"interactive-rebase-tool.listLineFormat",
+				"{action} {hash} {author} {subject}",
+			)
+			.unwrap();
+		let git_dir = String::from(repository.path().to_str().unwrap());
+		with_env_var(&[EnvVarAction::Set("GIT_DIR", git_dir)], || {
+			testers::process(
+				create_test_module_handler(TestModule::new()),
+				|testers::ProcessTestContext { process, app_data, .. }| {
+					let pick = format!("pick {} comment", commit.hash());
+					app_data
+						.todo_file()
+						.lock()
+						.set_lines(vec![Line::parse(pick.as_str()).unwrap()]);
+					assert!(app_data.commit_authors().is_empty());
+					let mut results = Results::new();
+					results.reload_config();
+					process.handle_results(results);
+					assert!(app_data.config().list_line_format.is_some());
+					assert_eq!(
+						app_data.commit_authors().get(commit.hash()).map(String::as_str),
+						Some("Tim Smith")
+					);
+				},
+			);
+		});
+	});
+}
+
+#[test]
+fn handle_reload_config_updates_options() {
+	with_temp_repository(|repository| {
+		let commit = create_commit(&repository, None);
+		let mut git_config = repository.config().unwrap();
+		git_config.set_i64("interactive-rebase-tool.undoLimit", 1).unwrap();
+		git_config.set_i64("diff.context", 5).unwrap();
+		let git_dir = String::from(repository.path().to_str().unwrap());
+		with_env_var(&[EnvVarAction::Set("GIT_DIR", git_dir)], || {
+			testers::process(
+				create_test_module_handler(TestModule::new()),
+				|testers::ProcessTestContext { process, app_data, .. }| {
+					let pick = format!("pick {} comment", commit.hash());
+					let todo_file = app_data.todo_file();
+					todo_file.lock().set_lines(vec![Line::parse(pick.as_str()).unwrap()]);
+					let mut results = Results::new();
+					results.reload_config();
+					process.handle_results(results);
+
+					let mut todo_file_lock = todo_file.lock();
+					todo_file_lock.update_range(0, 0, &EditContext::new().action(TodoAction::Drop));
+					todo_file_lock.update_range(0, 0, &EditContext::new().action(TodoAction::Edit));
+					assert!(todo_file_lock.undo().is_some());
+					assert!(todo_file_lock.undo().is_none());
+
+					let diff_state = app_data.diff_state();
+					assert!(diff_state.is_cancelled());
+					assert_eq!(diff_state.receive_update(), DiffAction::StatusChange);
+					assert!(matches!(
+						diff_state.receive_update(),
+						DiffAction::SetOptions(options) if options.context_lines == 5
+					));
+				},
+			);
+		});
+	});
+}
+
+#[test]
+fn handle_reload_config_error() {
+	let module = TestModule::new();
+	with_git_directory("fixtures/invalid-config", |_| {
+		testers::process(
+			create_test_module_handler(module.clone()),
+			|testers::ProcessTestContext { process, .. }| {
+				process.set_state(State::List);
+				let mut results = Results::new();
+				results.reload_config();
+				process.handle_results(results);
+				assert_eq!(process.state(), State::Error);
+				module.assert_trace(&[
+					"activate(state = List)",
+					"handle_error(error = Unable to reload the configuration)",
+				]);
+			},
+		);
+	});
+}
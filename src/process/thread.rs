@@ -4,7 +4,7 @@ use captur::capture;
 
 use crate::{
 	module,
-	module::{ExitStatus, State},
+	module::ExitStatus,
 	process::{Process, Results},
 	runtime::{Installer, RuntimeError, Threadable},
 };
@@ -24,7 +24,7 @@ impl<ModuleProvider: module::ModuleProvider + Send + 'static> Threadable for Thr
 				capture!(notifier, process);
 				notifier.busy();
 
-				process.handle_results(Results::from(State::List));
+				process.handle_results(Results::from(process.initial_module()));
 
 				while !process.should_exit() {
 					notifier.busy();
@@ -41,7 +41,7 @@ impl<ModuleProvider: module::ModuleProvider + Send + 'static> Threadable for Thr
 				}
 
 				if !process.is_exit_status_kill() {
-					if let Err(err) = process.write_todo_file() {
+					if let Err(err) = process.write_file() {
 						process.handle_results(Results::from(ExitStatus::FileWriteError));
 						notifier.error(RuntimeError::ThreadError(err.to_string()));
 						return;
@@ -77,7 +77,7 @@ mod tests {
 	use super::*;
 	use crate::{
 		input::{Event, StandardEvent},
-		module::Module,
+		module::{Module, State},
 		runtime::Status,
 		test_helpers::{create_default_test_module_handler, create_test_module_handler, testers},
 	};
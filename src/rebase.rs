@@ -0,0 +1,363 @@
+use std::{env, path::Path, process::Command};
+
+use git2::Repository;
+
+use crate::{arguments::Args, exit::Exit, git::open_repository_from_env, help::build_help, module::ExitStatus};
+
+/// Count the commits that are reachable from `HEAD` and not from the upstream.
+fn count_commits(repository: &Repository, upstream: &str) -> Result<usize, String> {
+	let upstream_commit = repository
+		.revparse_single(upstream)
+		.and_then(|object| object.peel_to_commit())
+		.map_err(|err| format!("Invalid upstream '{upstream}': {}", err.message()))?;
+	let head = repository
+		.head()
+		.and_then(|reference| reference.peel_to_commit())
+		.map_err(|err| format!("Unable to load HEAD: {}", err.message()))?;
+
+	let to_error = |err: git2::Error| format!("Unable to list the commits to rebase: {}", err.message());
+	let mut revwalk = repository.revwalk().map_err(to_error)?;
+	revwalk.push(head.id()).map_err(to_error)?;
+	revwalk.hide(upstream_commit.id()).map_err(to_error)?;
+	Ok(revwalk.count())
+}
+
+/// Quote a value for use in a shell command.
+fn shell_quote(value: &str) -> String {
+	format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Create the `git rebase --interactive` command for the repository. Git generates the todo file, with the filtering
+/// of commits that are already in the upstream, and the ordering of `--autosquash`, and opens it in the sequence
+/// editor.
+fn rebase_command(
+	repository: &Repository,
+	upstream: &str,
+	rebase_merges: bool,
+	autosquash_commits: bool,
+	sequence_editor: &str,
+) -> Command {
+	let mut command = Command::new("git");
+	_ = command.args(["rebase", "--interactive"]);
+	if rebase_merges {
+		_ = command.arg("--rebase-merges");
+	}
+	if autosquash_commits {
+		_ = command.arg("--autosquash");
+	}
+	_ = command
+		.arg(upstream)
+		.env("GIT_DIR", repository.path())
+		.env("GIT_SEQUENCE_EDITOR", sequence_editor);
+	if let Some(workdir) = repository.workdir() {
+		_ = command.current_dir(workdir).env("GIT_WORK_TREE", workdir);
+	}
+	command
+}
+
+fn run_git_rebase(mut command: Command) -> Result<(), Exit> {
+	let status = command.status().map_err(|err| {
+		Exit::new(
+			ExitStatus::StateError,
+			format!("Unable to start git rebase: {err}").as_str(),
+		)
+	})?;
+	if status.success() {
+		Ok(())
+	}
+	else {
+		Err(Exit::new(
+			ExitStatus::StateError,
+			format!("The git rebase did not complete: {status}").as_str(),
+		))
+	}
+}
+
+/// The tool is used as the sequence editor of the rebase, so the todo file that git generates is opened in the tool.
+fn sequence_editor(executable: &Path) -> String {
+	shell_quote(executable.to_string_lossy().as_ref())
+}
+
+fn run_rebase(upstream: &str, rebase_merges: bool, autosquash_commits: bool) -> Result<(), Exit> {
+	let repository = open_repository_from_env().map_err(|err| {
+		Exit::new(
+			ExitStatus::StateError,
+			format!("Unable to load Git repository: {err}").as_str(),
+		)
+	})?;
+	let commits =
+		count_commits(&repository, upstream).map_err(|err| Exit::new(ExitStatus::StateError, err.as_str()))?;
+	if commits == 0 {
+		return Err(Exit::new(
+			ExitStatus::Good,
+			format!("Nothing to rebase, there are no commits after '{upstream}'").as_str(),
+		));
+	}
+
+	let executable = env::current_exe().map_err(|err| {
+		Exit::new(
+			ExitStatus::StateError,
+			format!("Unable to find the path of the tool: {err}").as_str(),
+		)
+	})?;
+	run_git_rebase(rebase_command(
+		&repository,
+		upstream,
+		rebase_merges,
+		autosquash_commits,
+		sequence_editor(executable.as_path()).as_str(),
+	))
+}
+
+pub(crate) fn run(args: &Args) -> Exit {
+	let Some(upstream) = args.upstream()
+	else {
+		return Exit::new(
+			ExitStatus::StateError,
+			build_help(Some(String::from("An upstream must be provided to rebase."))).as_str(),
+		);
+	};
+	match run_rebase(upstream, args.rebase_merges(), args.autosquash()) {
+		Ok(()) => Exit::from(ExitStatus::Good),
+		Err(exit) => exit,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{ffi::OsString, path::PathBuf, process::Stdio};
+
+	use git2::Oid;
+
+	use super::*;
+	use crate::test_helpers::{CreateCommitOptions, create_commit, with_git_directory, with_temp_repository};
+
+	fn args(args: &[&str]) -> Args {
+		Args::try_from(args.iter().map(OsString::from).collect::<Vec<OsString>>()).unwrap()
+	}
+
+	fn commit(repository: &Repository, message: &str) {
+		_ = create_commit(repository, Some(CreateCommitOptions::new().message(message)));
+	}
+
+	fn set_identity(repository: &Repository) {
+		let mut config = repository.config().unwrap();
+		config.set_str("user.name", "Committer").unwrap();
+		config.set_str("user.email", "committer@example.com").unwrap();
+	}
+
+	fn quiet(mut command: Command) -> Command {
+		_ = command.stdout(Stdio::null()).stderr(Stdio::null());
+		command
+	}
+
+	fn head(repository: &Repository) -> String {
+		repository.head().unwrap().peel_to_commit().unwrap().id().to_string()
+	}
+
+	fn summaries(repository: &Repository, upstream: &str) -> Vec<String> {
+		let mut revwalk = repository.revwalk().unwrap();
+		revwalk.push_head().unwrap();
+		revwalk.hide(Oid::from_str(upstream).unwrap()).unwrap();
+		revwalk
+			.map(|id| {
+				String::from(
+					repository
+						.find_commit(id.unwrap())
+						.unwrap()
+						.summary()
+						.unwrap_or_default(),
+				)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn count_commits_after_upstream() {
+		with_temp_repository(|repository| {
+			let upstream = head(&repository);
+			commit(&repository, "Add feature");
+			commit(&repository, "Fix bug");
+			assert_eq!(count_commits(&repository, upstream.as_str()).unwrap(), 2);
+		});
+	}
+
+	#[test]
+	fn count_commits_up_to_date() {
+		with_temp_repository(|repository| {
+			let upstream = head(&repository);
+			assert_eq!(count_commits(&repository, upstream.as_str()).unwrap(), 0);
+		});
+	}
+
+	#[test]
+	fn count_commits_invalid_upstream() {
+		with_temp_repository(|repository| {
+			let err = count_commits(&repository, "does-not-exist").unwrap_err();
+			assert!(err.starts_with("Invalid upstream 'does-not-exist': "));
+		});
+	}
+
+	#[test]
+	fn shell_quote_value() {
+		assert_eq!(shell_quote("/tmp/it's here"), "'/tmp/it'\\''s here'");
+	}
+
+	#[test]
+	fn sequence_editor_quoted() {
+		assert_eq!(
+			sequence_editor(PathBuf::from("/usr/bin/interactive-rebase-tool").as_path()),
+			"'/usr/bin/interactive-rebase-tool'"
+		);
+	}
+
+	#[test]
+	fn rebase_command_arguments() {
+		with_temp_repository(|repository| {
+			let command = rebase_command(&repository, "main", false, false, "editor");
+			assert_eq!(command.get_program(), "git");
+			assert_eq!(command.get_args().collect::<Vec<_>>(), vec![
+				"rebase",
+				"--interactive",
+				"main"
+			]);
+			assert_eq!(command.get_current_dir(), repository.workdir());
+			assert!(
+				command
+					.get_envs()
+					.any(|(key, value)| key == "GIT_SEQUENCE_EDITOR" && value == Some("editor".as_ref()))
+			);
+			assert!(
+				command
+					.get_envs()
+					.any(|(key, value)| key == "GIT_DIR" && value == Some(repository.path().as_os_str()))
+			);
+		});
+	}
+
+	#[test]
+	fn rebase_command_with_options() {
+		with_temp_repository(|repository| {
+			let command = rebase_command(&repository, "main", true, true, "editor");
+			assert_eq!(command.get_args().collect::<Vec<_>>(), vec![
+				"rebase",
+				"--interactive",
+				"--rebase-merges",
+				"--autosquash",
+				"main"
+			]);
+		});
+	}
+
+	#[test]
+	fn run_git_rebase_with_edited_todo_file() {
+		with_temp_repository(|repository| {
+			set_identity(&repository);
+			let upstream = head(&repository);
+			commit(&repository, "Add feature");
+			commit(&repository, "Fix bug");
+			// the sequence editor drops the first commit of the todo file that git generated
+			let command = quiet(rebase_command(
+				&repository,
+				upstream.as_str(),
+				false,
+				false,
+				"sed -i -e 1s/^pick/drop/",
+			));
+			run_git_rebase(command).unwrap();
+			assert_eq!(summaries(&repository, upstream.as_str()), vec!["Fix bug"]);
+		});
+	}
+
+	#[test]
+	fn run_git_rebase_with_autosquash() {
+		with_temp_repository(|repository| {
+			set_identity(&repository);
+			let upstream = head(&repository);
+			commit(&repository, "Add feature");
+			commit(&repository, "Fix bug");
+			commit(&repository, "fixup! Add feature");
+			let command = quiet(rebase_command(&repository, upstream.as_str(), false, true, "true"));
+			run_git_rebase(command).unwrap();
+			assert_eq!(summaries(&repository, upstream.as_str()), vec![
+				"Fix bug",
+				"Add feature"
+			]);
+		});
+	}
+
+	#[test]
+	fn run_git_rebase_emptied_todo_file() {
+		with_temp_repository(|repository| {
+			let upstream = head(&repository);
+			commit(&repository, "Add feature");
+			let original_head = head(&repository);
+			let command = quiet(rebase_command(
+				&repository,
+				upstream.as_str(),
+				false,
+				false,
+				"sed -i -e d",
+			));
+			assert_eq!(
+				run_git_rebase(command).unwrap_err().get_status(),
+				&ExitStatus::StateError
+			);
+			assert_eq!(head(&repository), original_head);
+		});
+	}
+
+	#[test]
+	fn run_git_rebase_failure() {
+		with_temp_repository(|repository| {
+			let command = quiet(rebase_command(&repository, "does-not-exist", false, false, "true"));
+			let exit = run_git_rebase(command).unwrap_err();
+			assert_eq!(exit.get_status(), &ExitStatus::StateError);
+			assert!(
+				exit.get_message()
+					.unwrap()
+					.starts_with("The git rebase did not complete: ")
+			);
+		});
+	}
+
+	#[test]
+	fn run_up_to_date() {
+		with_git_directory("fixtures/simple", |_| {
+			let exit = run(&args(&["rebase", "HEAD"]));
+			assert_eq!(exit.get_status(), &ExitStatus::Good);
+			assert_eq!(
+				exit.get_message(),
+				Some("Nothing to rebase, there are no commits after 'HEAD'")
+			);
+		});
+	}
+
+	#[test]
+	fn run_invalid_upstream() {
+		with_git_directory("fixtures/simple", |_| {
+			assert_eq!(
+				run(&args(&["rebase", "does-not-exist"])).get_status(),
+				&ExitStatus::StateError
+			);
+		});
+	}
+
+	#[test]
+	fn run_repository_error() {
+		with_git_directory("fixtures/not-a-repository", |_| {
+			assert_eq!(run(&args(&["rebase", "main"])).get_status(), &ExitStatus::StateError);
+		});
+	}
+
+	#[test]
+	fn run_missing_upstream() {
+		let exit = run(&args(&["rebase"]));
+		assert_eq!(exit.get_status(), &ExitStatus::StateError);
+		assert!(
+			exit.get_message()
+				.unwrap()
+				.contains("An upstream must be provided to rebase.")
+		);
+	}
+}
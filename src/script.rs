@@ -0,0 +1,513 @@
+mod command;
+mod selector;
+
+use std::fs;
+
+pub(crate) use self::{
+	command::{Command, Position},
+	selector::Selector,
+};
+use crate::{
+	application::todo_file_options,
+	arguments::Args,
+	config::{Config, ConfigLoader},
+	exit::Exit,
+	git::open_repository_from_env,
+	help::build_help,
+	module::ExitStatus,
+	todo_file::{Action, EditContext, Line, TodoFile},
+};
+
+/// Parse a script, returning the commands with their line numbers.
+fn parse_script(script: &str) -> Result<Vec<(usize, Command)>, String> {
+	let mut commands = vec![];
+	for (index, line) in script.lines().enumerate() {
+		let number = index + 1;
+		if let Some(command) =
+			Command::parse(line).map_err(|err| format!("Error on line {number} of the script: {err}"))?
+		{
+			commands.push((number, command));
+		}
+	}
+	Ok(commands)
+}
+
+fn matching_indexes(todo_file: &TodoFile, selector: &Selector) -> Vec<usize> {
+	todo_file
+		.lines_iter()
+		.enumerate()
+		.filter(|&(_, line)| selector.matches(line))
+		.map(|(index, _)| index)
+		.collect()
+}
+
+// git rejects a todo file with a command that is not valid for the rebase or sequence
+fn unsupported_message(todo_file: &TodoFile, command: &str) -> String {
+	if todo_file.is_sequencer() {
+		format!("The {command} command can not be used in a cherry-pick or revert sequence")
+	}
+	else {
+		format!("The {command} command can not be used in a rebase")
+	}
+}
+
+fn set_action(todo_file: &mut TodoFile, action: Action, selector: &Selector) -> Result<(), String> {
+	if !todo_file.supports_action(action) {
+		return Err(unsupported_message(todo_file, action.to_string().as_str()));
+	}
+	let indexes = matching_indexes(todo_file, selector)
+		.into_iter()
+		.filter(|&index| {
+			todo_file
+				.get_line(index)
+				.is_some_and(|line| !line.get_action().is_static())
+		})
+		.collect::<Vec<usize>>();
+	if indexes.is_empty() {
+		return Err(format!("The selector '{selector}' did not match any commits"));
+	}
+	for index in indexes {
+		todo_file.update_range(index, index, &EditContext::new().action(action));
+	}
+	Ok(())
+}
+
+fn move_lines(
+	todo_file: &mut TodoFile,
+	selector: &Selector,
+	position: Position,
+	target: &Selector,
+) -> Result<(), String> {
+	let indexes = matching_indexes(todo_file, selector);
+	if indexes.is_empty() {
+		return Err(format!("The selector '{selector}' did not match any lines"));
+	}
+	let targets = matching_indexes(todo_file, target)
+		.into_iter()
+		.filter(|index| !indexes.contains(index))
+		.collect::<Vec<usize>>();
+	let &[target_index] = targets.as_slice()
+	else {
+		return Err(format!(
+			"The target '{target}' must match exactly one other line, but matched {}",
+			targets.len()
+		));
+	};
+
+	let lines = indexes
+		.iter()
+		.filter_map(|&index| todo_file.get_line(index).cloned())
+		.collect::<Vec<Line>>();
+	for &index in indexes.iter().rev() {
+		todo_file.remove_lines(index, index);
+	}
+	let target_index = target_index - indexes.iter().filter(|&&index| index < target_index).count();
+	let insert_index = match position {
+		Position::Before => target_index,
+		Position::After => target_index + 1,
+	};
+	for (offset, line) in lines.into_iter().enumerate() {
+		todo_file.add_line(insert_index + offset, line);
+	}
+	Ok(())
+}
+
+/// Add an exec line after each line that creates a commit, skipping lines that are followed by a squash or fixup,
+/// so the command runs once the commit is complete.
+fn exec_after_each(todo_file: &mut TodoFile, command: &str) -> Result<(), String> {
+	if !todo_file.supports_action(Action::Exec) {
+		return Err(unsupported_message(todo_file, "exec-after-each"));
+	}
+	let lines = todo_file.get_lines_owned();
+	let indexes = lines
+		.iter()
+		.enumerate()
+		.filter(|&(index, line)| {
+			matches!(
+				*line.get_action(),
+				Action::Pick | Action::Reword | Action::Edit | Action::Squash | Action::Fixup | Action::Merge
+			) && !lines
+				.get(index + 1)
+				.is_some_and(|next| matches!(*next.get_action(), Action::Squash | Action::Fixup))
+		})
+		.map(|(index, _)| index)
+		.collect::<Vec<usize>>();
+	for index in indexes.into_iter().rev() {
+		todo_file.add_line(index + 1, Line::new_exec(command));
+	}
+	Ok(())
+}
+
+fn apply_command(todo_file: &mut TodoFile, command: &Command) -> Result<(), String> {
+	match *command {
+		Command::SetAction { action, ref selector } => set_action(todo_file, action, selector),
+		Command::Move {
+			ref selector,
+			position,
+			ref target,
+		} => move_lines(todo_file, selector, position, target),
+		Command::ExecAfterEach(ref command) => exec_after_each(todo_file, command.as_str()),
+	}
+}
+
+fn run_script(script_path: &str, todo_file_path: &str) -> Result<(), Exit> {
+	let repository = open_repository_from_env().map_err(|err| {
+		Exit::new(
+			ExitStatus::StateError,
+			format!("Unable to load Git repository: {err}").as_str(),
+		)
+	})?;
+	let config = Config::try_from(&ConfigLoader::from(repository))
+		.map_err(|err| Exit::new(ExitStatus::ConfigError, format!("{err:#}").as_str()))?;
+
+	let script = fs::read_to_string(script_path).map_err(|err| {
+		Exit::new(
+			ExitStatus::FileReadError,
+			format!("Unable to read script '{script_path}': {err}").as_str(),
+		)
+	})?;
+	// the whole script is parsed before any change, so an invalid script does not change the todo file
+	let commands = parse_script(script.as_str()).map_err(|err| Exit::new(ExitStatus::StateError, err.as_str()))?;
+
+	let mut todo_file = TodoFile::new(todo_file_path, todo_file_options(&config));
+	todo_file
+		.load_file()
+		.map_err(|err| Exit::new(ExitStatus::FileReadError, err.to_string().as_str()))?;
+
+	for (number, command) in &commands {
+		apply_command(&mut todo_file, command).map_err(|err| {
+			Exit::new(
+				ExitStatus::StateError,
+				format!("Error on line {number} of the script: {err}").as_str(),
+			)
+		})?;
+	}
+
+	todo_file
+		.write_file()
+		.map_err(|err| Exit::new(ExitStatus::FileWriteError, err.to_string().as_str()))
+}
+
+pub(crate) fn run(args: &Args) -> Exit {
+	let (Some(script_path), Some(todo_file_path)) = (args.script_path(), args.todo_file_path())
+	else {
+		return Exit::new(
+			ExitStatus::StateError,
+			build_help(Some(String::from("A script and a todo file path must be provided."))).as_str(),
+		);
+	};
+	match run_script(script_path, todo_file_path) {
+		Ok(()) => Exit::from(ExitStatus::Good),
+		Err(exit) => exit,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{ffi::OsString, path::Path};
+
+	use claims::assert_err_eq;
+	use rstest::rstest;
+	use tempfile::tempdir;
+
+	use super::*;
+	use crate::{test_helpers::with_git_directory, todo_file::TodoFileOptions};
+
+	fn create_todo_file(path: &str, lines: &[&str]) -> TodoFile {
+		let mut todo_file = TodoFile::new(path, TodoFileOptions::new(10, "#"));
+		todo_file.set_lines(lines.iter().map(|line| Line::parse(line).unwrap()).collect());
+		todo_file
+	}
+
+	fn apply_script(lines: &[&str], script: &str) -> Result<Vec<String>, String> {
+		apply_script_to_file("", lines, script)
+	}
+
+	fn apply_script_to_file(path: &str, lines: &[&str], script: &str) -> Result<Vec<String>, String> {
+		let mut todo_file = create_todo_file(path, lines);
+		for (number, command) in parse_script(script)? {
+			apply_command(&mut todo_file, &command).map_err(|err| format!("{number}: {err}"))?;
+		}
+		Ok(todo_file.lines_iter().map(Line::to_text).collect())
+	}
+
+	fn args(args: &[&str]) -> Args {
+		Args::try_from(args.iter().map(OsString::from).collect::<Vec<OsString>>()).unwrap()
+	}
+
+	fn run_in_directory(todo: &str, script: &str) -> (Exit, String) {
+		let directory = tempdir().unwrap();
+		let todo_path = directory.path().join("git-rebase-todo");
+		let script_path = directory.path().join("script.txt");
+		fs::write(todo_path.as_path(), todo).unwrap();
+		fs::write(script_path.as_path(), script).unwrap();
+		let exit = run(&args(&[
+			"--script",
+			script_path.to_str().unwrap(),
+			todo_path.to_str().unwrap(),
+		]));
+		(exit, fs::read_to_string(todo_path).unwrap())
+	}
+
+	#[test]
+	fn parse_script_with_comments() {
+		let commands = parse_script("# squash the fixes\n\nfixup abcd\n  drop /WIP/\n").unwrap();
+		assert_eq!(
+			commands.iter().map(|&(number, _)| number).collect::<Vec<usize>>(),
+			vec![3, 4]
+		);
+	}
+
+	#[test]
+	fn parse_script_error() {
+		assert_err_eq!(
+			parse_script("fixup abcd\nlabel abcd"),
+			String::from("Error on line 2 of the script: Unknown command 'label'")
+		);
+	}
+
+	#[test]
+	fn set_action() {
+		assert_eq!(
+			apply_script(
+				&[
+					"pick aaaa1111 c1",
+					"pick bbbb2222 WIP c2",
+					"exec echo WIP",
+					"pick cccc3333 WIP c3"
+				],
+				"fixup aaaa\ndrop /WIP/"
+			)
+			.unwrap(),
+			vec![
+				"fixup aaaa1111 c1",
+				"drop bbbb2222 WIP c2",
+				"exec echo WIP",
+				"drop cccc3333 WIP c3"
+			]
+		);
+	}
+
+	#[test]
+	fn set_action_no_match() {
+		assert_err_eq!(
+			apply_script(&["pick aaaa1111 c1", "exec make"], "drop /make/"),
+			String::from("1: The selector '/make/' did not match any commits")
+		);
+	}
+
+	#[test]
+	fn set_action_revert_in_rebase() {
+		assert_err_eq!(
+			apply_script(&["pick aaaa1111 c1"], "revert aaaa"),
+			String::from("1: The revert command can not be used in a rebase")
+		);
+	}
+
+	#[rstest]
+	#[case::fixup("fixup aaaa", "fixup")]
+	#[case::reword("reword aaaa", "reword")]
+	#[case::revert("revert aaaa", "revert")]
+	#[case::exec_after_each("exec-after-each make", "exec-after-each")]
+	fn sequencer_unsupported_command(#[case] script: &str, #[case] command: &str) {
+		assert_err_eq!(
+			apply_script_to_file(".git/sequencer/todo", &["pick aaaa1111 c1", "pick bbbb2222 c2"], script),
+			format!("1: The {command} command can not be used in a cherry-pick or revert sequence")
+		);
+	}
+
+	#[test]
+	fn sequencer_supported_commands() {
+		assert_eq!(
+			apply_script_to_file(
+				".git/sequencer/todo",
+				&["revert aaaa1111 c1", "revert bbbb2222 c2"],
+				"drop aaaa\nmove bbbb before aaaa"
+			)
+			.unwrap(),
+			vec!["revert bbbb2222 c2", "drop aaaa1111 c1"]
+		);
+	}
+
+	#[test]
+	fn move_after() {
+		assert_eq!(
+			apply_script(
+				&[
+					"pick aaaa1111 c1",
+					"pick bbbb2222 c2",
+					"pick cccc3333 c3",
+					"pick dddd4444 c4"
+				],
+				"move aaaa after cccc"
+			)
+			.unwrap(),
+			vec![
+				"pick bbbb2222 c2",
+				"pick cccc3333 c3",
+				"pick aaaa1111 c1",
+				"pick dddd4444 c4"
+			]
+		);
+	}
+
+	#[test]
+	fn move_multiple_before() {
+		assert_eq!(
+			apply_script(
+				&[
+					"pick aaaa1111 c1",
+					"pick bbbb2222 fix",
+					"pick cccc3333 c3",
+					"pick dddd4444 fix"
+				],
+				"move /fix/ before aaaa"
+			)
+			.unwrap(),
+			vec![
+				"pick bbbb2222 fix",
+				"pick dddd4444 fix",
+				"pick aaaa1111 c1",
+				"pick cccc3333 c3"
+			]
+		);
+	}
+
+	#[test]
+	fn move_no_match() {
+		assert_err_eq!(
+			apply_script(&["pick aaaa1111 c1"], "move bbbb after aaaa"),
+			String::from("1: The selector 'bbbb' did not match any lines")
+		);
+	}
+
+	#[test]
+	fn move_ambiguous_target() {
+		assert_err_eq!(
+			apply_script(
+				&["pick aaaa1111 c1", "pick bbbb2222 c2", "pick cccc3333 c2"],
+				"move aaaa after /c2/"
+			),
+			String::from("1: The target '/c2/' must match exactly one other line, but matched 2")
+		);
+	}
+
+	#[test]
+	fn exec_after_each() {
+		assert_eq!(
+			apply_script(
+				&[
+					"pick aaaa1111 c1",
+					"fixup bbbb2222 c2",
+					"drop cccc3333 c3",
+					"reword dddd4444 c4",
+					"break"
+				],
+				"exec-after-each \"make test\""
+			)
+			.unwrap(),
+			vec![
+				"pick aaaa1111 c1",
+				"fixup bbbb2222 c2",
+				"exec make test",
+				"drop cccc3333 c3",
+				"reword dddd4444 c4",
+				"exec make test",
+				"break"
+			]
+		);
+	}
+
+	#[test]
+	fn run_success() {
+		with_git_directory("fixtures/simple", |_| {
+			let (exit, todo) = run_in_directory(
+				"pick aaaa1111 c1\npick bbbb2222 c2\n",
+				"fixup bbbb\nexec-after-each make\n",
+			);
+			assert_eq!(exit.get_status(), &ExitStatus::Good);
+			assert_eq!(todo, "pick aaaa1111 c1\nfixup bbbb2222 c2\nexec make\n");
+		});
+	}
+
+	#[test]
+	fn run_command_error_does_not_write() {
+		with_git_directory("fixtures/simple", |_| {
+			let (exit, todo) = run_in_directory("pick aaaa1111 c1\n", "fixup aaaa\ndrop bbbb\n");
+			assert_eq!(exit.get_status(), &ExitStatus::StateError);
+			assert_eq!(
+				exit.get_message().unwrap(),
+				"Error on line 2 of the script: The selector 'bbbb' did not match any commits"
+			);
+			assert_eq!(todo, "pick aaaa1111 c1\n");
+		});
+	}
+
+	#[test]
+	fn run_parse_error() {
+		with_git_directory("fixtures/simple", |_| {
+			let (exit, _) = run_in_directory("pick aaaa1111 c1\n", "squash\n");
+			assert_eq!(exit.get_status(), &ExitStatus::StateError);
+			assert_eq!(
+				exit.get_message().unwrap(),
+				"Error on line 1 of the script: The squash command must be in the form: squash <selector>"
+			);
+		});
+	}
+
+	#[test]
+	fn run_script_read_error() {
+		with_git_directory("fixtures/simple", |path| {
+			let todo_file = Path::new(path).join("rebase-todo");
+			let exit = run(&args(&["--script", "does-not-exist", todo_file.to_str().unwrap()]));
+			assert_eq!(exit.get_status(), &ExitStatus::FileReadError);
+		});
+	}
+
+	#[test]
+	fn run_todo_file_read_error() {
+		with_git_directory("fixtures/simple", |_| {
+			let directory = tempdir().unwrap();
+			let script_path = directory.path().join("script.txt");
+			fs::write(script_path.as_path(), "drop aaaa\n").unwrap();
+			let todo_path = directory.path().join("does-not-exist");
+			let exit = run(&args(&[
+				"--script",
+				script_path.to_str().unwrap(),
+				todo_path.to_str().unwrap(),
+			]));
+			assert_eq!(exit.get_status(), &ExitStatus::FileReadError);
+		});
+	}
+
+	#[test]
+	fn run_repository_error() {
+		with_git_directory("fixtures/not-a-repository", |_| {
+			assert_eq!(
+				run(&args(&["--script", "script.txt", "todofile"])).get_status(),
+				&ExitStatus::StateError
+			);
+		});
+	}
+
+	#[test]
+	fn run_config_error() {
+		with_git_directory("fixtures/invalid-config", |_| {
+			assert_eq!(
+				run(&args(&["--script", "script.txt", "todofile"])).get_status(),
+				&ExitStatus::ConfigError
+			);
+		});
+	}
+
+	#[test]
+	fn run_missing_todo_file() {
+		let exit = run(&args(&["--script", "script.txt"]));
+		assert_eq!(exit.get_status(), &ExitStatus::StateError);
+		assert!(
+			exit.get_message()
+				.unwrap()
+				.contains("A script and a todo file path must be provided.")
+		);
+	}
+}
@@ -0,0 +1,215 @@
+use crate::{script::Selector, todo_file::Action};
+
+/// Where lines are moved to, relative to the target line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Position {
+	/// Before the target line.
+	Before,
+	/// After the target line.
+	After,
+}
+
+/// A command of a script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Command {
+	/// Change the action of the selected lines.
+	SetAction {
+		/// The new action.
+		action: Action,
+		/// The lines to change.
+		selector: Selector,
+	},
+	/// Move the selected lines before or after the target line.
+	Move {
+		/// The lines to move.
+		selector: Selector,
+		/// Where to move the lines, relative to the target line.
+		position: Position,
+		/// The line to move the lines to.
+		target: Selector,
+	},
+	/// Add an exec line, with the command, after each commit.
+	ExecAfterEach(String),
+}
+
+/// Split the arguments of a command on whitespace, keeping text between slashes together.
+fn split_arguments(input: &str) -> Result<Vec<&str>, String> {
+	let mut arguments = vec![];
+	let mut rest = input.trim_start();
+	while !rest.is_empty() {
+		let end = if let Some(text) = rest.strip_prefix('/') {
+			text.find('/')
+				.map(|index| index + 2)
+				.ok_or_else(|| format!("Unclosed selector '{rest}'"))?
+		}
+		else {
+			rest.find(char::is_whitespace).unwrap_or(rest.len())
+		};
+		let (argument, remaining) = rest.split_at(end);
+		arguments.push(argument);
+		rest = remaining.trim_start();
+	}
+	Ok(arguments)
+}
+
+/// Remove the quotes from a quoted value, replacing the escaped quotes and backslashes. Values without quotes are
+/// returned unchanged.
+fn unquote(input: &str) -> Result<String, String> {
+	let Some(quoted) = input.strip_prefix('"')
+	else {
+		return Ok(String::from(input));
+	};
+	let mut value = String::new();
+	let mut chars = quoted.chars();
+	while let Some(c) = chars.next() {
+		match c {
+			'\\' => value.push(chars.next().ok_or_else(|| format!("Unclosed quote in {input}"))?),
+			'"' if chars.as_str().is_empty() => return Ok(value),
+			'"' => return Err(format!("Unexpected content after the closing quote in {input}")),
+			_ => value.push(c),
+		}
+	}
+	Err(format!("Unclosed quote in {input}"))
+}
+
+impl Command {
+	/// Parse a line of a script. Returns `None` for empty lines and comments, that start with `#`.
+	///
+	/// # Errors
+	/// Will result in an error describing the problem, if the line is not a valid command.
+	pub(crate) fn parse(input: &str) -> Result<Option<Self>, String> {
+		let input = input.trim();
+		if input.is_empty() || input.starts_with('#') {
+			return Ok(None);
+		}
+		let (name, rest) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+		let rest = rest.trim();
+
+		let command = match name {
+			"exec-after-each" => {
+				if rest.is_empty() {
+					return Err(String::from("The exec-after-each command requires a command to run"));
+				}
+				Self::ExecAfterEach(unquote(rest)?)
+			},
+			"move" => {
+				match split_arguments(rest)?.as_slice() {
+					&[selector, position, target] => {
+						Self::Move {
+							selector: Selector::parse(selector)?,
+							position: match position {
+								"before" => Position::Before,
+								"after" => Position::After,
+								_ => return Err(format!("Invalid position '{position}', must be 'before' or 'after'")),
+							},
+							target: Selector::parse(target)?,
+						}
+					},
+					_ => {
+						return Err(String::from(
+							"The move command must be in the form: move <selector> after <selector>",
+						));
+					},
+				}
+			},
+			_ => {
+				let action = Action::try_from(name)
+					.ok()
+					.filter(|action| !action.is_static())
+					.ok_or_else(|| format!("Unknown command '{name}'"))?;
+				match split_arguments(rest)?.as_slice() {
+					&[selector] => {
+						Self::SetAction {
+							action,
+							selector: Selector::parse(selector)?,
+						}
+					},
+					_ => return Err(format!("The {action} command must be in the form: {action} <selector>")),
+				}
+			},
+		};
+		Ok(Some(command))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_err_eq, assert_ok_eq};
+	use rstest::rstest;
+
+	use super::*;
+
+	fn hash(hash: &str) -> Selector {
+		Selector::Hash(String::from(hash))
+	}
+
+	fn text(text: &str) -> Selector {
+		Selector::Text(String::from(text))
+	}
+
+	#[rstest]
+	#[case::empty("")]
+	#[case::whitespace("  \t")]
+	#[case::comment("# fixup abcd")]
+	fn parse_ignored(#[case] input: &str) {
+		assert_ok_eq!(Command::parse(input), None);
+	}
+
+	#[rstest]
+	#[case::pick("pick abcd", Action::Pick, hash("abcd"))]
+	#[case::reword("reword abcd", Action::Reword, hash("abcd"))]
+	#[case::edit("edit abcd", Action::Edit, hash("abcd"))]
+	#[case::squash("squash abcd", Action::Squash, hash("abcd"))]
+	#[case::fixup("fixup abcd", Action::Fixup, hash("abcd"))]
+	#[case::drop("drop /WIP/", Action::Drop, text("WIP"))]
+	#[case::abbreviation("f abcd", Action::Fixup, hash("abcd"))]
+	#[case::text_with_spaces("drop /fix typo/", Action::Drop, text("fix typo"))]
+	fn parse_set_action(#[case] input: &str, #[case] action: Action, #[case] selector: Selector) {
+		assert_ok_eq!(Command::parse(input), Some(Command::SetAction { action, selector }));
+	}
+
+	#[rstest]
+	#[case::after("move abcd after /fix typo/", Position::After)]
+	#[case::before("move   abcd before  /fix typo/ ", Position::Before)]
+	fn parse_move(#[case] input: &str, #[case] position: Position) {
+		assert_ok_eq!(
+			Command::parse(input),
+			Some(Command::Move {
+				selector: hash("abcd"),
+				position,
+				target: text("fix typo"),
+			})
+		);
+	}
+
+	#[rstest]
+	#[case::quoted("exec-after-each \"make test\"", "make test")]
+	#[case::escaped("exec-after-each \"echo \\\"a\\\\b\\\"\"", "echo \"a\\b\"")]
+	#[case::unquoted("exec-after-each make test", "make test")]
+	fn parse_exec_after_each(#[case] input: &str, #[case] expected: &str) {
+		assert_ok_eq!(
+			Command::parse(input),
+			Some(Command::ExecAfterEach(String::from(expected)))
+		);
+	}
+
+	#[rstest]
+	#[case::unknown("label abcd", "Unknown command 'label'")]
+	#[case::missing_selector("fixup", "The fixup command must be in the form: fixup <selector>")]
+	#[case::extra_selector("fixup abcd ef01", "The fixup command must be in the form: fixup <selector>")]
+	#[case::unclosed_selector("drop /WIP", "Unclosed selector '/WIP'")]
+	#[case::move_missing_target(
+		"move abcd after",
+		"The move command must be in the form: move <selector> after <selector>"
+	)]
+	#[case::move_invalid_position("move abcd below ef01", "Invalid position 'below', must be 'before' or 'after'")]
+	#[case::exec_missing_command("exec-after-each", "The exec-after-each command requires a command to run")]
+	#[case::exec_unclosed_quote("exec-after-each \"make", "Unclosed quote in \"make")]
+	#[case::exec_content_after_quote(
+		"exec-after-each \"make\" test",
+		"Unexpected content after the closing quote in \"make\" test"
+	)]
+	fn parse_invalid(#[case] input: &str, #[case] expected: &str) {
+		assert_err_eq!(Command::parse(input), String::from(expected));
+	}
+}
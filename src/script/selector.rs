@@ -0,0 +1,116 @@
+use std::fmt::{Display, Formatter};
+
+use crate::todo_file::Line;
+
+const MINIMUM_HASH_LENGTH: usize = 4;
+
+/// Selects the lines of a todo file that a script command applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Selector {
+	/// Lines with a commit hash that starts with the hash, or with a hash that the hash starts with.
+	Hash(String),
+	/// Lines with content, such as the commit subject, that contains the text.
+	Text(String),
+}
+
+impl Selector {
+	/// Parse a selector, either a hash, such as `abc123`, or text between slashes, such as `/WIP/`.
+	///
+	/// # Errors
+	/// Will result in an error describing the problem, if the selector is not valid.
+	pub(crate) fn parse(input: &str) -> Result<Self, String> {
+		if let Some(text) = input.strip_prefix('/').and_then(|text| text.strip_suffix('/')) {
+			if text.is_empty() {
+				return Err(String::from("The text of a selector cannot be empty"));
+			}
+			return Ok(Self::Text(String::from(text)));
+		}
+		if input.len() >= MINIMUM_HASH_LENGTH && input.chars().all(|c| c.is_ascii_hexdigit()) {
+			return Ok(Self::Hash(input.to_ascii_lowercase()));
+		}
+		Err(format!(
+			"Invalid selector '{input}', must be a commit hash of at least {MINIMUM_HASH_LENGTH} characters or text \
+			 between slashes"
+		))
+	}
+
+	/// Does the selector match the line.
+	pub(crate) fn matches(&self, line: &Line) -> bool {
+		match *self {
+			Self::Hash(ref hash) => {
+				let line_hash = line.get_hash().to_ascii_lowercase();
+				line_hash.len() >= MINIMUM_HASH_LENGTH
+					&& (line_hash.starts_with(hash.as_str()) || hash.starts_with(line_hash.as_str()))
+			},
+			Self::Text(ref text) => line.get_content().contains(text.as_str()),
+		}
+	}
+}
+
+impl Display for Selector {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::Hash(ref hash) => write!(f, "{hash}"),
+			Self::Text(ref text) => write!(f, "/{text}/"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_err_eq, assert_ok_eq};
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::hash("abc123", Selector::Hash(String::from("abc123")))]
+	#[case::hash_upper_case("ABC123", Selector::Hash(String::from("abc123")))]
+	#[case::text("/WIP/", Selector::Text(String::from("WIP")))]
+	#[case::text_with_spaces("/fix typo/", Selector::Text(String::from("fix typo")))]
+	fn parse(#[case] input: &str, #[case] expected: Selector) {
+		assert_ok_eq!(Selector::parse(input), expected);
+	}
+
+	#[rstest]
+	#[case::short_hash("abc")]
+	#[case::not_hash("main")]
+	#[case::unclosed_text("/WIP")]
+	fn parse_invalid(#[case] input: &str) {
+		assert_err_eq!(
+			Selector::parse(input),
+			format!(
+				"Invalid selector '{input}', must be a commit hash of at least 4 characters or text between slashes"
+			)
+		);
+	}
+
+	#[test]
+	fn parse_empty_text() {
+		assert_err_eq!(
+			Selector::parse("//"),
+			String::from("The text of a selector cannot be empty")
+		);
+	}
+
+	#[rstest]
+	#[case::same_hash("abcd1234", "pick abcd1234 comment", true)]
+	#[case::shorter_hash("abcd", "pick abcd1234 comment", true)]
+	#[case::longer_hash("abcd1234ef", "pick abcd1234 comment", true)]
+	#[case::other_hash("abce", "pick abcd1234 comment", false)]
+	#[case::hash_no_reference("abcd", "exec abcd", false)]
+	#[case::text("/WIP/", "pick abcd1234 WIP: comment", true)]
+	#[case::text_exec("/make/", "exec make test", true)]
+	#[case::text_no_match("/WIP/", "pick abcd1234 comment", false)]
+	fn matches(#[case] selector: &str, #[case] line: &str, #[case] expected: bool) {
+		let selector = Selector::parse(selector).unwrap();
+		assert_eq!(selector.matches(&Line::parse(line).unwrap()), expected);
+	}
+
+	#[rstest]
+	#[case::hash("abcd")]
+	#[case::text("/WIP/")]
+	fn display(#[case] selector: &str) {
+		assert_eq!(Selector::parse(selector).unwrap().to_string(), selector);
+	}
+}
@@ -0,0 +1,283 @@
+use std::fs;
+
+use serde::Serialize;
+
+use crate::{
+	exit::Exit,
+	module::ExitStatus,
+	todo_file::{Line, TodoFile},
+};
+
+/// The environment variable used for the summary path, when it is not provided as an argument.
+pub(crate) const SUMMARY_JSON_ENV: &str = "INTERACTIVE_REBASE_TOOL_SUMMARY_JSON";
+
+const fn exit_status_name(exit_status: ExitStatus) -> &'static str {
+	match exit_status {
+		ExitStatus::None | ExitStatus::Good => "good",
+		ExitStatus::Abort => "abort",
+		ExitStatus::ConfigError => "configError",
+		ExitStatus::FileReadError => "fileReadError",
+		ExitStatus::FileWriteError => "fileWriteError",
+		ExitStatus::StateError => "stateError",
+		ExitStatus::Kill => "kill",
+		ExitStatus::CheckFailed => "checkFailed",
+	}
+}
+
+/// A line in the summary, where the positions and original action are only included when they apply.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SummaryLine {
+	action: String,
+	hash: String,
+	content: String,
+	option: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	position: Option<usize>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	original_action: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	original_position: Option<usize>,
+}
+
+impl SummaryLine {
+	fn new(line: &Line) -> Self {
+		Self {
+			action: line.get_action().to_string(),
+			hash: String::from(line.get_hash()),
+			content: String::from(line.get_content()),
+			option: line.option().map(String::from),
+			position: None,
+			original_action: None,
+			original_position: None,
+		}
+	}
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Summary {
+	exit_status: &'static str,
+	exit_code: u8,
+	aborted: bool,
+	lines: Vec<SummaryLine>,
+	inserted: Vec<SummaryLine>,
+	deleted: Vec<SummaryLine>,
+}
+
+/// Build the summary of the changes made to the todo file, as a JSON document.
+///
+/// A line without an original position was inserted, and a loaded line that is not the original of any line was
+/// deleted. Positions are zero-based indexes into the list of lines.
+pub(crate) fn build_summary(todo_file: &TodoFile, exit_status: ExitStatus) -> String {
+	let original_lines = todo_file.original_lines_iter().collect::<Vec<&Line>>();
+	let original_positions = todo_file.original_positions();
+	let mut lines = vec![];
+	let mut inserted = vec![];
+
+	for (position, (line, original_position)) in todo_file.lines_iter().zip(original_positions.iter()).enumerate() {
+		if let Some(original_position) = *original_position {
+			lines.push(SummaryLine {
+				position: Some(position),
+				original_action: Some(original_lines[original_position].get_action().to_string()),
+				original_position: Some(original_position),
+				..SummaryLine::new(line)
+			});
+		}
+		else {
+			inserted.push(SummaryLine {
+				position: Some(position),
+				..SummaryLine::new(line)
+			});
+		}
+	}
+
+	let deleted = original_lines
+		.iter()
+		.enumerate()
+		.filter(|&(index, _)| !original_positions.contains(&Some(index)))
+		.map(|(index, line)| {
+			SummaryLine {
+				original_position: Some(index),
+				..SummaryLine::new(line)
+			}
+		})
+		.collect::<Vec<SummaryLine>>();
+
+	let summary = Summary {
+		exit_status: exit_status_name(exit_status),
+		exit_code: exit_status.to_code(),
+		// Git aborts the rebase when the todo file is empty, or the editor exits with an error
+		aborted: exit_status != ExitStatus::Good || todo_file.is_empty(),
+		lines,
+		inserted,
+		deleted,
+	};
+	// serializing only fails for maps with non-string keys, which the summary does not contain
+	let json = serde_json::to_string_pretty(&summary).expect("The summary is always valid JSON");
+	format!("{json}\n")
+}
+
+/// Write the summary of the changes made to the todo file to a path.
+pub(crate) fn write_summary(path: &str, todo_file: &TodoFile, exit_status: ExitStatus) -> Result<(), Exit> {
+	fs::write(path, build_summary(todo_file, exit_status)).map_err(|err| {
+		Exit::new(
+			ExitStatus::FileWriteError,
+			format!("Unable to write summary file '{path}': {err}").as_str(),
+		)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+	use serde_json::{Value, json};
+
+	use super::*;
+	use crate::todo_file::{Action, EditContext, TodoFileOptions};
+
+	fn create_todo_file(lines: &[&str]) -> TodoFile {
+		let mut todo_file = TodoFile::new("git-rebase-todo", TodoFileOptions::new(10, "#"));
+		todo_file.set_lines(lines.iter().map(|line| Line::parse(line).unwrap()).collect());
+		todo_file
+	}
+
+	fn parse_summary(todo_file: &TodoFile, exit_status: ExitStatus) -> Value {
+		serde_json::from_str(build_summary(todo_file, exit_status).as_str()).unwrap()
+	}
+
+	#[rstest]
+	#[case::plain("abc")]
+	#[case::quote("a\"b")]
+	#[case::backslash("a\\b")]
+	#[case::newline("a\nb")]
+	#[case::tab("a\tb")]
+	#[case::control("a\u{1}b")]
+	#[case::unicode("a😀b")]
+	fn content_escape(#[case] content: &str) {
+		let mut todo_file = create_todo_file(&[]);
+		todo_file.add_line(0, Line::new_exec(content));
+		assert_eq!(
+			parse_summary(&todo_file, ExitStatus::Good)["inserted"][0]["content"],
+			json!(content)
+		);
+	}
+
+	#[test]
+	fn unchanged() {
+		let todo_file = create_todo_file(&["pick aaa c1"]);
+		assert_eq!(
+			parse_summary(&todo_file, ExitStatus::Good),
+			json!({
+				"exitStatus": "good",
+				"exitCode": 0,
+				"aborted": false,
+				"lines": [
+					{
+						"action": "pick",
+						"hash": "aaa",
+						"content": "c1",
+						"option": null,
+						"position": 0,
+						"originalAction": "pick",
+						"originalPosition": 0
+					}
+				],
+				"inserted": [],
+				"deleted": []
+			})
+		);
+	}
+
+	#[test]
+	fn changed() {
+		let mut todo_file = create_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		todo_file.update_range(1, 1, &EditContext::new().action(Action::Fixup).option("-C"));
+		_ = todo_file.swap_range_up(1, 1);
+		todo_file.remove_lines(2, 2);
+		todo_file.add_line(1, Line::parse("exec make").unwrap());
+		let summary = parse_summary(&todo_file, ExitStatus::Good);
+		assert_eq!(
+			summary["lines"],
+			json!([
+				{
+					"action": "fixup",
+					"hash": "bbb",
+					"content": "c2",
+					"option": "-C",
+					"position": 0,
+					"originalAction": "pick",
+					"originalPosition": 1
+				},
+				{
+					"action": "pick",
+					"hash": "aaa",
+					"content": "c1",
+					"option": null,
+					"position": 2,
+					"originalAction": "pick",
+					"originalPosition": 0
+				}
+			])
+		);
+		assert_eq!(
+			summary["inserted"],
+			json!([{"action": "exec", "hash": "", "content": "make", "option": null, "position": 1}])
+		);
+		assert_eq!(
+			summary["deleted"],
+			json!([{"action": "pick", "hash": "ccc", "content": "c3", "option": null, "originalPosition": 2}])
+		);
+	}
+
+	#[test]
+	fn duplicated_line() {
+		let mut todo_file = create_todo_file(&["pick aaa c1"]);
+		todo_file.add_line(1, Line::parse("pick aaa c1").unwrap());
+		let summary = parse_summary(&todo_file, ExitStatus::Good);
+		assert_eq!(summary["lines"][0]["originalPosition"], json!(0));
+		assert_eq!(
+			summary["inserted"],
+			json!([{"action": "pick", "hash": "aaa", "content": "c1", "option": null, "position": 1}])
+		);
+	}
+
+	#[rstest]
+	#[case::good(ExitStatus::Good, &["pick aaa c1"], "good", false)]
+	#[case::emptied(ExitStatus::Good, &[], "good", true)]
+	#[case::abort(ExitStatus::Abort, &[], "abort", true)]
+	#[case::kill(ExitStatus::Kill, &["pick aaa c1"], "kill", true)]
+	fn exit_status_and_aborted(
+		#[case] exit_status: ExitStatus,
+		#[case] lines: &[&str],
+		#[case] status_name: &str,
+		#[case] aborted: bool,
+	) {
+		let mut todo_file = create_todo_file(&["pick aaa c1"]);
+		todo_file.set_lines(lines.iter().map(|line| Line::parse(line).unwrap()).collect());
+		let summary = parse_summary(&todo_file, exit_status);
+		assert_eq!(summary["exitStatus"], json!(status_name));
+		assert_eq!(summary["exitCode"], json!(exit_status.to_code()));
+		assert_eq!(summary["aborted"], json!(aborted));
+	}
+
+	#[test]
+	fn write_summary_to_file() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("summary.json");
+		let todo_file = create_todo_file(&["pick aaa c1"]);
+		write_summary(path.to_str().unwrap(), &todo_file, ExitStatus::Good).unwrap();
+		assert_eq!(
+			fs::read_to_string(path).unwrap(),
+			build_summary(&todo_file, ExitStatus::Good)
+		);
+	}
+
+	#[test]
+	fn write_summary_error() {
+		let directory = tempfile::tempdir().unwrap();
+		let todo_file = create_todo_file(&["pick aaa c1"]);
+		let exit = write_summary(directory.path().to_str().unwrap(), &todo_file, ExitStatus::Good).unwrap_err();
+		assert_eq!(exit.get_status(), &ExitStatus::FileWriteError);
+	}
+}
@@ -8,6 +8,7 @@ pub(crate) fn render_style(line_segment: &LineSegment) -> String {
 		DisplayColor::ActionExec => String::from("ActionExec"),
 		DisplayColor::ActionFixup => String::from("ActionFixup"),
 		DisplayColor::ActionPick => String::from("ActionPick"),
+		DisplayColor::ActionRevert => String::from("ActionRevert"),
 		DisplayColor::ActionReword => String::from("ActionReword"),
 		DisplayColor::ActionSquash => String::from("ActionSquash"),
 		DisplayColor::DiffAddColor => String::from("DiffAddColor"),
@@ -15,7 +16,9 @@ pub(crate) fn render_style(line_segment: &LineSegment) -> String {
 		DisplayColor::DiffRemoveColor => String::from("DiffRemoveColor"),
 		DisplayColor::DiffContextColor => String::from("DiffContextColor"),
 		DisplayColor::DiffWhitespaceColor => String::from("DiffWhitespaceColor"),
+		DisplayColor::DiffHunkColor => String::from("DiffHunkColor"),
 		DisplayColor::IndicatorColor => String::from("IndicatorColor"),
+		DisplayColor::SearchMatchColor => String::from("SearchMatchColor"),
 		DisplayColor::Normal => String::from("Normal"),
 		DisplayColor::ActionLabel => String::from("ActionLabel"),
 		DisplayColor::ActionReset => String::from("ActionReset"),
@@ -27,6 +27,8 @@ fn _assert_results_format(artifacts: &[Artifact]) -> String {
 				Artifact::Searchable(ref _searchable) => String::from("SearchCancel(_)"),
 				Artifact::LoadDiff(ref hash) => format!("LoadDiff({hash:?})"),
 				Artifact::CancelDiff => String::from("CancelDiff"),
+				Artifact::ReloadConfig => String::from("ReloadConfig"),
+				Artifact::LoadRangeDiff(ref original, ref edited) => format!("LoadRangeDiff({original:?}, {edited:?})"),
 			}
 		})
 		.collect::<Vec<String>>()
@@ -48,8 +50,13 @@ fn compare_artifact(a: &Artifact, b: &Artifact) -> bool {
 		},
 		(Artifact::SearchTerm(self_term), Artifact::SearchTerm(other_term)) => self_term == other_term,
 		(Artifact::LoadDiff(self_hash), Artifact::LoadDiff(other_hash)) => self_hash == other_hash,
+		(
+			Artifact::LoadRangeDiff(self_original, self_edited),
+			Artifact::LoadRangeDiff(other_original, other_edited),
+		) => self_original == other_original && self_edited == other_edited,
 		(Artifact::SearchCancel, Artifact::SearchCancel)
 		| (Artifact::CancelDiff, Artifact::CancelDiff)
+		| (Artifact::ReloadConfig, Artifact::ReloadConfig)
 		| (Artifact::EnqueueResize, Artifact::EnqueueResize)
 		| (Artifact::Searchable(_), Artifact::Searchable(_)) => true,
 		_ => false,
@@ -9,6 +9,9 @@ pub(crate) struct CommitDiffBuilder {
 	number_files_changed: usize,
 	number_insertions: usize,
 	number_deletions: usize,
+	number_files_excluded: usize,
+	number_insertions_excluded: usize,
+	number_deletions_excluded: usize,
 }
 
 impl CommitDiffBuilder {
@@ -22,6 +25,9 @@ impl CommitDiffBuilder {
 			number_files_changed: 0,
 			number_insertions: 0,
 			number_deletions: 0,
+			number_files_excluded: 0,
+			number_insertions_excluded: 0,
+			number_deletions_excluded: 0,
 		}
 	}
 
@@ -67,6 +73,15 @@ impl CommitDiffBuilder {
 		self
 	}
 
+	/// Set the number of excluded files, and the line insertions and deletions in those files.
+	#[must_use]
+	pub(crate) const fn excluded(mut self, files: usize, insertions: usize, deletions: usize) -> Self {
+		self.number_files_excluded = files;
+		self.number_insertions_excluded = insertions;
+		self.number_deletions_excluded = deletions;
+		self
+	}
+
 	/// Return the built `CommitDiff`
 	#[must_use]
 	pub(crate) fn build(self) -> CommitDiff {
@@ -78,6 +93,11 @@ impl CommitDiffBuilder {
 			self.number_insertions,
 			self.number_deletions,
 		);
+		diff.update_excluded(
+			self.number_files_excluded,
+			self.number_insertions_excluded,
+			self.number_deletions_excluded,
+		);
 
 		diff
 	}
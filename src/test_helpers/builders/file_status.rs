@@ -1,14 +1,16 @@
 use std::path::{Path, PathBuf};
 
-use crate::diff::{Delta, FileMode, FileStatus, Status};
+use crate::diff::{BinaryDiff, Delta, FileMode, FileStatus, Status};
 
 /// Builder for creating a new reference.
 #[derive(Debug)]
 pub(crate) struct FileStatusBuilder {
+	binary_diff: Option<BinaryDiff>,
 	deltas: Vec<Delta>,
 	destination_is_binary: bool,
 	destination_mode: FileMode,
 	destination_path: PathBuf,
+	excluded: bool,
 	source_is_binary: bool,
 	source_mode: FileMode,
 	source_path: PathBuf,
@@ -20,10 +22,12 @@ impl FileStatusBuilder {
 	#[must_use]
 	pub(crate) fn new() -> Self {
 		Self {
+			binary_diff: None,
 			deltas: vec![],
 			destination_is_binary: false,
 			destination_mode: FileMode::Normal,
 			destination_path: PathBuf::default(),
+			excluded: false,
 			source_is_binary: false,
 			source_mode: FileMode::Normal,
 			source_path: PathBuf::default(),
@@ -31,6 +35,13 @@ impl FileStatusBuilder {
 		}
 	}
 
+	/// Set the `BinaryDiff`.
+	#[must_use]
+	pub(crate) fn binary_diff(mut self, binary_diff: BinaryDiff) -> Self {
+		self.binary_diff = Some(binary_diff);
+		self
+	}
+
 	/// Push a `Delta`.
 	#[must_use]
 	pub(crate) fn push_delta(mut self, delta: Delta) -> Self {
@@ -59,6 +70,13 @@ impl FileStatusBuilder {
 		self
 	}
 
+	/// Set if the file is excluded.
+	#[must_use]
+	pub(crate) const fn excluded(mut self, excluded: bool) -> Self {
+		self.excluded = excluded;
+		self
+	}
+
 	/// Set if the source is binary.
 	#[must_use]
 	pub(crate) const fn source_is_binary(mut self, binary: bool) -> Self {
@@ -102,6 +120,10 @@ impl FileStatusBuilder {
 		for delta in self.deltas {
 			file_status.add_delta(delta);
 		}
+		if let Some(binary_diff) = self.binary_diff {
+			file_status.set_binary_diff(binary_diff);
+		}
+		file_status.set_excluded(self.excluded);
 
 		file_status
 	}
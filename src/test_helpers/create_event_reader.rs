@@ -12,7 +12,8 @@ use crate::input::{Event, EventReaderFn};
 /// This function should be used sparingly, and instead `with_event_handler` should be used where possible.
 ///
 /// # Panics
-/// If provided an event generator that returns a `Event::MetaEvent` or `Event::StandardEvent` event type.
+/// If provided an event generator that returns a `Event::MetaEvent`, `Event::StandardEvent` or `Event::KeySequence`
+/// event type.
 pub(crate) fn create_event_reader<EventGeneratorFunction>(
 	event_generator: EventGeneratorFunction,
 ) -> impl EventReaderFn
@@ -31,8 +32,8 @@ where EventGeneratorFunction: Fn() -> Result<Option<Event>> + Sync + Send + 'sta
 					Event::Mouse(mouse_event) => Ok(Some(c_event::Event::Mouse(mouse_event))),
 					Event::None => Ok(None),
 					Event::Resize(width, height) => Ok(Some(c_event::Event::Resize(width, height))),
-					Event::Standard(_) => {
-						panic!("MetaEvent and Standard are not supported, please use other event types")
+					Event::Standard(_) | Event::KeySequence(_) => {
+						panic!("MetaEvent, Standard and KeySequence are not supported, please use other event types")
 					},
 				}
 			},
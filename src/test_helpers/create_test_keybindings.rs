@@ -24,9 +24,15 @@ pub(crate) fn create_test_keybindings() -> KeyBindings {
 		action_edit: map_keybindings(&[String::from("e")]),
 		action_fixup: map_keybindings(&[String::from("f")]),
 		action_pick: map_keybindings(&[String::from("p")]),
+		action_revert: map_keybindings(&[String::from("V")]),
 		action_reword: map_keybindings(&[String::from("r")]),
 		action_squash: map_keybindings(&[String::from("s")]),
 		confirm_yes: map_keybindings(&[String::from("y")]),
+		commit_message_abort: map_keybindings(&[String::from("Controlq")]),
+		commit_message_help: map_keybindings(&[String::from("F1")]),
+		commit_message_preview: map_keybindings(&[String::from("Controlp")]),
+		commit_message_save: map_keybindings(&[String::from("Controls")]),
+		commit_message_trailer: map_keybindings(&[String::from("Controlt")]),
 		edit: map_keybindings(&[String::from("E")]),
 		force_abort: map_keybindings(&[String::from("Q")]),
 		force_rebase: map_keybindings(&[String::from("W")]),
@@ -44,11 +50,15 @@ pub(crate) fn create_test_keybindings() -> KeyBindings {
 		move_up_step: map_keybindings(&[String::from("PageUp")]),
 		open_in_external_editor: map_keybindings(&[String::from('!')]),
 		rebase: map_keybindings(&[String::from('w')]),
+		reload_config: map_keybindings(&[String::from("F5")]),
 		remove_line: map_keybindings(&[String::from("Delete")]),
 		show_commit: map_keybindings(&[String::from("c")]),
 		show_diff: map_keybindings(&[String::from("d")]),
+		show_range_diff: map_keybindings(&[String::from("R")]),
+		toggle_excluded_files: map_keybindings(&[String::from("x")]),
 		toggle_visual_mode: map_keybindings(&[String::from("v")]),
 		fixup_keep_message: map_keybindings(&[String::from("u")]),
 		fixup_keep_message_with_editor: map_keybindings(&[String::from("U")]),
+		custom_commands: vec![],
 	}
 }
@@ -25,4 +25,8 @@ impl<M: Module> ModuleProvider for TestModuleProvider<M> {
 	fn get_module(&self, _state: State) -> &dyn Module {
 		&self.module
 	}
+
+	fn reload(&mut self, app_data: &AppData) {
+		self.module.reload(app_data);
+	}
 }
@@ -71,6 +71,7 @@ fn action_to_string(action: ViewAction) -> String {
 		ViewAction::Render => "Render",
 		ViewAction::Start => "Start",
 		ViewAction::End => "End",
+		ViewAction::Reconfigure(..) => "Reconfigure",
 	})
 }
 
@@ -1,10 +1,11 @@
 use std::fmt::{Display, Formatter};
 
-use crate::todo_file::ParseError;
+use crate::ParseError;
 
 /// Describes an rebase action.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub(crate) enum Action {
+#[expect(clippy::exhaustive_enums, reason = "The actions are matched on by consumers.")]
+pub enum Action {
 	/// A break action.
 	Break,
 	/// A drop action.
@@ -19,6 +20,8 @@ pub(crate) enum Action {
 	Noop,
 	/// A pick action.
 	Pick,
+	/// A revert action, used in the todo files of cherry-pick and revert sequences.
+	Revert,
 	/// A reword action.
 	Reword,
 	/// A squash action.
@@ -29,14 +32,14 @@ pub(crate) enum Action {
 	Reset,
 	/// A merge action.
 	Merge,
-	/// Update a reference
+	/// Update a reference.
 	UpdateRef,
 }
 
 impl Action {
-	/// Get the abbreviated version of the action.
+	/// Get the abbreviated version of the action. Git has no abbreviation for `revert`, so the full name is used.
 	#[must_use]
-	pub(crate) fn to_abbreviation(self) -> String {
+	pub fn to_abbreviation(self) -> String {
 		String::from(match self {
 			Self::Break => "b",
 			Self::Drop => "d",
@@ -47,6 +50,7 @@ impl Action {
 			Self::Merge => "m",
 			Self::Noop => "n",
 			Self::Pick => "p",
+			Self::Revert => "revert",
 			Self::Reset => "t",
 			Self::Reword => "r",
 			Self::Squash => "s",
@@ -56,10 +60,25 @@ impl Action {
 
 	/// Can the action be changed.
 	#[must_use]
-	pub(crate) const fn is_static(self) -> bool {
+	pub const fn is_static(self) -> bool {
 		match self {
 			Self::Break | Self::Exec | Self::Noop | Self::Reset | Self::Label | Self::Merge | Self::UpdateRef => true,
-			Self::Drop | Self::Edit | Self::Fixup | Self::Pick | Self::Reword | Self::Squash => false,
+			Self::Drop | Self::Edit | Self::Fixup | Self::Pick | Self::Revert | Self::Reword | Self::Squash => false,
+		}
+	}
+
+	/// Get the action that follows this action, when cycling through the actions of a commit. Static actions
+	/// are returned unchanged.
+	#[must_use]
+	pub const fn next(self) -> Self {
+		match self {
+			Self::Pick => Self::Reword,
+			Self::Reword => Self::Edit,
+			Self::Edit => Self::Squash,
+			Self::Squash => Self::Fixup,
+			Self::Fixup => Self::Drop,
+			Self::Drop | Self::Revert => Self::Pick,
+			Self::Break | Self::Exec | Self::Noop | Self::Reset | Self::Label | Self::Merge | Self::UpdateRef => self,
 		}
 	}
 }
@@ -76,6 +95,7 @@ impl Display for Action {
 			Self::Merge => "merge",
 			Self::Noop => "noop",
 			Self::Pick => "pick",
+			Self::Revert => "revert",
 			Self::Reset => "reset",
 			Self::Reword => "reword",
 			Self::Squash => "squash",
@@ -96,6 +116,7 @@ impl TryFrom<&str> for Action {
 			"fixup" | "f" => Ok(Self::Fixup),
 			"noop" | "n" => Ok(Self::Noop),
 			"pick" | "p" => Ok(Self::Pick),
+			"revert" => Ok(Self::Revert),
 			"reword" | "r" => Ok(Self::Reword),
 			"squash" | "s" => Ok(Self::Squash),
 			"label" | "l" => Ok(Self::Label),
@@ -122,6 +143,7 @@ mod tests {
 	#[case::fixup(Action::Fixup, "fixup")]
 	#[case::noop(Action::Noop, "noop")]
 	#[case::pick(Action::Pick, "pick")]
+	#[case::revert(Action::Revert, "revert")]
 	#[case::reword(Action::Reword, "reword")]
 	#[case::squash(Action::Squash, "squash")]
 	#[case::label(Action::Label, "label")]
@@ -147,6 +169,7 @@ mod tests {
 	#[case::noop("noop", Action::Noop)]
 	#[case::p("p", Action::Pick)]
 	#[case::pick("pick", Action::Pick)]
+	#[case::revert("revert", Action::Revert)]
 	#[case::r("r", Action::Reword)]
 	#[case::reword("reword", Action::Reword)]
 	#[case::s("s", Action::Squash)]
@@ -177,6 +200,7 @@ mod tests {
 	#[case::f(Action::Fixup, "f")]
 	#[case::n(Action::Noop, "n")]
 	#[case::p(Action::Pick, "p")]
+	#[case::revert(Action::Revert, "revert")]
 	#[case::r(Action::Reword, "r")]
 	#[case::s(Action::Squash, "s")]
 	#[case::l(Action::Label, "l")]
@@ -204,4 +228,22 @@ mod tests {
 	fn module_lifecycle(#[case] action: Action, #[case] expected: bool) {
 		assert_eq!(action.is_static(), expected);
 	}
+
+	#[rstest]
+	#[case::pick(Action::Pick, Action::Reword)]
+	#[case::reword(Action::Reword, Action::Edit)]
+	#[case::edit(Action::Edit, Action::Squash)]
+	#[case::squash(Action::Squash, Action::Fixup)]
+	#[case::fixup(Action::Fixup, Action::Drop)]
+	#[case::drop(Action::Drop, Action::Pick)]
+	#[case::break_action(Action::Break, Action::Break)]
+	#[case::exec(Action::Exec, Action::Exec)]
+	#[case::noop(Action::Noop, Action::Noop)]
+	#[case::label(Action::Label, Action::Label)]
+	#[case::reset(Action::Reset, Action::Reset)]
+	#[case::merge(Action::Merge, Action::Merge)]
+	#[case::update_ref(Action::UpdateRef, Action::UpdateRef)]
+	fn next(#[case] action: Action, #[case] expected: Action) {
+		assert_eq!(action.next(), expected);
+	}
 }
@@ -1,8 +1,8 @@
-use crate::todo_file::Action;
+use crate::Action;
 
 /// Describes a edit context for modifying a line.
 #[derive(Debug)]
-pub(crate) struct EditContext {
+pub struct EditContext {
 	action: Option<Action>,
 	content: Option<String>,
 	option: Option<String>,
@@ -11,7 +11,7 @@ pub(crate) struct EditContext {
 impl EditContext {
 	/// Create a new empty instance.
 	#[must_use]
-	pub(crate) const fn new() -> Self {
+	pub const fn new() -> Self {
 		Self {
 			action: None,
 			content: None,
@@ -21,40 +21,40 @@ impl EditContext {
 
 	/// Set the action.
 	#[must_use]
-	pub(crate) const fn action(mut self, action: Action) -> Self {
+	pub const fn action(mut self, action: Action) -> Self {
 		self.action = Some(action);
 		self
 	}
 
 	/// Set the content.
 	#[must_use]
-	pub(crate) fn content(mut self, content: &str) -> Self {
+	pub fn content(mut self, content: &str) -> Self {
 		self.content = Some(String::from(content));
 		self
 	}
 
 	/// Set the option.
 	#[must_use]
-	pub(crate) fn option(mut self, option: &str) -> Self {
+	pub fn option(mut self, option: &str) -> Self {
 		self.option = Some(String::from(option));
 		self
 	}
 
 	/// Get the action.
 	#[must_use]
-	pub(crate) const fn get_action(&self) -> Option<Action> {
+	pub const fn get_action(&self) -> Option<Action> {
 		self.action
 	}
 
 	/// Get the content.
 	#[must_use]
-	pub(crate) fn get_content(&self) -> Option<&str> {
+	pub fn get_content(&self) -> Option<&str> {
 		self.content.as_deref()
 	}
 
 	/// Get the option.
 	#[must_use]
-	pub(crate) fn get_option(&self) -> Option<&str> {
+	pub fn get_option(&self) -> Option<&str> {
 		self.option.as_deref()
 	}
 }
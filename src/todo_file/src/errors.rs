@@ -0,0 +1,14 @@
+//! Git Interactive Rebase Tool - Todo File Module Errors.
+//!
+//! # Description
+//! This module contains error types used in the Todo File Module.
+
+mod io;
+mod json;
+mod parse;
+
+pub use self::{
+	io::{FileReadErrorCause, FileWriteErrorCause, IoError},
+	json::JsonError,
+	parse::ParseError,
+};
@@ -2,18 +2,21 @@ use std::{io, path::PathBuf};
 
 use thiserror::Error;
 
-use crate::todo_file::ParseError;
+use crate::{JsonError, ParseError};
 
-/// The cause of a `FileRead` error
+/// The cause of a `FileRead` error.
 #[derive(Error, Debug)]
 #[non_exhaustive]
-pub(crate) enum FileReadErrorCause {
-	/// Caused by an io error
+pub enum FileReadErrorCause {
+	/// Caused by an io error.
 	#[error(transparent)]
 	IoError(#[from] io::Error),
-	/// Caused by a parse error
+	/// Caused by a parse error.
 	#[error(transparent)]
 	ParseError(#[from] ParseError),
+	/// Caused by invalid JSON.
+	#[error(transparent)]
+	JsonError(#[from] JsonError),
 }
 
 impl PartialEq for FileReadErrorCause {
@@ -22,23 +25,55 @@ impl PartialEq for FileReadErrorCause {
 		match (self, other) {
 			(Self::IoError(self_err), Self::IoError(other_err)) => self_err.kind() == other_err.kind(),
 			(Self::ParseError(self_err), Self::ParseError(other_err)) => self_err == other_err,
+			(Self::JsonError(self_err), Self::JsonError(other_err)) => self_err == other_err,
 			_ => false,
 		}
 	}
 }
 
-/// IO baser errors
+/// The cause of a `FileWrite` error.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum FileWriteErrorCause {
+	/// Caused by an io error.
+	#[error(transparent)]
+	IoError(#[from] io::Error),
+	/// Caused by a failure to serialize the JSON.
+	#[error(transparent)]
+	JsonError(#[from] JsonError),
+}
+
+impl PartialEq for FileWriteErrorCause {
+	#[expect(clippy::pattern_type_mismatch, reason = "Legacy, needs update")]
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::IoError(self_err), Self::IoError(other_err)) => self_err.kind() == other_err.kind(),
+			(Self::JsonError(self_err), Self::JsonError(other_err)) => self_err == other_err,
+			_ => false,
+		}
+	}
+}
+
+/// IO baser errors.
 #[derive(Error, Debug, PartialEq)]
 #[non_exhaustive]
-pub(crate) enum IoError {
-	/// The file could not be read
+pub enum IoError {
+	/// The file could not be read.
 	#[error("Unable to read file `{file}`")]
 	FileRead {
-		/// The file path that failed to read
+		/// The file path that failed to read.
 		file: PathBuf,
-		/// The reason for the read error
+		/// The reason for the read error.
 		cause: FileReadErrorCause,
 	},
+	/// The file could not be written.
+	#[error("Unable to write file `{file}`")]
+	FileWrite {
+		/// The file path that failed to write.
+		file: PathBuf,
+		/// The reason for the write error.
+		cause: FileWriteErrorCause,
+	},
 }
 
 #[cfg(test)]
@@ -69,6 +104,22 @@ mod test {
 		);
 	}
 
+	#[test]
+	fn partial_eq_file_write_error_cause_io_error_same_kind() {
+		assert_eq!(
+			FileWriteErrorCause::IoError(io::Error::from(io::ErrorKind::Other)),
+			FileWriteErrorCause::IoError(io::Error::from(io::ErrorKind::Other))
+		);
+	}
+
+	#[test]
+	fn partial_eq_file_write_error_cause_io_error_different_kind() {
+		assert_ne!(
+			FileWriteErrorCause::IoError(io::Error::from(io::ErrorKind::Other)),
+			FileWriteErrorCause::IoError(io::Error::from(io::ErrorKind::NotFound))
+		);
+	}
+
 	#[test]
 	fn partial_eq_file_read_error_cause_different_parse_error() {
 		assert_ne!(
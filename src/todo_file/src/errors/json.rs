@@ -0,0 +1,46 @@
+use thiserror::Error;
+
+/// An error reading or writing the JSON representation of a todo file.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{message}")]
+pub struct JsonError {
+	message: String,
+	line: usize,
+	column: usize,
+}
+
+impl JsonError {
+	pub(crate) fn new(err: &serde_json::Error) -> Self {
+		Self {
+			message: err.to_string(),
+			line: err.line(),
+			column: err.column(),
+		}
+	}
+
+	/// The one-based line of the JSON input where the error occurred, or zero when the error is not from the input.
+	#[must_use]
+	pub const fn line(&self) -> usize {
+		self.line
+	}
+
+	/// The one-based column of the JSON input where the error occurred, or zero when the error is not from the input.
+	#[must_use]
+	pub const fn column(&self) -> usize {
+		self.column
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn new() {
+		let err = serde_json::from_str::<serde_json::Value>("{\n}}").unwrap_err();
+		let json_error = JsonError::new(&err);
+		assert_eq!(json_error.to_string(), err.to_string());
+		assert_eq!(json_error.line(), 2);
+		assert_eq!(json_error.column(), 2);
+	}
+}
@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+/// Parsing errors.
+#[derive(Error, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+	/// The provided action string is not one of the allowed values.
+	#[error("The action `{0}` is not valid")]
+	InvalidAction(String),
+	/// The provided line is not valid.
+	#[error("The line `{0}` is not valid")]
+	InvalidLine(String),
+	/// The provided value contains a control character, such as a newline, that cannot be written to a line.
+	#[error("The value {0:?} contains a control character")]
+	ControlCharacter(String),
+	/// The action of the provided line is not supported by the todo file, such as `revert` in a rebase.
+	#[error("The action `{0}` is not supported by the todo file")]
+	UnsupportedAction(String),
+	/// The provided option is not valid for the action.
+	#[error("The option `{0}` is not valid for the action `{1}`")]
+	InvalidOption(String, String),
+}
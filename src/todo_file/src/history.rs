@@ -7,7 +7,7 @@ mod tests;
 use std::{cmp::min, collections::VecDeque};
 
 pub(crate) use self::{history_item::HistoryItem, operation::Operation};
-use crate::todo_file::{
+use crate::{
 	line::Line,
 	utils::{add_range, remove_range, swap_range_down, swap_range_up},
 };
@@ -18,6 +18,8 @@ pub(crate) struct History {
 	redo_history: VecDeque<HistoryItem>,
 	undo_history: VecDeque<HistoryItem>,
 	limit: usize,
+	// the index of the first undo entry of a group of changes that is in progress
+	group_start: Option<usize>,
 }
 
 impl History {
@@ -26,6 +28,7 @@ impl History {
 			redo_history: VecDeque::new(),
 			undo_history: VecDeque::from([HistoryItem::new_load()]),
 			limit: limit.try_into().expect("History limit is too large"),
+			group_start: None,
 		}
 	}
 
@@ -68,10 +71,43 @@ impl History {
 
 	pub(crate) fn record(&mut self, operations: HistoryItem) {
 		self.redo_history.clear();
-		// delete old entries on limit reached
 		self.undo_history.push_back(operations);
-		if self.undo_history.len() > self.limit {
+		self.trim_to_limit();
+	}
+
+	// delete old entries on limit reached
+	fn trim_to_limit(&mut self) {
+		while self.undo_history.len() > self.limit {
 			let _pop_result = self.undo_history.pop_front();
+			self.group_start = self.group_start.map(|start| start.saturating_sub(1));
+		}
+	}
+
+	pub(crate) fn set_limit(&mut self, limit: u32) {
+		self.limit = limit.try_into().expect("History limit is too large");
+		self.trim_to_limit();
+	}
+
+	pub(crate) fn start_group(&mut self) {
+		if self.group_start.is_none() {
+			self.group_start = Some(self.undo_history.len());
+		}
+	}
+
+	pub(crate) fn end_group(&mut self) {
+		self.group_start = None;
+	}
+
+	// end the group, replacing the entries recorded since the start of the group with a single entry, or with no entry
+	// when the group did not change anything
+	pub(crate) fn squash_group(&mut self, history_item: Option<HistoryItem>) {
+		let Some(start) = self.group_start.take()
+		else {
+			return;
+		};
+		self.undo_history.truncate(start);
+		if let Some(item) = history_item {
+			self.record(item);
 		}
 	}
 
@@ -101,6 +137,7 @@ impl History {
 	}
 
 	pub(crate) fn reset(&mut self) {
+		self.group_start = None;
 		self.undo_history.clear();
 		self.undo_history.push_back(HistoryItem::new_load());
 		self.redo_history.clear();
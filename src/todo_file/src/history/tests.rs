@@ -2,7 +2,6 @@
 use claims::assert_some_eq;
 
 use super::*;
-use crate::assert_empty;
 
 fn history_item_to_string(item: &HistoryItem) -> String {
 	let range = if item.start_index == item.end_index {
@@ -61,7 +60,7 @@ fn new() {
 	assert_eq!(history.limit, 100);
 	assert_eq!(history.undo_history.len(), 1);
 	assert_some_eq!(history.undo_history.pop_back(), HistoryItem::new_load());
-	assert_empty!(history.redo_history);
+	assert!(history.redo_history.is_empty());
 }
 
 #[test]
@@ -70,7 +69,7 @@ fn record_history() {
 	history.redo_history.push_front(HistoryItem::new_add(1, 1));
 	history.record(HistoryItem::new_add(1, 1));
 	assert_history_items!(history.undo_history, HistoryItem::new_add(1, 1));
-	assert_empty!(history.redo_history);
+	assert!(history.redo_history.is_empty());
 }
 
 #[test]
@@ -86,7 +85,37 @@ fn record_history_overflow_limit() {
 		HistoryItem::new_add(3, 3),
 		HistoryItem::new_add(4, 4)
 	);
-	assert_empty!(history.redo_history);
+	assert!(history.redo_history.is_empty());
+}
+
+#[test]
+fn set_limit_lower() {
+	let mut history = History::new(10);
+	history.record(HistoryItem::new_add(1, 1));
+	history.record(HistoryItem::new_add(2, 2));
+	history.record(HistoryItem::new_add(3, 3));
+	history.set_limit(2);
+	assert_eq!(history.limit, 2);
+	assert_eq!(history.undo_history.len(), 2);
+	assert_history_items!(
+		history.undo_history,
+		HistoryItem::new_add(2, 2),
+		HistoryItem::new_add(3, 3)
+	);
+}
+
+#[test]
+fn set_limit_higher() {
+	let mut history = History::new(2);
+	history.record(HistoryItem::new_add(1, 1));
+	history.set_limit(3);
+	history.record(HistoryItem::new_add(2, 2));
+	assert_eq!(history.undo_history.len(), 3);
+	assert_history_items!(
+		history.undo_history,
+		HistoryItem::new_add(1, 1),
+		HistoryItem::new_add(2, 2)
+	);
 }
 
 #[test]
@@ -899,5 +928,5 @@ fn reset() {
 	history.reset();
 	assert_eq!(history.undo_history.len(), 1);
 	assert_some_eq!(history.undo_history.pop_back(), HistoryItem::new_load());
-	assert_empty!(history.redo_history);
+	assert!(history.redo_history.is_empty());
 }
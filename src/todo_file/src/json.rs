@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Action, Line, ParseError};
+
+/// Metadata of the commit of a line, added to the JSON representation of the line when available.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[expect(clippy::exhaustive_structs, reason = "The metadata is created by consumers.")]
+pub struct CommitMetadata {
+	/// The full hash of the commit.
+	pub hash: String,
+	/// The name of the author of the commit.
+	pub author_name: Option<String>,
+	/// The email of the author of the commit.
+	pub author_email: Option<String>,
+	/// The date the commit was authored, in RFC 3339 format.
+	pub author_date: Option<String>,
+	/// The summary line of the commit message.
+	pub summary: Option<String>,
+}
+
+/// The JSON representation of a line of the todo file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JsonLine {
+	pub(crate) action: String,
+	#[serde(default)]
+	pub(crate) hash: String,
+	#[serde(default)]
+	pub(crate) content: String,
+	#[serde(default)]
+	pub(crate) option: Option<String>,
+	/// The position of the line when the todo file was loaded, ignored when importing.
+	#[serde(default, skip_deserializing)]
+	pub(crate) original_position: Option<usize>,
+	/// The metadata of the commit, ignored when importing.
+	#[serde(default, skip_deserializing, skip_serializing_if = "Option::is_none")]
+	pub(crate) commit: Option<CommitMetadata>,
+}
+
+impl JsonLine {
+	pub(crate) fn new(line: &Line, original_position: Option<usize>, commit: Option<CommitMetadata>) -> Self {
+		Self {
+			action: line.get_action().to_string(),
+			hash: String::from(line.get_hash()),
+			content: String::from(line.get_content()),
+			option: line.option().map(String::from),
+			original_position,
+			commit,
+		}
+	}
+
+	/// Convert to a line, using the todo file grammar, so the fields are validated in the same way as a todo file.
+	/// Values with control characters are rejected, since a newline would add another line to the todo file.
+	pub(crate) fn to_line(&self) -> Result<Line, ParseError> {
+		if let Some(value) = [self.action.as_str(), self.hash.as_str(), self.content.as_str()]
+			.into_iter()
+			.chain(self.option.as_deref())
+			.find(|value| value.chars().any(char::is_control))
+		{
+			return Err(ParseError::ControlCharacter(String::from(value)));
+		}
+		let action = Action::try_from(self.action.as_str())?;
+		if let Some(option) = self.option.as_deref().filter(|option| !option.is_empty()) {
+			if action != Action::Fixup || (option != "-C" && option != "-c") {
+				return Err(ParseError::InvalidOption(String::from(option), action.to_string()));
+			}
+		}
+		let mut text = action.to_string();
+		for part in [
+			self.option.as_deref(),
+			Some(self.hash.as_str()),
+			Some(self.content.as_str()),
+		]
+		.into_iter()
+		.flatten()
+		.filter(|part| !part.is_empty())
+		{
+			text.push(' ');
+			text.push_str(part);
+		}
+		Line::parse(text.as_str())
+	}
+}
+
+/// The JSON representation of the todo file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct JsonTodoFile {
+	pub(crate) lines: Vec<JsonLine>,
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_err_eq, assert_ok_eq};
+	use rstest::rstest;
+
+	use super::*;
+
+	fn json_line(action: &str, hash: &str, content: &str, option: Option<&str>) -> JsonLine {
+		JsonLine {
+			action: String::from(action),
+			hash: String::from(hash),
+			content: String::from(content),
+			option: option.map(String::from),
+			original_position: None,
+			commit: None,
+		}
+	}
+
+	#[rstest]
+	#[case::pick("pick", "aaa", "comment", None, "pick aaa comment")]
+	#[case::pick_without_content("pick", "aaa", "", None, "pick aaa")]
+	#[case::revert("revert", "aaa", "comment", None, "revert aaa comment")]
+	#[case::fixup_option("fixup", "aaa", "comment", Some("-C"), "fixup -C aaa comment")]
+	#[case::exec("exec", "", "make test", None, "exec make test")]
+	#[case::merge("merge", "", "-C aaa feature", None, "merge -C aaa feature")]
+	#[case::break_action("break", "", "", None, "break")]
+	fn to_line(
+		#[case] action: &str,
+		#[case] hash: &str,
+		#[case] content: &str,
+		#[case] option: Option<&str>,
+		#[case] expected: &str,
+	) {
+		assert_ok_eq!(
+			json_line(action, hash, content, option).to_line(),
+			Line::parse(expected).unwrap()
+		);
+	}
+
+	#[test]
+	fn to_line_invalid_action() {
+		assert_err_eq!(
+			json_line("invalid", "aaa", "", None).to_line(),
+			ParseError::InvalidAction(String::from("invalid"))
+		);
+	}
+
+	#[test]
+	fn to_line_missing_hash() {
+		assert_err_eq!(
+			json_line("pick", "", "", None).to_line(),
+			ParseError::InvalidLine(String::from("pick"))
+		);
+	}
+
+	#[rstest]
+	#[case::action_newline("pick\nexec touch file", "aaa", "", None)]
+	#[case::hash_newline("pick", "aaa\nexec touch file", "", None)]
+	#[case::hash_carriage_return("pick", "aaa\r", "", None)]
+	#[case::content_newline("pick", "aaa", "c1\nexec touch file", None)]
+	#[case::content_carriage_return("pick", "aaa", "c1\rexec touch file", None)]
+	#[case::content_escape("pick", "aaa", "c1\u{1b}[2J", None)]
+	#[case::content_tab("exec", "", "make\ttest", None)]
+	#[case::option_newline("fixup", "aaa", "c1", Some("-C\nexec touch file"))]
+	fn to_line_control_character(
+		#[case] action: &str,
+		#[case] hash: &str,
+		#[case] content: &str,
+		#[case] option: Option<&str>,
+	) {
+		let value = [action, hash, content]
+			.into_iter()
+			.chain(option)
+			.find(|value| value.chars().any(char::is_control))
+			.unwrap();
+		assert_err_eq!(
+			json_line(action, hash, content, option).to_line(),
+			ParseError::ControlCharacter(String::from(value))
+		);
+	}
+
+	#[rstest]
+	#[case::pick("pick", "-C")]
+	#[case::squash("squash", "-c")]
+	#[case::exec("exec", "-C")]
+	#[case::fixup_invalid("fixup", "x")]
+	fn to_line_invalid_option(#[case] action: &str, #[case] option: &str) {
+		assert_err_eq!(
+			json_line(action, "aaa", "comment", Some(option)).to_line(),
+			ParseError::InvalidOption(String::from(option), String::from(action))
+		);
+	}
+
+	#[test]
+	fn new() {
+		let line = Line::parse("fixup -c aaa comment").unwrap();
+		assert_eq!(JsonLine::new(&line, Some(2), None), JsonLine {
+			original_position: Some(2),
+			..json_line("fixup", "aaa", "comment", Some("-c"))
+		});
+	}
+
+	#[test]
+	fn deserialize_ignores_export_fields() {
+		let json_todo_file: JsonTodoFile = serde_json::from_str(
+			r#"{"lines": [{"action": "exec", "content": "make", "originalPosition": 3, "commit": {"hash": "aaa"}}]}"#,
+		)
+		.unwrap();
+		assert_eq!(json_todo_file.lines, vec![json_line("exec", "", "make", None)]);
+	}
+}
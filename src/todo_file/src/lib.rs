@@ -0,0 +1,1786 @@
+#![cfg_attr(
+	test,
+	allow(
+		clippy::allow_attributes_without_reason,
+		clippy::let_underscore_must_use,
+		clippy::let_underscore_untyped,
+		clippy::missing_const_for_fn,
+		clippy::needless_pass_by_value,
+		clippy::panic,
+		clippy::shadow_reuse,
+		clippy::shadow_unrelated,
+		let_underscore_drop,
+		missing_docs,
+		unfulfilled_lint_expectations,
+		reason = "Relaxed for tests"
+	)
+)]
+//! Git Interactive Rebase Tool - Todo File.
+//!
+//! # Description
+//! This crate is used to handle working with the rebase todo file. It provides parsing of the todo file lines,
+//! editing of the lines with undo and redo, and writing the todo file, including the exec commands added by the
+//! `postModifiedLineExecCommand` option.
+//!
+//! ```no_run
+//! use girt_todo_file::{Action, EditContext, TodoFile, TodoFileOptions};
+//!
+//! # fn main() -> Result<(), girt_todo_file::IoError> {
+//! let mut todo_file = TodoFile::new(".git/rebase-merge/git-rebase-todo", TodoFileOptions::new(100, "#"));
+//! todo_file.load_file()?;
+//! todo_file.update_range(0, 0, &EditContext::new().action(Action::Reword));
+//! _ = todo_file.undo();
+//! todo_file.write_file()?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod action;
+mod edit_content;
+mod errors;
+mod history;
+mod json;
+mod line;
+mod line_parser;
+mod rebase_progress;
+mod todo_file_options;
+mod utils;
+
+use std::{
+	fs::{File, read_to_string},
+	io::Write as _,
+	path::{Path, PathBuf},
+	slice::Iter,
+};
+
+use version_track::Version;
+
+pub use self::{
+	action::Action,
+	edit_content::EditContext,
+	errors::{FileReadErrorCause, FileWriteErrorCause, IoError, JsonError, ParseError},
+	json::CommitMetadata,
+	line::Line,
+	line_parser::LineParser,
+	rebase_progress::RebaseProgress,
+	todo_file_options::TodoFileOptions,
+};
+use self::{
+	history::{History, HistoryItem},
+	utils::{get_merge_commit_hash, remove_range, swap_range_down, swap_range_up},
+};
+use crate::{
+	history::Operation,
+	json::{JsonLine, JsonTodoFile},
+};
+
+/// Represents a rebase file.
+#[derive(Debug)]
+pub struct TodoFile {
+	filepath: PathBuf,
+	history: History,
+	history_group_lines: Option<Vec<Line>>,
+	is_noop: bool,
+	is_sequencer: bool,
+	sequencer_action: Option<Action>,
+	lines: Vec<Line>,
+	options: TodoFileOptions,
+	original_lines: Vec<Line>,
+	rebase_progress: Option<RebaseProgress>,
+	selected_line_index: usize,
+	version: Version,
+}
+
+impl TodoFile {
+	/// Create a new instance.
+	#[must_use]
+	pub fn new<Path: AsRef<std::path::Path>>(path: Path, options: TodoFileOptions) -> Self {
+		let history = History::new(options.undo_limit);
+		let filepath = PathBuf::from(path.as_ref());
+		// cherry-pick and revert sequences store the todo file as `sequencer/todo` in the Git directory
+		let is_sequencer = filepath.file_name().is_some_and(|name| name == "todo")
+			&& filepath
+				.parent()
+				.and_then(std::path::Path::file_name)
+				.is_some_and(|name| name == "sequencer");
+
+		Self {
+			filepath,
+			history,
+			history_group_lines: None,
+			is_noop: false,
+			is_sequencer,
+			lines: vec![],
+			sequencer_action: None,
+			options,
+			original_lines: vec![],
+			rebase_progress: None,
+			selected_line_index: 0,
+			version: Version::new(),
+		}
+	}
+
+	/// Set the options, such as after the configuration is reloaded. The history of changes is trimmed to the new
+	/// undo limit.
+	pub fn set_options(&mut self, options: TodoFileOptions) {
+		self.history.set_limit(options.undo_limit);
+		self.options = options;
+	}
+
+	/// Set the rebase lines.
+	pub fn set_lines(&mut self, lines: Vec<Line>) {
+		self.is_noop = !lines.is_empty() && lines[0].get_action() == &Action::Noop;
+		self.lines = if self.is_noop {
+			vec![]
+		}
+		else {
+			lines.into_iter().filter(|l| l.get_action() != &Action::Noop).collect()
+		};
+		// the first set of lines is retained, so the edited list can be compared against it
+		if self.original_lines.is_empty() {
+			self.original_lines = self.lines.clone();
+			// git rejects a sequence that changes between cherry-pick and revert, so the command is kept
+			if self.is_sequencer {
+				self.sequencer_action = self
+					.lines
+					.iter()
+					.map(|line| *line.get_action())
+					.find(|action| matches!(action, Action::Pick | Action::Revert));
+			}
+		}
+		if self.selected_line_index >= self.lines.len() {
+			self.selected_line_index = if self.lines.is_empty() { 0 } else { self.lines.len() - 1 };
+		}
+		self.version.reset();
+		self.history.reset();
+		self.history_group_lines = None;
+	}
+
+	/// Load the rebase file from disk.
+	///
+	/// # Errors
+	///
+	/// Returns error if the file cannot be read.
+	pub fn load_file(&mut self) -> Result<(), IoError> {
+		let lines: Result<Vec<Line>, IoError> = read_to_string(self.filepath.as_path())
+			.map_err(|err| {
+				IoError::FileRead {
+					file: self.filepath.clone(),
+					cause: FileReadErrorCause::from(err),
+				}
+			})?
+			.lines()
+			.filter_map(|l| {
+				if l.starts_with(self.options.comment_prefix.as_str()) || l.is_empty() {
+					None
+				}
+				else {
+					Some(Line::parse(l).map_err(|err| {
+						IoError::FileRead {
+							file: self.filepath.clone(),
+							cause: FileReadErrorCause::from(err),
+						}
+					}))
+				}
+			})
+			.collect();
+		self.set_lines(lines?);
+		// an interactive rebase stores the applied lines, and its progress, alongside the todo file
+		self.rebase_progress = if self.filepath.file_name().is_some_and(|name| name == "git-rebase-todo") {
+			self.filepath
+				.parent()
+				.and_then(|directory| RebaseProgress::load(directory, self.options.comment_prefix.as_str()))
+		}
+		else {
+			None
+		};
+		Ok(())
+	}
+
+	/// Load the lines from a JSON file, in place of the rebase file. The lines are used as the lines of the todo
+	/// file as it was loaded, and the rebase file is only changed when it is written.
+	///
+	/// # Errors
+	///
+	/// Returns error if the file cannot be read, or contains invalid JSON or lines, or a line with an action that is
+	/// not supported by the todo file.
+	pub fn load_json_file<Path: AsRef<std::path::Path>>(&mut self, json_path: Path) -> Result<(), IoError> {
+		let path = json_path.as_ref();
+		let map_err = |cause: FileReadErrorCause| {
+			IoError::FileRead {
+				file: PathBuf::from(path),
+				cause,
+			}
+		};
+		let contents = read_to_string(path).map_err(|err| map_err(FileReadErrorCause::from(err)))?;
+		let json_todo_file: JsonTodoFile = serde_json::from_str(contents.as_str())
+			.map_err(|err| map_err(FileReadErrorCause::from(JsonError::new(&err))))?;
+		let lines = json_todo_file
+			.lines
+			.iter()
+			.map(JsonLine::to_line)
+			.collect::<Result<Vec<Line>, ParseError>>()
+			.map_err(|err| map_err(FileReadErrorCause::from(err)))?;
+		self.set_lines(lines);
+		// git rejects a todo file with an action that is not valid for the rebase or sequence
+		if let Some(line) = self.lines.iter().find(|line| !self.supports_action(*line.get_action())) {
+			return Err(map_err(FileReadErrorCause::from(ParseError::UnsupportedAction(
+				line.get_action().to_string(),
+			))));
+		}
+		Ok(())
+	}
+
+	/// Create a JSON representation of the lines, with the metadata of the commit of each line, when available.
+	///
+	/// # Errors
+	///
+	/// Returns error if the lines cannot be serialized.
+	pub fn to_json<MetadataFn>(&self, commit_metadata: MetadataFn) -> Result<String, JsonError>
+	where MetadataFn: Fn(&Line) -> Option<CommitMetadata> {
+		let json_todo_file = JsonTodoFile {
+			lines: self
+				.lines
+				.iter()
+				.zip(self.original_positions())
+				.map(|(line, original_position)| JsonLine::new(line, original_position, commit_metadata(line)))
+				.collect(),
+		};
+		serde_json::to_string_pretty(&json_todo_file).map_err(|err| JsonError::new(&err))
+	}
+
+	/// Write the JSON representation of the lines to a file.
+	///
+	/// # Errors
+	///
+	/// Returns error if the file cannot be written.
+	pub fn write_json_file<Path: AsRef<std::path::Path>, MetadataFn>(
+		&self,
+		json_path: Path,
+		commit_metadata: MetadataFn,
+	) -> Result<(), IoError>
+	where
+		MetadataFn: Fn(&Line) -> Option<CommitMetadata>,
+	{
+		let path = json_path.as_ref();
+		let map_err = |cause: FileWriteErrorCause| {
+			IoError::FileWrite {
+				file: PathBuf::from(path),
+				cause,
+			}
+		};
+		let json = self
+			.to_json(commit_metadata)
+			.map_err(|err| map_err(FileWriteErrorCause::from(err)))?;
+		std::fs::write(path, format!("{json}\n")).map_err(|err| map_err(FileWriteErrorCause::from(err)))
+	}
+
+	/// Write the rebase file to disk.
+	/// # Errors
+	///
+	/// Returns error if the file cannot be written.
+	pub fn write_file(&self) -> Result<(), IoError> {
+		let mut file = File::create(&self.filepath).map_err(|err| {
+			IoError::FileRead {
+				file: self.filepath.clone(),
+				cause: FileReadErrorCause::from(err),
+			}
+		})?;
+		let file_contents = if self.is_noop {
+			String::from("noop")
+		}
+		else {
+			// the sequencer does not support the drop action, so dropped lines are removed instead
+			self.lines
+				.iter()
+				.filter(|l| !(self.is_sequencer && l.get_action() == &Action::Drop))
+				.flat_map(|l| {
+					let mut lines = vec![Line::to_text(l)];
+					if let Some(command) = self.options.line_changed_command.as_deref() {
+						if l.is_modified() && !self.is_sequencer {
+							let action = l.get_action();
+
+							match *action {
+								Action::Break | Action::Noop => {},
+								Action::Drop
+								| Action::Fixup
+								| Action::Edit
+								| Action::Pick
+								| Action::Revert
+								| Action::Reword
+								| Action::Squash => {
+									lines.push(format!("exec {command} \"{}\" \"{}\"", action, l.get_hash()));
+								},
+								Action::Exec | Action::Label | Action::Reset | Action::Merge | Action::UpdateRef => {
+									let original_label =
+										l.original().map_or_else(|| l.get_content(), Line::get_content);
+									lines.push(format!(
+										"exec {command} \"{}\" \"{}\" \"{}\"",
+										action,
+										original_label,
+										l.get_content()
+									));
+								},
+							}
+						}
+					}
+					lines
+				})
+				.collect::<Vec<String>>()
+				.join("\n")
+		};
+		writeln!(file, "{file_contents}").map_err(|err| {
+			IoError::FileRead {
+				file: self.filepath.clone(),
+				cause: FileReadErrorCause::from(err),
+			}
+		})?;
+		Ok(())
+	}
+
+	/// Set the selected line index returning the new index based after ensuring within range.
+	pub fn set_selected_line_index(&mut self, selected_line_index: usize) -> usize {
+		self.selected_line_index = if self.lines.is_empty() {
+			0
+		}
+		else if selected_line_index >= self.lines.len() {
+			self.lines.len() - 1
+		}
+		else {
+			selected_line_index
+		};
+		self.selected_line_index
+	}
+
+	/// Swap a range of lines up.
+	pub fn swap_range_up(&mut self, start_index: usize, end_index: usize) -> bool {
+		if end_index == 0 || start_index == 0 || self.lines.is_empty() {
+			return false;
+		}
+
+		let max_index = self.lines.len() - 1;
+		let end = if end_index > max_index { max_index } else { end_index };
+		let start = if start_index > max_index {
+			max_index
+		}
+		else {
+			start_index
+		};
+
+		swap_range_up(&mut self.lines, start, end);
+		self.version.increment();
+		self.history.record(HistoryItem::new_swap_up(start, end));
+		true
+	}
+
+	/// Swap a range of lines down.
+	pub fn swap_range_down(&mut self, start_index: usize, end_index: usize) -> bool {
+		let len = self.lines.len();
+		let max_index = if len == 0 { 0 } else { len - 1 };
+
+		if end_index == max_index || start_index == max_index {
+			return false;
+		}
+
+		swap_range_down(&mut self.lines, start_index, end_index);
+		self.version.increment();
+		self.history.record(HistoryItem::new_swap_down(start_index, end_index));
+		true
+	}
+
+	/// Add a new line.
+	pub fn add_line(&mut self, index: usize, line: Line) {
+		let i = if index > self.lines.len() {
+			self.lines.len()
+		}
+		else {
+			index
+		};
+		self.lines.insert(i, line);
+		self.version.increment();
+		self.history.record(HistoryItem::new_add(i, i));
+	}
+
+	/// Remove a range of lines.
+	pub fn remove_lines(&mut self, start_index: usize, end_index: usize) {
+		if self.lines.is_empty() {
+			return;
+		}
+
+		let max_index = self.lines.len() - 1;
+		let end = if end_index > max_index { max_index } else { end_index };
+		let start = if start_index > max_index {
+			max_index
+		}
+		else {
+			start_index
+		};
+
+		let removed_lines = remove_range(&mut self.lines, start, end);
+		self.version.increment();
+		self.history.record(HistoryItem::new_remove(start, end, removed_lines));
+	}
+
+	/// Update a range of lines.
+	pub fn update_range(&mut self, start_index: usize, end_index: usize, edit_context: &EditContext) {
+		if self.lines.is_empty() {
+			return;
+		}
+
+		let max_index = self.lines.len() - 1;
+		let end = if end_index > max_index { max_index } else { end_index };
+		let start = if start_index > max_index {
+			max_index
+		}
+		else {
+			start_index
+		};
+
+		let range = if end <= start { end..=start } else { start..=end };
+
+		let mut lines = vec![];
+		for index in range {
+			let line = &mut self.lines[index];
+			lines.push(line.clone());
+			if let Some(action) = edit_context.get_action() {
+				line.set_action(action);
+			}
+
+			if let Some(content) = edit_context.get_content() {
+				line.edit_content(content);
+			}
+
+			if let Some(option) = edit_context.get_option() {
+				line.toggle_option(option);
+			}
+		}
+		self.version.increment();
+		self.history.record(HistoryItem::new_modify(start, end, lines));
+	}
+
+	/// Start grouping the following modifications into a single entry of the undo history, such as the moves of a
+	/// drag, until `end_history_group` is called.
+	pub fn start_history_group(&mut self) {
+		if self.history_group_lines.is_none() {
+			self.history.start_group();
+			self.history_group_lines = Some(self.lines.clone());
+		}
+	}
+
+	/// End the grouping of modifications started with `start_history_group`. The modifications are recorded as a
+	/// single modification of the lines that changed, when the number of lines did not change.
+	pub fn end_history_group(&mut self) {
+		let Some(original_lines) = self.history_group_lines.take()
+		else {
+			return;
+		};
+		if original_lines.len() != self.lines.len() {
+			self.history.end_group();
+			return;
+		}
+		let is_changed = |(original, current): (&Line, &Line)| original != current;
+		let first = original_lines.iter().zip(&self.lines).position(is_changed);
+		let last = original_lines.iter().zip(&self.lines).rposition(is_changed);
+		self.history.squash_group(match (first, last) {
+			(Some(start), Some(end)) => {
+				Some(HistoryItem::new_modify(
+					start,
+					end,
+					original_lines[start..=end].to_vec(),
+				))
+			},
+			_ => None,
+		});
+	}
+
+	/// Undo the last modification.
+	pub fn undo(&mut self) -> Option<(usize, usize)> {
+		self.version.increment();
+		if let Some((operation, start, end)) = self.history.undo(&mut self.lines) {
+			return if operation == Operation::Load {
+				None
+			}
+			else {
+				Some((start, end))
+			};
+		}
+		None
+	}
+
+	/// Redo the last undone modification.
+	pub fn redo(&mut self) -> Option<(usize, usize)> {
+		self.version.increment();
+		self.history.redo(&mut self.lines).map(|(_, start, end)| (start, end))
+	}
+
+	/// Get the current version.
+	#[must_use]
+	pub const fn version(&self) -> &Version {
+		&self.version
+	}
+
+	/// Get the selected line.
+	#[must_use]
+	pub fn get_selected_line(&self) -> Option<&Line> {
+		self.lines.get(self.selected_line_index)
+	}
+
+	/// Get the index of the last line that can be selected.
+	#[must_use]
+	pub fn get_max_selected_line_index(&self) -> usize {
+		let len = self.lines.len();
+		if len == 0 { 0 } else { len - 1 }
+	}
+
+	/// Get the selected line index.
+	#[must_use]
+	pub const fn get_selected_line_index(&self) -> usize {
+		self.selected_line_index
+	}
+
+	/// Get the file path to the rebase file.
+	#[must_use]
+	pub fn get_filepath(&self) -> &Path {
+		self.filepath.as_path()
+	}
+
+	/// Get a line by index.
+	#[must_use]
+	pub fn get_line(&self, index: usize) -> Option<&Line> {
+		self.lines.get(index)
+	}
+
+	/// Get the revision that a line refers to, if any.
+	///
+	/// Lines with a hash resolve to that hash, a `merge -C <hash>` resolves to the original merge
+	/// commit, an `update-ref` resolves to the reference name, and `label` and `reset` lines resolve
+	/// to the commit that the label will point to, using the original commits of the preceding lines.
+	#[must_use]
+	pub fn get_line_revision(&self, index: usize) -> Option<String> {
+		let line = self.lines.get(index)?;
+		match *line.get_action() {
+			Action::Drop
+			| Action::Edit
+			| Action::Fixup
+			| Action::Pick
+			| Action::Revert
+			| Action::Reword
+			| Action::Squash => Some(String::from(line.get_hash())),
+			Action::Merge => get_merge_commit_hash(line.get_content()).map(String::from),
+			Action::UpdateRef => line.get_content().split_whitespace().next().map(String::from),
+			Action::Label => self.resolve_head(index),
+			Action::Reset => {
+				let label = line.get_content().split_whitespace().next()?;
+				if label == "[new" {
+					return None;
+				}
+				let label_index = self.lines[..index].iter().rposition(|l| {
+					l.get_action() == &Action::Label && l.get_content().split_whitespace().next() == Some(label)
+				});
+				label_index.map_or_else(|| Some(String::from(label)), |i| self.resolve_head(i))
+			},
+			Action::Break | Action::Exec | Action::Noop => None,
+		}
+	}
+
+	// find the commit that HEAD will be at before the line at `index` is run, walking back through the
+	// preceding lines, and falling back to the commit that the rebase is onto
+	fn resolve_head(&self, index: usize) -> Option<String> {
+		for (i, line) in self.lines[..index].iter().enumerate().rev() {
+			match *line.get_action() {
+				Action::Edit | Action::Fixup | Action::Pick | Action::Revert | Action::Reword | Action::Squash => {
+					return Some(String::from(line.get_hash()));
+				},
+				Action::Merge | Action::Reset => return self.get_line_revision(i),
+				Action::Break | Action::Drop | Action::Exec | Action::Label | Action::Noop | Action::UpdateRef => {},
+			}
+		}
+		read_to_string(self.filepath.with_file_name("onto"))
+			.ok()
+			.map(|onto| String::from(onto.trim()))
+			.filter(|onto| !onto.is_empty())
+	}
+
+	/// Get an owned copy of the lines.
+	#[must_use]
+	pub fn get_lines_owned(&self) -> Vec<Line> {
+		self.lines.clone()
+	}
+
+	/// Is the rebase file a noop.
+	#[must_use]
+	pub const fn is_noop(&self) -> bool {
+		self.is_noop
+	}
+
+	/// Get an iterator over the lines.
+	pub fn lines_iter(&self) -> Iter<'_, Line> {
+		self.lines.iter()
+	}
+
+	/// Get an iterator over the lines as they were first loaded, before any edits.
+	pub fn original_lines_iter(&self) -> Iter<'_, Line> {
+		self.original_lines.iter()
+	}
+
+	/// Get the progress of the in progress rebase, if the todo file belongs to one that has applied lines.
+	#[must_use]
+	pub const fn rebase_progress(&self) -> Option<&RebaseProgress> {
+		self.rebase_progress.as_ref()
+	}
+
+	/// Get the position of each line in the lines as they were first loaded, or `None` for a line that was inserted.
+	///
+	/// Lines are matched using their state before any modifications, so when a line is duplicated, only the first
+	/// copy has a position.
+	#[must_use]
+	pub fn original_positions(&self) -> Vec<Option<usize>> {
+		let mut matched = vec![false; self.original_lines.len()];
+		self.lines
+			.iter()
+			.map(|line| {
+				let original = line.original()?;
+				let position = self
+					.original_lines
+					.iter()
+					.enumerate()
+					.position(|(index, original_line)| !matched[index] && original_line.original() == Some(original))?;
+				matched[position] = true;
+				Some(position)
+			})
+			.collect()
+	}
+
+	/// Is the file the todo file of a cherry-pick or revert sequence, rather than an interactive rebase.
+	#[must_use]
+	pub const fn is_sequencer(&self) -> bool {
+		self.is_sequencer
+	}
+
+	/// Can a line have the action. The todo file of a cherry-pick or revert sequence only supports the command of
+	/// the sequence, `pick` or `revert` as found in the loaded lines, and the drop action, which removes the line. The
+	/// todo file of a rebase supports all actions except `revert`.
+	#[must_use]
+	pub const fn supports_action(&self, action: Action) -> bool {
+		if !self.is_sequencer {
+			return !matches!(action, Action::Revert);
+		}
+		matches!(
+			(action, self.sequencer_action),
+			(Action::Drop, _) | (Action::Pick, Some(Action::Pick)) | (Action::Revert, Some(Action::Revert))
+		)
+	}
+
+	/// Does the rebase file contain no lines.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.lines.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_none, assert_some, assert_some_eq};
+	use rstest::rstest;
+	use tempfile::{Builder, NamedTempFile};
+
+	use super::*;
+
+	fn create_line(line: &str) -> Line {
+		Line::parse(line).unwrap()
+	}
+
+	fn create_and_load_todo_file_with_options(
+		file_contents: &[&str],
+		todo_file_options: TodoFileOptions,
+	) -> (TodoFile, NamedTempFile) {
+		let todo_file_path = Builder::new()
+			.prefix("git-rebase-todo-scratch")
+			.suffix("")
+			.tempfile()
+			.unwrap();
+		write!(todo_file_path.as_file(), "{}", file_contents.join("\n")).unwrap();
+		let mut todo_file = TodoFile::new(todo_file_path.path().to_str().unwrap(), todo_file_options);
+		todo_file.load_file().unwrap();
+		(todo_file, todo_file_path)
+	}
+
+	fn create_and_load_todo_file(file_contents: &[&str]) -> (TodoFile, NamedTempFile) {
+		create_and_load_todo_file_with_options(file_contents, TodoFileOptions::new(1, "#"))
+	}
+
+	macro_rules! assert_read_todo_file {
+		($todo_file_path:expr, $($arg:expr),*) => {
+			let expected = [$( $arg, )*];
+			let content = read_to_string(Path::new($todo_file_path)).unwrap();
+			pretty_assertions::assert_str_eq!(content, format!("{}\n", expected.join("\n")));
+		};
+	}
+
+	macro_rules! assert_todo_lines {
+		($todo_file_path:expr, $($arg:expr),*) => {
+			let actual_lines = $todo_file_path.get_lines_owned();
+
+			let expected = vec![$( create_line($arg), )*];
+			pretty_assertions::assert_str_eq!(
+				actual_lines.iter().map(Line::to_text).collect::<Vec<String>>().join("\n"),
+				expected.iter().map(Line::to_text).collect::<Vec<String>>().join("\n")
+			);
+		};
+	}
+
+	#[test]
+	fn load_file() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa foobar"]);
+		assert_todo_lines!(todo_file, "pick aaa foobar");
+		assert_ne!(todo_file.version(), &Version::new());
+	}
+
+	#[test]
+	fn load_noop_file() {
+		let (todo_file, _) = create_and_load_todo_file(&["noop"]);
+		assert!(todo_file.is_empty());
+		assert!(todo_file.is_noop());
+	}
+
+	#[test]
+	fn load_ignore_comments() {
+		let (todo_file, _) = create_and_load_todo_file(&["# pick aaa comment", "pick aaa foo", "# pick aaa comment"]);
+		assert_todo_lines!(todo_file, "pick aaa foo");
+	}
+
+	#[test]
+	fn load_ignore_newlines() {
+		let (todo_file, _) = create_and_load_todo_file(&["", "pick aaa foobar", ""]);
+		assert_todo_lines!(todo_file, "pick aaa foobar");
+	}
+
+	#[test]
+	fn set_lines() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[]);
+		let old_version = todo_file.version;
+		todo_file.set_lines(vec![create_line("pick bbb comment")]);
+		assert_todo_lines!(todo_file, "pick bbb comment");
+		assert_ne!(todo_file.version(), &old_version);
+	}
+
+	#[test]
+	fn set_lines_reset_history() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[]);
+		todo_file.history.record(HistoryItem::new_add(1, 1));
+		todo_file.set_lines(vec![create_line("pick bbb comment")]);
+		assert_none!(todo_file.undo());
+	}
+
+	#[test]
+	fn set_lines_reset_selected_index() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick a a", "pick b b", "pick c c"]);
+		todo_file.selected_line_index = 2;
+		todo_file.set_lines(vec![create_line("pick a a"), create_line("pick b b")]);
+		assert_eq!(todo_file.selected_line_index, 1);
+	}
+
+	#[test]
+	fn set_lines_reset_selected_index_empty_lis() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick a a", "pick b b", "pick c c"]);
+		todo_file.selected_line_index = 2;
+		todo_file.set_lines(vec![]);
+		assert_eq!(todo_file.selected_line_index, 0);
+	}
+
+	#[test]
+	fn set_lines_retains_original_lines() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick a a", "pick b b"]);
+		todo_file.set_lines(vec![create_line("pick b b")]);
+		let original_lines: Vec<String> = todo_file.original_lines_iter().map(Line::to_text).collect();
+		assert_eq!(original_lines, vec!["pick a a", "pick b b"]);
+	}
+
+	#[test]
+	fn original_lines_not_changed_by_edits() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick a a", "pick b b"]);
+		todo_file.remove_lines(0, 0);
+		let original_lines: Vec<String> = todo_file.original_lines_iter().map(Line::to_text).collect();
+		assert_eq!(original_lines, vec!["pick a a", "pick b b"]);
+	}
+
+	#[test]
+	fn load_file_rebase_progress() {
+		let directory = tempfile::tempdir().unwrap();
+		std::fs::write(directory.path().join("git-rebase-todo"), "pick bbb c2\n").unwrap();
+		std::fs::write(directory.path().join("done"), "pick aaa c1\n").unwrap();
+		let mut todo_file = TodoFile::new(directory.path().join("git-rebase-todo"), TodoFileOptions::new(1, "#"));
+		todo_file.load_file().unwrap();
+		let progress = todo_file.rebase_progress().unwrap();
+		assert_eq!(progress.done_lines(), &[create_line("pick aaa c1")]);
+	}
+
+	#[test]
+	fn load_file_rebase_progress_other_file() {
+		let directory = tempfile::tempdir().unwrap();
+		std::fs::write(directory.path().join("todo"), "pick bbb c2\n").unwrap();
+		std::fs::write(directory.path().join("done"), "pick aaa c1\n").unwrap();
+		let mut todo_file = TodoFile::new(directory.path().join("todo"), TodoFileOptions::new(1, "#"));
+		todo_file.load_file().unwrap();
+		assert_none!(todo_file.rebase_progress());
+	}
+
+	#[test]
+	fn load_json_file() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("plan.json");
+		std::fs::write(
+			path.as_path(),
+			r#"{"lines": [{"action": "pick", "hash": "aaa", "content": "c1"}, {"action": "break"}]}"#,
+		)
+		.unwrap();
+		let mut todo_file = TodoFile::new(directory.path().join("git-rebase-todo"), TodoFileOptions::new(1, "#"));
+		todo_file.load_json_file(path.as_path()).unwrap();
+		assert_todo_lines!(todo_file, "pick aaa c1", "break");
+		assert_eq!(todo_file.original_positions(), vec![Some(0), Some(1)]);
+	}
+
+	#[rstest]
+	#[case::invalid_json("{")]
+	#[case::missing_lines("{}")]
+	#[case::invalid_action(r#"{"lines": [{"action": "invalid"}]}"#)]
+	#[case::invalid_line(r#"{"lines": [{"action": "pick"}]}"#)]
+	#[case::injected_line(r#"{"lines": [{"action": "pick", "hash": "aaa", "content": "c1\nexec touch file"}]}"#)]
+	#[case::invalid_option(r#"{"lines": [{"action": "pick", "hash": "aaa", "option": "x"}]}"#)]
+	fn load_json_file_invalid(#[case] contents: &str) {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("plan.json");
+		std::fs::write(path.as_path(), contents).unwrap();
+		let mut todo_file = TodoFile::new(directory.path().join("git-rebase-todo"), TodoFileOptions::new(1, "#"));
+		assert!(matches!(
+			todo_file.load_json_file(path.as_path()),
+			Err(IoError::FileRead { ref file, .. }) if file == &path
+		));
+	}
+
+	#[rstest]
+	#[case::revert_in_rebase("git-rebase-todo", r#"{"lines": [{"action": "revert", "hash": "aaa"}]}"#, "revert")]
+	#[case::squash_in_sequencer(
+		"todo",
+		r#"{"lines": [{"action": "pick", "hash": "aaa"}, {"action": "squash", "hash": "bbb"}]}"#,
+		"squash"
+	)]
+	#[case::exec_in_sequencer(
+		"todo",
+		r#"{"lines": [{"action": "pick", "hash": "aaa"}, {"action": "exec", "content": "make"}]}"#,
+		"exec"
+	)]
+	fn load_json_file_unsupported_action(#[case] todo_file_name: &str, #[case] contents: &str, #[case] action: &str) {
+		let directory = tempfile::tempdir().unwrap();
+		let sequencer_directory = directory.path().join("sequencer");
+		std::fs::create_dir(sequencer_directory.as_path()).unwrap();
+		let path = directory.path().join("plan.json");
+		std::fs::write(path.as_path(), contents).unwrap();
+		let mut todo_file = TodoFile::new(sequencer_directory.join(todo_file_name), TodoFileOptions::new(1, "#"));
+		assert_eq!(
+			todo_file.load_json_file(path.as_path()),
+			Err(IoError::FileRead {
+				file: path,
+				cause: FileReadErrorCause::from(ParseError::UnsupportedAction(String::from(action))),
+			})
+		);
+	}
+
+	#[test]
+	fn load_json_file_read_error() {
+		let mut todo_file = TodoFile::new("git-rebase-todo", TodoFileOptions::new(1, "#"));
+		assert!(todo_file.load_json_file("does-not-exist.json").is_err());
+	}
+
+	#[test]
+	fn original_positions() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		todo_file.update_range(0, 0, &EditContext::new().action(Action::Drop));
+		_ = todo_file.swap_range_down(0, 0);
+		todo_file.remove_lines(2, 2);
+		todo_file.add_line(0, create_line("exec make"));
+		todo_file.add_line(3, create_line("pick bbb c2"));
+		assert_eq!(todo_file.original_positions(), vec![None, Some(1), Some(0), None]);
+	}
+
+	#[test]
+	fn to_json() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1"]);
+		todo_file.add_line(1, create_line("exec make"));
+		let json = todo_file
+			.to_json(|line| {
+				line.has_reference().then(|| {
+					CommitMetadata {
+						hash: String::from("aaaaaaaa"),
+						author_name: Some(String::from("Author")),
+						author_email: None,
+						author_date: None,
+						summary: Some(String::from("Summary")),
+					}
+				})
+			})
+			.unwrap();
+		pretty_assertions::assert_str_eq!(
+			json,
+			r#"{
+  "lines": [
+    {
+      "action": "pick",
+      "hash": "aaa",
+      "content": "c1",
+      "option": null,
+      "originalPosition": 0,
+      "commit": {
+        "hash": "aaaaaaaa",
+        "authorName": "Author",
+        "authorEmail": null,
+        "authorDate": null,
+        "summary": "Summary"
+      }
+    },
+    {
+      "action": "exec",
+      "hash": "",
+      "content": "make",
+      "option": null,
+      "originalPosition": null
+    }
+  ]
+}"#
+		);
+	}
+
+	#[test]
+	fn write_json_file() {
+		let directory = tempfile::tempdir().unwrap();
+		let path = directory.path().join("export.json");
+		let (todo_file, _) = create_and_load_todo_file(&["fixup -C aaa c1"]);
+		todo_file.write_json_file(path.as_path(), |_| None).unwrap();
+
+		let mut imported = TodoFile::new("git-rebase-todo", TodoFileOptions::new(1, "#"));
+		imported.load_json_file(path.as_path()).unwrap();
+		assert_todo_lines!(imported, "fixup -C aaa c1");
+	}
+
+	#[test]
+	fn write_json_file_error() {
+		let directory = tempfile::tempdir().unwrap();
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa c1"]);
+		assert!(matches!(
+			todo_file.write_json_file(directory.path(), |_| None),
+			Err(IoError::FileWrite { ref file, .. }) if file == directory.path()
+		));
+	}
+
+	#[test]
+	fn write_file() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[]);
+		todo_file.set_lines(vec![create_line("pick bbb comment")]);
+		todo_file.write_file().unwrap();
+		assert_todo_lines!(todo_file, "pick bbb comment");
+	}
+
+	#[test]
+	fn write_file_sequencer() {
+		let directory = tempfile::tempdir().unwrap();
+		let sequencer_directory = directory.path().join("sequencer");
+		std::fs::create_dir_all(sequencer_directory.as_path()).unwrap();
+		let mut options = TodoFileOptions::new(10, "#");
+		options.line_changed_command("command");
+		let mut todo_file = TodoFile::new(sequencer_directory.join("todo"), options);
+		todo_file.set_lines(vec![
+			create_line("revert aaa comment"),
+			create_line("revert bbb comment"),
+		]);
+		todo_file.update_range(1, 1, &EditContext::new().action(Action::Drop));
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(&sequencer_directory.join("todo"), "revert aaa comment");
+	}
+
+	#[rstest]
+	#[case::sequencer(".git/sequencer/todo", true)]
+	#[case::rebase(".git/rebase-merge/git-rebase-todo", false)]
+	#[case::todo_in_other_directory(".git/rebase-merge/todo", false)]
+	#[case::other_file_in_sequencer(".git/sequencer/head", false)]
+	fn is_sequencer(#[case] path: &str, #[case] expected: bool) {
+		assert_eq!(
+			TodoFile::new(path, TodoFileOptions::new(1, "#")).is_sequencer(),
+			expected
+		);
+	}
+
+	#[rstest]
+	#[case::pick(Action::Pick, true)]
+	#[case::revert(Action::Revert, false)]
+	#[case::drop(Action::Drop, true)]
+	#[case::squash(Action::Squash, false)]
+	#[case::break_action(Action::Break, false)]
+	fn supports_action_sequencer(#[case] action: Action, #[case] expected: bool) {
+		let mut todo_file = TodoFile::new(".git/sequencer/todo", TodoFileOptions::new(1, "#"));
+		todo_file.set_lines(vec![Line::parse("pick aaa comment").unwrap()]);
+		assert_eq!(todo_file.supports_action(action), expected);
+	}
+
+	#[rstest]
+	#[case::pick(Action::Pick, false)]
+	#[case::revert(Action::Revert, true)]
+	#[case::drop(Action::Drop, true)]
+	#[case::squash(Action::Squash, false)]
+	#[case::break_action(Action::Break, false)]
+	fn supports_action_revert_sequencer(#[case] action: Action, #[case] expected: bool) {
+		let mut todo_file = TodoFile::new(".git/sequencer/todo", TodoFileOptions::new(1, "#"));
+		todo_file.set_lines(vec![Line::parse("revert aaa comment").unwrap()]);
+		assert_eq!(todo_file.supports_action(action), expected);
+	}
+
+	#[test]
+	fn supports_action_sequencer_keeps_loaded_command() {
+		let mut todo_file = TodoFile::new(".git/sequencer/todo", TodoFileOptions::new(1, "#"));
+		todo_file.set_lines(vec![Line::parse("revert aaa comment").unwrap()]);
+		todo_file.set_lines(vec![Line::parse("drop aaa comment").unwrap()]);
+		assert!(todo_file.supports_action(Action::Revert));
+		assert!(!todo_file.supports_action(Action::Pick));
+	}
+
+	#[test]
+	fn supports_action_empty_sequencer() {
+		let todo_file = TodoFile::new(".git/sequencer/todo", TodoFileOptions::new(1, "#"));
+		assert!(!todo_file.supports_action(Action::Pick));
+		assert!(!todo_file.supports_action(Action::Revert));
+		assert!(todo_file.supports_action(Action::Drop));
+	}
+
+	#[rstest]
+	#[case::squash(Action::Squash, true)]
+	#[case::break_action(Action::Break, true)]
+	#[case::revert(Action::Revert, false)]
+	fn supports_action_rebase(#[case] action: Action, #[case] expected: bool) {
+		let todo_file = TodoFile::new(".git/rebase-merge/git-rebase-todo", TodoFileOptions::new(1, "#"));
+		assert_eq!(todo_file.supports_action(action), expected);
+	}
+
+	#[test]
+	fn write_file_with_exec_command_modified_line_with_reference() {
+		fn create_modified_line(action: &str) -> Line {
+			let mut parsed = create_line(format!("{action} label").as_str());
+			parsed.edit_content("new-label");
+			parsed
+		}
+		let mut options = TodoFileOptions::new(10, "#");
+		options.line_changed_command("command");
+		let (mut todo_file, _) = create_and_load_todo_file_with_options(&[], options);
+		todo_file.set_lines(vec![
+			create_modified_line("label"),
+			create_modified_line("reset"),
+			create_modified_line("merge"),
+			create_modified_line("update-ref"),
+		]);
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(
+			todo_file.get_filepath(),
+			"label new-label",
+			"exec command \"label\" \"label\" \"new-label\"",
+			"reset new-label",
+			"exec command \"reset\" \"label\" \"new-label\"",
+			"merge new-label",
+			"exec command \"merge\" \"label\" \"new-label\"",
+			"update-ref new-label",
+			"exec command \"update-ref\" \"label\" \"new-label\""
+		);
+	}
+
+	#[test]
+	fn write_file_with_exec_command_modified_line_with_hash() {
+		fn create_modified_line(action: &str) -> Line {
+			let mut parsed = create_line(format!("{action} bbb comment").as_str());
+			parsed.set_action(
+				if parsed.get_action() == &Action::Fixup {
+					Action::Pick
+				}
+				else {
+					Action::Fixup
+				},
+			);
+			parsed
+		}
+		let mut options = TodoFileOptions::new(10, "#");
+		options.line_changed_command("command");
+		let (mut todo_file, _) = create_and_load_todo_file_with_options(&[], options);
+		let mut line = create_line("pick bbb comment");
+		line.set_action(Action::Fixup);
+		todo_file.set_lines(vec![
+			create_modified_line("drop"),
+			create_modified_line("fixup"),
+			create_modified_line("edit"),
+			create_modified_line("pick"),
+			create_modified_line("reword"),
+			create_modified_line("squash"),
+		]);
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(
+			todo_file.get_filepath(),
+			"fixup bbb comment",
+			"exec command \"fixup\" \"bbb\"",
+			"pick bbb comment",
+			"exec command \"pick\" \"bbb\"",
+			"fixup bbb comment",
+			"exec command \"fixup\" \"bbb\"",
+			"fixup bbb comment",
+			"exec command \"fixup\" \"bbb\"",
+			"fixup bbb comment",
+			"exec command \"fixup\" \"bbb\"",
+			"fixup bbb comment",
+			"exec command \"fixup\" \"bbb\""
+		);
+	}
+
+	#[test]
+	fn write_file_with_exec_command_modified_line_with_exec() {
+		let mut options = TodoFileOptions::new(10, "#");
+		options.line_changed_command("command");
+		let (mut todo_file, _) = create_and_load_todo_file_with_options(&[], options);
+		let mut line = create_line("exec command");
+		line.edit_content("new-command");
+		todo_file.set_lines(vec![line]);
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(
+			todo_file.get_filepath(),
+			"exec new-command",
+			"exec command \"exec\" \"command\" \"new-command\""
+		);
+	}
+
+	#[test]
+	fn write_file_with_exec_command_modified_line_with_break() {
+		let mut options = TodoFileOptions::new(10, "#");
+		options.line_changed_command("command");
+		let (mut todo_file, _) = create_and_load_todo_file_with_options(&[], options);
+		todo_file.set_lines(vec![create_line("break")]);
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(todo_file.get_filepath(), "break");
+	}
+
+	#[test]
+	fn set_options_line_changed_command() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[]);
+		let mut options = TodoFileOptions::new(1, "#");
+		options.line_changed_command("command");
+		todo_file.set_options(options);
+		let mut line = create_line("exec command");
+		line.edit_content("new-command");
+		todo_file.set_lines(vec![line]);
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(
+			todo_file.get_filepath(),
+			"exec new-command",
+			"exec command \"exec\" \"command\" \"new-command\""
+		);
+	}
+
+	#[test]
+	fn set_options_undo_limit() {
+		let mut options = TodoFileOptions::new(10, "#");
+		options.line_changed_command("command");
+		let (mut todo_file, _) = create_and_load_todo_file_with_options(&["pick aaa comment"], options);
+		todo_file.update_range(0, 0, &EditContext::new().action(Action::Drop));
+		todo_file.update_range(0, 0, &EditContext::new().action(Action::Edit));
+		todo_file.set_options(TodoFileOptions::new(1, "#"));
+		assert_eq!(todo_file.options, TodoFileOptions::new(1, "#"));
+		assert_some_eq!(todo_file.undo(), (0, 0));
+		assert_none!(todo_file.undo());
+		assert_todo_lines!(todo_file, "drop aaa comment");
+	}
+
+	#[test]
+	fn write_file_noop() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[]);
+		todo_file.set_lines(vec![create_line("noop")]);
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(todo_file.get_filepath(), "noop");
+	}
+
+	#[test]
+	fn add_line_index_miss() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		todo_file.add_line(100, create_line("fixup ddd comment"));
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa comment",
+			"drop bbb comment",
+			"edit ccc comment",
+			"fixup ddd comment"
+		);
+	}
+
+	#[test]
+	fn add_line() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		let old_version = *todo_file.version();
+		todo_file.add_line(1, create_line("fixup ddd comment"));
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa comment",
+			"fixup ddd comment",
+			"drop bbb comment",
+			"edit ccc comment"
+		);
+		assert_ne!(todo_file.version(), &old_version);
+	}
+
+	#[test]
+	fn add_line_record_history() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment"]);
+		todo_file.add_line(1, create_line("fixup ddd comment"));
+		let _undo_result = todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa comment");
+	}
+
+	#[test]
+	fn remove_lines_index_miss_start() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		todo_file.remove_lines(100, 1);
+		assert_todo_lines!(todo_file, "pick aaa comment");
+	}
+
+	#[test]
+	fn remove_lines_index_miss_end() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		todo_file.remove_lines(1, 100);
+		assert_todo_lines!(todo_file, "pick aaa comment");
+	}
+
+	#[test]
+	fn remove_lines_index_miss_start_and_end() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		todo_file.remove_lines(100, 100);
+		assert_todo_lines!(todo_file, "pick aaa comment", "drop bbb comment");
+	}
+
+	#[test]
+	fn remove_lines() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		let old_version = *todo_file.version();
+		todo_file.remove_lines(1, 1);
+		assert_todo_lines!(todo_file, "pick aaa comment", "edit ccc comment");
+		assert_ne!(todo_file.version(), &old_version);
+	}
+
+	#[test]
+	fn remove_lines_empty_list() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[]);
+		todo_file.remove_lines(1, 1);
+	}
+
+	#[test]
+	fn remove_lines_record_history() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment", "edit ccc comment"]);
+		todo_file.remove_lines(1, 1);
+		let _undo_result = todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa comment", "edit ccc comment");
+	}
+
+	#[test]
+	fn update_range_full_set_action() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		let old_version = *todo_file.version();
+		todo_file.update_range(0, 2, &EditContext::new().action(Action::Reword));
+		assert_todo_lines!(
+			todo_file,
+			"reword aaa comment",
+			"reword bbb comment",
+			"reword ccc comment"
+		);
+		assert_ne!(todo_file.version(), &old_version);
+	}
+
+	#[test]
+	fn update_range_full_set_content() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["exec foo", "exec bar", "exec foobar"]);
+		todo_file.update_range(0, 2, &EditContext::new().content("echo"));
+		assert_todo_lines!(todo_file, "exec echo", "exec echo", "exec echo");
+	}
+
+	#[test]
+	fn update_range_set_option() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["fixup aaa comment"]);
+		let old_version = *todo_file.version();
+		todo_file.update_range(0, 2, &EditContext::new().option("-c"));
+		assert_todo_lines!(todo_file, "fixup -c aaa comment");
+		assert_ne!(todo_file.version(), &old_version);
+	}
+
+	#[test]
+	fn update_range_reverse_indexes() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		todo_file.update_range(2, 0, &EditContext::new().action(Action::Reword));
+		assert_todo_lines!(
+			todo_file,
+			"reword aaa comment",
+			"reword bbb comment",
+			"reword ccc comment"
+		);
+	}
+
+	#[test]
+	fn update_range_record_history() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment"]);
+		todo_file.update_range(0, 0, &EditContext::new().action(Action::Reword));
+		let _undo_result = todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa comment");
+	}
+
+	#[test]
+	fn update_range_empty_list() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[]);
+		todo_file.update_range(0, 0, &EditContext::new().action(Action::Reword));
+	}
+
+	#[test]
+	fn update_range_start_index_overflow() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment", "pick bbb comment"]);
+		todo_file.update_range(2, 0, &EditContext::new().action(Action::Reword));
+		assert_todo_lines!(todo_file, "reword aaa comment", "reword bbb comment");
+	}
+
+	#[test]
+	fn update_range_end_index_overflow() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa comment", "pick bbb comment"]);
+		todo_file.update_range(0, 2, &EditContext::new().action(Action::Reword));
+		assert_todo_lines!(todo_file, "reword aaa comment", "reword bbb comment");
+	}
+
+	#[test]
+	fn undo_load_operation() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		assert_none!(todo_file.undo());
+	}
+
+	#[test]
+	fn undo_empty_history() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		// set short history, to remove load entry
+		todo_file.history = History::new(1);
+		todo_file.update_range(0, 0, &EditContext::new().action(Action::Drop));
+		_ = todo_file.undo(); // remove Drop operation
+		assert_none!(todo_file.undo());
+	}
+
+	#[test]
+	fn undo_operation() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		todo_file.update_range(0, 1, &EditContext::new().action(Action::Drop));
+		assert_some_eq!(todo_file.undo(), (0, 1));
+	}
+
+	#[test]
+	fn history_undo_redo() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		todo_file.update_range(0, 0, &EditContext::new().action(Action::Drop));
+		let old_version = *todo_file.version();
+		let _undo_result = todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa comment", "drop bbb comment", "edit ccc comment");
+		assert_ne!(todo_file.version(), &old_version);
+		let old_version = *todo_file.version();
+		_ = todo_file.redo();
+		assert_todo_lines!(todo_file, "drop aaa comment", "drop bbb comment", "edit ccc comment");
+		assert_ne!(todo_file.version(), &old_version);
+	}
+
+	#[test]
+	fn redo_empty_history() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		assert_none!(todo_file.redo());
+	}
+
+	#[test]
+	fn redo_operation() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "drop bbb comment", "edit ccc comment"]);
+		todo_file.update_range(0, 1, &EditContext::new().action(Action::Drop));
+		_ = todo_file.undo();
+		assert_some_eq!(todo_file.redo(), (0, 1));
+	}
+
+	#[test]
+	fn history_group_recorded_as_single_entry() {
+		let (mut todo_file, _) = create_and_load_todo_file_with_options(
+			&[
+				"pick aaa comment",
+				"pick bbb comment",
+				"pick ccc comment",
+				"pick ddd comment",
+			],
+			TodoFileOptions::new(10, "#"),
+		);
+		todo_file.update_range(3, 3, &EditContext::new().action(Action::Drop));
+		todo_file.start_history_group();
+		_ = todo_file.swap_range_down(0, 0);
+		_ = todo_file.swap_range_down(1, 1);
+		todo_file.end_history_group();
+		assert_todo_lines!(
+			todo_file,
+			"pick bbb comment",
+			"pick ccc comment",
+			"pick aaa comment",
+			"drop ddd comment"
+		);
+		assert_some_eq!(todo_file.undo(), (0, 2));
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa comment",
+			"pick bbb comment",
+			"pick ccc comment",
+			"drop ddd comment"
+		);
+		assert_some_eq!(todo_file.redo(), (0, 2));
+		assert_todo_lines!(
+			todo_file,
+			"pick bbb comment",
+			"pick ccc comment",
+			"pick aaa comment",
+			"drop ddd comment"
+		);
+		_ = todo_file.undo();
+		_ = todo_file.undo();
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa comment",
+			"pick bbb comment",
+			"pick ccc comment",
+			"pick ddd comment"
+		);
+	}
+
+	#[test]
+	fn history_group_without_change() {
+		let (mut todo_file, _) = create_and_load_todo_file_with_options(
+			&["pick aaa comment", "pick bbb comment", "pick ccc comment"],
+			TodoFileOptions::new(10, "#"),
+		);
+		todo_file.start_history_group();
+		_ = todo_file.swap_range_down(0, 0);
+		_ = todo_file.swap_range_up(1, 1);
+		todo_file.end_history_group();
+		assert_none!(todo_file.undo());
+	}
+
+	#[test]
+	fn history_group_with_changed_line_count() {
+		let (mut todo_file, _) = create_and_load_todo_file_with_options(
+			&["pick aaa comment", "pick bbb comment"],
+			TodoFileOptions::new(10, "#"),
+		);
+		todo_file.start_history_group();
+		_ = todo_file.swap_range_down(0, 0);
+		todo_file.remove_lines(0, 0);
+		todo_file.end_history_group();
+		assert_some!(todo_file.undo());
+		assert_todo_lines!(todo_file, "pick bbb comment", "pick aaa comment");
+		assert_some!(todo_file.undo());
+		assert_todo_lines!(todo_file, "pick aaa comment", "pick bbb comment");
+	}
+
+	#[test]
+	fn swap_up() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		let old_version = *todo_file.version();
+		assert!(todo_file.swap_range_up(1, 2));
+		assert_todo_lines!(todo_file, "pick bbb comment", "pick ccc comment", "pick aaa comment");
+		assert_ne!(todo_file.version(), &old_version);
+	}
+
+	#[test]
+	fn swap_up_records_history() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		_ = todo_file.swap_range_up(1, 2);
+		let _undo_result = todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa comment", "pick bbb comment", "pick ccc comment");
+	}
+
+	#[test]
+	fn swap_up_reverse_index() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		assert!(todo_file.swap_range_up(2, 1));
+		assert_todo_lines!(todo_file, "pick bbb comment", "pick ccc comment", "pick aaa comment");
+	}
+
+	#[test]
+	fn swap_up_single_line() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		assert!(todo_file.swap_range_up(1, 1));
+		assert_todo_lines!(todo_file, "pick bbb comment", "pick aaa comment", "pick ccc comment");
+	}
+
+	#[test]
+	fn swap_up_at_top_start_index() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		assert!(!todo_file.swap_range_up(0, 1));
+		assert_todo_lines!(todo_file, "pick aaa comment", "pick bbb comment", "pick ccc comment");
+	}
+
+	#[test]
+	fn swap_up_at_top_end_index() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		assert!(!todo_file.swap_range_up(1, 0));
+		assert_todo_lines!(todo_file, "pick aaa comment", "pick bbb comment", "pick ccc comment");
+	}
+
+	#[test]
+	fn swap_up_start_index_overflow() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		assert!(todo_file.swap_range_up(3, 1));
+		assert_todo_lines!(todo_file, "pick bbb comment", "pick ccc comment", "pick aaa comment");
+	}
+
+	#[test]
+	fn swap_up_end_index_overflow() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		assert!(todo_file.swap_range_up(3, 1));
+		assert_todo_lines!(todo_file, "pick bbb comment", "pick ccc comment", "pick aaa comment");
+	}
+
+	#[test]
+	fn swap_up_empty_list_index_out_of_bounds() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[]);
+		assert!(!todo_file.swap_range_up(1, 1));
+	}
+
+	#[test]
+	fn swap_down() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		let old_version = *todo_file.version();
+		assert!(todo_file.swap_range_down(0, 1));
+		assert_todo_lines!(todo_file, "pick ccc comment", "pick aaa comment", "pick bbb comment");
+		assert_ne!(todo_file.version(), &old_version);
+	}
+
+	#[test]
+	fn swap_down_records_history() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		let _swap_result = todo_file.swap_range_down(0, 1);
+		let _undo_result = todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa comment", "pick bbb comment", "pick ccc comment");
+	}
+
+	#[test]
+	fn swap_down_reverse_index() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		assert!(todo_file.swap_range_down(1, 0));
+		assert_todo_lines!(todo_file, "pick ccc comment", "pick aaa comment", "pick bbb comment");
+	}
+
+	#[test]
+	fn swap_down_single_line() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		assert!(todo_file.swap_range_down(0, 0));
+		assert_todo_lines!(todo_file, "pick bbb comment", "pick aaa comment", "pick ccc comment");
+	}
+
+	#[test]
+	fn swap_down_at_bottom_end_index() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		assert!(!todo_file.swap_range_down(1, 2));
+		assert_todo_lines!(todo_file, "pick aaa comment", "pick bbb comment", "pick ccc comment");
+	}
+
+	#[test]
+	fn swap_down_at_bottom_start_index() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa comment", "pick bbb comment", "pick ccc comment"]);
+		assert!(!todo_file.swap_range_down(2, 1));
+		assert_todo_lines!(todo_file, "pick aaa comment", "pick bbb comment", "pick ccc comment");
+	}
+
+	#[test]
+	fn selected_line_index() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["exec foo", "exec bar", "exec foobar"]);
+		let selected_line_index = todo_file.set_selected_line_index(1);
+		assert_eq!(selected_line_index, 1);
+		assert_eq!(todo_file.get_selected_line_index(), 1);
+	}
+
+	#[test]
+	fn selected_line_index_overflow() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["exec foo", "exec bar", "exec foobar"]);
+		let selected_line_index = todo_file.set_selected_line_index(3);
+		assert_eq!(selected_line_index, 2);
+		assert_eq!(todo_file.get_selected_line_index(), 2);
+	}
+
+	#[test]
+	fn selected_line() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["exec foo", "exec bar", "exec foobar"]);
+		_ = todo_file.set_selected_line_index(0);
+		assert_some_eq!(todo_file.get_selected_line(), &create_line("exec foo"));
+	}
+
+	#[test]
+	fn selected_line_empty_list() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[]);
+		_ = todo_file.set_selected_line_index(0);
+		assert_none!(todo_file.get_selected_line());
+	}
+
+	#[test]
+	fn get_max_selected_line() {
+		let (todo_file, _) = create_and_load_todo_file(&["exec foo", "exec bar", "exec foobar"]);
+		assert_eq!(todo_file.get_max_selected_line_index(), 2);
+	}
+
+	#[test]
+	fn get_max_selected_line_empty_list() {
+		let (todo_file, _) = create_and_load_todo_file(&[]);
+		assert_eq!(todo_file.get_max_selected_line_index(), 0);
+	}
+
+	#[test]
+	fn get_line_miss_high() {
+		let (todo_file, _) = create_and_load_todo_file(&["exec foo", "exec bar", "exec foobar"]);
+		assert_none!(todo_file.get_line(4));
+	}
+
+	#[test]
+	fn get_line_hit() {
+		let (todo_file, _) = create_and_load_todo_file(&["exec foo", "exec bar", "exec foobar"]);
+		assert_some_eq!(todo_file.get_line(1), &create_line("exec bar"));
+	}
+
+	#[test]
+	fn get_line_revision_index_miss() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa comment"]);
+		assert_none!(todo_file.get_line_revision(1));
+	}
+
+	#[rstest]
+	#[case::drop("drop aaa comment", Some("aaa"))]
+	#[case::edit("edit aaa comment", Some("aaa"))]
+	#[case::fixup("fixup aaa comment", Some("aaa"))]
+	#[case::pick("pick aaa comment", Some("aaa"))]
+	#[case::reword("reword aaa comment", Some("aaa"))]
+	#[case::squash("squash aaa comment", Some("aaa"))]
+	#[case::merge_keep_message("merge -C aaa label # comment", Some("aaa"))]
+	#[case::merge_edit_message("merge -c aaa label # comment", Some("aaa"))]
+	#[case::merge_new_commit("merge label # comment", None)]
+	#[case::update_ref("update-ref refs/heads/branch", Some("refs/heads/branch"))]
+	#[case::reset_unknown_label("reset aaa # comment", Some("aaa"))]
+	#[case::reset_new_root("reset [new root]", None)]
+	#[case::exec("exec command", None)]
+	#[case::break_action("break", None)]
+	fn get_line_revision(#[case] line: &str, #[case] expected: Option<&str>) {
+		let (todo_file, _) = create_and_load_todo_file(&[line]);
+		assert_eq!(todo_file.get_line_revision(0).as_deref(), expected);
+	}
+
+	#[test]
+	fn get_line_revision_label() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "exec make", "label l1"]);
+		assert_some_eq!(todo_file.get_line_revision(3), String::from("bbb"));
+	}
+
+	#[test]
+	fn get_line_revision_label_after_merge() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "merge -C bbb l1", "label l2"]);
+		assert_some_eq!(todo_file.get_line_revision(2), String::from("bbb"));
+	}
+
+	#[test]
+	fn get_line_revision_label_after_reset() {
+		let (todo_file, _) =
+			create_and_load_todo_file(&["pick aaa c1", "label l1", "pick bbb c2", "reset l1", "label l2"]);
+		assert_some_eq!(todo_file.get_line_revision(4), String::from("aaa"));
+	}
+
+	#[test]
+	fn get_line_revision_reset_to_label() {
+		let (todo_file, _) = create_and_load_todo_file(&[
+			"pick aaa c1",
+			"label l1",
+			"pick bbb c2",
+			"label l2",
+			"pick ccc c3",
+			"reset l1",
+		]);
+		assert_some_eq!(todo_file.get_line_revision(5), String::from("aaa"));
+	}
+
+	#[test]
+	fn get_line_revision_reset_uses_last_preceding_label() {
+		let (todo_file, _) = create_and_load_todo_file(&[
+			"pick aaa c1",
+			"label l1",
+			"pick bbb c2",
+			"label l1",
+			"reset l1",
+			"label l1",
+		]);
+		assert_some_eq!(todo_file.get_line_revision(4), String::from("bbb"));
+	}
+
+	#[test]
+	fn get_line_revision_label_without_onto() {
+		let (todo_file, _) = create_and_load_todo_file(&["label onto", "pick aaa c1"]);
+		assert_none!(todo_file.get_line_revision(0));
+	}
+
+	#[test]
+	fn get_line_revision_label_with_onto() {
+		let directory = Builder::new().prefix("rebase-merge").tempdir().unwrap();
+		let todo_file_path = directory.path().join("git-rebase-todo");
+		std::fs::write(&todo_file_path, "label onto\nreset onto\npick aaa c1\n").unwrap();
+		std::fs::write(directory.path().join("onto"), "0123456789abcdef\n").unwrap();
+		let mut todo_file = TodoFile::new(todo_file_path, TodoFileOptions::new(1, "#"));
+		todo_file.load_file().unwrap();
+		assert_some_eq!(todo_file.get_line_revision(0), String::from("0123456789abcdef"));
+		assert_some_eq!(todo_file.get_line_revision(1), String::from("0123456789abcdef"));
+	}
+
+	#[test]
+	fn get_file_path() {
+		let (todo_file, filepath) = create_and_load_todo_file(&["exec foo", "exec bar", "exec foobar"]);
+		assert_eq!(todo_file.get_filepath(), filepath.path());
+	}
+
+	#[test]
+	fn iter() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa comment"]);
+		assert_some_eq!(todo_file.lines_iter().next(), &create_line("pick aaa comment"));
+	}
+
+	#[test]
+	fn is_empty_true() {
+		let (todo_file, _) = create_and_load_todo_file(&[]);
+		assert!(todo_file.is_empty());
+	}
+
+	#[test]
+	fn is_empty_false() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa comment"]);
+		assert!(!todo_file.is_empty());
+	}
+}
@@ -1,9 +1,9 @@
-use crate::todo_file::{Action, LineParser, ParseError};
+use crate::{Action, LineParser, ParseError};
 
 /// Represents a line in the rebase file.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[expect(clippy::struct_field_names, reason = "Clarity")]
-pub(crate) struct Line {
+pub struct Line {
 	action: Action,
 	content: String,
 	hash: String,
@@ -43,43 +43,49 @@ impl Line {
 
 	/// Create a new pick line.
 	#[must_use]
-	pub(crate) fn new_pick(hash: &str) -> Self {
+	pub fn new_pick(hash: &str) -> Self {
 		Self::new(Action::Pick, hash, "", None)
 	}
 
+	/// Create a new revert line.
+	#[must_use]
+	pub fn new_revert(hash: &str) -> Self {
+		Self::new(Action::Revert, hash, "", None)
+	}
+
 	/// Create a new break line.
 	#[must_use]
-	pub(crate) fn new_break() -> Self {
+	pub fn new_break() -> Self {
 		Self::new(Action::Break, "", "", None)
 	}
 
 	/// Create a new exec line.
 	#[must_use]
-	pub(crate) fn new_exec(command: &str) -> Self {
+	pub fn new_exec(command: &str) -> Self {
 		Self::new(Action::Exec, "", command, None)
 	}
 
 	/// Create a new merge line.
 	#[must_use]
-	pub(crate) fn new_merge(label: &str) -> Self {
+	pub fn new_merge(label: &str) -> Self {
 		Self::new(Action::Merge, "", label, None)
 	}
 
 	/// Create a new label line.
 	#[must_use]
-	pub(crate) fn new_label(label: &str) -> Self {
+	pub fn new_label(label: &str) -> Self {
 		Self::new(Action::Label, "", label, None)
 	}
 
 	/// Create a new reset line.
 	#[must_use]
-	pub(crate) fn new_reset(label: &str) -> Self {
+	pub fn new_reset(label: &str) -> Self {
 		Self::new(Action::Reset, "", label, None)
 	}
 
 	/// Create a new update-ref line.
 	#[must_use]
-	pub(crate) fn new_update_ref(ref_name: &str) -> Self {
+	pub fn new_update_ref(ref_name: &str) -> Self {
 		Self::new(Action::UpdateRef, "", ref_name, None)
 	}
 
@@ -88,14 +94,14 @@ impl Line {
 	/// # Errors
 	///
 	/// Returns an error if an invalid line is provided.
-	pub(crate) fn parse(input_line: &str) -> Result<Self, ParseError> {
+	pub fn parse(input_line: &str) -> Result<Self, ParseError> {
 		let mut line_parser = LineParser::new(input_line);
 
 		let action = Action::try_from(line_parser.next()?)?;
 		Ok(match action {
 			Action::Noop => Self::new_noop(),
 			Action::Break => Self::new_break(),
-			Action::Pick | Action::Reword | Action::Edit | Action::Squash | Action::Drop => {
+			Action::Pick | Action::Revert | Action::Reword | Action::Edit | Action::Squash | Action::Drop => {
 				Self::new(action, line_parser.next()?, line_parser.take_remaining(), None)
 			},
 			Action::Fixup => {
@@ -122,7 +128,7 @@ impl Line {
 	}
 
 	/// Set the action of the line.
-	pub(crate) fn set_action(&mut self, action: Action) {
+	pub fn set_action(&mut self, action: Action) {
 		if !self.action.is_static() && self.action != action {
 			self.mutated = true;
 			self.action = action;
@@ -131,7 +137,7 @@ impl Line {
 	}
 
 	/// Edit the content of the line, if it is editable.
-	pub(crate) fn edit_content(&mut self, content: &str) {
+	pub fn edit_content(&mut self, content: &str) {
 		if self.is_editable() {
 			self.content = String::from(content);
 			self.mutated = true;
@@ -139,7 +145,7 @@ impl Line {
 	}
 
 	/// Set the option on the line, toggling if the existing option matches.
-	pub(crate) fn toggle_option(&mut self, option: &str) {
+	pub fn toggle_option(&mut self, option: &str) {
 		// try toggle off first
 		if let Some(current) = self.option.as_deref() {
 			if current == option {
@@ -150,45 +156,45 @@ impl Line {
 		self.option = Some(String::from(option));
 	}
 
-	/// Get the original line, before any modifications
+	/// Get the original line, before any modifications.
 	#[must_use]
-	pub(crate) fn original(&self) -> Option<&Line> {
+	pub fn original(&self) -> Option<&Line> {
 		self.original_line.as_deref()
 	}
 
 	/// Get the action of the line.
 	#[must_use]
-	pub(crate) const fn get_action(&self) -> &Action {
+	pub const fn get_action(&self) -> &Action {
 		&self.action
 	}
 
 	/// Get the content of the line.
 	#[must_use]
-	pub(crate) fn get_content(&self) -> &str {
+	pub fn get_content(&self) -> &str {
 		self.content.as_str()
 	}
 
 	/// Get the commit hash for the line.
 	#[must_use]
-	pub(crate) fn get_hash(&self) -> &str {
+	pub fn get_hash(&self) -> &str {
 		self.hash.as_str()
 	}
 
 	/// Get the commit hash for the line.
 	#[must_use]
-	pub(crate) fn option(&self) -> Option<&str> {
+	pub fn option(&self) -> Option<&str> {
 		self.option.as_deref()
 	}
 
 	/// Does this line contain a commit reference.
 	#[must_use]
-	pub(crate) fn has_reference(&self) -> bool {
+	pub fn has_reference(&self) -> bool {
 		!self.hash.is_empty()
 	}
 
 	/// Can this line be edited.
 	#[must_use]
-	pub(crate) const fn is_editable(&self) -> bool {
+	pub const fn is_editable(&self) -> bool {
 		match self.action {
 			Action::Exec | Action::Label | Action::Reset | Action::Merge | Action::UpdateRef => true,
 			Action::Break
@@ -197,6 +203,7 @@ impl Line {
 			| Action::Fixup
 			| Action::Noop
 			| Action::Pick
+			| Action::Revert
 			| Action::Reword
 			| Action::Squash => false,
 		}
@@ -204,7 +211,7 @@ impl Line {
 
 	/// Can this line be duplicated.
 	#[must_use]
-	pub(crate) const fn is_duplicatable(&self) -> bool {
+	pub const fn is_duplicatable(&self) -> bool {
 		match self.action {
 			Action::Exec
 			| Action::Label
@@ -215,23 +222,30 @@ impl Line {
 			| Action::Edit
 			| Action::Fixup
 			| Action::Pick
+			| Action::Revert
 			| Action::Reword
 			| Action::Squash => true,
 			Action::Break | Action::Noop => false,
 		}
 	}
 
-	/// Has this line been modified
+	/// Has this line been modified.
 	#[must_use]
-	pub(crate) fn is_modified(&self) -> bool {
+	pub fn is_modified(&self) -> bool {
 		self.mutated
 	}
 
 	/// Create a string containing a textual version of the line, as would be seen in the rebase file.
 	#[must_use]
-	pub(crate) fn to_text(&self) -> String {
+	pub fn to_text(&self) -> String {
 		match self.action {
-			Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
+			Action::Drop
+			| Action::Edit
+			| Action::Fixup
+			| Action::Pick
+			| Action::Revert
+			| Action::Reword
+			| Action::Squash => {
 				if let Some(opt) = self.option.as_ref() {
 					format!("{} {opt} {} {}", self.action, self.hash, self.content)
 				}
@@ -293,6 +307,26 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn line_new_revert() {
+		assert_eq!(Line::new_revert("abc123"), Line {
+			action: Action::Revert,
+			hash: String::from("abc123"),
+			content: String::new(),
+			mutated: false,
+			option: None,
+			original_line: Some(Box::new(Line {
+				action: Action::Revert,
+				hash: String::from("abc123"),
+				content: String::new(),
+				mutated: false,
+				option: None,
+				original_line: None,
+			}))
+		});
+		assert_eq!(Line::new_revert("abc123").to_text(), "revert abc123 ");
+	}
+
 	#[test]
 	fn line_new_break() {
 		assert_eq!(Line::new_break(), Line {
@@ -1,12 +1,16 @@
-use crate::todo_file::ParseError;
+use crate::ParseError;
 
-pub(crate) struct LineParser<'line> {
+/// A parser for the whitespace delimited arguments of a todo file line.
+#[derive(Debug)]
+pub struct LineParser<'line> {
 	input: &'line str,
 	index: usize,
 }
 
 impl<'line> LineParser<'line> {
-	pub(crate) fn new(input: &'line str) -> Self {
+	/// Create a new parser for a line.
+	#[must_use]
+	pub fn new(input: &'line str) -> Self {
 		let mut index = 0;
 		while input.get(index..=index).unwrap_or("") == " " {
 			index += 1;
@@ -14,11 +18,12 @@ impl<'line> LineParser<'line> {
 		Self { input, index }
 	}
 
-	pub(crate) const fn has_more(&self) -> bool {
+	/// Does the line have more arguments to parse.
+	#[must_use]
+	pub const fn has_more(&self) -> bool {
 		self.index < self.input.len()
 	}
 
-	#[expect(clippy::unwrap_in_result, reason = "Guarded unwrap")]
 	fn scan(&mut self) -> Result<(&'line str, usize), ParseError> {
 		let mut new_index = self.index;
 		if !self.has_more() {
@@ -39,17 +44,27 @@ impl<'line> LineParser<'line> {
 		}
 	}
 
-	pub(crate) fn next(&mut self) -> Result<&'line str, ParseError> {
+	/// Take the next whitespace delimited argument.
+	///
+	/// # Errors
+	///
+	/// Returns an error if there are no more arguments.
+	#[expect(clippy::should_implement_trait, reason = "The parser returns errors, not options.")]
+	pub fn next(&mut self) -> Result<&'line str, ParseError> {
 		let (slice, new_index) = self.scan()?;
 		self.index = new_index;
 		Ok(slice)
 	}
 
-	pub(crate) fn take_remaining(self) -> &'line str {
+	/// Take the remainder of the line, without parsing.
+	#[must_use]
+	pub fn take_remaining(self) -> &'line str {
 		self.input.get(self.index..self.input.len()).unwrap_or("")
 	}
 
-	pub(crate) fn parse_error(&self) -> ParseError {
+	/// Create an error for the parsed line.
+	#[must_use]
+	pub fn parse_error(&self) -> ParseError {
 		ParseError::InvalidLine(String::from(self.input))
 	}
 }
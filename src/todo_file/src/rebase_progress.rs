@@ -0,0 +1,151 @@
+use std::{fs::read_to_string, path::Path};
+
+use crate::Line;
+
+fn read_number(directory: &Path, name: &str) -> Option<usize> {
+	read_to_string(directory.join(name)).ok()?.trim().parse().ok()
+}
+
+/// The state of an in progress rebase, read from the files Git writes next to the rebase todo file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RebaseProgress {
+	done_lines: Vec<Line>,
+	current: Option<usize>,
+	total: Option<usize>,
+	stopped_hash: Option<String>,
+}
+
+impl RebaseProgress {
+	/// Load the progress of the rebase from the state directory, returning `None` if no lines have been applied.
+	///
+	/// The state files are informational, so lines of the `done` file that cannot be parsed are ignored.
+	#[must_use]
+	pub fn load(directory: &Path, comment_prefix: &str) -> Option<Self> {
+		let done_lines = read_to_string(directory.join("done"))
+			.ok()?
+			.lines()
+			.filter(|l| !l.is_empty() && !l.starts_with(comment_prefix))
+			.filter_map(|l| Line::parse(l).ok())
+			.collect::<Vec<Line>>();
+		if done_lines.is_empty() {
+			return None;
+		}
+
+		Some(Self {
+			done_lines,
+			current: read_number(directory, "msgnum"),
+			total: read_number(directory, "end"),
+			stopped_hash: read_to_string(directory.join("stopped-sha"))
+				.ok()
+				.map(|hash| String::from(hash.trim()))
+				.filter(|hash| !hash.is_empty()),
+		})
+	}
+
+	/// Get the lines that have already been applied.
+	#[must_use]
+	pub fn done_lines(&self) -> &[Line] {
+		self.done_lines.as_slice()
+	}
+
+	/// Get the number of the line being applied, and the total number of lines, if known.
+	#[must_use]
+	pub fn position(&self) -> Option<(usize, usize)> {
+		self.current.zip(self.total)
+	}
+
+	/// Get the hash of the commit the rebase stopped at, if it is stopped.
+	#[must_use]
+	pub fn stopped_hash(&self) -> Option<&str> {
+		self.stopped_hash.as_deref()
+	}
+
+	/// Is the line the one the rebase stopped at. Either hash may be abbreviated.
+	#[must_use]
+	pub fn is_stopped_line(&self, line: &Line) -> bool {
+		let hash = line.get_hash();
+		!hash.is_empty()
+			&& self
+				.stopped_hash
+				.as_deref()
+				.is_some_and(|stopped| stopped.starts_with(hash) || hash.starts_with(stopped))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::{assert_none, assert_some, assert_some_eq};
+	use tempfile::{TempDir, tempdir};
+
+	use super::*;
+
+	fn create_state(files: &[(&str, &str)]) -> TempDir {
+		let directory = tempdir().unwrap();
+		for &(name, content) in files {
+			std::fs::write(directory.path().join(name), content).unwrap();
+		}
+		directory
+	}
+
+	#[test]
+	fn load() {
+		let directory = create_state(&[
+			("done", "pick aaa c1\n# comment\n\nexec make\nedit bbbbbbbb c2\n"),
+			("msgnum", "3\n"),
+			("end", "5\n"),
+			("stopped-sha", "bbbbbbbbcccccccc\n"),
+		]);
+		let progress = RebaseProgress::load(directory.path(), "#").unwrap();
+		assert_eq!(progress.done_lines(), &[
+			Line::parse("pick aaa c1").unwrap(),
+			Line::parse("exec make").unwrap(),
+			Line::parse("edit bbbbbbbb c2").unwrap(),
+		]);
+		assert_some_eq!(progress.position(), (3, 5));
+		assert_some_eq!(progress.stopped_hash(), "bbbbbbbbcccccccc");
+	}
+
+	#[test]
+	fn load_without_done_file() {
+		let directory = create_state(&[("msgnum", "1"), ("end", "2")]);
+		assert_none!(RebaseProgress::load(directory.path(), "#"));
+	}
+
+	#[test]
+	fn load_empty_done_file() {
+		let directory = create_state(&[("done", "# comment\n")]);
+		assert_none!(RebaseProgress::load(directory.path(), "#"));
+	}
+
+	#[test]
+	fn load_ignores_invalid_lines() {
+		let directory = create_state(&[("done", "invalid aaa\npick aaa c1\n")]);
+		let progress = RebaseProgress::load(directory.path(), "#").unwrap();
+		assert_eq!(progress.done_lines(), &[Line::parse("pick aaa c1").unwrap()]);
+	}
+
+	#[test]
+	fn load_without_optional_files() {
+		let directory = create_state(&[("done", "pick aaa c1\n"), ("msgnum", "invalid")]);
+		let progress = RebaseProgress::load(directory.path(), "#").unwrap();
+		assert_none!(progress.position());
+		assert_none!(progress.stopped_hash());
+	}
+
+	#[test]
+	fn is_stopped_line() {
+		let directory = create_state(&[("done", "pick aaa c1\n"), ("stopped-sha", "bbbbbbbbcccccccc")]);
+		let progress = RebaseProgress::load(directory.path(), "#").unwrap();
+		assert!(progress.is_stopped_line(&Line::parse("pick bbbbbbbb c1").unwrap()));
+		assert!(progress.is_stopped_line(&Line::parse("pick bbbbbbbbccccccccdddd c1").unwrap()));
+		assert!(!progress.is_stopped_line(&Line::parse("pick aaa c1").unwrap()));
+		assert!(!progress.is_stopped_line(&Line::parse("exec make").unwrap()));
+	}
+
+	#[test]
+	fn is_stopped_line_not_stopped() {
+		let directory = create_state(&[("done", "pick aaa c1\n")]);
+		let progress = assert_some!(RebaseProgress::load(directory.path(), "#"));
+		assert!(!progress.is_stopped_line(&Line::parse("pick aaa c1").unwrap()));
+	}
+}
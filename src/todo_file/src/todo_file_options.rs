@@ -1,15 +1,16 @@
-/// Options for `TodoFile`
+/// Options for `TodoFile`.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct TodoFileOptions {
-	pub(crate) comment_prefix: String,
-	pub(crate) line_changed_command: Option<String>,
-	pub(crate) undo_limit: u32,
+#[non_exhaustive]
+pub struct TodoFileOptions {
+	pub comment_prefix: String,
+	pub line_changed_command: Option<String>,
+	pub undo_limit: u32,
 }
 
 impl TodoFileOptions {
-	/// Create a new instance of `TodoFileOptions`
+	/// Create a new instance of `TodoFileOptions`.
 	#[must_use]
-	pub(crate) fn new(undo_limit: u32, comment_prefix: &str) -> Self {
+	pub fn new(undo_limit: u32, comment_prefix: &str) -> Self {
 		Self {
 			comment_prefix: String::from(comment_prefix),
 			line_changed_command: None,
@@ -17,8 +18,8 @@ impl TodoFileOptions {
 		}
 	}
 
-	/// Set a command to be added after each changed line
-	pub(crate) fn line_changed_command(&mut self, command: &str) {
+	/// Set a command to be added after each changed line.
+	pub fn line_changed_command(&mut self, command: &str) {
 		self.line_changed_command = Some(String::from(command));
 	}
 }
@@ -1,4 +1,4 @@
-use crate::todo_file::Line;
+use crate::Line;
 
 pub(crate) fn swap_range_up(lines: &mut [Line], start_index: usize, end_index: usize) {
 	let range = if end_index <= start_index {
@@ -53,3 +53,11 @@ pub(crate) fn add_range(lines: &mut Vec<Line>, new_lines: &[Line], start_index:
 		lines.insert(index, new_lines[add_index].clone());
 	}
 }
+
+pub(crate) fn get_merge_commit_hash(content: &str) -> Option<&str> {
+	let mut parts = content.split_whitespace();
+	match parts.next() {
+		Some("-C" | "-c") => parts.next(),
+		_ => None,
+	}
+}
@@ -38,11 +38,15 @@ pub(crate) use self::{
 	view_line::ViewLine,
 	view_lines::ViewLines,
 };
-use crate::display::{Display, DisplayColor, Tui};
+use crate::{
+	config::Theme,
+	display::{Display, DisplayColor, Tui},
+};
 
 const TITLE: &str = "Git Interactive Rebase Tool";
 const TITLE_SHORT: &str = "Git Rebase";
 const TITLE_HELP_INDICATOR_LABEL: &str = "Help: ";
+const TITLE_PENDING_KEYS_LABEL: &str = "Keys: ";
 const SCROLLBAR_INDICATOR_CHARACTER: &str = "\u{2588}"; // "█"
 
 /// Represents a view.
@@ -65,6 +69,15 @@ impl<C: Tui> View<C> {
 		}
 	}
 
+	/// Replace the theme and help indicator key, forcing a full render on the next render.
+	pub(crate) fn reconfigure(&mut self, theme: &Theme, help_indicator_key: &str) {
+		self.character_vertical_spacing
+			.clone_from(&theme.character_vertical_spacing);
+		self.help_indicator_key = String::from(help_indicator_key);
+		self.display.set_theme(theme);
+		self.last_render_version = u32::MAX;
+	}
+
 	/// End processing of the view.
 	///
 	/// # Errors
@@ -99,7 +112,7 @@ impl<C: Tui> View<C> {
 		self.display.ensure_at_line_start()?;
 		if render_slice.show_title() {
 			self.display.ensure_at_line_start()?;
-			self.draw_title(render_slice.show_help())?;
+			self.draw_title(render_slice.show_help(), render_slice.pending_keys())?;
 			self.display.next_line()?;
 		}
 
@@ -175,29 +188,30 @@ impl<C: Tui> View<C> {
 		Ok(())
 	}
 
-	fn draw_title(&mut self, show_help: bool) -> Result<()> {
+	fn draw_title(&mut self, show_help: bool, pending_keys: &str) -> Result<()> {
 		self.display.color(DisplayColor::Normal, false)?;
 		self.display.set_style(false, true, false)?;
 		let window_width = self.display.get_window_size().width();
 
-		let title_help_indicator_total_length = TITLE_HELP_INDICATOR_LABEL.len() + self.help_indicator_key.len();
+		// the keys of a started key sequence take the place of the help indicator
+		let indicator = if !pending_keys.is_empty() {
+			format!("{TITLE_PENDING_KEYS_LABEL}{pending_keys}")
+		}
+		else if show_help {
+			format!("{TITLE_HELP_INDICATOR_LABEL}{}", self.help_indicator_key)
+		}
+		else {
+			String::new()
+		};
+		let indicator_length = indicator.chars().count();
 
 		if window_width >= TITLE.len() {
 			self.display.draw_str(TITLE)?;
-			// only draw help if there is room
-			if window_width > TITLE.len() + title_help_indicator_total_length {
-				if (window_width - TITLE.len() - title_help_indicator_total_length) > 0 {
-					let padding = " ".repeat(window_width - TITLE.len() - title_help_indicator_total_length);
-					self.display.draw_str(padding.as_str())?;
-				}
-				if show_help {
-					self.display
-						.draw_str(format!("{TITLE_HELP_INDICATOR_LABEL}{}", self.help_indicator_key).as_str())?;
-				}
-				else {
-					let padding = " ".repeat(title_help_indicator_total_length);
-					self.display.draw_str(padding.as_str())?;
-				}
+			// only draw the indicator if there is room
+			if indicator_length > 0 && window_width > TITLE.len() + indicator_length {
+				let padding = " ".repeat(window_width - TITLE.len() - indicator_length);
+				self.display.draw_str(padding.as_str())?;
+				self.display.draw_str(indicator.as_str())?;
 			}
 			else if (window_width - TITLE.len()) > 0 {
 				let padding = " ".repeat(window_width - TITLE.len());
@@ -20,6 +20,7 @@ pub(crate) struct RenderSlice {
 	lines_leading_count: usize,
 	lines_trailing_count: usize,
 	padding_height: usize,
+	pending_keys: String,
 	scroll_position: ScrollPosition,
 	scroll_position_cache: HashMap<String, ScrollPosition>,
 	should_show_scrollbar: bool,
@@ -41,6 +42,7 @@ impl RenderSlice {
 			lines_leading_count: 0,
 			lines_trailing_count: 0,
 			padding_height: 0,
+			pending_keys: String::new(),
 			scroll_position: ScrollPosition::new(),
 			scroll_position_cache: HashMap::new(),
 			should_show_scrollbar: false,
@@ -174,6 +176,24 @@ impl RenderSlice {
 		self.show_help
 	}
 
+	pub(crate) fn pending_keys(&self) -> &str {
+		self.pending_keys.as_str()
+	}
+
+	pub(crate) fn set_pending_keys(&mut self, pending_keys: &str) {
+		self.pending_keys = String::from(pending_keys);
+		self.version += 1;
+	}
+
+	/// Get the index of the body line, of the last synced view data, that is rendered at a row of the view.
+	pub(crate) fn get_line_index_at_row(&self, row: usize) -> Option<usize> {
+		let first_body_row = if self.show_title { 1 } else { 0 } + self.lines_leading_count;
+		let body_rows_count =
+			(self.lines.count() as usize).saturating_sub(self.lines_leading_count + self.lines_trailing_count);
+		let body_row = row.checked_sub(first_body_row)?;
+		(body_row < body_rows_count).then(|| self.scroll_position.get_top_position() + body_row)
+	}
+
 	pub(crate) const fn get_leading_lines_count(&self) -> usize {
 		self.lines_leading_count
 	}
@@ -1274,3 +1274,29 @@ fn calculate_max_line_length_ignore_pinned() {
 	]);
 	assert_eq!(RenderSlice::calculate_max_line_length(&view_lines, 0, 4), 16);
 }
+
+#[test]
+fn get_line_index_at_row() {
+	let view_data = create_view_data(2, 10, 1);
+	let mut render_slice = create_render_slice(10, 6, &view_data);
+	render_slice.record_scroll_down();
+	render_slice.record_scroll_down();
+	render_slice.record_scroll_down();
+	render_slice.sync_view_data(&view_data);
+	assert_eq!(render_slice.get_line_index_at_row(1), None);
+	assert_eq!(render_slice.get_line_index_at_row(2), Some(3));
+	assert_eq!(render_slice.get_line_index_at_row(4), Some(5));
+	assert_eq!(render_slice.get_line_index_at_row(5), None);
+}
+
+#[test]
+fn get_line_index_at_row_with_title() {
+	let view_data = ViewData::new(|updater| {
+		updater.set_show_title(true);
+		updater.push_line(ViewLine::from("B(1)"));
+	});
+	let render_slice = create_render_slice(10, 6, &view_data);
+	assert_eq!(render_slice.get_line_index_at_row(0), None);
+	assert_eq!(render_slice.get_line_index_at_row(1), Some(0));
+	assert_eq!(render_slice.get_line_index_at_row(2), None);
+}
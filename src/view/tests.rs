@@ -1,5 +1,5 @@
 use super::*;
-use crate::{config::Theme, display::Size, test_helpers::mocks};
+use crate::{display::Size, test_helpers::mocks};
 
 fn assert_render_slice(width: usize, height: usize, render_slice: &RenderSlice, expected: &[&str]) {
 	let theme = Theme::new_with_config(None).unwrap();
@@ -79,6 +79,20 @@ fn render_title_full_width_with_help_enabled_but_not_enough_length() {
 	);
 }
 
+#[test]
+fn render_title_full_width_with_pending_keys() {
+	let mut expected = vec!["Git Interactive Rebase Tool Keys: g"];
+	expected.extend(vec!["~"; 9]);
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(35, 10);
+	render_slice.sync_view_data(&ViewData::new(|updater| {
+		updater.set_show_title(true);
+		updater.set_show_help(true);
+	}));
+	render_slice.set_pending_keys("g");
+	assert_render_slice(35, 10, &render_slice, &expected);
+}
+
 #[test]
 fn render_leading_lines() {
 	let mut expected = vec!["This is a leading line"];
@@ -302,3 +316,28 @@ fn render_after_trailing_lines_change() {
 	render_slice.sync_view_data(&view_data);
 	assert_render_slice(width, height, &render_slice, &["This is line 3█", "This is line 4"]);
 }
+
+#[test]
+fn render_after_reconfigure() {
+	let mut theme = Theme::new_with_config(None).unwrap();
+	let mut crossterm = mocks::CrossTerm::new();
+	let readonly_tui = crossterm.clone();
+	crossterm.set_size(Size::new(40, 2));
+	let display = Display::new(crossterm, &theme);
+	let mut view = View::new(display, "~", "?");
+	let mut render_slice = RenderSlice::new();
+	render_slice.record_resize(40, 2);
+	render_slice.sync_view_data(&ViewData::new(|updater| {
+		updater.set_show_title(true);
+		updater.set_show_help(true);
+	}));
+	view.render(&render_slice).unwrap();
+
+	theme.character_vertical_spacing = String::from("-");
+	view.reconfigure(&theme, "F1");
+	view.render(&render_slice).unwrap();
+	assert_eq!(
+		readonly_tui.get_output().join(""),
+		"Git Interactive Rebase Tool     Help: F1\n-\n"
+	);
+}
@@ -102,6 +102,10 @@ impl<ViewTui: Tui + Send + 'static> Thread<ViewTui> {
 							}
 						},
 						ViewAction::Refresh => {},
+						ViewAction::Reconfigure(theme, help_indicator_key) => {
+							view.lock().reconfigure(&theme, help_indicator_key.as_str());
+							should_render = true;
+						},
 						ViewAction::End => break,
 					}
 
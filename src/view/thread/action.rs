@@ -1,5 +1,7 @@
+use crate::config::Theme;
+
 /// An action to send to the thread handling updates to the view.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub(crate) enum ViewAction {
 	/// Stop processing actions.
 	Stop,
@@ -11,4 +13,6 @@ pub(crate) enum ViewAction {
 	Start,
 	/// End the thread and the processing of actions.
 	End,
+	/// Replace the theme and help indicator key of the view.
+	Reconfigure(Box<Theme>, String),
 }
@@ -9,7 +9,10 @@ use std::{
 use crossbeam_channel::unbounded;
 use parking_lot::Mutex;
 
-use crate::view::{RenderSlice, ViewAction, ViewData};
+use crate::{
+	config::Theme,
+	view::{RenderSlice, ViewAction, ViewData},
+};
 
 /// Represents a message sender and receiver for passing actions between threads.
 #[derive(Clone, Debug)]
@@ -93,6 +96,14 @@ impl State {
 		self.send_update(ViewAction::Refresh);
 	}
 
+	/// Queue a reconfigure action, replacing the theme and help indicator key of the view.
+	pub(crate) fn reconfigure(&self, theme: &Theme, help_indicator_key: &str) {
+		self.send_update(ViewAction::Reconfigure(
+			Box::new(theme.clone()),
+			String::from(help_indicator_key),
+		));
+	}
+
 	/// Queue a scroll up action.
 	pub(crate) fn scroll_top(&self) {
 		self.render_slice.lock().borrow_mut().record_scroll_top();
@@ -141,6 +152,21 @@ impl State {
 			.record_resize(width as usize, height as usize);
 	}
 
+	/// Get the index of the body line that was last rendered at a row of the view, if the row contains a body line.
+	pub(crate) fn line_index_at_row(&self, row: u16) -> Option<usize> {
+		self.render_slice.lock().get_line_index_at_row(row as usize)
+	}
+
+	/// Set the keys of a started key sequence, queueing a render action if they changed.
+	pub(crate) fn set_pending_keys(&self, pending_keys: &str) {
+		let mut render_slice = self.render_slice.lock();
+		if render_slice.pending_keys() != pending_keys {
+			render_slice.set_pending_keys(pending_keys);
+			drop(render_slice);
+			self.send_update(ViewAction::Render);
+		}
+	}
+
 	/// Sync the `ViewData` and queue a render action.
 	///
 	/// # Errors
@@ -153,6 +179,8 @@ impl State {
 
 #[cfg(test)]
 mod tests {
+	use claims::{assert_none, assert_some_eq};
+
 	use crate::{
 		test_helpers::with_view_state,
 		view::{ViewData, ViewLine},
@@ -289,4 +317,37 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn line_index_at_row() {
+		with_view_state(|context| {
+			context.state.resize(300, 100);
+			context.state.render(&ViewData::new(|updater| {
+				updater.set_show_title(true);
+				updater.push_line(ViewLine::from("Foo"));
+				updater.push_line(ViewLine::from("Bar"));
+			}));
+			assert_none!(context.state.line_index_at_row(0));
+			assert_some_eq!(context.state.line_index_at_row(1), 0);
+			assert_some_eq!(context.state.line_index_at_row(2), 1);
+			assert_none!(context.state.line_index_at_row(3));
+		});
+	}
+
+	#[test]
+	fn set_pending_keys() {
+		with_view_state(|context| {
+			context.state.set_pending_keys("g");
+			context.assert_sent_messages(vec!["Render"]);
+			assert_eq!(context.state.render_slice().lock().pending_keys(), "g");
+		});
+	}
+
+	#[test]
+	fn set_pending_keys_unchanged() {
+		with_view_state(|context| {
+			context.state.set_pending_keys("");
+			context.assert_sent_messages(vec![]);
+		});
+	}
 }
@@ -64,6 +64,11 @@ impl<'view_data> ViewDataUpdater<'view_data> {
 		self.view_data.push_line(view_line);
 	}
 
+	/// Get the number of body lines in the view data.
+	pub(crate) fn lines_count(&self) -> usize {
+		self.view_data.lines().count() as usize
+	}
+
 	/// Push a set of new body lines to the view data automatically split on newlines.
 	pub(crate) fn push_lines(&mut self, lines: &str) {
 		self.modified = true;